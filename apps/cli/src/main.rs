@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use tracing::info;
 use std::fs;
 use pinpath_core::{FileWatcher, FileEvent, FileEventType, EnhancedWatcher};
-use pinpath_parser::{detect_language, languages::{javascript::JavaScriptParser, python::PythonParser, php::PhpParser}, LanguageParser, IncrementalParser, EndpointChanges};
+use pinpath_parser::{detect_language, parser_for_language, project, IncrementalParser, EndpointChanges};
 use pinpath_parser::config::ConfigDiscovery;
 use pinpath_diff::{ChangeSource, DiffProcessor};
 
@@ -201,6 +201,11 @@ async fn main() -> Result<()> {
             } else {
                 IncrementalParser::new()
             };
+
+            // Changed files are linked against the rest of the project
+            for (path, err) in incremental_parser.load_project(&read_source_files(&path)?) {
+                eprintln!("⚠️  Skipping {}: {:#}", path.display(), err);
+            }
             
             println!("👁️  Incremental watcher started. Press Ctrl+C to stop.");
             
@@ -327,34 +332,42 @@ fn discover_endpoints(path: &PathBuf) -> Result<Vec<pinpath_parser::Endpoint>> {
         let endpoints = parse_file_content(&content, path)?;
         all_endpoints.extend(endpoints);
     } else if path.is_dir() {
-        for entry in walkdir::WalkDir::new(path) {
-            let entry = entry?;
-            let path = entry.path();
-            
-            if path.is_file() && should_parse_file(&path.to_path_buf()) {
-                let content = fs::read_to_string(path)?;
-                let endpoints = parse_file_content(&content, &path.to_path_buf())?;
-                all_endpoints.extend(endpoints);
-            }
+        // Parse the whole tree together so router mounts resolve across files
+        let files = read_source_files(path)?;
+        
+        // A file that fails to parse is left out rather than failing the run
+        let parsed = project::parse_project(&files);
+        for (path, err) in &parsed.failures {
+            eprintln!("⚠️  Skipping {}: {:#}", path.display(), err);
         }
+        all_endpoints.extend(parsed.endpoints.into_iter().map(|e| e.endpoint));
     }
     
     Ok(all_endpoints)
 }
 
+/// Read every parseable source file under `path`.
+fn read_source_files(path: &PathBuf) -> Result<Vec<(PathBuf, String)>> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        let path = entry.path();
+        
+        if path.is_file() && should_parse_file(&path.to_path_buf()) {
+            files.push((path.to_path_buf(), fs::read_to_string(path)?));
+        }
+    }
+    Ok(files)
+}
+
 fn parse_file_content(content: &str, path: &PathBuf) -> Result<Vec<pinpath_parser::Endpoint>> {
-    let extension = path.extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
-    
-    let parser: Box<dyn LanguageParser> = match extension {
-        "js" | "mjs" | "ts" | "tsx" => Box::new(JavaScriptParser),
-        "py" => Box::new(PythonParser),
-        "php" => Box::new(PhpParser::new()?),
-        _ => return Ok(vec![]),
+    let parser = match detect_language(path).and_then(parser_for_language) {
+        Some(parser) => parser,
+        None => return Ok(vec![]),
     };
     
-    parser.parse(content)
+    // Through parse_module so routes implied by the file path are included
+    Ok(parser.parse_module(path, content)?.into_endpoints())
 }
 
 /// Print endpoint changes in a formatted way
@@ -2,8 +2,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use tauri::{AppHandle, Emitter, TitleBarStyle, WebviewUrl, WebviewWindowBuilder, Manager};
-use pinpath_parser::{Endpoint, LanguageParser, detect_language};
-use pinpath_parser::languages::{javascript::JavaScriptParser, python::PythonParser, php::PhpParser};
+use pinpath_parser::{Endpoint, ProjectEndpoint, detect_language, project};
 
 mod storage;
 mod watcher;
@@ -171,22 +170,6 @@ fn is_supported_source_file(path: &Path) -> bool {
     detect_language(path).is_some()
 }
 
-fn parse_file_content(content: &str, path: &Path) -> anyhow::Result<Vec<Endpoint>> {
-    let extension = path
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
-
-    let parser: Box<dyn LanguageParser> = match extension {
-        "js" | "mjs" | "ts" | "tsx" => Box::new(JavaScriptParser),
-        "py" => Box::new(PythonParser),
-        "php" => Box::new(PhpParser::new()?),
-        _ => return Ok(vec![]),
-    };
-
-    parser.parse(content)
-}
-
 #[tauri::command]
 async fn discover_endpoints(path: String) -> Result<Vec<Endpoint>, String> {
     let project_path = PathBuf::from(&path);
@@ -201,8 +184,9 @@ async fn discover_endpoints(path: String) -> Result<Vec<Endpoint>, String> {
 
     let mut endpoints = Vec::new();
     let mut endpoint_records: Vec<EndpointRecord> = Vec::new();
+    let mut files = Vec::new();
 
-    // Walk through the project directory recursively and parse files
+    // Walk through the project directory recursively and collect source files
     for entry in walkdir::WalkDir::new(&project_path)
         .into_iter()
         .filter_entry(|e| should_visit(e.path()))
@@ -211,28 +195,29 @@ async fn discover_endpoints(path: String) -> Result<Vec<Endpoint>, String> {
         let file_path = entry.path().to_path_buf();
         if entry.file_type().is_file() && is_supported_source_file(&file_path) {
             if let Ok(content) = std::fs::read_to_string(&file_path) {
-                match parse_file_content(&content, &file_path) {
-                    Ok(file_endpoints) => {
-                        // Use relative file path as handler for UI uniqueness
-                        let rel_file = pathdiff::diff_paths(&file_path, &project_path)
-                            .unwrap_or(file_path.clone())
-                            .to_string_lossy()
-                            .to_string();
-
-                        for mut ep in file_endpoints {
-                            ep.handler = rel_file.clone();
-                            endpoints.push(ep.clone());
-                            endpoint_records.push(EndpointRecord::from_endpoint(ep, &file_path));
-                        }
-                    }
-                    Err(err) => {
-                        eprintln!("Failed to parse {}: {}", file_path.display(), err);
-                    }
-                }
+                files.push((file_path, content));
             }
         }
     }
 
+    // Parse everything together so router mounts resolve across files
+    let parsed = project::parse_project(&files);
+    for (file_path, err) in &parsed.failures {
+        eprintln!("Failed to parse {}: {}", file_path.display(), err);
+    }
+
+    for ProjectEndpoint { file, endpoint: mut ep } in parsed.endpoints {
+        // Use relative file path as handler for UI uniqueness
+        let rel_file = pathdiff::diff_paths(&file, &project_path)
+            .unwrap_or(file.clone())
+            .to_string_lossy()
+            .to_string();
+
+        ep.handler = rel_file;
+        endpoints.push(ep.clone());
+        endpoint_records.push(EndpointRecord::from_endpoint(ep, &file));
+    }
+
     // Persist to filesystem manifest for transparency
     if let Err(e) = storage.save_endpoints(&endpoint_records) {
         eprintln!("Failed to save endpoints manifest: {}", e);
@@ -20,6 +20,8 @@ pub struct EndpointEntry {
     pub id: String,
     pub method: String,
     pub path: String,
+    #[serde(default)]
+    pub template: String,
     pub file: String,
     pub line: usize,
     pub framework: String,
@@ -82,10 +84,12 @@ impl PinPathStorage {
                 .to_string_lossy()
                 .to_string();
 
+            let template = rec.endpoint.route_template();
             let entry = EndpointEntry {
-                id: format!("{}:{}", method.to_uppercase(), rec.endpoint.path),
+                id: format!("{}:{}", method.to_uppercase(), template),
                 method,
                 path: rec.endpoint.path.clone(),
+                template,
                 file: rel_file.clone(),
                 line: rec.endpoint.line,
                 framework: "auto".to_string(),
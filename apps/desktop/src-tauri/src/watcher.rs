@@ -6,8 +6,7 @@ use std::sync::mpsc;
 use tauri::{AppHandle, Emitter};
 use tokio::task;
 
-use pinpath_parser::{IncrementalParser, incremental::EndpointState, Endpoint, detect_language, LanguageParser};
-use pinpath_parser::languages::{javascript::JavaScriptParser, python::PythonParser, php::PhpParser};
+use pinpath_parser::{IncrementalParser, incremental::EndpointState, Endpoint, detect_language};
 
 use crate::storage::{PinPathStorage, EndpointRecord};
 
@@ -57,7 +56,14 @@ impl WatcherRegistry {
             file_hashes: HashMap::new(),
             last_updated: std::time::SystemTime::now(),
         });
-        let parser = Arc::new(Mutex::new(IncrementalParser::with_state(previous_state)));
+        let mut parser = IncrementalParser::with_state(previous_state);
+
+        // Parse the whole project once so changed files are linked against
+        // the routers that mount them
+        for (file_path, err) in parser.load_project(&read_project_files(&project_path)) {
+            eprintln!("Failed to parse {}: {}", file_path.display(), err);
+        }
+        let parser = Arc::new(Mutex::new(parser));
         
         // Store the watcher
         {
@@ -108,7 +114,7 @@ impl WatcherRegistry {
 
 async fn handle_file_event(
     event: Event,
-    _parser: &Arc<Mutex<IncrementalParser>>,
+    parser: &Arc<Mutex<IncrementalParser>>,
     storage: &PinPathStorage,
     app_handle: &AppHandle,
     project_path: &Path,
@@ -124,8 +130,9 @@ async fn handle_file_event(
                         Err(_) => continue, // File might be deleted or inaccessible
                     };
                     
-                    // Parse the file with appropriate parser
-                    let new_endpoints = match parse_file_content(&content, &path) {
+                    // Re-parse the file and link it with the rest of the project
+                    let updated = parser.lock().unwrap().update_file(&path, &content);
+                    let new_endpoints = match updated {
                         Ok(endpoints) => endpoints,
                         Err(e) => {
                             eprintln!("Failed to parse {}: {}", path.display(), e);
@@ -198,18 +205,14 @@ fn is_supported_source_file(path: &Path) -> bool {
     detect_language(path).is_some()
 }
 
-fn parse_file_content(content: &str, path: &Path) -> anyhow::Result<Vec<Endpoint>> {
-    let extension = path
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
-
-    let parser: Box<dyn LanguageParser> = match extension {
-        "js" | "mjs" | "ts" | "tsx" => Box::new(JavaScriptParser),
-        "py" => Box::new(PythonParser),
-        "php" => Box::new(PhpParser::new()?),
-        _ => return Ok(vec![]),
-    };
-
-    parser.parse(content)
+fn read_project_files(project_path: &Path) -> Vec<(PathBuf, String)> {
+    walkdir::WalkDir::new(project_path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && is_supported_source_file(e.path()))
+        .filter_map(|e| {
+            let content = std::fs::read_to_string(e.path()).ok()?;
+            Some((e.path().to_path_buf(), content))
+        })
+        .collect()
 }
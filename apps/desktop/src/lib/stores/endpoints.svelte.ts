@@ -1,6 +1,6 @@
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
-import type { Endpoint, HttpResponse, RequestConfig, ProjectState, FileChangeEvent, EndpointManifest, Environment } from '../types';
+import type { Endpoint, HttpResponse, RequestConfig, ProjectState, FileChangeEvent, EndpointManifest, Environment, Schema } from '../types';
 
 function isTauri() {
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -29,9 +29,33 @@ function sanitizeEndpoint(e: Partial<Endpoint> & { method?: string; path?: strin
     line: typeof e.line === 'number' ? e.line : 0,
     column: typeof e.column === 'number' ? e.column : 0,
     documentation: (e as any).documentation,
+    parameters: Array.isArray(e.parameters) ? e.parameters : [],
+    body: e.body ?? null,
+    middleware: Array.isArray(e.middleware) ? e.middleware : [],
+    responses: Array.isArray(e.responses) ? e.responses : [],
+    requires_auth: e.requires_auth === true,
   };
 }
 
+// Placeholder value for a request body field, following the schema's shape.
+function exampleValue(schema: Schema): unknown {
+  switch (schema.type) {
+    case 'object':
+      return Object.fromEntries((schema.properties ?? []).map(p => [p.name, exampleValue(p.schema)]));
+    case 'array':
+      return [];
+    case 'integer':
+    case 'number':
+      return 0;
+    case 'boolean':
+      return false;
+    case 'string':
+      return '';
+    default:
+      return null;
+  }
+}
+
 class EndpointStore {
   // Svelte 5 runes for reactive state
   projectState = $state<ProjectState>({
@@ -177,9 +201,24 @@ class EndpointStore {
   selectEndpoint(endpoint: Endpoint) {
     this.selectedEndpoint = endpoint;
     this.requestConfig.endpoint = endpoint;
-    // Reset form state
+    // Reset form state, pre-filling the query parameters and headers the
+    // handler reads
     this.requestConfig.params = {};
-    this.requestConfig.body = '';
+    for (const param of endpoint.parameters ?? []) {
+      if (param.location === 'Query') {
+        this.requestConfig.params[param.name] = param.default ?? '';
+      } else if (param.location === 'Header') {
+        this.requestConfig.headers[param.name] ??= param.default ?? '';
+      }
+    }
+    // Routes behind auth middleware need credentials
+    if (endpoint.requires_auth) {
+      this.requestConfig.headers['Authorization'] ??= 'Bearer ';
+    }
+    // Start from the shape of the body the handler expects; unresolved
+    // references leave it empty
+    const body = endpoint.body;
+    this.requestConfig.body = body && body.type ? JSON.stringify(exampleValue(body), null, 2) : '';
     this.lastResponse = null;
   }
 
@@ -1,12 +1,55 @@
 export type HttpMethod = 'Get' | 'Post' | 'Put' | 'Delete' | 'Patch' | 'Options' | 'Head';
 
+export type ParameterLocation = 'Path' | 'Query' | 'Header' | 'Body';
+
+export interface Parameter {
+  name: string;
+  location: ParameterLocation;
+  param_type?: string | null;
+  required: boolean;
+  default?: string | null;
+}
+
+export interface Schema {
+  type: string;
+  name?: string | null;
+  format?: string | null;
+  properties?: { name: string; schema: Schema; required: boolean }[];
+  items?: Schema | null;
+  $ref?: string | null;
+}
+
+export interface EndpointResponse {
+  status: number;
+  body?: Schema | null;
+}
+
+export interface ParamDoc {
+  name: string;
+  param_type?: string | null;
+  description: string;
+}
+
+export interface Documentation {
+  summary?: string | null;
+  description?: string | null;
+  params?: ParamDoc[];
+  returns?: string | null;
+  deprecated?: string | null;
+}
+
 export interface Endpoint {
   method: HttpMethod;
   path: string;
   handler: string;
   line: number;
   column: number;
-  documentation?: string;
+  documentation?: Documentation | null;
+  parameters?: Parameter[];
+  body?: Schema | null;
+  responses?: EndpointResponse[];
+  middleware?: string[];
+  requires_auth?: boolean;
 }
 
 export interface HttpResponse {
@@ -0,0 +1,120 @@
+/// Words in a middleware, guard or dependency name that mean it checks
+/// who the caller is: `auth`, `auth:sanctum`, `passport.authenticate`,
+/// `JwtAuthGuard`, `login_required`, `ensureLoggedIn`, `verify_token`,
+/// `RolesGuard`, `can:update`. Two-word markers match adjacent words.
+const AUTH_MARKERS: [&str; 17] = [
+    "auth", "oauth", "authenticate", "authenticated", "authentication", "authorize", "authorized", "authorization",
+    "login", "loggedin", "jwt", "token", "bearer", "apikey", "permission", "permissions", "roles",
+];
+
+/// Words that only mean an access check next to one of [`CHECK_WORDS`]:
+/// `isAdmin` and `CheckRole`, but not `adminDashboardStats`.
+const QUALIFIED_MARKERS: [&str; 2] = ["admin", "role"];
+
+const CHECK_WORDS: [&str; 9] = ["is", "require", "requires", "required", "ensure", "only", "guard", "check", "has"];
+
+/// Words that mean the opposite: Laravel's `guest`, `optionalAuth`,
+/// `allow_anonymous`, and CSRF token checks.
+const PUBLIC_MARKERS: [&str; 5] = ["guest", "optional", "anonymous", "public", "csrf"];
+
+/// Whether a route running `middleware` needs the caller's credentials.
+pub fn requires_auth(middleware: &[String]) -> bool {
+    middleware.iter().any(|name| is_auth_middleware(name))
+}
+
+/// Whether one middleware, guard or dependency authenticates or authorizes
+/// the caller, judged by the words of its name.
+pub fn is_auth_middleware(name: &str) -> bool {
+    // `passport.authenticate('jwt')` is named by its callee, `auth:sanctum`
+    // and `can:update,post` by the part before the parameters
+    let callee = name.split('(').next().unwrap_or(name).trim();
+    if callee.starts_with("can:") {
+        return true;
+    }
+    let words = words(callee);
+    let has = |marker: &str| {
+        words.iter().any(|word| word == marker)
+            || words.windows(2).any(|pair| pair.concat() == marker)
+    };
+    if words.is_empty() || PUBLIC_MARKERS.iter().any(|marker| has(marker)) {
+        return false;
+    }
+    // FastAPI's `get_current_user` / `get_current_active_user`
+    let current_user = has("current") && has("user");
+    let qualified = QUALIFIED_MARKERS.iter().any(|marker| has(marker)) && CHECK_WORDS.iter().any(|word| has(word));
+    current_user || qualified || AUTH_MARKERS.iter().any(|marker| has(marker))
+}
+
+/// Lowercased words of an identifier, split on camelCase humps and on
+/// anything that isn't a letter or digit: `JWTAuthGuard` is `jwt auth guard`.
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        let prev = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1);
+        let hump = c.is_uppercase() && match prev {
+            Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+            Some(prev) if prev.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+            _ => false,
+        };
+        if hump && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_middleware_names() {
+        for name in [
+            "auth", "auth:sanctum", "can:update,post", "passport.authenticate('jwt', { session: false })",
+            "requireAuth", "JwtAuthGuard", "AuthGuard('jwt')", "login_required", "ensureLoggedIn",
+            "verify_token", "get_current_active_user", "RolesGuard", "jwt_required", ":require_authenticated_user",
+        ] {
+            assert!(is_auth_middleware(name), "{}", name);
+        }
+        for name in ["guest", "optionalAuth", "cors()", "rate_limit", "throttle:60,1", "VerifyCsrfToken", "validate(schema)", "web"] {
+            assert!(!is_auth_middleware(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_markers_match_whole_words() {
+        for name in ["authorsRouter", "tokenizeBody", "roleplayLimiter", "adminDashboardStats"] {
+            assert!(!is_auth_middleware(name), "{}", name);
+        }
+        for name in ["isAdmin", "admin_required", "verifyJWT", "ApiKeyMiddleware", "TokenAuthentication"] {
+            assert!(is_auth_middleware(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(words("JWTAuthGuard"), vec!["jwt", "auth", "guard"]);
+        assert_eq!(words("get_current_active-user.id"), vec!["get", "current", "active", "user", "id"]);
+        assert_eq!(words("ensureLoggedIn"), vec!["ensure", "logged", "in"]);
+    }
+
+    #[test]
+    fn test_requires_auth() {
+        assert!(requires_auth(&["api".to_string(), "auth:sanctum".to_string()]));
+        assert!(!requires_auth(&["api".to_string(), "throttle:api".to_string()]));
+        assert!(!requires_auth(&[]));
+    }
+}
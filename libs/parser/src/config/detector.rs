@@ -1,4 +1,5 @@
 use super::*;
+use super::patterns::PatternLibrary;
 use anyhow::Result;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -17,13 +18,32 @@ impl FrameworkDetector {
         Self {
             detectors: vec![
                 Box::new(ExpressDetector),
+                Box::new(NestJsDetector),
                 Box::new(NextJsDetector),
                 Box::new(FastApiDetector),
                 Box::new(FlaskDetector),
+                Box::new(DjangoDetector),
                 Box::new(LaravelDetector),
+                Box::new(SymfonyDetector),
+                Box::new(SlimDetector),
                 Box::new(SpringBootDetector),
+                Box::new(KtorDetector),
                 Box::new(ActixDetector),
+                Box::new(AxumDetector),
+                Box::new(RocketDetector),
+                Box::new(RailsDetector),
+                Box::new(SinatraDetector),
+                Box::new(PhoenixDetector),
                 Box::new(GinDetector),
+                Box::new(EchoDetector),
+                Box::new(AspNetCoreDetector),
+                Box::new(SvelteKitDetector),
+                Box::new(RemixDetector),
+                Box::new(NuxtDetector),
+                Box::new(FastifyDetector),
+                Box::new(KoaDetector),
+                Box::new(HonoDetector),
+                Box::new(HapiDetector),
             ],
         }
     }
@@ -92,6 +112,12 @@ impl Detector for ExpressDetector {
         
         // Check package.json for express dependency
         if let Ok(content) = fs::read_to_string(&package_json) {
+            // routes/ files look the same under the other Node routers
+            let other_router = ["\"fastify\"", "\"koa\"", "\"hono\"", "\"@hapi/hapi\""]
+                .iter()
+                .any(|dependency| content.contains(dependency));
+            if other_router && !content.contains("\"express\"") { return None; }
+
             if content.contains("\"express\"") {
                 signals.push(DetectionSignal {
                     signal_type: "package.json".to_string(),
@@ -200,6 +226,72 @@ impl Detector for ExpressDetector {
     }
 }
 
+// NestJS Detector
+struct NestJsDetector;
+impl Detector for NestJsDetector {
+    fn name(&self) -> &str { "nestjs" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let package_json = path.join("package.json");
+        if !package_json.exists() { return None; }
+
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // Check package.json for the Nest core dependency
+        if let Ok(content) = fs::read_to_string(&package_json) {
+            if content.contains("\"@nestjs/core\"") {
+                signals.push(DetectionSignal {
+                    signal_type: "package.json".to_string(),
+                    value: "@nestjs/core dependency found".to_string(),
+                    confidence_boost: 0.9,
+                    source: package_json.to_string_lossy().to_string(),
+                });
+                confidence += 0.9;
+            }
+        }
+
+        // Check for the Nest CLI config
+        let nest_cli = path.join("nest-cli.json");
+        if nest_cli.exists() {
+            signals.push(DetectionSignal {
+                signal_type: "config_file".to_string(),
+                value: "nest-cli.json found".to_string(),
+                confidence_boost: 0.8,
+                source: nest_cli.to_string_lossy().to_string(),
+            });
+            confidence += 0.8;
+        }
+
+        // Check the bootstrap file
+        for filename in ["src/main.ts", "src/main.js", "main.ts"] {
+            let file_path = path.join(filename);
+            if let Ok(content) = fs::read_to_string(&file_path) {
+                if content.contains("NestFactory.create") {
+                    signals.push(DetectionSignal {
+                        signal_type: "code_pattern".to_string(),
+                        value: "NestFactory.create found".to_string(),
+                        confidence_boost: 0.9,
+                        source: file_path.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.9;
+                    break;
+                }
+            }
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "nestjs".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_nestjs_patterns(),
+        })
+    }
+}
+
 // Next.js Detector
 struct NextJsDetector;
 impl Detector for NextJsDetector {
@@ -425,6 +517,70 @@ impl Detector for FlaskDetector {
     }
 }
 
+// Django Detector
+struct DjangoDetector;
+impl Detector for DjangoDetector {
+    fn name(&self) -> &str { "django" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let manage_py = path.join("manage.py");
+
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // manage.py marks the project root
+        if fs::read_to_string(&manage_py).map(|c| c.contains("django")).unwrap_or(false) {
+            signals.push(DetectionSignal {
+                signal_type: "config_file".to_string(),
+                value: "manage.py found".to_string(),
+                confidence_boost: 0.8,
+                source: manage_py.to_string_lossy().to_string(),
+            });
+            confidence += 0.8;
+        }
+
+        // Check the Python manifests
+        for manifest in ["requirements.txt", "pyproject.toml", "Pipfile"] {
+            let manifest_path = path.join(manifest);
+            if let Ok(content) = fs::read_to_string(&manifest_path) {
+                if content.to_lowercase().contains("django") {
+                    signals.push(DetectionSignal {
+                        signal_type: manifest.to_string(),
+                        value: "django dependency found".to_string(),
+                        confidence_boost: 0.8,
+                        source: manifest_path.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.8;
+                    break;
+                }
+            }
+        }
+
+        // A settings module alone doesn't make a project root
+        if signals.is_empty() { return None; }
+
+        if let Some(settings) = find_file_containing(path, "INSTALLED_APPS", 2) {
+            signals.push(DetectionSignal {
+                signal_type: "code_pattern".to_string(),
+                value: "INSTALLED_APPS found".to_string(),
+                confidence_boost: 0.9,
+                source: settings.to_string_lossy().to_string(),
+            });
+            confidence += 0.9;
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "django".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_django_patterns(),
+        })
+    }
+}
+
 // Laravel Detector
 struct LaravelDetector;
 impl Detector for LaravelDetector {
@@ -468,34 +624,751 @@ impl Detector for LaravelDetector {
             framework: "laravel".to_string(),
             confidence: confidence.min(1.0),
             signals,
-            patterns: vec![
-                RoutePattern {
-                    name: "laravel.routes".to_string(),
-                    files: "routes/**/*.php".to_string(),
-                    routes: vec!["Route::{method}('{path}', {handler})".to_string()],
-                    convention: Some("/api/users -> routes/api.php + UserController".to_string()),
-                    confidence: 0.95,
-                },
-            ],
+            patterns: PatternLibrary::get_laravel_patterns(),
         })
     }
 }
 
-// Placeholder detectors for other frameworks
+// Symfony Detector
+struct SymfonyDetector;
+impl Detector for SymfonyDetector {
+    fn name(&self) -> &str { "symfony" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let entry_points = [path.join("src/Kernel.php"), path.join("public/index.php")];
+        detect_composer(path, "symfony", "symfony/framework-bundle", &entry_points, &["MicroKernelTrait", "Kernel("])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_symfony_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Slim Detector
+struct SlimDetector;
+impl Detector for SlimDetector {
+    fn name(&self) -> &str { "slim" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let entry_points = ["public/index.php", "index.php", "app/routes.php", "src/routes.php"].map(|file| path.join(file));
+        detect_composer(path, "slim", "slim/slim", &entry_points, &["AppFactory::create", "new \\Slim\\App", "new App("])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_slim_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Spring Boot Detector
 struct SpringBootDetector;
 impl Detector for SpringBootDetector {
     fn name(&self) -> &str { "spring-boot" }
-    fn detect(&self, _path: &Path) -> Option<FrameworkDetection> { None }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let pom_xml = path.join("pom.xml");
+
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // Check pom.xml
+        if let Ok(content) = fs::read_to_string(&pom_xml) {
+            if content.contains("spring-boot") {
+                signals.push(DetectionSignal {
+                    signal_type: "pom.xml".to_string(),
+                    value: "spring-boot dependency found".to_string(),
+                    confidence_boost: 0.8,
+                    source: pom_xml.to_string_lossy().to_string(),
+                });
+                confidence += 0.8;
+            }
+        }
+
+        // Check build.gradle (Groovy or Kotlin DSL)
+        for filename in ["build.gradle", "build.gradle.kts"] {
+            let build_gradle = path.join(filename);
+            if let Ok(content) = fs::read_to_string(&build_gradle) {
+                if content.contains("org.springframework.boot") {
+                    signals.push(DetectionSignal {
+                        signal_type: "build.gradle".to_string(),
+                        value: "org.springframework.boot plugin found".to_string(),
+                        confidence_boost: 0.8,
+                        source: build_gradle.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.8;
+                    break;
+                }
+            }
+        }
+
+        // Check for the application class somewhere under the source root
+        for source_root in ["src/main/java", "src/main/kotlin"] {
+            if let Some(file_path) = find_file_containing(&path.join(source_root), "@SpringBootApplication", 8) {
+                signals.push(DetectionSignal {
+                    signal_type: "code_pattern".to_string(),
+                    value: "@SpringBootApplication found".to_string(),
+                    confidence_boost: 0.9,
+                    source: file_path.to_string_lossy().to_string(),
+                });
+                confidence += 0.9;
+                break;
+            }
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "spring-boot".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_spring_boot_patterns(),
+        })
+    }
 }
 
+// Ktor Detector
+struct KtorDetector;
+impl Detector for KtorDetector {
+    fn name(&self) -> &str { "ktor" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        for filename in ["build.gradle.kts", "build.gradle", "pom.xml"] {
+            let build_file = path.join(filename);
+            if let Ok(content) = fs::read_to_string(&build_file) {
+                if content.contains("io.ktor") {
+                    signals.push(DetectionSignal {
+                        signal_type: filename.to_string(),
+                        value: "io.ktor dependency found".to_string(),
+                        confidence_boost: 0.8,
+                        source: build_file.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.8;
+                    break;
+                }
+            }
+        }
+
+        if let Some(file_path) = find_file_containing(&path.join("src/main/kotlin"), "io.ktor.server", 8) {
+            signals.push(DetectionSignal {
+                signal_type: "code_pattern".to_string(),
+                value: "io.ktor.server import found".to_string(),
+                confidence_boost: 0.9,
+                source: file_path.to_string_lossy().to_string(),
+            });
+            confidence += 0.9;
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "ktor".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_ktor_patterns(),
+        })
+    }
+}
+
+/// Depth-limited search for a source file containing `marker`; JVM sources
+/// sit under package directories, so the entry point has no fixed path.
+fn find_file_containing(dir: &Path, marker: &str, depth: usize) -> Option<PathBuf> {
+    let entries = fs::read_dir(dir).ok()?;
+    let mut subdirs = Vec::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            subdirs.push(path);
+        } else if fs::read_to_string(&path).map(|c| c.contains(marker)).unwrap_or(false) {
+            return Some(path);
+        }
+    }
+
+    if depth == 0 {
+        return None;
+    }
+    subdirs.sort();
+    subdirs.into_iter().find_map(|subdir| find_file_containing(&subdir, marker, depth - 1))
+}
+
+// Actix-web Detector
 struct ActixDetector;
 impl Detector for ActixDetector {
     fn name(&self) -> &str { "actix" }
-    fn detect(&self, _path: &Path) -> Option<FrameworkDetection> { None }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_dependency(path, "actix", "Cargo.toml", "actix-web", &rust_entry_points(path), &["App::new()", "HttpServer::new"])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_actix_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Axum Detector
+struct AxumDetector;
+impl Detector for AxumDetector {
+    fn name(&self) -> &str { "axum" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_dependency(path, "axum", "Cargo.toml", "axum", &rust_entry_points(path), &["Router::new()", "axum::serve"])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_axum_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Rocket Detector
+struct RocketDetector;
+impl Detector for RocketDetector {
+    fn name(&self) -> &str { "rocket" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_dependency(path, "rocket", "Cargo.toml", "rocket", &rust_entry_points(path), &["rocket::build()", "#[launch]"])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_rocket_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Rails Detector
+struct RailsDetector;
+impl Detector for RailsDetector {
+    fn name(&self) -> &str { "rails" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let gemfile = path.join("Gemfile");
+        let routes_rb = path.join("config/routes.rb");
+
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // Check Gemfile
+        if let Ok(content) = fs::read_to_string(&gemfile) {
+            if gemfile_declares(&content, "rails") {
+                signals.push(DetectionSignal {
+                    signal_type: "Gemfile".to_string(),
+                    value: "rails gem found".to_string(),
+                    confidence_boost: 0.8,
+                    source: gemfile.to_string_lossy().to_string(),
+                });
+                confidence += 0.8;
+            }
+        }
+
+        // Check for the routes file
+        if let Ok(content) = fs::read_to_string(&routes_rb) {
+            if content.contains("routes.draw") {
+                signals.push(DetectionSignal {
+                    signal_type: "file".to_string(),
+                    value: "config/routes.rb with routes.draw found".to_string(),
+                    confidence_boost: 0.9,
+                    source: routes_rb.to_string_lossy().to_string(),
+                });
+                confidence += 0.9;
+            }
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "rails".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_rails_patterns(),
+        })
+    }
+}
+
+// Sinatra Detector
+struct SinatraDetector;
+impl Detector for SinatraDetector {
+    fn name(&self) -> &str { "sinatra" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let gemfile = path.join("Gemfile");
+
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // Check Gemfile
+        if let Ok(content) = fs::read_to_string(&gemfile) {
+            if gemfile_declares(&content, "sinatra") {
+                signals.push(DetectionSignal {
+                    signal_type: "Gemfile".to_string(),
+                    value: "sinatra gem found".to_string(),
+                    confidence_boost: 0.8,
+                    source: gemfile.to_string_lossy().to_string(),
+                });
+                confidence += 0.8;
+            }
+        }
+
+        // Check for Sinatra code patterns
+        for filename in ["app.rb", "server.rb", "config.ru", "lib/app.rb"] {
+            let file_path = path.join(filename);
+            if let Ok(content) = fs::read_to_string(&file_path) {
+                if content.contains("require 'sinatra") || content.contains("require \"sinatra") || content.contains("Sinatra::Base") {
+                    signals.push(DetectionSignal {
+                        signal_type: "code_pattern".to_string(),
+                        value: "require 'sinatra' or Sinatra::Base found".to_string(),
+                        confidence_boost: 0.9,
+                        source: file_path.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.9;
+                    break;
+                }
+            }
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "sinatra".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_sinatra_patterns(),
+        })
+    }
+}
+
+/// `gem 'rails'` or `gem "rails", "~> 7.1"` in a Gemfile.
+fn gemfile_declares(content: &str, gem: &str) -> bool {
+    content.lines().any(|line| {
+        let line = line.trim();
+        line.starts_with(&format!("gem '{}'", gem)) || line.starts_with(&format!("gem \"{}\"", gem))
+    })
+}
+
+/// `axum = "0.7"`, `axum.workspace = true` or a `[dependencies.axum]` table
+/// in a Cargo.toml, including target-specific and workspace dependencies.
+/// Crates that only share a prefix (`axum-extra`) don't count.
+fn cargo_declares(content: &str, krate: &str) -> bool {
+    let mut in_dependencies = false;
+    content.lines().any(|line| {
+        let line = line.trim();
+        if let Some(section) = line.strip_prefix('[') {
+            let section = section.trim_end_matches(']').trim();
+            in_dependencies = section == "dependencies" || section.ends_with(".dependencies");
+            let table = section.strip_prefix("dependencies.").or_else(|| section.split_once(".dependencies.").map(|(_, name)| name));
+            return table.map(|name| name.trim_matches('"') == krate).unwrap_or(false);
+        }
+        let key = line.split(['=', '.']).next().unwrap_or_default().trim().trim_matches('"');
+        in_dependencies && line.contains('=') && key == krate
+    })
+}
+
+// Phoenix Detector
+struct PhoenixDetector;
+impl Detector for PhoenixDetector {
+    fn name(&self) -> &str { "phoenix" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let mix_exs = path.join("mix.exs");
+
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // Check mix.exs deps
+        if let Ok(content) = fs::read_to_string(&mix_exs) {
+            if content.contains("{:phoenix,") {
+                signals.push(DetectionSignal {
+                    signal_type: "mix.exs".to_string(),
+                    value: "phoenix dependency found".to_string(),
+                    confidence_boost: 0.8,
+                    source: mix_exs.to_string_lossy().to_string(),
+                });
+                confidence += 0.8;
+            }
+        }
+
+        // Check for the router in lib/<app>_web/
+        if let Some(router) = phoenix_routers(path).into_iter().next() {
+            signals.push(DetectionSignal {
+                signal_type: "file".to_string(),
+                value: "lib/*_web/router.ex found".to_string(),
+                confidence_boost: 0.9,
+                source: router.to_string_lossy().to_string(),
+            });
+            confidence += 0.9;
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "phoenix".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_phoenix_patterns(),
+        })
+    }
 }
 
+/// `lib/<app>_web/router.ex` files that use a router.
+fn phoenix_routers(path: &Path) -> Vec<PathBuf> {
+    let mut routers: Vec<PathBuf> = fs::read_dir(path.join("lib"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|dir| dir.file_name().and_then(|n| n.to_str()).map(|n| n.ends_with("_web")).unwrap_or(false))
+        .map(|dir| dir.join("router.ex"))
+        .filter(|router| {
+            fs::read_to_string(router)
+                .map(|c| c.contains(":router") || c.contains("Phoenix.Router"))
+                .unwrap_or(false)
+        })
+        .collect();
+    routers.sort();
+    routers
+}
+
+// Gin Detector
 struct GinDetector;
 impl Detector for GinDetector {
     fn name(&self) -> &str { "gin" }
-    fn detect(&self, _path: &Path) -> Option<FrameworkDetection> { None }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_dependency(path, "gin", "go.mod", "github.com/gin-gonic/gin", &go_entry_points(path), &["gin.Default()", "gin.New()"])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_gin_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Echo Detector
+struct EchoDetector;
+impl Detector for EchoDetector {
+    fn name(&self) -> &str { "echo" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_dependency(path, "echo", "go.mod", "github.com/labstack/echo", &go_entry_points(path), &["echo.New()"])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_echo_patterns(),
+                ..detection
+            })
+    }
+}
+
+// ASP.NET Core Detector
+struct AspNetCoreDetector;
+impl Detector for AspNetCoreDetector {
+    fn name(&self) -> &str { "aspnetcore" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        let mut signals = Vec::new();
+        let mut confidence: f32 = 0.0;
+
+        // The project file is named after the project, so look for any *.csproj
+        let mut projects: Vec<PathBuf> = fs::read_dir(path).ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("csproj"))
+            .collect();
+        projects.sort();
+
+        for csproj in projects {
+            if let Ok(content) = fs::read_to_string(&csproj) {
+                if content.contains("Microsoft.NET.Sdk.Web") || content.contains("Microsoft.AspNetCore") {
+                    signals.push(DetectionSignal {
+                        signal_type: ".csproj".to_string(),
+                        value: "Microsoft.NET.Sdk.Web project found".to_string(),
+                        confidence_boost: 0.8,
+                        source: csproj.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.8;
+                    break;
+                }
+            }
+        }
+
+        // Check for the host builder in the entry point
+        for filename in ["Program.cs", "Startup.cs"] {
+            let file_path = path.join(filename);
+            if let Ok(content) = fs::read_to_string(&file_path) {
+                if ["WebApplication.CreateBuilder", "UseStartup", "MapControllers"].iter().any(|m| content.contains(m)) {
+                    signals.push(DetectionSignal {
+                        signal_type: "code_pattern".to_string(),
+                        value: "ASP.NET Core host setup found".to_string(),
+                        confidence_boost: 0.9,
+                        source: file_path.to_string_lossy().to_string(),
+                    });
+                    confidence += 0.9;
+                    break;
+                }
+            }
+        }
+
+        if confidence < 0.6 { return None; }
+
+        Some(FrameworkDetection {
+            path: path.to_string_lossy().to_string(),
+            framework: "aspnetcore".to_string(),
+            confidence: confidence.min(1.0),
+            signals,
+            patterns: PatternLibrary::get_aspnetcore_patterns(),
+        })
+    }
+}
+
+// SvelteKit Detector
+struct SvelteKitDetector;
+impl Detector for SvelteKitDetector {
+    fn name(&self) -> &str { "sveltekit" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_file_routed(path, "sveltekit", &["\"@sveltejs/kit\""], &["svelte.config.js", "svelte.config.ts"], "src/routes")
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_sveltekit_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Remix Detector
+struct RemixDetector;
+impl Detector for RemixDetector {
+    fn name(&self) -> &str { "remix" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_file_routed(path, "remix", &["\"@remix-run/"], &["remix.config.js", "remix.config.mjs"], "app/routes")
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_remix_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Nuxt Detector
+struct NuxtDetector;
+impl Detector for NuxtDetector {
+    fn name(&self) -> &str { "nuxt" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_file_routed(path, "nuxt", &["\"nuxt\"", "\"nitropack\""], &["nuxt.config.ts", "nuxt.config.js", "nitro.config.ts"], "server/api")
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_nuxt_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Fastify Detector
+struct FastifyDetector;
+impl Detector for FastifyDetector {
+    fn name(&self) -> &str { "fastify" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_package(path, "fastify", "\"fastify\"", &["fastify(", "require('fastify')", "from 'fastify'"])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_fastify_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Koa Detector
+struct KoaDetector;
+impl Detector for KoaDetector {
+    fn name(&self) -> &str { "koa" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_package(path, "koa", "\"koa\"", &["new Koa(", "new Router("])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_koa_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Hono Detector
+struct HonoDetector;
+impl Detector for HonoDetector {
+    fn name(&self) -> &str { "hono" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_package(path, "hono", "\"hono\"", &["new Hono("])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_hono_patterns(),
+                ..detection
+            })
+    }
+}
+
+// Hapi Detector
+struct HapiDetector;
+impl Detector for HapiDetector {
+    fn name(&self) -> &str { "hapi" }
+
+    fn detect(&self, path: &Path) -> Option<FrameworkDetection> {
+        detect_package(path, "hapi", "\"@hapi/hapi\"", &["Hapi.server(", "Hapi.Server("])
+            .map(|detection| FrameworkDetection {
+                patterns: PatternLibrary::get_hapi_patterns(),
+                ..detection
+            })
+    }
+}
+
+/// Detection for file-routed JS meta-frameworks, scored like Next.js: the
+/// package.json dependency, a framework config file and the routes
+/// directory. Patterns are filled in by the caller.
+fn detect_file_routed(
+    path: &Path,
+    framework: &str,
+    dependencies: &[&str],
+    config_files: &[&str],
+    routes_dir: &str,
+) -> Option<FrameworkDetection> {
+    let package_json = path.join("package.json");
+
+    let mut signals = Vec::new();
+    let mut confidence: f32 = 0.0;
+
+    // Check package.json for the framework dependency
+    if let Ok(content) = fs::read_to_string(&package_json) {
+        if let Some(dependency) = dependencies.iter().find(|d| content.contains(*d)) {
+            signals.push(DetectionSignal {
+                signal_type: "package.json".to_string(),
+                value: format!("{} dependency found", dependency.trim_matches('"').trim_end_matches('/')),
+                confidence_boost: 0.9,
+                source: package_json.to_string_lossy().to_string(),
+            });
+            confidence += 0.9;
+        }
+    }
+
+    // Check for the framework config file
+    if let Some(config) = config_files.iter().map(|f| path.join(f)).find(|f| f.exists()) {
+        signals.push(DetectionSignal {
+            signal_type: "config_file".to_string(),
+            value: format!("{} found", config.file_name().unwrap_or_default().to_string_lossy()),
+            confidence_boost: 0.8,
+            source: config.to_string_lossy().to_string(),
+        });
+        confidence += 0.8;
+    }
+
+    // Check for the routes directory
+    let routes = path.join(routes_dir);
+    if routes.is_dir() {
+        signals.push(DetectionSignal {
+            signal_type: "directory".to_string(),
+            value: format!("{} directory found", routes_dir),
+            confidence_boost: 0.85,
+            source: routes.to_string_lossy().to_string(),
+        });
+        confidence += 0.85;
+    }
+
+    // A routes directory alone is too common to count
+    if confidence < 0.9 { return None; }
+
+    Some(FrameworkDetection {
+        path: path.to_string_lossy().to_string(),
+        framework: framework.to_string(),
+        confidence: confidence.min(1.0),
+        signals,
+        patterns: Vec::new(),
+    })
+}
+
+/// Go entry points usually live in main.go or cmd/<name>/main.go.
+fn go_entry_points(path: &Path) -> Vec<PathBuf> {
+    let mut candidates = vec![path.join("main.go"), path.join("server.go")];
+    if let Ok(entries) = fs::read_dir(path.join("cmd")) {
+        candidates.extend(entries.flatten().map(|entry| entry.path().join("main.go")));
+    }
+    candidates
+}
+
+/// Node servers start from the package root or src/, as .js or .ts.
+fn node_entry_points(path: &Path) -> Vec<PathBuf> {
+    ["index", "app", "server", "main", "src/index", "src/app", "src/server", "src/main"]
+        .iter()
+        .flat_map(|stem| [path.join(format!("{}.js", stem)), path.join(format!("{}.ts", stem))])
+        .collect()
+}
+
+/// `detect_dependency` for Node packages. Like Express, a package.json is
+/// required so a bare src/ directory isn't reported as its own app.
+fn detect_package(path: &Path, framework: &str, dependency: &str, code_markers: &[&str]) -> Option<FrameworkDetection> {
+    if !path.join("package.json").exists() { return None; }
+    detect_dependency(path, framework, "package.json", dependency, &node_entry_points(path), code_markers)
+}
+
+fn rust_entry_points(path: &Path) -> Vec<PathBuf> {
+    vec![path.join("src/main.rs"), path.join("src/lib.rs")]
+}
+
+/// `detect_dependency` for composer packages; the composer.json is required.
+fn detect_composer(path: &Path, framework: &str, dependency: &str, entry_points: &[PathBuf], code_markers: &[&str]) -> Option<FrameworkDetection> {
+    if !path.join("composer.json").exists() { return None; }
+    detect_dependency(path, framework, "composer.json", dependency, entry_points, code_markers)
+}
+
+/// Detection for ecosystems that declare frameworks in a manifest
+/// (go.mod, Cargo.toml, package.json, composer.json): the dependency plus a code marker in an entry point.
+/// Patterns are filled in by the caller.
+fn detect_dependency(
+    path: &Path,
+    framework: &str,
+    manifest: &str,
+    dependency: &str,
+    entry_points: &[PathBuf],
+    code_markers: &[&str],
+) -> Option<FrameworkDetection> {
+    let manifest_path = path.join(manifest);
+
+    let mut signals = Vec::new();
+    let mut confidence: f32 = 0.0;
+
+    // Check the manifest
+    if let Ok(content) = fs::read_to_string(&manifest_path) {
+        let declared = if manifest == "Cargo.toml" { cargo_declares(&content, dependency) } else { content.contains(dependency) };
+        if declared {
+            signals.push(DetectionSignal {
+                signal_type: manifest.to_string(),
+                value: format!("{} dependency found", dependency.trim_matches('"')),
+                confidence_boost: 0.8,
+                source: manifest_path.to_string_lossy().to_string(),
+            });
+            confidence += 0.8;
+        }
+    }
+
+    // Check for framework code patterns
+    for file_path in entry_points {
+        if let Ok(content) = fs::read_to_string(file_path) {
+            if code_markers.iter().any(|marker| content.contains(marker)) {
+                signals.push(DetectionSignal {
+                    signal_type: "code_pattern".to_string(),
+                    value: format!("{} found", code_markers.join(" or ")),
+                    confidence_boost: 0.9,
+                    source: file_path.to_string_lossy().to_string(),
+                });
+                confidence += 0.9;
+                break;
+            }
+        }
+    }
+
+    if confidence < 0.6 { return None; }
+
+    Some(FrameworkDetection {
+        path: path.to_string_lossy().to_string(),
+        framework: framework.to_string(),
+        confidence: confidence.min(1.0),
+        signals,
+        patterns: Vec::new(),
+    })
 }
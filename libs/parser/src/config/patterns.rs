@@ -26,6 +26,21 @@ impl PatternLibrary {
         ]
     }
 
+    pub fn get_nestjs_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "nestjs.controllers".to_string(),
+                files: "src/**/*.controller.ts".to_string(),
+                routes: vec![
+                    "@Controller('{prefix}')".to_string(),
+                    "@{Method}('{path}')".to_string(),
+                ],
+                convention: Some("global prefix + @Controller prefix + method decorator path".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
     pub fn get_nextjs_patterns() -> Vec<RoutePattern> {
         vec![
             RoutePattern {
@@ -54,7 +69,7 @@ impl PatternLibrary {
                     "@app.{method}('{path}')".to_string(),
                     "@router.{method}('{path}')".to_string(),
                 ],
-                convention: None,
+                convention: Some("include_router prefix + APIRouter(prefix=...) + decorator path".to_string()),
                 confidence: 0.95,
             },
         ]
@@ -65,8 +80,28 @@ impl PatternLibrary {
             RoutePattern {
                 name: "flask.decorators".to_string(),
                 files: "**/*.py".to_string(),
-                routes: vec!["@app.route('{path}', methods=['{method}'])".to_string()],
-                convention: None,
+                routes: vec![
+                    "@app.route('{path}', methods=['{method}'])".to_string(),
+                    "@bp.route('{path}', methods=['{method}'])".to_string(),
+                    "app.add_url_rule('{path}', view_func={View}.as_view('{name}'))".to_string(),
+                ],
+                convention: Some("register_blueprint url_prefix + Blueprint url_prefix + rule".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_django_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "django.urlpatterns".to_string(),
+                files: "**/urls.py".to_string(),
+                routes: vec![
+                    "path('{path}', {view})".to_string(),
+                    "path('{prefix}', include('{module}.urls'))".to_string(),
+                    "router.register(r'{prefix}', {ViewSet})".to_string(),
+                ],
+                convention: Some("ROOT_URLCONF urlpatterns + include() prefixes".to_string()),
                 confidence: 0.95,
             },
         ]
@@ -77,10 +112,334 @@ impl PatternLibrary {
             RoutePattern {
                 name: "laravel.routes".to_string(),
                 files: "routes/**/*.php".to_string(),
-                routes: vec!["Route::{method}('{path}', {handler})".to_string()],
+                routes: vec![
+                    "Route::{method}('{path}', {handler})".to_string(),
+                    "Route::prefix('{prefix}')->middleware('{middleware}')->group({routes})".to_string(),
+                    "Route::resource('{name}', {Controller}::class)".to_string(),
+                ],
                 convention: Some("/api/users -> routes/api.php + UserController".to_string()),
                 confidence: 0.95,
             },
         ]
     }
+
+    pub fn get_symfony_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "symfony.attributes".to_string(),
+                files: "src/Controller/**/*.php".to_string(),
+                routes: vec![
+                    "#[Route('{path}', methods: ['{METHOD}'])]".to_string(),
+                    "@Route(\"{path}\", methods={\"{METHOD}\"})".to_string(),
+                ],
+                convention: Some("class-level #[Route] prefix + method #[Route] path".to_string()),
+                confidence: 0.95,
+            },
+            RoutePattern {
+                name: "symfony.yaml".to_string(),
+                files: "config/routes/**/*.yaml".to_string(),
+                routes: vec!["{name}: { path: {path}, controller: {Controller}::{method} }".to_string()],
+                convention: None,
+                confidence: 0.90,
+            },
+        ]
+    }
+
+    pub fn get_slim_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "slim.routes".to_string(),
+                files: "**/*.php".to_string(),
+                routes: vec![
+                    "$app->{method}('{path}', {handler})".to_string(),
+                    "$app->group('{prefix}', function ($group) { ... })".to_string(),
+                ],
+                convention: Some("group prefix + route path".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_gin_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "gin.routes".to_string(),
+                files: "**/*.go".to_string(),
+                routes: vec![
+                    "r.{METHOD}(\"{path}\", {handler})".to_string(),
+                    "r.Group(\"{prefix}\").{METHOD}(\"{path}\", {handler})".to_string(),
+                ],
+                convention: None,
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_echo_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "echo.routes".to_string(),
+                files: "**/*.go".to_string(),
+                routes: vec![
+                    "e.{METHOD}(\"{path}\", {handler})".to_string(),
+                    "e.Group(\"{prefix}\").{METHOD}(\"{path}\", {handler})".to_string(),
+                ],
+                convention: None,
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_actix_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "actix.attributes".to_string(),
+                files: "src/**/*.rs".to_string(),
+                routes: vec!["#[{method}(\"{path}\")]".to_string()],
+                convention: None,
+                confidence: 0.95,
+            },
+            RoutePattern {
+                name: "actix.builder".to_string(),
+                files: "src/**/*.rs".to_string(),
+                routes: vec![
+                    "web::scope(\"{prefix}\").route(\"{path}\", web::{method}().to({handler}))".to_string(),
+                    "web::resource(\"{path}\").route(web::{method}().to({handler}))".to_string(),
+                ],
+                convention: None,
+                confidence: 0.90,
+            },
+        ]
+    }
+
+    pub fn get_axum_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "axum.router".to_string(),
+                files: "src/**/*.rs".to_string(),
+                routes: vec![
+                    "Router::new().route(\"{path}\", {method}({handler}))".to_string(),
+                    "Router::new().nest(\"{prefix}\", {router})".to_string(),
+                ],
+                convention: None,
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_rocket_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "rocket.attributes".to_string(),
+                files: "src/**/*.rs".to_string(),
+                routes: vec![
+                    "#[{method}(\"{path}\")]".to_string(),
+                    "rocket::build().mount(\"{prefix}\", routes![{handler}])".to_string(),
+                ],
+                convention: None,
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_spring_boot_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "spring.controllers".to_string(),
+                files: "src/main/java/**/*.java".to_string(),
+                routes: vec![
+                    "@{Method}Mapping(\"{path}\")".to_string(),
+                    "@RequestMapping(value = \"{path}\", method = RequestMethod.{METHOD})".to_string(),
+                ],
+                convention: Some("class-level @RequestMapping prefix + method mapping".to_string()),
+                confidence: 0.95,
+            },
+            RoutePattern {
+                name: "spring.kotlin_controllers".to_string(),
+                files: "src/main/kotlin/**/*.kt".to_string(),
+                routes: vec![
+                    "@{Method}Mapping(\"{path}\")".to_string(),
+                    "@RequestMapping(value = [\"{path}\"], method = [RequestMethod.{METHOD}])".to_string(),
+                ],
+                convention: Some("class-level @RequestMapping prefix + method mapping".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_ktor_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "ktor.routing".to_string(),
+                files: "src/main/kotlin/**/*.kt".to_string(),
+                routes: vec![
+                    "{method}(\"{path}\") { }".to_string(),
+                    "route(\"{path}\") { }".to_string(),
+                    "route(\"{path}\", HttpMethod.{Method}) { }".to_string(),
+                ],
+                convention: Some("nested route(\"/prefix\") blocks and Route extension functions".to_string()),
+                confidence: 0.9,
+            },
+        ]
+    }
+
+    pub fn get_rails_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "rails.routes".to_string(),
+                files: "config/routes.rb".to_string(),
+                routes: vec![
+                    "resources :{name}".to_string(),
+                    "{method} '{path}', to: '{controller}#{action}'".to_string(),
+                ],
+                convention: Some("resources :users -> 7 RESTful routes on UsersController".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_phoenix_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "phoenix.router".to_string(),
+                files: "lib/*_web/router.ex".to_string(),
+                routes: vec![
+                    "{method} \"{path}\", {Controller}, :{action}".to_string(),
+                    "resources \"{path}\", {Controller}".to_string(),
+                ],
+                convention: Some("scope \"/api\", MyAppWeb do ... end prefixes paths and controller aliases".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_sinatra_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "sinatra.blocks".to_string(),
+                files: "**/*.rb".to_string(),
+                routes: vec!["{method} '{path}' do".to_string()],
+                convention: None,
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_aspnetcore_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "aspnetcore.minimal".to_string(),
+                files: "**/*.cs".to_string(),
+                routes: vec![
+                    "app.Map{Method}(\"{path}\", {handler})".to_string(),
+                    "app.MapGroup(\"{prefix}\")".to_string(),
+                ],
+                convention: None,
+                confidence: 0.95,
+            },
+            RoutePattern {
+                name: "aspnetcore.controllers".to_string(),
+                files: "Controllers/**/*.cs".to_string(),
+                routes: vec!["[Http{Method}(\"{path}\")]".to_string()],
+                convention: Some("[Route(\"api/[controller]\")] -> /api/{name without Controller suffix}".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_sveltekit_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "sveltekit.server-routes".to_string(),
+                files: "src/routes/**/+server.{js,ts}".to_string(),
+                routes: vec!["export async function {METHOD}()".to_string()],
+                convention: Some("file-based routing: /api/posts -> src/routes/api/posts/+server.ts".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_remix_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "remix.flat-routes".to_string(),
+                files: "app/routes/**/*.{js,jsx,ts,tsx}".to_string(),
+                routes: vec![
+                    "export async function loader()".to_string(),
+                    "export async function action()".to_string(),
+                ],
+                convention: Some("flat routes: /api/users/$id -> app/routes/api.users.$id.ts".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_nuxt_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "nuxt.server-api".to_string(),
+                files: "server/api/**/*.{js,ts}".to_string(),
+                routes: vec!["export default defineEventHandler({handler})".to_string()],
+                convention: Some("file-based routing: GET /api/users -> server/api/users.get.ts".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_fastify_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "fastify.routes".to_string(),
+                files: "**/*.{js,ts}".to_string(),
+                routes: vec![
+                    "fastify.{method}('{path}', {options}, {handler})".to_string(),
+                    "fastify.route({ method, url, handler })".to_string(),
+                ],
+                convention: Some("plugin prefix from fastify.register(plugin, { prefix })".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_koa_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "koa.router".to_string(),
+                files: "**/*.{js,ts}".to_string(),
+                routes: vec![
+                    "router.{method}('{path}', {handler})".to_string(),
+                    "router.{method}('{name}', '{path}', {handler})".to_string(),
+                ],
+                convention: Some("new Router({ prefix }) or router.prefix('{prefix}')".to_string()),
+                confidence: 0.90,
+            },
+        ]
+    }
+
+    pub fn get_hono_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "hono.routes".to_string(),
+                files: "**/*.{js,ts}".to_string(),
+                routes: vec![
+                    "app.{method}('{path}', {handler})".to_string(),
+                    "app.on('{method}', '{path}', {handler})".to_string(),
+                ],
+                convention: Some("basePath('{prefix}') + app.route('{prefix}', {subapp})".to_string()),
+                confidence: 0.95,
+            },
+        ]
+    }
+
+    pub fn get_hapi_patterns() -> Vec<RoutePattern> {
+        vec![
+            RoutePattern {
+                name: "hapi.routes".to_string(),
+                files: "**/*.{js,ts}".to_string(),
+                routes: vec!["server.route({ method, path, handler })".to_string()],
+                convention: None,
+                confidence: 0.95,
+            },
+        ]
+    }
 }
@@ -0,0 +1,260 @@
+use crate::{Documentation, ParamDoc};
+
+/// Parse a `/** ... */` JSDoc or PHPDoc block.
+///
+/// Prose before the first tag is split into the summary (its first
+/// paragraph) and the description. `@param {Type} name desc` (JSDoc) and
+/// `@param Type $name desc` (PHPDoc), `@returns`/`@return`, `@deprecated`,
+/// `@summary` and `@description` are read; other tags are ignored.
+pub fn parse_doc_block(comment: &str) -> Documentation {
+    let body = comment.trim().trim_start_matches("/**").trim_end_matches("*/");
+    let lines: Vec<&str> = body.lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim()
+        })
+        .collect();
+
+    let mut prose = Vec::new();
+    let mut tags: Vec<(String, String)> = Vec::new();
+    for line in lines {
+        if let Some(tag) = line.strip_prefix('@') {
+            let (name, rest) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tags.push((name.to_string(), rest.trim().to_string()));
+        } else if let Some((_, text)) = tags.last_mut() {
+            if !line.is_empty() {
+                text.push(' ');
+                text.push_str(line);
+                *text = text.trim().to_string();
+            }
+        } else {
+            prose.push(line);
+        }
+    }
+
+    let mut documentation = prose_documentation(&prose);
+    for (name, text) in tags {
+        match name.as_str() {
+            "param" | "arg" | "argument" => documentation.params.extend(tag_param(&text)),
+            "returns" | "return" => {
+                let (param_type, rest) = braced_type(&text);
+                documentation.returns = non_empty(rest).or(param_type);
+            }
+            "deprecated" => documentation.deprecated = Some(text),
+            "summary" => documentation.summary = non_empty(&text),
+            "description" => documentation.description = non_empty(&text),
+            _ => {}
+        }
+    }
+    documentation
+}
+
+/// Parse a Python docstring, with reST fields (`:param name: ...`,
+/// `:returns: ...`), Google-style `Args:`/`Returns:`/`Deprecated:` sections
+/// and the `.. deprecated::` directive.
+pub fn parse_docstring(docstring: &str) -> Documentation {
+    #[derive(PartialEq)]
+    enum Section {
+        Prose,
+        Args,
+        Returns,
+        Deprecated,
+        Other,
+    }
+
+    let mut prose = Vec::new();
+    let mut params: Vec<ParamDoc> = Vec::new();
+    let mut returns: Vec<&str> = Vec::new();
+    let mut deprecated: Option<Vec<&str>> = None;
+    let mut section = Section::Prose;
+
+    for line in docstring.lines().map(str::trim) {
+        let header = match line {
+            "Args:" | "Arguments:" | "Parameters:" | "Params:" => Some(Section::Args),
+            "Returns:" | "Return:" => Some(Section::Returns),
+            "Deprecated:" => Some(Section::Deprecated),
+            "Raises:" | "Yields:" | "Examples:" | "Example:" | "Note:" | "Notes:" => Some(Section::Other),
+            _ => None,
+        };
+        if let Some(header) = header {
+            if header == Section::Deprecated {
+                deprecated = Some(Vec::new());
+            }
+            section = header;
+            continue;
+        }
+
+        if let Some(field) = line.strip_prefix(':') {
+            let (name, text) = field.split_once(':').unwrap_or((field, ""));
+            let text = text.trim();
+            let mut words = name.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (Some("param" | "parameter" | "arg"), Some(name), None) => params.push(param_doc(name, None, text)),
+                (Some("param" | "parameter" | "arg"), Some(param_type), Some(name)) => params.push(param_doc(name, Some(param_type), text)),
+                (Some("returns" | "return"), _, _) => returns = vec![text],
+                _ => {}
+            }
+            section = Section::Other;
+            continue;
+        }
+        if let Some(note) = line.strip_prefix(".. deprecated::") {
+            deprecated = Some(vec![note.trim()]);
+            section = Section::Deprecated;
+            continue;
+        }
+        if line.is_empty() && section != Section::Prose {
+            section = Section::Other;
+            continue;
+        }
+
+        match section {
+            Section::Prose => prose.push(line),
+            Section::Args => match google_param(line) {
+                Some(param) => params.push(param),
+                None => {
+                    if let Some(last) = params.last_mut() {
+                        last.description = format!("{} {}", last.description, line).trim().to_string();
+                    }
+                }
+            },
+            Section::Returns => returns.push(line),
+            Section::Deprecated => deprecated.get_or_insert_with(Vec::new).push(line),
+            Section::Other => {}
+        }
+    }
+
+    let mut documentation = prose_documentation(&prose);
+    documentation.params = params;
+    documentation.returns = non_empty(&returns.join(" "));
+    documentation.deprecated = deprecated.map(|note| note.join(" ").trim().to_string());
+    documentation
+}
+
+/// Summary and description from the lines before any tags or sections.
+fn prose_documentation(lines: &[&str]) -> Documentation {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in lines.iter().chain(std::iter::once(&"")) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    let mut paragraphs = paragraphs.into_iter();
+    Documentation {
+        summary: paragraphs.next(),
+        description: non_empty(&paragraphs.collect::<Vec<_>>().join("\n\n")),
+        ..Default::default()
+    }
+}
+
+/// `{string} name - desc`, `{number} [limit=20] desc`, `string $name desc`, `$name desc`
+fn tag_param(text: &str) -> Option<ParamDoc> {
+    let (mut param_type, rest) = braced_type(text);
+    let mut words: Vec<&str> = rest.split_whitespace().collect();
+    // PHPDoc puts the type before the `$name`
+    if param_type.is_none() && words.len() > 1 && !words[0].starts_with('$') && words[1].starts_with('$') {
+        param_type = Some(words.remove(0).to_string());
+    }
+    let name = words.first()?;
+    let name = name.trim_start_matches('$').trim_start_matches('[').trim_end_matches(']');
+    let name = name.split('=').next().unwrap_or(name);
+    let description = words[1..].join(" ");
+    Some(param_doc(name, param_type.as_deref(), description.trim_start_matches("- ")))
+}
+
+/// Google style `name (type): desc` or `name: desc`.
+fn google_param(line: &str) -> Option<ParamDoc> {
+    let (head, description) = line.split_once(':')?;
+    let (name, param_type) = match head.split_once('(') {
+        Some((name, param_type)) => (name.trim(), Some(param_type.trim_end_matches(')').trim())),
+        None => (head.trim(), None),
+    };
+    let name_like = !name.is_empty() && name.trim_start_matches('*').chars().all(|c| c.is_alphanumeric() || c == '_');
+    name_like.then(|| param_doc(name, param_type, description.trim()))
+}
+
+/// A leading JSDoc `{Type}` and the text after it.
+fn braced_type(text: &str) -> (Option<String>, &str) {
+    match text.strip_prefix('{').and_then(|rest| rest.split_once('}')) {
+        Some((param_type, rest)) => (Some(param_type.trim().to_string()), rest.trim()),
+        None => (None, text.trim()),
+    }
+}
+
+fn param_doc(name: &str, param_type: Option<&str>, description: &str) -> ParamDoc {
+    ParamDoc {
+        name: name.to_string(),
+        param_type: param_type.map(str::to_string),
+        description: description.to_string(),
+    }
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jsdoc_block() {
+        let doc = parse_doc_block(r#"/**
+         * List users.
+         *
+         * Results are paginated
+         * and sorted by name.
+         *
+         * @param {number} [page=1] - Page to return
+         * @param {string} sort Field to sort by,
+         *   ascending
+         * @returns {User[]} The users on the page
+         * @deprecated Use /v2/users
+         */"#);
+
+        assert_eq!(doc.summary.as_deref(), Some("List users."));
+        assert_eq!(doc.description.as_deref(), Some("Results are paginated and sorted by name."));
+        let params: Vec<_> = doc.params.iter().map(|p| (p.name.as_str(), p.param_type.as_deref(), p.description.as_str())).collect();
+        assert_eq!(params, vec![
+            ("page", Some("number"), "Page to return"),
+            ("sort", Some("string"), "Field to sort by, ascending"),
+        ]);
+        assert_eq!(doc.returns.as_deref(), Some("The users on the page"));
+        assert_eq!(doc.deprecated.as_deref(), Some("Use /v2/users"));
+    }
+
+    #[test]
+    fn test_parse_phpdoc_block() {
+        let doc = parse_doc_block("/**\n * Store a post.\n *\n * @param  StorePostRequest  $request\n * @return \\Illuminate\\Http\\JsonResponse\n * @deprecated\n */");
+
+        assert_eq!(doc.summary.as_deref(), Some("Store a post."));
+        assert_eq!(doc.description, None);
+        assert_eq!(doc.params[0].name, "request");
+        assert_eq!(doc.params[0].param_type.as_deref(), Some("StorePostRequest"));
+        assert_eq!(doc.returns.as_deref(), Some("\\Illuminate\\Http\\JsonResponse"));
+        assert_eq!(doc.deprecated.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_parse_docstrings() {
+        let google = parse_docstring("Read an item.\n\n    Args:\n        item_id (int): The item.\n        q: Search text,\n            optional.\n\n    Returns:\n        The item.\n    ");
+        assert_eq!(google.summary.as_deref(), Some("Read an item."));
+        let params: Vec<_> = google.params.iter().map(|p| (p.name.as_str(), p.param_type.as_deref(), p.description.as_str())).collect();
+        assert_eq!(params, vec![("item_id", Some("int"), "The item."), ("q", None, "Search text, optional.")]);
+        assert_eq!(google.returns.as_deref(), Some("The item."));
+        assert_eq!(google.deprecated, None);
+
+        let rest = parse_docstring("Delete a user.\n\n:param int user_id: The user.\n:returns: Nothing.\n\n.. deprecated:: 2.0\n");
+        assert_eq!(rest.params[0].name, "user_id");
+        assert_eq!(rest.params[0].param_type.as_deref(), Some("int"));
+        assert_eq!(rest.returns.as_deref(), Some("Nothing."));
+        assert_eq!(rest.deprecated.as_deref(), Some("2.0"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::project::ProjectModules;
 use crate::{Endpoint, LanguageParser};
 use anyhow::Result;
 use pinpath_diff::{ChangeEvent, CodeRegion, FileDiff};
@@ -11,6 +12,9 @@ use std::time::SystemTime;
 pub struct IncrementalParser {
     state: EndpointState,
     language_parsers: HashMap<String, Box<dyn LanguageParser>>,
+    /// Parsed modules of the project, so changed files are linked with the
+    /// routers that mount them.
+    project: ProjectModules,
 }
 
 /// Persistent state of discovered endpoints
@@ -54,13 +58,10 @@ impl IncrementalParser {
         let mut language_parsers: HashMap<String, Box<dyn LanguageParser>> = HashMap::new();
         
         // Initialize language parsers
-        language_parsers.insert("javascript".to_string(), Box::new(crate::languages::javascript::JavaScriptParser));
-        language_parsers.insert("typescript".to_string(), Box::new(crate::languages::javascript::JavaScriptParser));
-        language_parsers.insert("python".to_string(), Box::new(crate::languages::python::PythonParser));
-        
-        // Add PHP parser - need to handle the Result
-        if let Ok(php_parser) = crate::languages::php::PhpParser::new() {
-            language_parsers.insert("php".to_string(), Box::new(php_parser));
+        for language in ["javascript", "typescript", "tsx", "python", "go", "rust", "ruby", "php", "java", "csharp", "kotlin", "elixir", "yaml"] {
+            if let Some(parser) = crate::parser_for_language(language) {
+                language_parsers.insert(language.to_string(), parser);
+            }
         }
 
         Self {
@@ -70,6 +71,7 @@ impl IncrementalParser {
                 last_updated: SystemTime::now(),
             },
             language_parsers,
+            project: ProjectModules::default(),
         }
     }
 
@@ -80,6 +82,19 @@ impl IncrementalParser {
         parser
     }
 
+    /// Parse the project's files up front so a changed file picks up the
+    /// prefixes of routers declared elsewhere. Returns the files that failed.
+    pub fn load_project(&mut self, files: &[(PathBuf, String)]) -> Vec<(PathBuf, anyhow::Error)> {
+        self.project.add_files(files)
+    }
+
+    /// Parse `content` as the current version of `path` and return its
+    /// endpoints, linked against the rest of the loaded project.
+    pub fn update_file(&mut self, path: &Path, content: &str) -> Result<Vec<Endpoint>> {
+        self.project.update(path, content)?;
+        Ok(self.project.endpoints_in(path))
+    }
+
     /// Process change events and return what endpoints changed
     pub async fn parse_changes(&mut self, change_event: ChangeEvent) -> Result<EndpointChanges> {
         let mut all_changes = EndpointChanges::new();
@@ -102,16 +117,16 @@ impl IncrementalParser {
             None => return Ok(EndpointChanges::new()), // Skip unsupported files
         };
 
-        let parser = match parser {
-            Some(p) => p,
-            None => return Ok(EndpointChanges::new()),
-        };
+        if parser.is_none() {
+            return Ok(EndpointChanges::new());
+        }
 
         // Get old endpoints for this file
         let old_endpoints = self.get_endpoints_for_file(&file_diff.path);
         
-        // Parse new content
-        let new_endpoints = parser.parse(&file_diff.new_content)?;
+        // Parse new content and link it with the rest of the project, so
+        // mount prefixes declared in other files still apply
+        let new_endpoints = self.update_file(&file_diff.path, &file_diff.new_content)?;
         
         // Update file hash
         let new_hash = self.calculate_content_hash(&file_diff.new_content);
@@ -135,16 +150,17 @@ impl IncrementalParser {
             None => return Ok(vec![]),
         };
 
-        let parser = match parser {
-            Some(p) => p,
-            None => return Ok(vec![]),
-        };
+        if parser.is_none() {
+            return Ok(vec![]);
+        }
 
         let mut all_endpoints = Vec::new();
         
         for region in regions {
             if region.has_changes {
-                let endpoints = parser.parse(&region.content)?;
+                let mut project = self.project.clone();
+                project.update(path, &region.content)?;
+                let endpoints = project.endpoints_in(path);
                 
                 // Adjust line numbers based on region start
                 let adjusted_endpoints: Vec<Endpoint> = endpoints
@@ -241,11 +257,12 @@ impl IncrementalParser {
         changes
     }
 
-    /// Generate a unique ID for an endpoint
+    /// Generate a unique ID for an endpoint, keyed on its canonical template
+    /// so `/users/:id` and `/users/:id(\\d+)` are the same route
     fn generate_endpoint_id(&self, endpoint: &Endpoint) -> String {
         format!("{}:{}:{}", 
             format!("{:?}", endpoint.method).to_lowercase(),
-            endpoint.path,
+            endpoint.route_template(),
             endpoint.line
         )
     }
@@ -257,16 +274,19 @@ impl IncrementalParser {
             && old.handler == new.handler
             && old.line == new.line
             && old.column == new.column
+            && old.middleware == new.middleware
     }
 
     /// Detect what type of change occurred
     fn detect_change_type(&self, old: &Endpoint, new: &Endpoint) -> ChangeType {
-        if old.path != new.path {
+        if old.route_template() != new.route_template() {
             ChangeType::PathChanged
         } else if old.method != new.method {
             ChangeType::MethodChanged
         } else if old.handler != new.handler {
             ChangeType::HandlerChanged
+        } else if old.middleware != new.middleware {
+            ChangeType::MiddlewareChanged
         } else if old.line != new.line || old.column != new.column {
             ChangeType::LineChanged
         } else {
@@ -363,6 +383,13 @@ mod tests {
             line: 1,
             column: 1,
             documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
         });
 
         let mut changes2 = EndpointChanges::new();
@@ -373,6 +400,13 @@ mod tests {
             line: 2,
             column: 1,
             documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
         });
 
         changes1.merge(changes2);
@@ -389,9 +423,100 @@ mod tests {
             line: 10,
             column: 5,
             documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
         };
 
         let id = parser.generate_endpoint_id(&endpoint);
         assert_eq!(id, "get:/api/users:10");
+
+        let endpoint = Endpoint { path: "/api/users/<int:id>/".to_string(), ..endpoint };
+        assert_eq!(parser.generate_endpoint_id(&endpoint), "get:/api/users/{id}:10");
+    }
+
+    #[test]
+    fn test_detect_middleware_change() {
+        let parser = IncrementalParser::new();
+        let old = Endpoint {
+            method: HttpMethod::Get,
+            path: "/me".to_string(),
+            handler: "me".to_string(),
+            line: 3,
+            column: 1,
+            documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        };
+        let new = Endpoint { middleware: vec!["auth".to_string()], requires_auth: true, ..old.clone() };
+
+        assert!(!parser.endpoints_equal(&old, &new));
+        assert!(matches!(parser.detect_change_type(&old, &new), ChangeType::MiddlewareChanged));
+    }
+
+    #[tokio::test]
+    async fn test_route_handlers_take_their_path_from_the_file() {
+        let mut parser = IncrementalParser::new();
+        let path = PathBuf::from("app/api/users/[id]/route.ts");
+        let content = "export async function GET(request: Request) {}\nexport async function DELETE() {}\n";
+        let diff = FileDiff { path: path.clone(), old_content: None, new_content: content.to_string(), changes: Vec::new() };
+
+        let changes = parser.parse_file_diff(&diff).await.unwrap();
+        let mut added: Vec<_> = changes.added.iter().map(|e| (e.method.clone(), e.path.as_str())).collect();
+        added.sort_by_key(|(method, _)| format!("{:?}", method));
+        assert_eq!(added, vec![(HttpMethod::Delete, "/api/users/[id]"), (HttpMethod::Get, "/api/users/[id]")]);
+
+        let region = CodeRegion { start_line: 2, end_line: 2, content: "export async function DELETE() {}\n".to_string(), has_changes: true, change_types: Vec::new() };
+        let endpoints = parser.parse_regions(vec![region], &path).unwrap();
+        assert_eq!((endpoints[0].path.as_str(), endpoints[0].line), ("/api/users/[id]", 2));
+    }
+
+    #[tokio::test]
+    async fn test_file_routed_frameworks_keep_their_routes() {
+        let files = [
+            ("src/routes/api/users/[id]/+server.ts", "export async function GET({ params }) {}\n"),
+            ("app/routes/api.orders.$id.ts", "export async function loader({ params }) {}\n"),
+            ("server/api/items/[id].get.ts", "export default defineEventHandler((event) => {})\n"),
+        ];
+
+        let mut parser = IncrementalParser::new();
+        let mut routes = Vec::new();
+        for (path, content) in files {
+            let diff = FileDiff { path: PathBuf::from(path), old_content: None, new_content: content.to_string(), changes: Vec::new() };
+            let changes = parser.parse_file_diff(&diff).await.unwrap();
+            routes.extend(changes.added.into_iter().map(|e| (e.method, e.path)));
+        }
+        assert_eq!(routes, vec![
+            (HttpMethod::Get, "/api/users/[id]".to_string()),
+            (HttpMethod::Get, "/api/orders/$id".to_string()),
+            (HttpMethod::Get, "/api/items/[id]".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_changed_router_keeps_its_mount_prefix() {
+        let mut parser = IncrementalParser::new();
+        let failures = parser.load_project(&[
+            (PathBuf::from("src/app.js"), "const users = require('./users');\napp.use('/api/users', users);\n".to_string()),
+            (PathBuf::from("src/users.js"), "const router = express.Router();\nrouter.get('/', list);\nmodule.exports = router;\n".to_string()),
+        ]);
+        assert!(failures.is_empty());
+
+        let content = "const router = express.Router();\nrouter.get('/', list);\nrouter.get('/:id', show);\nmodule.exports = router;\n";
+        let diff = FileDiff { path: PathBuf::from("src/users.js"), old_content: None, new_content: content.to_string(), changes: Vec::new() };
+
+        let changes = parser.parse_file_diff(&diff).await.unwrap();
+        let mut added: Vec<_> = changes.added.iter().map(|e| e.path.as_str()).collect();
+        added.sort();
+        assert_eq!(added, vec!["/api/users", "/api/users/:id"]);
     }
 }
@@ -0,0 +1,681 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, Parameter, ParameterLocation, parse_http_method};
+use crate::project::{Import, ModuleRoutes, Mount, RoutedEndpoint, join_route};
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+/// Parser for ASP.NET Core: minimal APIs (`app.MapGet`, `MapGroup`) and
+/// attribute-routed controllers (`[Route("api/[controller]")]`, `[HttpGet("{id}")]`).
+///
+/// Minimal API groups are bindings like Go and Express routers; endpoint
+/// registration split into extension methods (`app.MapTodoEndpoints()`) is
+/// linked through positional parameters, across files when needed.
+pub struct CSharpParser;
+
+impl LanguageParser for CSharpParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        Ok(self.parse_module(Path::new(""), content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "cs"
+    }
+
+    fn parse_module(&self, _path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_c_sharp::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse C# code"))?;
+
+        let mut walker = RouteWalker {
+            content,
+            module: ModuleRoutes::default(),
+            scopes: Vec::new(),
+            methods: HashSet::new(),
+        };
+        walker.collect_methods(tree.root_node());
+        walker.visit(tree.root_node());
+        Ok(walker.module)
+    }
+}
+
+struct Scope {
+    function: String,
+    bindings: HashMap<String, String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    module: ModuleRoutes,
+    scopes: Vec<Scope>,
+    /// Methods declared in this file; calls to them link without an import.
+    methods: HashSet<String>,
+}
+
+impl<'a> RouteWalker<'a> {
+    fn collect_methods(&mut self, node: Node) {
+        if node.kind() == "method_declaration" {
+            if let Some(name) = node.child_by_field_name("name") {
+                self.methods.insert(self.text(name).to_string());
+            }
+        }
+        for child in self.named_children(node) {
+            self.collect_methods(child);
+        }
+    }
+
+    fn visit(&mut self, node: Node) {
+        match node.kind() {
+            "class_declaration" => self.visit_controller(node),
+            "method_declaration" | "local_function_statement" => {
+                self.visit_method(node);
+                return;
+            }
+            "variable_declarator" => {
+                self.visit_declarator(node);
+                return;
+            }
+            "invocation_expression" if self.visit_invocation(node) => return,
+            _ => {}
+        }
+
+        for child in self.named_children(node) {
+            self.visit(child);
+        }
+    }
+
+    fn visit_method(&mut self, node: Node) {
+        let name = match node.child_by_field_name("name") {
+            Some(name) => self.text(name).to_string(),
+            None => return,
+        };
+
+        self.scopes.push(Scope { function: name.clone(), bindings: HashMap::new() });
+
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            let params: Vec<Node> = self.named_children(parameters)
+                .into_iter()
+                .filter(|p| p.kind() == "parameter")
+                .collect();
+            for (i, param) in params.into_iter().enumerate() {
+                let binding = match param.child_by_field_name("name") {
+                    Some(param) => self.declare(self.text(param)),
+                    None => continue,
+                };
+                // Positional alias: `this IEndpointRouteBuilder app` is #0
+                let positional = format!("{}:#{}", name, i);
+                self.module.mounts.push(Mount {
+                    parent: positional.clone(),
+                    prefix: String::new(),
+                    target: binding,
+                });
+                self.module.exports.insert(positional.clone(), positional);
+            }
+        }
+
+        for child in self.named_children(node) {
+            if !matches!(child.kind(), "parameter_list" | "attribute_list") {
+                self.visit(child);
+            }
+        }
+        self.scopes.pop();
+    }
+
+    /// `var api = app.MapGroup("/api");`
+    fn visit_declarator(&mut self, node: Node) {
+        let children = self.named_children(node);
+        let name = children.iter().find(|c| c.kind() == "identifier").map(|n| self.text(*n).to_string());
+        let value = children.iter()
+            .find(|c| c.kind() == "equals_value_clause")
+            .and_then(|clause| clause.named_child(0));
+
+        let (name, value) = match (name, value) {
+            (Some(name), Some(value)) => (name, value),
+            _ => {
+                for child in children {
+                    self.visit(child);
+                }
+                return;
+            }
+        };
+
+        match self.router_expression(value) {
+            Some(router) => {
+                let binding = self.declare(&name);
+                self.module.mounts.push(Mount { parent: router, prefix: String::new(), target: binding });
+            }
+            None => self.visit(value),
+        }
+    }
+
+    /// Returns true when the invocation and its arguments were handled.
+    fn visit_invocation(&mut self, node: Node) -> bool {
+        let (function, args) = match self.invocation_parts(node) {
+            Some(parts) => parts,
+            None => return false,
+        };
+        if function.kind() != "member_access_expression" {
+            return false;
+        }
+        let (receiver, name) = match (function.child_by_field_name("expression"), function.child_by_field_name("name")) {
+            (Some(receiver), Some(name)) => (receiver, name),
+            _ => return false,
+        };
+        let method = self.method_name(name);
+
+        let methods = match method {
+            "MapGet" => vec![HttpMethod::Get],
+            "MapPost" => vec![HttpMethod::Post],
+            "MapPut" => vec![HttpMethod::Put],
+            "MapDelete" => vec![HttpMethod::Delete],
+            "MapPatch" => vec![HttpMethod::Patch],
+            // `Map` matches every verb; report it as GET
+            "Map" => vec![HttpMethod::Get],
+            "MapMethods" => args.get(1)
+                .map(|verbs| self.strings(*verbs))
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|m| parse_http_method(m).ok())
+                .collect(),
+            "MapGroup" => {
+                // Chained use: the group is handled by router_expression
+                self.router_expression(node);
+                return true;
+            }
+            _ => {
+                self.link_router_arguments(receiver, method, &args);
+                return false;
+            }
+        };
+
+        let path = match args.first().and_then(|a| self.string(*a)) {
+            Some(path) => path,
+            None => return false,
+        };
+        let owner = self.router_expression(receiver).unwrap_or_default();
+
+        let pos = name.start_position();
+        let handler = args.last()
+            .filter(|h| matches!(h.kind(), "identifier" | "member_access_expression"))
+            .map(|h| self.text(*h).to_string())
+            .unwrap_or_else(|| format!("{}:{}", pos.row + 1, pos.column + 1));
+
+        for method in methods {
+            self.module.endpoints.push(RoutedEndpoint {
+                owner: owner.clone(),
+                endpoint: endpoint(method, path.clone(), handler.clone(), pos, Vec::new()),
+            });
+        }
+
+        for arg in args.iter().skip(1) {
+            self.visit(*arg);
+        }
+        true
+    }
+
+    /// `app.MapTodoEndpoints()` (extension method: the receiver is #0) and
+    /// `TodoEndpoints.Map(app)` (static call on a type).
+    fn link_router_arguments(&mut self, receiver: Node, method: &str, args: &[Node]) {
+        let receiver_text = self.text(receiver);
+        let is_type = receiver.kind() == "identifier"
+            && receiver_text.starts_with(char::is_uppercase)
+            && !self.is_local(receiver_text);
+
+        let mut routers = Vec::new();
+        if !is_type {
+            routers.push(receiver);
+        }
+        routers.extend(args.iter().copied());
+
+        for (i, arg) in routers.into_iter().enumerate() {
+            if !matches!(arg.kind(), "identifier" | "member_access_expression" | "invocation_expression") {
+                continue;
+            }
+            let parent = match self.router_expression(arg) {
+                Some(parent) => parent,
+                None => continue,
+            };
+
+            let positional = format!("{}:#{}", method, i);
+            let target = if self.methods.contains(method) {
+                positional
+            } else {
+                // Declared in another file; C# namespaces don't follow paths
+                let binding = format!("call@{}:{}", arg.start_byte(), i);
+                self.module.imports.insert(binding.clone(), Import {
+                    source: String::new(),
+                    name: Some(positional),
+                });
+                binding
+            };
+            self.module.mounts.push(Mount { parent, prefix: String::new(), target });
+        }
+    }
+
+    /// The binding routes registered on `node` belong to: a variable, or a
+    /// `MapGroup` chain. Fluent configuration calls (`.WithTags()`,
+    /// `.RequireAuthorization()`) return the same builder.
+    fn router_expression(&mut self, node: Node) -> Option<String> {
+        match node.kind() {
+            "identifier" => return Some(self.lookup(self.text(node))),
+            "member_access_expression" => return Some(self.text(node).to_string()),
+            "invocation_expression" => {}
+            _ => return None,
+        }
+
+        let (function, args) = self.invocation_parts(node)?;
+        if function.kind() != "member_access_expression" {
+            return None;
+        }
+        let receiver = function.child_by_field_name("expression")?;
+        let method = self.method_name(function.child_by_field_name("name")?);
+
+        match method {
+            "MapGroup" => {
+                let prefix = self.string(*args.first()?)?;
+                let parent = self.router_expression(receiver).unwrap_or_default();
+                let group = format!("group@{}", node.start_byte());
+                self.module.mounts.push(Mount { parent, prefix, target: group.clone() });
+                Some(group)
+            }
+            "WithTags" | "WithName" | "WithOpenApi" | "RequireAuthorization" | "AllowAnonymous"
+            | "RequireCors" | "RequireRateLimiting" | "AddEndpointFilter" | "WithGroupName"
+            | "WithMetadata" | "DisableAntiforgery" | "CacheOutput" => self.router_expression(receiver),
+            _ => None,
+        }
+    }
+
+    /// Attribute-routed controller: class `[Route]` templates combined with
+    /// `[HttpGet("{id}")]`-style attributes on its actions.
+    fn visit_controller(&mut self, class: Node) {
+        let class_name = match class.child_by_field_name("name") {
+            Some(name) => self.text(name).to_string(),
+            None => return,
+        };
+        let attributes = self.attributes(class);
+        let is_controller = attributes.iter().any(|(name, _)| matches!(name.as_str(), "ApiController" | "Route"))
+            || class_name.ends_with("Controller");
+        if !is_controller {
+            return;
+        }
+
+        let controller = class_name.strip_suffix("Controller").unwrap_or(&class_name).to_string();
+        let area = attributes.iter()
+            .find(|(name, _)| name == "Area")
+            .and_then(|(_, attribute)| self.attribute_strings(*attribute).into_iter().next());
+
+        let mut prefixes: Vec<String> = attributes.iter()
+            .filter(|(name, _)| name == "Route")
+            .filter_map(|(_, attribute)| self.attribute_strings(*attribute).into_iter().next())
+            .collect();
+        if prefixes.is_empty() {
+            prefixes.push(String::new());
+        }
+
+        let body = match class.child_by_field_name("body") {
+            Some(body) => body,
+            None => return,
+        };
+
+        for method in self.named_children(body).into_iter().filter(|n| n.kind() == "method_declaration") {
+            let action = method.child_by_field_name("name").map(|n| self.text(n)).unwrap_or_default();
+            let action_token = action.strip_suffix("Async").unwrap_or(action);
+            let method_attributes = self.attributes(method);
+
+            // Templates on `[Route]` apply to the verb attributes that have none
+            let route_templates: Vec<String> = method_attributes.iter()
+                .filter(|(name, _)| name == "Route")
+                .filter_map(|(_, attribute)| self.attribute_strings(*attribute).into_iter().next())
+                .collect();
+
+            let mut routes: Vec<(HttpMethod, Option<String>, Node)> = Vec::new();
+            for (name, attribute) in &method_attributes {
+                let verbs = match name.as_str() {
+                    "HttpGet" => vec![HttpMethod::Get],
+                    "HttpPost" => vec![HttpMethod::Post],
+                    "HttpPut" => vec![HttpMethod::Put],
+                    "HttpDelete" => vec![HttpMethod::Delete],
+                    "HttpPatch" => vec![HttpMethod::Patch],
+                    "HttpHead" => vec![HttpMethod::Head],
+                    "HttpOptions" => vec![HttpMethod::Options],
+                    "AcceptVerbs" => self.attribute_strings(*attribute)
+                        .iter()
+                        .filter_map(|v| parse_http_method(v).ok())
+                        .collect(),
+                    _ => continue,
+                };
+                let template = if name == "AcceptVerbs" {
+                    self.named_attribute_string(*attribute, "Route")
+                } else {
+                    self.attribute_strings(*attribute).into_iter().next()
+                };
+                for verb in verbs {
+                    routes.push((verb, template.clone(), *attribute));
+                }
+            }
+            if routes.is_empty() && !route_templates.is_empty() {
+                if let Some((_, attribute)) = method_attributes.iter().find(|(name, _)| name == "Route") {
+                    routes.push((HttpMethod::Get, None, *attribute));
+                }
+            }
+
+            let parameters = self.action_parameters(method);
+            let handler = format!("{}.{}", class_name, action);
+
+            for (verb, template, attribute) in routes {
+                let templates = match template {
+                    Some(template) => vec![template],
+                    None if route_templates.is_empty() => vec![String::new()],
+                    None => route_templates.clone(),
+                };
+
+                for template in &templates {
+                    // `/x` and `~/x` on an action ignore the controller prefix
+                    let absolute = template.starts_with('/') || template.starts_with("~/");
+                    let prefixes = if absolute { vec![String::new()] } else { prefixes.clone() };
+
+                    for prefix in &prefixes {
+                        let path = join_route(prefix, template.trim_start_matches('~'));
+                        let path = substitute_tokens(&path, &controller, action_token, area.as_deref());
+                        let path = if path.starts_with('/') { path } else { format!("/{}", path) };
+
+                        self.module.endpoints.push(RoutedEndpoint {
+                            owner: String::new(),
+                            endpoint: endpoint(verb.clone(), path, handler.clone(), attribute.start_position(), parameters.clone()),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// `[FromRoute] int id`, `[FromQuery(Name = "q")] string? search`,
+    /// `[FromHeader(Name = "X-Tenant")] string tenant`, `[FromBody] CreateTodo todo`
+    fn action_parameters(&self, method: Node) -> Vec<Parameter> {
+        let parameters = match method.child_by_field_name("parameters") {
+            Some(parameters) => parameters,
+            None => return Vec::new(),
+        };
+
+        let mut result = Vec::new();
+        for param in self.named_children(parameters).into_iter().filter(|p| p.kind() == "parameter") {
+            let param_name = param.child_by_field_name("name").map(|n| self.text(n)).unwrap_or_default();
+            let param_type = param.child_by_field_name("type").map(|t| self.text(t).to_string());
+            let default = self.named_children(param)
+                .into_iter()
+                .find(|c| c.kind() == "equals_value_clause")
+                .and_then(|clause| clause.named_child(0))
+                .map(|value| self.string(value).unwrap_or_else(|| self.text(value).to_string()));
+            let nullable = param_type.as_deref().map(|t| t.ends_with('?')).unwrap_or(false);
+
+            for (name, attribute) in self.attributes(param) {
+                let location = match name.as_str() {
+                    "FromRoute" => ParameterLocation::Path,
+                    "FromQuery" => ParameterLocation::Query,
+                    "FromHeader" => ParameterLocation::Header,
+                    "FromBody" => ParameterLocation::Body,
+                    _ => continue,
+                };
+                result.push(Parameter {
+                    name: self.named_attribute_string(attribute, "Name").unwrap_or_else(|| param_name.to_string()),
+                    location,
+                    param_type: param_type.clone(),
+                    required: !nullable && default.is_none(),
+                    default: default.clone(),
+                });
+            }
+        }
+        result
+    }
+
+    /// Attributes on a declaration by simple name (`Microsoft.AspNetCore.Mvc.HttpGet` -> `HttpGet`,
+    /// `HttpGetAttribute` -> `HttpGet`).
+    fn attributes(&self, node: Node<'a>) -> Vec<(String, Node<'a>)> {
+        self.named_children(node)
+            .into_iter()
+            .filter(|c| c.kind() == "attribute_list")
+            .flat_map(|list| self.named_children(list))
+            .filter(|a| a.kind() == "attribute")
+            .filter_map(|attribute| {
+                let name = self.text(attribute.child_by_field_name("name")?);
+                let name = name.rsplit('.').next().unwrap_or(name);
+                let name = name.strip_suffix("Attribute").unwrap_or(name);
+                Some((name.to_string(), attribute))
+            })
+            .collect()
+    }
+
+    /// Positional string arguments of an attribute.
+    fn attribute_strings(&self, attribute: Node) -> Vec<String> {
+        self.attribute_arguments(attribute)
+            .into_iter()
+            .filter(|arg| !self.named_children(*arg).iter().any(|c| matches!(c.kind(), "name_equals" | "name_colon")))
+            .filter_map(|arg| arg.named_child(0).and_then(|value| self.string(value)))
+            .collect()
+    }
+
+    /// `Name = "x"` argument of an attribute.
+    fn named_attribute_string(&self, attribute: Node, key: &str) -> Option<String> {
+        self.attribute_arguments(attribute).into_iter().find_map(|arg| {
+            let children = self.named_children(arg);
+            let name = children.iter().find(|c| c.kind() == "name_equals")?;
+            if self.text(*name).trim_end_matches('=').trim() != key {
+                return None;
+            }
+            children.last().and_then(|value| self.string(*value))
+        })
+    }
+
+    fn attribute_arguments(&self, attribute: Node<'a>) -> Vec<Node<'a>> {
+        self.named_children(attribute)
+            .into_iter()
+            .filter(|c| c.kind() == "attribute_argument_list")
+            .flat_map(|list| self.named_children(list))
+            .collect()
+    }
+
+    fn invocation_parts(&self, node: Node<'a>) -> Option<(Node<'a>, Vec<Node<'a>>)> {
+        let function = node.child_by_field_name("function")?;
+        let args = self.named_children(node.child_by_field_name("arguments")?)
+            .into_iter()
+            .filter_map(|arg| arg.named_children(&mut arg.walk()).last())
+            .collect();
+        Some((function, args))
+    }
+
+    /// `MapGet` from `MapGet` or `MapGet<T>`.
+    fn method_name(&self, name: Node) -> &'a str {
+        let text = self.text(name);
+        text.split('<').next().unwrap_or(text)
+    }
+
+    /// String elements of `new[] { "GET", "HEAD" }` or a single literal.
+    fn strings(&self, node: Node) -> Vec<String> {
+        if let Some(value) = self.string(node) {
+            return vec![value];
+        }
+        self.named_children(node)
+            .into_iter()
+            .flat_map(|child| self.strings(child))
+            .collect()
+    }
+
+    fn string(&self, node: Node) -> Option<String> {
+        let text = self.text(node);
+        match node.kind() {
+            "string_literal" => Some(text.trim_matches('"').to_string()),
+            "verbatim_string_literal" => Some(text.trim_start_matches('@').trim_matches('"').to_string()),
+            _ => None,
+        }
+    }
+
+    fn declare(&mut self, name: &str) -> String {
+        match self.scopes.last_mut() {
+            Some(scope) => {
+                let binding = format!("{}:{}", scope.function, name);
+                scope.bindings.insert(name.to_string(), binding.clone());
+                binding
+            }
+            None => name.to_string(),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> String {
+        self.scopes.iter()
+            .rev()
+            .find_map(|scope| scope.bindings.get(name).cloned())
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    fn is_local(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| scope.bindings.contains_key(name))
+    }
+
+    fn named_children(&self, node: Node<'a>) -> Vec<Node<'a>> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).collect()
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+/// Replace `[controller]`, `[action]` and `[area]` route tokens.
+fn substitute_tokens(template: &str, controller: &str, action: &str, area: Option<&str>) -> String {
+    let mut path = template
+        .replace("[controller]", controller)
+        .replace("[action]", action);
+    if let Some(area) = area {
+        path = path.replace("[area]", area);
+    }
+    path
+}
+
+fn endpoint(method: HttpMethod, path: String, handler: String, pos: tree_sitter::Point, parameters: Vec<Parameter>) -> Endpoint {
+    Endpoint {
+        method,
+        path,
+        handler,
+        line: pos.row + 1,
+        column: pos.column + 1,
+        documentation: None,
+        parameters,
+        tags: Vec::new(),
+        middleware: Vec::new(),
+        template: String::new(),
+        body: None,
+        responses: Vec::new(),
+        requires_auth: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(code: &str) -> Vec<(HttpMethod, String, String)> {
+        CSharpParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path, e.handler))
+            .collect()
+    }
+
+    #[test]
+    fn test_supports_csharp_extension() {
+        assert!(CSharpParser.supports_extension("cs"));
+        assert!(!CSharpParser.supports_extension("java"));
+    }
+
+    #[test]
+    fn test_parse_minimal_api() {
+        let code = r#"
+var builder = WebApplication.CreateBuilder(args);
+var app = builder.Build();
+
+app.MapGet("/", () => "Hello World!");
+
+var api = app.MapGroup("/api").RequireAuthorization();
+var todos = api.MapGroup("/todos").WithTags("Todos");
+todos.MapGet("/", TodoHandlers.GetAll);
+todos.MapGet("/{id:int}", GetTodo);
+todos.MapPost("/", async (Todo todo, TodoDb db) => Results.Created());
+app.MapMethods("/ping", new[] { "GET", "HEAD" }, () => "pong");
+
+api.MapUserEndpoints();
+
+app.Run();
+
+static class UserEndpoints
+{
+    public static void MapUserEndpoints(this IEndpointRouteBuilder routes)
+    {
+        var users = routes.MapGroup("/users");
+        users.MapDelete("/{id}", DeleteUser);
+    }
+}
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/".to_string(), "5:5".to_string()),
+            (HttpMethod::Get, "/api/todos".to_string(), "TodoHandlers.GetAll".to_string()),
+            (HttpMethod::Get, "/api/todos/{id:int}".to_string(), "GetTodo".to_string()),
+            (HttpMethod::Post, "/api/todos".to_string(), "11:7".to_string()),
+            (HttpMethod::Get, "/ping".to_string(), "12:5".to_string()),
+            (HttpMethod::Head, "/ping".to_string(), "12:5".to_string()),
+            (HttpMethod::Delete, "/api/users/{id}".to_string(), "DeleteUser".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_attribute_controller() {
+        let code = r#"
+namespace Shop.Controllers;
+
+[ApiController]
+[Route("api/[controller]")]
+public class ProductsController : ControllerBase
+{
+    [HttpGet]
+    public IEnumerable<Product> List([FromQuery] int page = 1) => _db.Products;
+
+    [HttpGet("{id}", Name = "GetProduct")]
+    public ActionResult<Product> Get([FromRoute] int id) => Ok();
+
+    [HttpPost]
+    public async Task<IActionResult> CreateAsync([FromBody] CreateProduct request,
+        [FromHeader(Name = "X-Tenant")] string tenant) => Ok();
+
+    [Route("[action]")]
+    [AcceptVerbs("PUT", "PATCH")]
+    public IActionResult Restock() => Ok();
+
+    [HttpDelete("/admin/products/{id}")]
+    public IActionResult Purge(int id) => Ok();
+
+    private void Helper() {}
+}
+"#;
+
+        let endpoints = CSharpParser.parse(code).unwrap();
+        let found: Vec<_> = endpoints.iter().map(|e| (e.method.clone(), e.path.as_str())).collect();
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/api/Products"),
+            (HttpMethod::Get, "/api/Products/{id}"),
+            (HttpMethod::Post, "/api/Products"),
+            (HttpMethod::Put, "/api/Products/Restock"),
+            (HttpMethod::Patch, "/api/Products/Restock"),
+            (HttpMethod::Delete, "/admin/products/{id}"),
+        ]);
+        assert_eq!(endpoints[2].handler, "ProductsController.CreateAsync");
+
+        let page = &endpoints[0].parameters[0];
+        assert_eq!((page.name.as_str(), page.required, page.default.as_deref()), ("page", false, Some("1")));
+        let create = &endpoints[2].parameters;
+        assert_eq!(create[0].location, ParameterLocation::Body);
+        assert_eq!(create[1].name, "X-Tenant");
+        assert_eq!(create[1].location, ParameterLocation::Header);
+    }
+}
@@ -0,0 +1,582 @@
+//! Django and Django REST Framework URL discovery for the Python parser.
+//!
+//! `urlpatterns` lists mount views under `path()`/`re_path()` routes and
+//! `include()` other URLconfs, so each list is a router: a module's
+//! `urlpatterns` is served wherever another module includes it. Views own
+//! their endpoints at an empty path: function views (methods from
+//! `@api_view`/`@require_http_methods`, or `request.method` checks), class
+//! views (verb methods and DRF generic views) and ViewSets, which DRF routers
+//! expand into list/detail routes plus `@action` extras.
+
+use crate::{HttpMethod, parse_http_method};
+use crate::languages::python::{arguments, arguments_of, endpoint, keyword, list_items, positional, string_value};
+use crate::project::{Import, ModuleRoutes, Mount, RoutedEndpoint};
+use tree_sitter::Node;
+
+/// ViewSet actions DRF routers map to routes: (action, method, detail).
+const ACTIONS: [(&str, HttpMethod, bool); 6] = [
+    ("list", HttpMethod::Get, false),
+    ("create", HttpMethod::Post, false),
+    ("retrieve", HttpMethod::Get, true),
+    ("update", HttpMethod::Put, true),
+    ("partial_update", HttpMethod::Patch, true),
+    ("destroy", HttpMethod::Delete, true),
+];
+
+/// Add URLconf mounts, router registrations and view endpoints found in the tree.
+pub(crate) fn collect(root: Node, content: &str, module: &mut ModuleRoutes) {
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        let definition = match statement.kind() {
+            "decorated_definition" => statement.child_by_field_name("definition"),
+            _ => Some(statement),
+        };
+        match definition.map(|d| (d.kind(), d)) {
+            Some(("function_definition", function)) => function_view(statement, function, content, module),
+            Some(("class_definition", class)) => class_view(class, content, module),
+            _ => {}
+        }
+    }
+    visit(root, content, module);
+}
+
+fn visit(node: Node, content: &str, module: &mut ModuleRoutes) {
+    match node.kind() {
+        "assignment" | "augmented_assignment" => urlpatterns(node, content, module),
+        "call" => register(node, content, module),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        visit(child, content, module);
+    }
+}
+
+/// `urlpatterns = [...]`, `urlpatterns += [...]`, `urlpatterns = router.urls`
+fn urlpatterns(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let (left, right) = match (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+        (Some(left), Some(right)) if text(left, content) == "urlpatterns" => (left, right),
+        _ => return,
+    };
+    let owner = text(left, content).to_string();
+
+    match right.kind() {
+        "list" => patterns(&owner, right, content, module),
+        "attribute" if text(right, content).ends_with(".urls") => {
+            if let Some(router) = right.child_by_field_name("object") {
+                module.mounts.push(Mount {
+                    parent: owner,
+                    prefix: String::new(),
+                    target: text(router, content).to_string(),
+                });
+            }
+        }
+        // `urlpatterns = [...] + router.urls`
+        "binary_operator" => {
+            for side in ["left", "right"] {
+                if let Some(operand) = right.child_by_field_name(side) {
+                    match operand.kind() {
+                        "list" => patterns(&owner, operand, content, module),
+                        "attribute" => {
+                            if let Some(router) = operand.child_by_field_name("object").filter(|_| text(operand, content).ends_with(".urls")) {
+                                module.mounts.push(Mount {
+                                    parent: owner.clone(),
+                                    prefix: String::new(),
+                                    target: text(router, content).to_string(),
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `path("users/<int:pk>/", views.user_detail)`, `re_path(r"^api/", include("api.urls"))`
+fn patterns(owner: &str, list: Node, content: &str, module: &mut ModuleRoutes) {
+    for entry in list_items(list) {
+        let callee = match entry.child_by_field_name("function").filter(|_| entry.kind() == "call") {
+            Some(callee) => text(callee, content).rsplit('.').next().unwrap_or_default(),
+            None => continue,
+        };
+        if !matches!(callee, "path" | "re_path" | "url") {
+            continue;
+        }
+
+        let args = arguments(entry);
+        let route = match positional(&args, 0).or_else(|| keyword(&args, "route", content)).and_then(|r| string_value(r, content)) {
+            Some(route) => route,
+            None => continue,
+        };
+        let route = match callee {
+            "path" => route,
+            _ => route.trim_start_matches('^').trim_end_matches('$').to_string(),
+        };
+        let prefix = format!("/{}", route.trim_start_matches('/'));
+
+        let view = match positional(&args, 1).or_else(|| keyword(&args, "view", content)) {
+            Some(view) => view,
+            None => continue,
+        };
+        mount_view(owner, prefix, view, content, module);
+    }
+}
+
+fn mount_view(owner: &str, prefix: String, view: Node, content: &str, module: &mut ModuleRoutes) {
+    let target = match view.kind() {
+        "call" => {
+            let callee = view.child_by_field_name("function");
+            let args = arguments(view);
+            match callee.map(|c| (c.kind(), text(c, content))) {
+                Some((_, "include")) | Some((_, "django.urls.include")) => {
+                    match include_target(view, args.first().copied(), content, module) {
+                        Some(target) => target,
+                        None => return,
+                    }
+                }
+                // `UserView.as_view()`, `UserViewSet.as_view({"get": "list"})`
+                Some(("attribute", callee_text)) if callee_text.ends_with(".as_view") => {
+                    let class = text(callee.and_then(|c| c.child_by_field_name("object")).unwrap_or(view), content).to_string();
+                    if let Some(actions) = args.first().filter(|a| a.kind() == "dictionary") {
+                        for (method, action) in action_map(*actions, content) {
+                            module.endpoints.push(RoutedEndpoint {
+                                owner: owner.to_string(),
+                                endpoint: endpoint(method, prefix.clone(), format!("{}.{}", class, action), view.start_position()),
+                            });
+                        }
+                        return;
+                    }
+                    class
+                }
+                _ => return,
+            }
+        }
+        "identifier" | "attribute" => text(view, content).to_string(),
+        _ => return,
+    };
+
+    module.mounts.push(Mount { parent: owner.to_string(), prefix, target });
+}
+
+/// `include("blog.urls")`, `include(("blog.urls", "blog"))`,
+/// `include(router.urls)`, `include([path(...), ...])`
+fn include_target(call: Node, arg: Option<Node>, content: &str, module: &mut ModuleRoutes) -> Option<String> {
+    let arg = arg?;
+    let arg = match arg.kind() {
+        "tuple" => list_items(arg).into_iter().next()?,
+        _ => arg,
+    };
+
+    match arg.kind() {
+        "string" => {
+            let local = format!("include@{}", call.start_byte());
+            module.imports.insert(local.clone(), Import {
+                source: string_value(arg, content)?,
+                name: Some("urlpatterns".to_string()),
+            });
+            Some(local)
+        }
+        "attribute" if text(arg, content).ends_with(".urls") => {
+            arg.child_by_field_name("object").map(|router| text(router, content).to_string())
+        }
+        "list" => {
+            let local = format!("urls@{}", call.start_byte());
+            patterns(&local, arg, content, module);
+            Some(local)
+        }
+        _ => Some(text(arg, content).to_string()),
+    }
+}
+
+/// DRF `router.register(r"users", UserViewSet, basename="user")`
+fn register(call: Node, content: &str, module: &mut ModuleRoutes) {
+    let function = match call.child_by_field_name("function").filter(|f| f.kind() == "attribute") {
+        Some(function) => function,
+        None => return,
+    };
+    let router = match (function.child_by_field_name("object"), function.child_by_field_name("attribute")) {
+        (Some(router), Some(attribute)) if text(attribute, content) == "register" => router,
+        _ => return,
+    };
+
+    let args = arguments(call);
+    let prefix = match positional(&args, 0).or_else(|| keyword(&args, "prefix", content)).and_then(|p| string_value(p, content)) {
+        Some(prefix) => prefix,
+        None => return,
+    };
+    let viewset = match positional(&args, 1).or_else(|| keyword(&args, "viewset", content)) {
+        Some(viewset) => text(viewset, content).to_string(),
+        None => return,
+    };
+
+    module.mounts.push(Mount {
+        parent: text(router, content).to_string(),
+        prefix: format!("/{}", prefix.trim_start_matches('^').trim_matches('/')),
+        target: viewset,
+    });
+}
+
+/// Module-level function views: DRF `@api_view([...])`, Django's
+/// `@require_http_methods([...])`/`@require_POST`, or any function taking
+/// `request` first, which serves GET plus every method it checks
+/// `request.method` against.
+fn function_view(statement: Node, function: Node, content: &str, module: &mut ModuleRoutes) {
+    let name = match function.child_by_field_name("name") {
+        Some(name) => text(name, content).to_string(),
+        None => return,
+    };
+
+    let mut methods = None;
+    let mut cursor = statement.walk();
+    for decorator in statement.named_children(&mut cursor).filter(|c| c.kind() == "decorator") {
+        let expression = match decorator.named_child(0) {
+            Some(expression) => expression,
+            None => continue,
+        };
+        let (callee, args) = match expression.kind() {
+            "call" => (expression.child_by_field_name("function").map(|f| text(f, content)).unwrap_or_default(), arguments(expression)),
+            _ => (text(expression, content), Vec::new()),
+        };
+        let declared = match callee.rsplit('.').next().unwrap_or_default() {
+            "api_view" | "require_http_methods" => match args.first() {
+                Some(list) => strings(*list, content),
+                None => vec!["GET".to_string()],
+            },
+            "require_GET" => vec!["GET".to_string()],
+            "require_POST" => vec!["POST".to_string()],
+            "require_safe" => vec!["GET".to_string(), "HEAD".to_string()],
+            _ => continue,
+        };
+        methods = Some(declared.iter().filter_map(|m| parse_http_method(m).ok()).collect::<Vec<_>>());
+    }
+
+    let methods = match methods {
+        Some(methods) => methods,
+        None if takes_request(function, content) => {
+            let mut methods = vec![HttpMethod::Get];
+            if let Some(body) = function.child_by_field_name("body") {
+                method_checks(body, content, &mut methods);
+            }
+            methods
+        }
+        None => return,
+    };
+
+    for method in methods {
+        module.endpoints.push(RoutedEndpoint {
+            owner: name.clone(),
+            endpoint: endpoint(method, String::new(), name.clone(), function.start_position()),
+        });
+    }
+}
+
+fn takes_request(function: Node, content: &str) -> bool {
+    let first = function.child_by_field_name("parameters").and_then(|p| p.named_child(0));
+    match first.map(|p| (p.kind(), p)) {
+        Some(("identifier", param)) => text(param, content) == "request",
+        Some(("typed_parameter", param)) => {
+            param.named_child(0).map(|n| text(n, content) == "request").unwrap_or(false)
+                && param.child_by_field_name("type").map(|t| text(t, content).contains("HttpRequest")).unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+/// `request.method == "POST"`, `request.method in ("PUT", "PATCH")`
+fn method_checks(node: Node, content: &str, methods: &mut Vec<HttpMethod>) {
+    if node.kind() == "comparison_operator" {
+        let mut cursor = node.walk();
+        let operands: Vec<Node> = node.named_children(&mut cursor).collect();
+        if operands.len() == 2 && text(operands[0], content) == "request.method" {
+            for method in strings(operands[1], content).iter().filter_map(|m| parse_http_method(m).ok()) {
+                if !methods.contains(&method) {
+                    methods.push(method);
+                }
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        method_checks(child, content, methods);
+    }
+}
+
+/// DRF `APIView` subclasses and generic views, Django generic views, and
+/// ViewSets. Plain `View` subclasses are handled with Flask's `MethodView`.
+fn class_view(class: Node, content: &str, module: &mut ModuleRoutes) {
+    let bases: Vec<String> = class.child_by_field_name("superclasses")
+        .map(|bases| arguments_of(bases).into_iter()
+            .map(|base| text(base, content).rsplit('.').next().unwrap_or_default().to_string())
+            .collect())
+        .unwrap_or_default();
+    let (name, body) = match (class.child_by_field_name("name"), class.child_by_field_name("body")) {
+        (Some(name), Some(body)) => (text(name, content).to_string(), body),
+        _ => return,
+    };
+
+    let methods = class_methods(body, content);
+    let push = |module: &mut ModuleRoutes, method: HttpMethod, path: String, handler: &str, node: Node| {
+        module.endpoints.push(RoutedEndpoint {
+            owner: name.clone(),
+            endpoint: endpoint(method, path, format!("{}.{}", name, handler), node.start_position()),
+        });
+    };
+
+    if bases.iter().any(|base| base.ends_with("ViewSet") || base.ends_with("ModelMixin")) {
+        let lookup = class_attribute(body, "lookup_url_kwarg", content)
+            .or_else(|| class_attribute(body, "lookup_field", content))
+            .unwrap_or_else(|| "pk".to_string());
+        let detail_path = format!("/<{}>", lookup);
+
+        for (action, method, detail) in ACTIONS {
+            let defined = methods.iter().find(|(name, _, _)| name == action);
+            if defined.is_none() && !bases.iter().any(|base| base_actions(base).contains(&action)) {
+                continue;
+            }
+            let path = if detail { detail_path.clone() } else { String::new() };
+            let node = defined.map(|(_, node, _)| *node).unwrap_or(class);
+            push(module, method, path, action, node);
+        }
+
+        // `@action(detail=True, methods=["post"], url_path="set-password")`
+        for (method_name, node, decorators) in &methods {
+            let args = match decorators.iter().find(|(callee, _)| callee == "action") {
+                Some((_, args)) => args,
+                None => continue,
+            };
+            let detail = keyword(args, "detail", content).map(|d| text(d, content) == "True").unwrap_or(false);
+            let url_path = keyword(args, "url_path", content)
+                .and_then(|p| string_value(p, content))
+                .unwrap_or_else(|| method_name.clone());
+            let path = if detail { format!("{}/{}", detail_path, url_path) } else { format!("/{}", url_path) };
+            let verbs = keyword(args, "methods", content)
+                .map(|m| strings(m, content))
+                .unwrap_or_else(|| vec!["GET".to_string()]);
+            for verb in verbs.iter().filter_map(|m| parse_http_method(m).ok()) {
+                push(module, verb, path.clone(), method_name, *node);
+            }
+        }
+        return;
+    }
+
+    let generic: Vec<HttpMethod> = bases.iter().flat_map(|base| generic_methods(base)).collect();
+    if generic.is_empty() && !bases.iter().any(|base| base == "APIView") {
+        return;
+    }
+
+    let mut served = Vec::new();
+    for (method_name, node, _) in &methods {
+        if let Ok(method) = parse_http_method(method_name) {
+            push(module, method.clone(), String::new(), method_name, *node);
+            served.push(method);
+        }
+    }
+    for method in generic {
+        if !served.contains(&method) {
+            let handler = format!("{:?}", method).to_lowercase();
+            push(module, method.clone(), String::new(), &handler, class);
+            served.push(method);
+        }
+    }
+}
+
+type ClassMethod<'t> = (String, Node<'t>, Vec<(String, Vec<Node<'t>>)>);
+
+/// Methods defined in a class body with their decorators (callee name, args).
+fn class_methods<'t>(body: Node<'t>, content: &str) -> Vec<ClassMethod<'t>> {
+    let mut methods = Vec::new();
+    let mut cursor = body.walk();
+    for statement in body.named_children(&mut cursor) {
+        let (function, decorators) = match statement.kind() {
+            "function_definition" => (statement, Vec::new()),
+            "decorated_definition" => {
+                let function = match statement.child_by_field_name("definition") {
+                    Some(function) if function.kind() == "function_definition" => function,
+                    _ => continue,
+                };
+                let mut cursor = statement.walk();
+                let decorators = statement.named_children(&mut cursor)
+                    .filter(|c| c.kind() == "decorator")
+                    .filter_map(|d| d.named_child(0))
+                    .map(|expression| match expression.kind() {
+                        "call" => (
+                            expression.child_by_field_name("function").map(|f| text(f, content)).unwrap_or_default(),
+                            arguments(expression),
+                        ),
+                        _ => (text(expression, content), Vec::new()),
+                    })
+                    .map(|(callee, args)| (callee.rsplit('.').next().unwrap_or_default().to_string(), args))
+                    .collect();
+                (function, decorators)
+            }
+            _ => continue,
+        };
+        if let Some(name) = function.child_by_field_name("name") {
+            methods.push((text(name, content).to_string(), name, decorators));
+        }
+    }
+    methods
+}
+
+/// A string class attribute (`lookup_field = "username"`).
+fn class_attribute(body: Node, name: &str, content: &str) -> Option<String> {
+    let mut cursor = body.walk();
+    let statements: Vec<Node> = body.named_children(&mut cursor).collect();
+    statements.into_iter().find_map(|statement| {
+        let assignment = statement.named_child(0).filter(|a| a.kind() == "assignment")?;
+        let left = assignment.child_by_field_name("left")?;
+        if text(left, content) != name {
+            return None;
+        }
+        string_value(assignment.child_by_field_name("right")?, content)
+    })
+}
+
+/// Actions a ViewSet base class or mixin provides.
+fn base_actions(base: &str) -> &'static [&'static str] {
+    match base {
+        "ModelViewSet" => &["list", "create", "retrieve", "update", "partial_update", "destroy"],
+        "ReadOnlyModelViewSet" => &["list", "retrieve"],
+        "ListModelMixin" => &["list"],
+        "CreateModelMixin" => &["create"],
+        "RetrieveModelMixin" => &["retrieve"],
+        "UpdateModelMixin" => &["update", "partial_update"],
+        "DestroyModelMixin" => &["destroy"],
+        _ => &[],
+    }
+}
+
+/// Methods a generic view serves without defining them: DRF
+/// `ListCreateAPIView` and friends by the parts of their name, and Django's
+/// display and editing views.
+fn generic_methods(base: &str) -> Vec<HttpMethod> {
+    match base {
+        "TemplateView" | "ListView" | "DetailView" | "RedirectView" => vec![HttpMethod::Get],
+        "FormView" | "CreateView" | "UpdateView" | "DeleteView" => vec![HttpMethod::Get, HttpMethod::Post],
+        base if base.ends_with("APIView") && base != "APIView" && base != "GenericAPIView" => {
+            let mut methods = Vec::new();
+            if base.contains("List") || base.contains("Retrieve") {
+                methods.push(HttpMethod::Get);
+            }
+            if base.contains("Create") {
+                methods.push(HttpMethod::Post);
+            }
+            if base.contains("Update") {
+                methods.extend([HttpMethod::Put, HttpMethod::Patch]);
+            }
+            if base.contains("Destroy") {
+                methods.push(HttpMethod::Delete);
+            }
+            methods
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// `{"get": "list", "post": "create"}` passed to `ViewSet.as_view`
+fn action_map(dictionary: Node, content: &str) -> Vec<(HttpMethod, String)> {
+    let mut cursor = dictionary.walk();
+    let pairs: Vec<Node> = dictionary.named_children(&mut cursor).filter(|p| p.kind() == "pair").collect();
+    pairs.into_iter()
+        .filter_map(|pair| {
+            let method = parse_http_method(&string_value(pair.child_by_field_name("key")?, content)?).ok()?;
+            let action = string_value(pair.child_by_field_name("value")?, content)?;
+            Some((method, action))
+        })
+        .collect()
+}
+
+fn strings(node: Node, content: &str) -> Vec<String> {
+    match node.kind() {
+        "list" | "tuple" | "set" => list_items(node).into_iter().filter_map(|item| string_value(item, content)).collect(),
+        _ => string_value(node, content).into_iter().collect(),
+    }
+}
+
+fn text<'a>(node: Node, content: &'a str) -> &'a str {
+    &content[node.byte_range()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HttpMethod, LanguageParser};
+    use crate::languages::python::PythonParser;
+
+    fn routes(code: &str) -> Vec<(String, HttpMethod, String)> {
+        let mut routes: Vec<_> = PythonParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.path, e.method, e.handler))
+            .collect();
+        routes.sort_by(|a, b| a.0.cmp(&b.0));
+        routes
+    }
+
+    #[test]
+    fn test_parse_urlpatterns_with_function_and_class_views() {
+        let code = r#"
+@api_view(["GET", "POST"])
+def article_list(request):
+    return Response([])
+
+def contact(request):
+    if request.method == "POST":
+        return redirect("/")
+    return render(request, "contact.html")
+
+class ArticleDetail(generics.RetrieveUpdateDestroyAPIView):
+    queryset = Article.objects.all()
+
+urlpatterns = [
+    path("articles/", article_list),
+    re_path(r"^articles/(?P<pk>[0-9]+)/$", ArticleDetail.as_view()),
+    path("", include([path("contact/", contact)])),
+]
+"#;
+
+        assert_eq!(routes(code), vec![
+            ("/articles".to_string(), HttpMethod::Get, "article_list".to_string()),
+            ("/articles".to_string(), HttpMethod::Post, "article_list".to_string()),
+            ("/articles/(?P<pk>[0-9]+)".to_string(), HttpMethod::Get, "ArticleDetail.get".to_string()),
+            ("/articles/(?P<pk>[0-9]+)".to_string(), HttpMethod::Put, "ArticleDetail.put".to_string()),
+            ("/articles/(?P<pk>[0-9]+)".to_string(), HttpMethod::Patch, "ArticleDetail.patch".to_string()),
+            ("/articles/(?P<pk>[0-9]+)".to_string(), HttpMethod::Delete, "ArticleDetail.delete".to_string()),
+            ("/contact".to_string(), HttpMethod::Get, "contact".to_string()),
+            ("/contact".to_string(), HttpMethod::Post, "contact".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_expand_router_viewsets_and_actions() {
+        let code = r#"
+class UserViewSet(viewsets.ReadOnlyModelViewSet):
+    lookup_field = "username"
+
+    @action(detail=True, methods=["post"], url_path="set-password")
+    def set_password(self, request, username=None):
+        return Response()
+
+    @action(detail=False)
+    def recent(self, request):
+        return Response()
+
+router = DefaultRouter()
+router.register(r"users", UserViewSet, basename="user")
+
+urlpatterns = [
+    path("api/", include(router.urls)),
+]
+"#;
+
+        assert_eq!(routes(code), vec![
+            ("/api/users".to_string(), HttpMethod::Get, "UserViewSet.list".to_string()),
+            ("/api/users/<username>".to_string(), HttpMethod::Get, "UserViewSet.retrieve".to_string()),
+            ("/api/users/<username>/set-password".to_string(), HttpMethod::Post, "UserViewSet.set_password".to_string()),
+            ("/api/users/recent".to_string(), HttpMethod::Get, "UserViewSet.recent".to_string()),
+        ]);
+    }
+}
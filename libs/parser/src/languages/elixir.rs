@@ -0,0 +1,512 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, parse_http_method};
+use crate::params::describe_path;
+use crate::project::join_route;
+use anyhow::{Result, anyhow};
+use tree_sitter::{Node, Parser};
+
+/// Parser for Phoenix routers (`lib/my_app_web/router.ex`).
+///
+/// Routes are only read in files that `use` a router. `scope "/api",
+/// MyAppWeb do` blocks add their path and alias to the routes nested in
+/// them. `pipe_through` pipelines become the middleware of the rest of their
+/// scope, and `resources` expands to the standard actions.
+pub struct ElixirParser;
+
+const ALL_METHODS: [HttpMethod; 7] = [
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Delete,
+    HttpMethod::Patch,
+    HttpMethod::Head,
+    HttpMethod::Options,
+];
+
+const VERBS: [&str; 7] = ["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// The actions generated by `resources`, in `mix phx.routes` order.
+const PLURAL_ACTIONS: [(&str, HttpMethod, &str); 8] = [
+    ("index", HttpMethod::Get, ""),
+    ("edit", HttpMethod::Get, "/:id/edit"),
+    ("new", HttpMethod::Get, "/new"),
+    ("show", HttpMethod::Get, "/:id"),
+    ("create", HttpMethod::Post, ""),
+    ("update", HttpMethod::Patch, "/:id"),
+    ("update", HttpMethod::Put, "/:id"),
+    ("delete", HttpMethod::Delete, "/:id"),
+];
+
+/// `singleton: true` resources have no index and no id segment.
+const SINGLETON_ACTIONS: [(&str, HttpMethod, &str); 7] = [
+    ("edit", HttpMethod::Get, "/edit"),
+    ("new", HttpMethod::Get, "/new"),
+    ("show", HttpMethod::Get, ""),
+    ("create", HttpMethod::Post, ""),
+    ("update", HttpMethod::Patch, ""),
+    ("update", HttpMethod::Put, ""),
+    ("delete", HttpMethod::Delete, ""),
+];
+
+impl LanguageParser for ElixirParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_elixir::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse Elixir code"))?;
+
+        let mut walker = RouteWalker { content, endpoints: Vec::new() };
+        if walker.is_router(tree.root_node()) {
+            walker.visit(tree.root_node(), &mut RouteScope::default());
+        }
+        walker.endpoints.iter_mut().for_each(describe_path);
+        Ok(walker.endpoints)
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "ex" || extension == "exs"
+    }
+}
+
+/// A macro argument, reduced to the shapes routes use.
+#[derive(Debug, Clone)]
+enum Value {
+    Str(String),
+    Atom(String),
+    /// `MyAppWeb.Admin`
+    Alias(String),
+    List(Vec<Value>),
+    /// `only: [...]`
+    Named(String, Box<Value>),
+    Other,
+}
+
+/// Where routes declared in the current block end up.
+#[derive(Clone, Default)]
+struct RouteScope {
+    path: String,
+    /// Module alias prepended to controllers.
+    alias: String,
+    /// Pipelines from `pipe_through`.
+    pipelines: Vec<String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    endpoints: Vec<Endpoint>,
+}
+
+impl<'a> RouteWalker<'a> {
+    /// `use MyAppWeb, :router` or `use Phoenix.Router`.
+    fn is_router(&self, node: Node<'a>) -> bool {
+        if self.call_name(node) == Some("use") {
+            let args = self.arguments(node);
+            match (args.first(), args.get(1)) {
+                (Some(Value::Alias(alias)), _) if alias.ends_with("Phoenix.Router") => return true,
+                (Some(Value::Alias(_)), Some(Value::Atom(atom))) if atom == "router" => return true,
+                _ => {}
+            }
+        }
+        let mut cursor = node.walk();
+        let found = node.named_children(&mut cursor).any(|child| self.is_router(child));
+        found
+    }
+
+    /// Read routes under `node`. `pipe_through` adds to `scope` for the
+    /// rest of the enclosing block.
+    fn visit(&mut self, node: Node<'a>, scope: &mut RouteScope) {
+        if node.kind() == "call" && self.visit_call(node, scope) {
+            return;
+        }
+
+        let mut cursor = node.walk();
+        let children: Vec<Node<'a>> = node.named_children(&mut cursor).collect();
+        for child in children {
+            self.visit(child, scope);
+        }
+    }
+
+    /// Returns true when the call (and its block) has been handled.
+    fn visit_call(&mut self, node: Node<'a>, scope: &mut RouteScope) -> bool {
+        // `Mod.get(...)` is a function call, not the router macro
+        let name = match self.call_name(node) {
+            Some(name) => name,
+            None => return false,
+        };
+        let args = self.arguments(node);
+        let block = child_of_kind(node, "do_block");
+
+        match name {
+            // Plugs inside a pipeline aren't routes
+            "pipeline" => {}
+            "scope" => {
+                if let Some(block) = block {
+                    let path = named(&args, "path").or_else(|| positional(&args).find(|v| matches!(v, Value::Str(_))));
+                    let alias = named(&args, "alias").or_else(|| positional(&args).find(|v| matches!(v, Value::Alias(_))));
+                    let mut inner = scope.clone();
+                    if let Some(Value::Str(path)) = path {
+                        inner.path = join_route(&scope.path, path);
+                    }
+                    if let Some(Value::Alias(alias)) = alias {
+                        inner.alias = qualify(&scope.alias, alias);
+                    }
+                    self.visit(block, &mut inner);
+                }
+            }
+            "pipe_through" => {
+                scope.pipelines.extend(args.iter().flat_map(atoms));
+            }
+            "resources" => {
+                let nested = self.resources(node, &args, scope);
+                if let (Some(mut nested), Some(block)) = (nested, block) {
+                    self.visit(block, &mut nested);
+                }
+            }
+            "match" => {
+                let methods = match args.first() {
+                    Some(Value::Atom(verb)) if verb == "*" => ALL_METHODS.to_vec(),
+                    Some(verb) => atoms(verb).iter().filter_map(|v| parse_http_method(v).ok()).collect(),
+                    None => Vec::new(),
+                };
+                for method in methods {
+                    self.route(node, method, args.get(1..).unwrap_or_default(), scope);
+                }
+            }
+            // LiveView routes are mounted over GET
+            "live" => self.route(node, HttpMethod::Get, &args, scope),
+            verb if VERBS.contains(&verb) => {
+                if let Ok(method) = parse_http_method(verb) {
+                    self.route(node, method, &args, scope);
+                }
+            }
+            // Walk into other blocks (`defmodule ... do`)
+            _ => return false,
+        }
+        true
+    }
+
+    /// `get "/path", PageController, :action`
+    fn route(&mut self, node: Node<'a>, method: HttpMethod, args: &[Value], scope: &RouteScope) {
+        let mut positional = positional(args);
+        let (path, controller) = match (positional.next(), positional.next()) {
+            (Some(Value::Str(path)), Some(Value::Alias(controller))) => (path, controller),
+            _ => return,
+        };
+        let controller = qualify(&scope.alias, controller);
+        let handler = match positional.next() {
+            Some(Value::Atom(action)) => format!("{}.{}", controller, action),
+            _ => controller,
+        };
+        self.push(node, method, join_route(&scope.path, path), handler, scope);
+    }
+
+    /// Expand `resources "/users", UserController, only: [...]` and return the
+    /// scope for resources nested in its block.
+    fn resources(&mut self, node: Node<'a>, args: &[Value], scope: &RouteScope) -> Option<RouteScope> {
+        let mut positional = positional(args);
+        let (path, controller) = match (positional.next(), positional.next()) {
+            (Some(Value::Str(path)), Some(Value::Alias(controller))) => (path.clone(), controller.clone()),
+            _ => return None,
+        };
+        let singleton = matches!(named(args, "singleton"), Some(Value::Atom(v)) if v == "true");
+        let param = match named(args, "param") {
+            Some(Value::Str(param)) => param.clone(),
+            _ => "id".to_string(),
+        };
+        let only = named(args, "only").map(atoms);
+        let except = named(args, "except").map(atoms).unwrap_or_default();
+
+        let base = join_route(&scope.path, &path);
+        let handler = qualify(&scope.alias, &controller);
+        let actions: &[(&str, HttpMethod, &str)] = if singleton { &SINGLETON_ACTIONS } else { &PLURAL_ACTIONS };
+        for (action, method, suffix) in actions {
+            if only.as_ref().map(|only| !only.iter().any(|a| a == action)).unwrap_or(false)
+                || except.iter().any(|a| a == action)
+            {
+                continue;
+            }
+            let path = format!("{}{}", base, suffix.replace(":id", &format!(":{}", param)));
+            self.push(node, method.clone(), path, format!("{}.{}", handler, action), scope);
+        }
+
+        // Nested resources take the parent id as `:user_id`, named after `as:` or the controller
+        let resource = match named(args, "as") {
+            Some(Value::Atom(name)) => name.clone(),
+            _ => underscore(controller.rsplit('.').next().unwrap_or_default().trim_end_matches("Controller")),
+        };
+        Some(RouteScope {
+            path: if singleton { base } else { format!("{}/:{}_id", base, resource) },
+            ..scope.clone()
+        })
+    }
+
+    fn push(&mut self, node: Node<'a>, method: HttpMethod, path: String, handler: String, scope: &RouteScope) {
+        let start = node.start_position();
+        self.endpoints.push(Endpoint {
+            method,
+            path: if path.starts_with('/') { path } else { format!("/{}", path) },
+            handler,
+            line: start.row + 1,
+            column: start.column + 1,
+            documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: scope.pipelines.clone(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        });
+    }
+
+    /// The macro name of a local call: `get` in `get "/", ...`.
+    fn call_name(&self, node: Node<'a>) -> Option<&'a str> {
+        if node.kind() != "call" {
+            return None;
+        }
+        node.child_by_field_name("target")
+            .filter(|target| target.kind() == "identifier")
+            .map(|target| self.text(target))
+    }
+
+    /// Macro arguments, with or without parentheses.
+    fn arguments(&self, call: Node<'a>) -> Vec<Value> {
+        child_of_kind(call, "arguments").map(|args| self.items(args)).unwrap_or_default()
+    }
+
+    /// Values under `node`, with trailing `key: value` pairs as [`Value::Named`].
+    fn items(&self, node: Node<'a>) -> Vec<Value> {
+        let mut values = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if child.kind() != "keywords" {
+                values.push(self.value(child));
+                continue;
+            }
+            let mut pairs = child.walk();
+            for pair in child.named_children(&mut pairs) {
+                if let (Some(key), Some(value)) = (pair.child_by_field_name("key"), pair.child_by_field_name("value")) {
+                    let key = self.text(key).trim_end().trim_end_matches(':');
+                    values.push(Value::Named(key.to_string(), Box::new(self.value(value))));
+                }
+            }
+        }
+        values
+    }
+
+    /// Reduce one expression to a [`Value`]. Anything that isn't a literal,
+    /// alias or list is [`Value::Other`].
+    fn value(&self, node: Node<'a>) -> Value {
+        match node.kind() {
+            "string" => self.string_literal(node).map(Value::Str).unwrap_or(Value::Other),
+            // `~p"/users"` is a verified route path; other sigils are skipped
+            "sigil" => match child_of_kind(node, "sigil_name") {
+                Some(name) if self.text(name) == "p" => self.string_literal(node).map(Value::Str).unwrap_or(Value::Other),
+                _ => Value::Other,
+            },
+            "atom" => Value::Atom(self.text(node).trim_start_matches(':').to_string()),
+            "quoted_atom" => self.string_literal(node).map(Value::Atom).unwrap_or(Value::Other),
+            // `true`/`false` are atoms
+            "boolean" => Value::Atom(self.text(node).to_string()),
+            "alias" => Value::Alias(self.text(node).split_whitespace().collect()),
+            "list" => Value::List(self.items(node)),
+            _ => Value::Other,
+        }
+    }
+
+    /// The text of a string, sigil or quoted atom; `None` when it has
+    /// `#{}` interpolation.
+    fn string_literal(&self, node: Node<'a>) -> Option<String> {
+        let mut text = String::new();
+        let mut cursor = node.walk();
+        for part in node.named_children(&mut cursor) {
+            match part.kind() {
+                "quoted_content" => text.push_str(self.text(part)),
+                "escape_sequence" => text.extend(self.text(part).chars().nth(1)),
+                "interpolation" => return None,
+                _ => {}
+            }
+        }
+        Some(text)
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+fn child_of_kind<'t>(node: Node<'t>, kind: &str) -> Option<Node<'t>> {
+    let mut cursor = node.walk();
+    let child = node.named_children(&mut cursor).find(|child| child.kind() == kind);
+    child
+}
+
+fn named<'v>(args: &'v [Value], name: &str) -> Option<&'v Value> {
+    args.iter().find_map(|arg| match arg {
+        Value::Named(key, value) if key == name => Some(value.as_ref()),
+        Value::List(items) => named(items, name),
+        _ => None,
+    })
+}
+
+fn positional(args: &[Value]) -> impl Iterator<Item = &Value> {
+    args.iter().filter(|arg| !matches!(arg, Value::Named(..) | Value::List(_)))
+}
+
+/// Atoms of `:api` or `[:api, :auth]`.
+fn atoms(value: &Value) -> Vec<String> {
+    match value {
+        Value::Atom(atom) => vec![atom.clone()],
+        Value::List(items) => items.iter().flat_map(atoms).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn qualify(alias: &str, module: &str) -> String {
+    if alias.is_empty() { module.to_string() } else { format!("{}.{}", alias, module) }
+}
+
+/// `UserProfile` -> `user_profile`
+fn underscore(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(code: &str) -> Vec<(HttpMethod, String, String)> {
+        ElixirParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path, e.handler))
+            .collect()
+    }
+
+    #[test]
+    fn test_supports_elixir_extensions() {
+        assert!(ElixirParser.supports_extension("ex"));
+        assert!(ElixirParser.supports_extension("exs"));
+        assert!(!ElixirParser.supports_extension("erl"));
+    }
+
+    #[test]
+    fn test_parse_scopes_and_pipelines() {
+        let code = r#"
+defmodule MyAppWeb.Router do
+  use MyAppWeb, :router
+
+  pipeline :api do
+    plug :accepts, ["json"]
+    get "/not-a-route", NopeController, :index
+  end
+
+  pipeline :auth do
+    plug MyAppWeb.Plugs.RequireUser
+  end
+
+  scope "/", MyAppWeb do
+    get "/", PageController, :home
+  end
+
+  scope "/api", MyAppWeb do
+    pipe_through :api
+
+    get "/health", HealthController, :show
+    match :*, "/echo", EchoController, :echo
+
+    scope "/admin", Admin, as: :admin do
+      pipe_through [:auth]
+      post("/reindex", SearchController, :reindex)
+      live "/dashboard", DashboardLive, :index
+    end
+  end
+end
+"#;
+
+        let endpoints = ElixirParser.parse(code).unwrap();
+        let summary: Vec<_> = endpoints.iter()
+            .filter(|e| e.path != "/api/echo")
+            .map(|e| (e.method.clone(), e.path.as_str(), e.handler.as_str(), e.middleware.join(",")))
+            .collect();
+        assert_eq!(summary, vec![
+            (HttpMethod::Get, "/", "MyAppWeb.PageController.home", String::new()),
+            (HttpMethod::Get, "/api/health", "MyAppWeb.HealthController.show", "api".to_string()),
+            (HttpMethod::Post, "/api/admin/reindex", "MyAppWeb.Admin.SearchController.reindex", "api,auth".to_string()),
+            (HttpMethod::Get, "/api/admin/dashboard", "MyAppWeb.Admin.DashboardLive.index", "api,auth".to_string()),
+        ]);
+        assert_eq!(endpoints.iter().filter(|e| e.path == "/api/echo").count(), 7);
+        assert_eq!((endpoints[0].line, endpoints[0].column), (15, 5));
+    }
+
+    #[test]
+    fn test_expands_resources() {
+        let code = r#"
+defmodule Blog.Router do
+  use Phoenix.Router
+
+  scope "/", Blog do
+    resources "/users", UserController, only: [:index, :show] do
+      resources "/posts", PostController, except: [:new, :edit, :update]
+    end
+    resources "/account", AccountController, singleton: true, only: [:show]
+    resources "/tags", TagController, param: "slug", only: [:show]
+  end
+end
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/users".to_string(), "Blog.UserController.index".to_string()),
+            (HttpMethod::Get, "/users/:id".to_string(), "Blog.UserController.show".to_string()),
+            (HttpMethod::Get, "/users/:user_id/posts".to_string(), "Blog.PostController.index".to_string()),
+            (HttpMethod::Get, "/users/:user_id/posts/:id".to_string(), "Blog.PostController.show".to_string()),
+            (HttpMethod::Post, "/users/:user_id/posts".to_string(), "Blog.PostController.create".to_string()),
+            (HttpMethod::Delete, "/users/:user_id/posts/:id".to_string(), "Blog.PostController.delete".to_string()),
+            (HttpMethod::Get, "/account".to_string(), "Blog.AccountController.show".to_string()),
+            (HttpMethod::Get, "/tags/:slug".to_string(), "Blog.TagController.show".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_ignores_non_router_modules() {
+        let code = r#"
+defmodule MyAppWeb.PageControllerTest do
+  use MyAppWeb.ConnCase
+
+  test "GET /", %{conn: conn} do
+    conn = get(conn, ~p"/")
+    assert html_response(conn, 200)
+  end
+end
+"#;
+
+        assert!(ElixirParser.parse(code).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sigil_and_interpolated_paths() {
+        let code = r#"
+defmodule MyAppWeb.Router do
+  use MyAppWeb, :router
+
+  scope ~p"/api", MyAppWeb do
+    get ~p"/users/me", UserController, :me
+    get "/users/#{@prefix}/list", UserController, :index
+    get ~s(/raw), RawController, :raw
+    post("/users", UserController, :create, as: :"new user")
+  end
+end
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/api/users/me".to_string(), "MyAppWeb.UserController.me".to_string()),
+            (HttpMethod::Post, "/api/users".to_string(), "MyAppWeb.UserController.create".to_string()),
+        ]);
+    }
+}
@@ -0,0 +1,451 @@
+//! File-system routing: endpoints whose path comes from where the file lives
+//! rather than from a registration call (Next.js, SvelteKit, Remix, Nuxt).
+//! Shared by the JavaScript and TypeScript parsers.
+
+use crate::{Endpoint, HttpMethod, parse_http_method};
+use crate::languages::javascript::{string_value, text};
+use crate::project::{ModuleRoutes, RoutedEndpoint};
+use std::path::Path;
+use tree_sitter::Node;
+
+const SOURCE_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "ts", "tsx"];
+
+/// Add the file-based routes declared by the module at `path`.
+///
+/// A path can fit more than one convention (`app/routes/x/route.ts` is both
+/// a Next.js route handler and a Remix route module); the first one whose
+/// handlers the file actually exports wins.
+pub(crate) fn collect(path: &Path, root: Node, content: &str, module: &mut ModuleRoutes) {
+    for (convention, route) in conventions(path) {
+        let handlers = match convention {
+            Convention::NextApp | Convention::SvelteKit => method_exports(root, content),
+            Convention::NextPages => default_handler(root, content, None),
+            Convention::Remix => remix_exports(root, content),
+            Convention::Nuxt(method) => default_handler(root, content, method),
+        };
+        if handlers.is_empty() {
+            continue;
+        }
+        for (method, handler, node) in handlers {
+            module.endpoints.push(file_endpoint(method, &route, handler, node));
+        }
+        return;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Convention {
+    /// Next.js `pages/api/**`: one default-exported handler per file
+    NextPages,
+    /// Next.js `app/**/route.ts`: one exported function per HTTP method
+    NextApp,
+    /// SvelteKit `src/routes/**/+server.ts`: exported `GET`, `POST`, ...
+    SvelteKit,
+    /// Remix `app/routes/*.tsx`: `loader` (GET) and `action` (POST)
+    Remix,
+    /// Nuxt/Nitro `server/api/**`: default export, method from a
+    /// `.get.ts`-style suffix when there is one
+    Nuxt(Option<HttpMethod>),
+}
+
+/// Every convention `path` fits, with the URL it maps to. Dynamic segments
+/// (`[id]`, `[...slug]`, `$id`) are kept as the framework writes them.
+fn conventions(path: &Path) -> Vec<(Convention, String)> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+    let (stem, parent) = match (path.file_stem().and_then(|s| s.to_str()), path.parent()) {
+        (Some(stem), Some(parent)) if SOURCE_EXTENSIONS.contains(&extension) => (stem, parent),
+        _ => return Vec::new(),
+    };
+    let components: Vec<&str> = parent
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+
+    // The innermost routing root wins everywhere, so a project nested under a
+    // directory that happens to be called `app` or `routes` still resolves
+    [
+        sveltekit_route(stem, &components),
+        nuxt_route(stem, &components),
+        remix_route(stem, &components),
+        next_route(stem, &components),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// `app/api/users/[id]/route.ts` -> `/api/users/[id]`,
+/// `src/pages/api/posts/index.ts` -> `/api/posts`. Route groups `(group)`
+/// and parallel route slots `@slot` don't appear in the URL; `_private`
+/// folders and files are not routable.
+fn next_route(stem: &str, components: &[&str]) -> Option<(Convention, String)> {
+    let (convention, segments) = if stem == "route" {
+        let root = components.iter().rposition(|c| *c == "app")?;
+        (Convention::NextApp, components[root + 1..].to_vec())
+    } else {
+        let root = components.windows(2).rposition(|w| w == ["pages", "api"])?;
+        let mut segments = components[root + 1..].to_vec();
+        if stem != "index" {
+            segments.push(stem);
+        }
+        (Convention::NextPages, segments)
+    };
+
+    if segments.iter().any(|s| s.starts_with('_')) {
+        return None;
+    }
+    let segments: Vec<&str> = segments.into_iter()
+        .filter(|s| !is_group(s) && !s.starts_with('@'))
+        .collect();
+
+    Some((convention, format!("/{}", segments.join("/"))))
+}
+
+/// `src/routes/(app)/api/posts/[slug]/+server.ts` -> `/api/posts/[slug]`
+fn sveltekit_route(stem: &str, components: &[&str]) -> Option<(Convention, String)> {
+    if stem != "+server" {
+        return None;
+    }
+    let root = components.iter().rposition(|c| *c == "routes")?;
+    let segments: Vec<&str> = components[root + 1..].iter()
+        .copied()
+        .filter(|s| !is_group(s))
+        .collect();
+
+    Some((Convention::SvelteKit, format!("/{}", segments.join("/"))))
+}
+
+/// Remix flat routes: dots separate segments, so `app/routes/api.users.$id.ts`
+/// and the folder route `app/routes/api.users.$id/route.ts` are both
+/// `/api/users/$id`. `_index` and `_layout.` prefixes add no segment, a
+/// trailing `_` only opts out of layout nesting, and `[.]` escapes a dot.
+fn remix_route(stem: &str, components: &[&str]) -> Option<(Convention, String)> {
+    let root = components.windows(2).rposition(|w| w == ["app", "routes"])?;
+    let name = match &components[root + 2..] {
+        [] => stem,
+        [folder] if stem == "route" => *folder,
+        _ => return None,
+    };
+
+    let segments: Vec<String> = remix_segments(name)
+        .into_iter()
+        .filter(|s| !s.starts_with('_'))
+        .map(|s| match s.strip_suffix('_') {
+            Some(stripped) if !stripped.is_empty() => stripped.to_string(),
+            _ => s,
+        })
+        .collect();
+
+    Some((Convention::Remix, format!("/{}", segments.join("/"))))
+}
+
+/// Split a flat route name on `.`, treating `[...]` as literal text.
+fn remix_segments(name: &str) -> Vec<String> {
+    let mut segments = vec![String::new()];
+    let mut escaped = false;
+    for c in name.chars() {
+        match c {
+            '[' if !escaped => escaped = true,
+            ']' if escaped => escaped = false,
+            '.' if !escaped => segments.push(String::new()),
+            _ => segments.last_mut().unwrap().push(c),
+        }
+    }
+    segments.retain(|s| !s.is_empty());
+    segments
+}
+
+/// `server/api/users/[id].get.ts` -> GET `/api/users/[id]`,
+/// `server/routes/sitemap.xml.ts` -> `/sitemap.xml`.
+fn nuxt_route(stem: &str, components: &[&str]) -> Option<(Convention, String)> {
+    let root = components.windows(2).rposition(|w| w == ["server", "api"] || w == ["server", "routes"])?;
+    let prefix = if components[root + 1] == "api" { "/api" } else { "" };
+
+    let (name, method) = match stem.rsplit_once('.') {
+        Some((name, suffix)) if !suffix.chars().any(|c| c.is_uppercase()) => match parse_http_method(suffix) {
+            Ok(method) => (name, Some(method)),
+            Err(_) => (stem, None),
+        },
+        _ => (stem, None),
+    };
+
+    let mut segments = components[root + 2..].to_vec();
+    if name != "index" {
+        segments.push(name);
+    }
+    if segments.iter().any(|s| s.starts_with('_')) {
+        return None;
+    }
+
+    let path = format!("{}/{}", prefix, segments.join("/"));
+    let path = if path.len() > 1 { path.trim_end_matches('/').to_string() } else { path };
+    Some((Convention::Nuxt(method), path))
+}
+
+fn is_group(segment: &str) -> bool {
+    segment.starts_with('(') && segment.ends_with(')')
+}
+
+/// Names exported by the module as (exported name, local name, node):
+/// `export async function GET()`, `export const POST = ...`,
+/// `export { handler as GET, handler as POST }`.
+fn named_exports<'a>(root: Node<'a>, content: &str) -> Vec<(String, String, Node<'a>)> {
+    let mut exports = Vec::new();
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor).filter(|n| n.kind() == "export_statement") {
+        if let Some(declaration) = statement.child_by_field_name("declaration") {
+            let mut names = Vec::new();
+            match declaration.kind() {
+                "function_declaration" | "generator_function_declaration" => {
+                    names.extend(declaration.child_by_field_name("name"));
+                }
+                "lexical_declaration" | "variable_declaration" => {
+                    let mut inner = declaration.walk();
+                    for declarator in declaration.named_children(&mut inner) {
+                        names.extend(declarator.child_by_field_name("name").filter(|n| n.kind() == "identifier"));
+                    }
+                }
+                _ => {}
+            }
+            for name in names {
+                let name = text(name, content).to_string();
+                exports.push((name.clone(), name, statement));
+            }
+            continue;
+        }
+
+        let mut inner = statement.walk();
+        let clause = statement.named_children(&mut inner).find(|n| n.kind() == "export_clause");
+        if let Some(clause) = clause {
+            let mut inner = clause.walk();
+            for specifier in clause.named_children(&mut inner) {
+                let name = match specifier.child_by_field_name("name") {
+                    Some(name) => text(name, content),
+                    None => continue,
+                };
+                let exported = specifier.child_by_field_name("alias")
+                    .map(|alias| text(alias, content))
+                    .unwrap_or(name);
+                exports.push((exported.to_string(), name.to_string(), specifier));
+            }
+        }
+    }
+
+    exports
+}
+
+/// Route handlers exported under the upper-case method name.
+fn method_exports<'a>(root: Node<'a>, content: &str) -> Vec<(HttpMethod, String, Node<'a>)> {
+    named_exports(root, content)
+        .into_iter()
+        .filter(|(exported, _, _)| !exported.chars().any(|c| c.is_lowercase()))
+        .filter_map(|(exported, local, node)| Some((parse_http_method(&exported).ok()?, local, node)))
+        .collect()
+}
+
+/// A Remix route module serves GET through its `loader` and form
+/// submissions (reported as POST) through its `action`.
+fn remix_exports<'a>(root: Node<'a>, content: &str) -> Vec<(HttpMethod, String, Node<'a>)> {
+    named_exports(root, content)
+        .into_iter()
+        .filter_map(|(exported, local, node)| match exported.as_str() {
+            "loader" => Some((HttpMethod::Get, local, node)),
+            "action" => Some((HttpMethod::Post, local, node)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The default-exported handler, reported for `method` when the file name
+/// fixes it, else once for every method the handler checks the request
+/// method against (GET when it doesn't check).
+fn default_handler<'a>(root: Node<'a>, content: &str, method: Option<HttpMethod>) -> Vec<(HttpMethod, String, Node<'a>)> {
+    let mut cursor = root.walk();
+    let export = root.named_children(&mut cursor).find(|statement| {
+        let mut inner = statement.walk();
+        statement.kind() == "export_statement"
+            && statement.children(&mut inner).any(|c| c.kind() == "default")
+    });
+    let export = match export {
+        Some(export) => export,
+        None => return Vec::new(),
+    };
+
+    let handler = export.child_by_field_name("declaration")
+        .or_else(|| export.child_by_field_name("value"))
+        .and_then(|value| match value.kind() {
+            "identifier" => Some(value),
+            _ => value.child_by_field_name("name"),
+        })
+        .map(|name| text(name, content).to_string())
+        .unwrap_or_else(|| "default".to_string());
+
+    let methods = match method {
+        Some(method) => vec![method],
+        None => {
+            let mut methods = Vec::new();
+            collect_method_checks(root, content, &mut methods);
+            if methods.is_empty() {
+                methods.push(HttpMethod::Get);
+            }
+            methods
+        }
+    };
+
+    methods.into_iter()
+        .map(|method| (method, handler.clone(), export))
+        .collect()
+}
+
+/// Methods the request method is compared against:
+/// `req.method === 'POST'`, `switch (req.method) { case 'GET': }`,
+/// `['PUT', 'PATCH'].includes(method)`, and h3's `isMethod(event, 'POST')` /
+/// `assertMethod(event, ['GET', 'HEAD'])`.
+fn collect_method_checks(node: Node, content: &str, methods: &mut Vec<HttpMethod>) {
+    let mut candidates = Vec::new();
+    match node.kind() {
+        "binary_expression" => {
+            if let (Some(left), Some(right)) = (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+                if is_method_reference(left, content) {
+                    candidates.push(right);
+                } else if is_method_reference(right, content) {
+                    candidates.push(left);
+                }
+            }
+        }
+        "switch_statement" => {
+            let value = node.child_by_field_name("value");
+            if value.map(|v| is_method_reference(v, content)).unwrap_or(false) {
+                if let Some(body) = node.child_by_field_name("body") {
+                    let mut cursor = body.walk();
+                    for case in body.named_children(&mut cursor).filter(|c| c.kind() == "switch_case") {
+                        candidates.extend(case.child_by_field_name("value"));
+                    }
+                }
+            }
+        }
+        "call_expression" => {
+            let function = node.child_by_field_name("function");
+            let arguments = node.child_by_field_name("arguments");
+            if let (Some(function), Some(arguments)) = (function, arguments) {
+                let mut cursor = arguments.walk();
+                let args: Vec<Node> = arguments.named_children(&mut cursor).collect();
+
+                if matches!(text(function, content), "isMethod" | "assertMethod") {
+                    if let Some(allowed) = args.get(1) {
+                        candidates.push(*allowed);
+                        let mut cursor = allowed.walk();
+                        candidates.extend(allowed.named_children(&mut cursor).filter(|_| allowed.kind() == "array"));
+                    }
+                }
+
+                let property = function.child_by_field_name("property").map(|p| text(p, content));
+                if property == Some("includes") && args.iter().any(|a| is_method_reference(*a, content)) {
+                    if let Some(object) = function.child_by_field_name("object").filter(|o| o.kind() == "array") {
+                        let mut cursor = object.walk();
+                        candidates.extend(object.named_children(&mut cursor));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    for candidate in candidates {
+        let method = string_value(candidate, content).and_then(|m| parse_http_method(&m).ok());
+        if let Some(method) = method {
+            if !methods.contains(&method) {
+                methods.push(method);
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_method_checks(child, content, methods);
+    }
+}
+
+fn is_method_reference(node: Node, content: &str) -> bool {
+    match node.kind() {
+        "identifier" => text(node, content) == "method",
+        "member_expression" => node.child_by_field_name("property")
+            .map(|p| text(p, content) == "method")
+            .unwrap_or(false),
+        "call_expression" => node.child_by_field_name("function")
+            .map(|f| text(f, content) == "getMethod")
+            .unwrap_or(false),
+        "parenthesized_expression" => node.named_child(0)
+            .map(|inner| is_method_reference(inner, content))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn file_endpoint(method: HttpMethod, path: &str, handler: String, node: Node) -> RoutedEndpoint {
+    let pos = node.start_position();
+    RoutedEndpoint {
+        owner: String::new(),
+        endpoint: Endpoint {
+            method,
+            path: path.to_string(),
+            handler,
+            line: pos.row + 1,
+            column: pos.column + 1,
+            documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(path: &str) -> Option<(Convention, String)> {
+        conventions(Path::new(path)).into_iter().next()
+    }
+
+    fn url(convention: Convention, path: &str) -> Option<(Convention, String)> {
+        Some((convention, path.to_string()))
+    }
+
+    #[test]
+    fn test_next_route_paths() {
+        assert_eq!(route("web/app/api/users/[id]/route.ts"), url(Convention::NextApp, "/api/users/[id]"));
+        assert_eq!(route("src/app/(shop)/@modal/api/cart/route.js"), url(Convention::NextApp, "/api/cart"));
+        assert_eq!(route("app/docs/[[...slug]]/route.ts"), url(Convention::NextApp, "/docs/[[...slug]]"));
+        assert_eq!(route("pages/api/posts/index.ts"), url(Convention::NextPages, "/api/posts"));
+        assert_eq!(route("src/pages/api/files/[...path].js"), url(Convention::NextPages, "/api/files/[...path]"));
+        assert_eq!(route("pages/api/_utils/db.ts"), None);
+        assert_eq!(route("app/_lib/route.ts"), None);
+        assert_eq!(route("pages/about.tsx"), None);
+        assert_eq!(route("app/users/page.tsx"), None);
+    }
+
+    #[test]
+    fn test_sveltekit_remix_and_nuxt_paths() {
+        assert_eq!(route("src/routes/(api)/posts/[slug=word]/+server.ts"), url(Convention::SvelteKit, "/posts/[slug=word]"));
+        assert_eq!(route("src/routes/+server.js"), url(Convention::SvelteKit, "/"));
+        assert_eq!(route("src/routes/+page.server.ts"), None);
+
+        assert_eq!(route("app/routes/api.users.$id.ts"), url(Convention::Remix, "/api/users/$id"));
+        assert_eq!(route("app/routes/_auth.login.tsx"), url(Convention::Remix, "/login"));
+        assert_eq!(route("app/routes/notes_.$id.edit/route.tsx"), url(Convention::Remix, "/notes/$id/edit"));
+        assert_eq!(route("app/routes/sitemap[.]xml.ts"), url(Convention::Remix, "/sitemap.xml"));
+        assert_eq!(route("app/routes/_index.tsx"), url(Convention::Remix, "/"));
+        assert_eq!(route("app/routes/notes/helpers.ts"), None);
+
+        assert_eq!(route("server/api/users/[id].get.ts"), url(Convention::Nuxt(Some(HttpMethod::Get)), "/api/users/[id]"));
+        assert_eq!(route("server/api/todos/index.post.ts"), url(Convention::Nuxt(Some(HttpMethod::Post)), "/api/todos"));
+        assert_eq!(route("server/api/index.ts"), url(Convention::Nuxt(None), "/api"));
+        assert_eq!(route("server/routes/sitemap.xml.ts"), url(Convention::Nuxt(None), "/sitemap.xml"));
+    }
+}
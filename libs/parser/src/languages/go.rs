@@ -0,0 +1,943 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, Parameter, ParameterLocation, parse_http_method};
+use crate::params::{add_inputs, input};
+use crate::project::{Handler, Import, ModuleRoutes, Mount, RoutedEndpoint};
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+/// Parser for Go HTTP routers: net/http (including Go 1.22 method patterns),
+/// Gin, Echo, Fiber, Chi and gorilla/mux.
+///
+/// Bindings are scoped per function (`setup:api`) so the same variable name
+/// in two functions does not merge their groups. Routers passed to functions
+/// flow through positional parameter bindings (`register:#0`), which are
+/// exported so calls from other files in the package can be linked.
+pub struct GoParser;
+
+const ALL_METHODS: [HttpMethod; 7] = [
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Delete,
+    HttpMethod::Patch,
+    HttpMethod::Head,
+    HttpMethod::Options,
+];
+
+impl LanguageParser for GoParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        Ok(self.parse_module(Path::new(""), content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "go"
+    }
+
+    fn parse_module(&self, _path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_go::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse Go code"))?;
+
+        let mut walker = RouteWalker::new(content);
+        walker.collect_declarations(tree.root_node());
+        walker.visit(tree.root_node());
+        Ok(walker.module)
+    }
+}
+
+/// A router-valued expression and which way prefixes flow when it is bound
+/// to a variable.
+enum RouterRef {
+    /// `r.Group("/x")`, `other`: the variable is served under the expression.
+    Prefixed(String),
+    /// `newRouter()`: routes inside the function are served wherever the
+    /// variable ends up mounted.
+    Returned(String),
+}
+
+impl RouterRef {
+    fn into_binding(self) -> String {
+        match self {
+            RouterRef::Prefixed(binding) | RouterRef::Returned(binding) => binding,
+        }
+    }
+}
+
+struct Scope {
+    function: String,
+    bindings: HashMap<String, String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    module: ModuleRoutes,
+    /// Functions and methods declared in this file.
+    functions: HashSet<String>,
+    /// Import alias -> import path.
+    packages: HashMap<String, String>,
+    scopes: Vec<Scope>,
+    /// `HandleFunc` calls already emitted through a gorilla `.Methods(...)` chain.
+    consumed: HashSet<usize>,
+}
+
+impl<'a> RouteWalker<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            module: ModuleRoutes::default(),
+            functions: HashSet::new(),
+            packages: HashMap::new(),
+            scopes: Vec::new(),
+            consumed: HashSet::new(),
+        }
+    }
+
+    /// Record imports and top-level functions before walking bodies, so calls
+    /// can be linked regardless of declaration order. Functions that read
+    /// query parameters or headers are recorded as handlers for routes
+    /// registered anywhere in the package.
+    fn collect_declarations(&mut self, root: Node) {
+        let mut cursor = root.walk();
+        for child in root.named_children(&mut cursor) {
+            match child.kind() {
+                "import_declaration" => self.collect_imports(child),
+                "function_declaration" | "method_declaration" => {
+                    if let Some(name) = child.child_by_field_name("name") {
+                        let name = self.text(name).to_string();
+                        let parameters = self.handler_inputs(child);
+                        if !parameters.is_empty() {
+                            self.module.handlers.insert(name.clone(), Handler { parameters, ..Default::default() });
+                        }
+                        self.functions.insert(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_imports(&mut self, node: Node) {
+        if node.kind() == "import_spec" {
+            if let Some(path) = node.child_by_field_name("path").and_then(|p| self.string(p)) {
+                let alias = match node.child_by_field_name("name") {
+                    Some(name) => self.text(name).to_string(),
+                    None => path.rsplit('/').next().unwrap_or(&path).to_string(),
+                };
+                self.packages.insert(alias, path);
+            }
+            return;
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_imports(child);
+        }
+    }
+
+    fn visit(&mut self, node: Node) {
+        match node.kind() {
+            "function_declaration" | "method_declaration" => {
+                self.visit_function(node);
+                return;
+            }
+            "func_literal" => {
+                let function = self.current_function();
+                self.visit_closure(node, &function, None);
+                return;
+            }
+            "short_var_declaration" | "assignment_statement" => {
+                if let (Some(left), Some(right)) = (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+                    self.visit_children(right);
+                    let declare = node.kind() == "short_var_declaration";
+                    self.bind_assignments(self.named_children(left), self.named_children(right), declare);
+                }
+                return;
+            }
+            "var_spec" => {
+                if let Some(value) = node.child_by_field_name("value") {
+                    self.visit_children(value);
+                    let mut cursor = node.walk();
+                    let names: Vec<Node> = node.children_by_field_name("name", &mut cursor).collect();
+                    self.bind_assignments(names, self.named_children(value), true);
+                }
+                return;
+            }
+            "return_statement" => {
+                if let Some(function) = self.scopes.first().map(|s| s.function.clone()) {
+                    let values: Vec<Node> = self.named_children(node)
+                        .into_iter()
+                        .flat_map(|list| self.named_children(list))
+                        .collect();
+                    if let Some(value) = values.first() {
+                        if let Some(router) = self.router_expression(*value) {
+                            self.module.mounts.push(Mount {
+                                parent: format!("{}:return", function),
+                                prefix: String::new(),
+                                target: router.into_binding(),
+                            });
+                        }
+                    }
+                }
+            }
+            "call_expression" if self.visit_call(node) => return,
+            _ => {}
+        }
+
+        self.visit_children(node);
+    }
+
+    fn visit_children(&mut self, node: Node) {
+        for child in self.named_children(node) {
+            self.visit(child);
+        }
+    }
+
+    fn visit_function(&mut self, node: Node) {
+        let name = match node.child_by_field_name("name") {
+            Some(name) => self.text(name).to_string(),
+            None => return,
+        };
+
+        self.scopes.push(Scope { function: name.clone(), bindings: HashMap::new() });
+
+        if let Some(receiver) = node.child_by_field_name("receiver") {
+            for param in self.parameter_names(receiver) {
+                self.declare(&param);
+            }
+        }
+
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            for (i, param) in self.parameter_names(parameters).into_iter().enumerate() {
+                let binding = self.declare(&param);
+                // Positional alias so callers (in this file or the package) can link routers in
+                let positional = format!("{}:#{}", name, i);
+                self.module.mounts.push(Mount {
+                    parent: positional.clone(),
+                    prefix: String::new(),
+                    target: binding,
+                });
+                self.module.exports.insert(positional.clone(), positional);
+            }
+        }
+        let returned = format!("{}:return", name);
+        self.module.exports.insert(returned.clone(), returned);
+
+        if let Some(body) = node.child_by_field_name("body") {
+            self.visit(body);
+        }
+        self.scopes.pop();
+    }
+
+    /// Walk a function literal; `router` binds its first parameter to a known
+    /// router (Chi `r.Route("/x", func(r chi.Router) { ... })`).
+    fn visit_closure(&mut self, node: Node, function: &str, router: Option<String>) {
+        self.scopes.push(Scope { function: function.to_string(), bindings: HashMap::new() });
+
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            for (i, param) in self.parameter_names(parameters).into_iter().enumerate() {
+                match (&router, i) {
+                    (Some(router), 0) => {
+                        self.scopes.last_mut().unwrap().bindings.insert(param, router.clone());
+                    }
+                    _ => {
+                        self.declare(&param);
+                    }
+                }
+            }
+        }
+
+        if let Some(body) = node.child_by_field_name("body") {
+            self.visit(body);
+        }
+        self.scopes.pop();
+    }
+
+    /// `api := r.Group("/api")`, `sub := r.PathPrefix("/x").Subrouter()`,
+    /// `r := newRouter()`
+    fn bind_assignments(&mut self, left: Vec<Node>, right: Vec<Node>, declare: bool) {
+        for (name, value) in left.into_iter().zip(right) {
+            let router = self.router_expression(value);
+
+            let binding = match name.kind() {
+                "identifier" if declare => self.declare(self.text(name)),
+                "identifier" => self.lookup(self.text(name)),
+                "selector_expression" => self.text(name).to_string(),
+                _ => continue,
+            };
+
+            let (parent, target) = match router {
+                Some(RouterRef::Prefixed(router)) => (router, binding),
+                Some(RouterRef::Returned(router)) => (binding, router),
+                None => continue,
+            };
+            if parent != target {
+                self.module.mounts.push(Mount { parent, prefix: String::new(), target });
+            }
+        }
+    }
+
+    /// Returns true when the call's children were already walked.
+    fn visit_call(&mut self, node: Node) -> bool {
+        let function = match node.child_by_field_name("function") {
+            Some(function) => function,
+            None => return false,
+        };
+        let args = match node.child_by_field_name("arguments") {
+            Some(arguments) => self.named_children(arguments),
+            None => return false,
+        };
+
+        if function.kind() == "identifier" {
+            self.link_router_arguments(node, self.text(function).to_string(), None, &args);
+            return false;
+        }
+        if function.kind() != "selector_expression" {
+            return false;
+        }
+
+        let (operand, field) = match (function.child_by_field_name("operand"), function.child_by_field_name("field")) {
+            (Some(operand), Some(field)) => (operand, field),
+            _ => return false,
+        };
+        let method = self.text(field);
+
+        match method {
+            "Route" | "Group" if args.iter().any(|a| a.kind() == "func_literal") => {
+                // Chi/Fiber nested routers: r.Route("/x", func(r chi.Router) {...})
+                let prefix = args.first().and_then(|a| self.string(*a)).unwrap_or_default();
+                let router = format!("route@{}", node.start_byte());
+                let parent = self.owner(operand);
+                self.module.mounts.push(Mount { parent, prefix, target: router.clone() });
+
+                let function = self.current_function();
+                for arg in args {
+                    if arg.kind() == "func_literal" {
+                        self.visit_closure(arg, &function, Some(router.clone()));
+                    } else {
+                        self.visit(arg);
+                    }
+                }
+                return true;
+            }
+            "Mount" if args.len() >= 2 => {
+                // Chi/Fiber sub-routers: r.Mount("/admin", adminRouter())
+                if let (Some(prefix), Some(target)) = (self.string(args[0]), self.router_expression(args[1])) {
+                    let parent = self.owner(operand);
+                    self.module.mounts.push(Mount { parent, prefix, target: target.into_binding() });
+                }
+            }
+            "Methods" => {
+                // gorilla/mux: r.HandleFunc("/x", h).Methods("GET", "POST")
+                if operand.kind() == "call_expression" {
+                    let methods: Vec<HttpMethod> = args.iter()
+                        .filter_map(|a| self.string(*a))
+                        .filter_map(|m| parse_http_method(&m).ok())
+                        .collect();
+                    if self.emit_handle(operand, Some(methods)) {
+                        self.consumed.insert(operand.id());
+                    }
+                }
+            }
+            "HandleFunc" | "Handle" => {
+                if !self.consumed.contains(&node.id()) {
+                    self.emit_handle(node, None);
+                }
+            }
+            "Add" | "Method" | "MethodFunc" if args.len() >= 3 => {
+                // Gin/Echo/Fiber/Chi explicit method: r.Add("GET", "/x", h)
+                if let (Some(method), Some(path)) = (self.string(args[0]), self.string(args[1])) {
+                    if let Ok(method) = parse_http_method(&method) {
+                        let owner = self.owner(operand);
+                        self.push_endpoints(owner, vec![method], path, args.last().copied(), field);
+                    }
+                }
+            }
+            "Match" if args.len() >= 3 => {
+                // Echo/Gin: e.Match([]string{"GET", "POST"}, "/x", h)
+                if let Some(path) = self.string(args[1]) {
+                    let methods: Vec<HttpMethod> = self.string_list(args[0])
+                        .iter()
+                        .filter_map(|m| parse_http_method(m).ok())
+                        .collect();
+                    let owner = self.owner(operand);
+                    self.push_endpoints(owner, methods, path, args.last().copied(), field);
+                }
+            }
+            _ => {
+                let methods = match method.to_lowercase().as_str() {
+                    "any" | "all" => ALL_METHODS.to_vec(),
+                    other => match parse_http_method(other) {
+                        Ok(method) => vec![method],
+                        Err(_) => {
+                            // routes.Register(api) in another package, or
+                            // handlers.Register(api) on a value: link by name
+                            let package = self.package_of(operand);
+                            self.link_router_arguments(node, method.to_string(), package, &args);
+                            return false;
+                        }
+                    },
+                };
+                if args.len() < 2 {
+                    return false;
+                }
+                // Only string paths that look like routes: skips c.Get("key", ...) and friends
+                if let Some(path) = self.string(args[0]).filter(|p| p.is_empty() || p.starts_with('/')) {
+                    let owner = self.owner(operand);
+                    self.push_endpoints(owner, methods, path, args.last().copied(), field);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// `HandleFunc`/`Handle` with either a net/http pattern (`"GET /x"`, `"/x"`)
+    /// or Gin's `Handle("GET", "/x", h)`. Returns whether an endpoint was emitted.
+    fn emit_handle(&mut self, node: Node, methods: Option<Vec<HttpMethod>>) -> bool {
+        let (function, args) = match (node.child_by_field_name("function"), node.child_by_field_name("arguments")) {
+            (Some(function), Some(arguments)) => (function, self.named_children(arguments)),
+            _ => return false,
+        };
+        let (operand, field) = match (function.child_by_field_name("operand"), function.child_by_field_name("field")) {
+            (Some(operand), Some(field)) => (operand, field),
+            _ => return false,
+        };
+        if !matches!(self.text(field), "HandleFunc" | "Handle") || args.len() < 2 {
+            return false;
+        }
+        let pattern = match self.string(args[0]) {
+            Some(pattern) => pattern,
+            None => return false,
+        };
+
+        if let (Ok(method), Some(path)) = (parse_http_method(&pattern), self.string(args[1])) {
+            let owner = self.owner(operand);
+            self.push_endpoints(owner, vec![method], path, args.last().copied(), field);
+            return true;
+        }
+
+        // http.Handle("/api/", http.StripPrefix("/api", apiMux)) mounts a sub-mux
+        if let Some((prefix, target)) = self.strip_prefix(args[1]) {
+            let parent = self.owner(operand);
+            self.module.mounts.push(Mount { parent, prefix, target });
+            return true;
+        }
+
+        let (pattern_methods, path) = split_pattern(&pattern);
+        if !path.starts_with('/') {
+            return false;
+        }
+        // A bare net/http pattern matches every method; report it as GET
+        let methods = methods
+            .filter(|m| !m.is_empty())
+            .or(pattern_methods.map(|m| vec![m]))
+            .unwrap_or_else(|| vec![HttpMethod::Get]);
+
+        let owner = self.owner(operand);
+        self.push_endpoints(owner, methods, path.to_string(), args.last().copied(), field);
+        true
+    }
+
+    fn strip_prefix(&mut self, node: Node) -> Option<(String, String)> {
+        if node.kind() != "call_expression" {
+            return None;
+        }
+        let function = node.child_by_field_name("function")?;
+        if !self.text(function).ends_with("StripPrefix") {
+            return None;
+        }
+        let args = self.named_children(node.child_by_field_name("arguments")?);
+        let prefix = self.string(*args.first()?)?;
+        let target = self.router_expression(*args.get(1)?)?;
+        Some((prefix, target.into_binding()))
+    }
+
+    /// Link routers passed as arguments to a function's positional parameters.
+    fn link_router_arguments(&mut self, node: Node, function: String, package: Option<String>, args: &[Node]) {
+        let local = package.is_none() && self.functions.contains(&function);
+
+        for (i, arg) in args.iter().enumerate() {
+            if !matches!(arg.kind(), "identifier" | "selector_expression") {
+                continue;
+            }
+            let parent = self.owner(*arg);
+            let positional = format!("{}:#{}", function, i);
+            let target = if local {
+                positional
+            } else {
+                // Declared in another file of this package, or in another package
+                let binding = format!("call@{}:{}", node.start_byte(), i);
+                self.module.imports.insert(binding.clone(), Import {
+                    source: package.clone().unwrap_or_default(),
+                    name: Some(positional),
+                });
+                binding
+            };
+            self.module.mounts.push(Mount { parent, prefix: String::new(), target });
+        }
+    }
+
+    /// The binding that routes registered on `node` belong to.
+    fn owner(&mut self, node: Node) -> String {
+        match node.kind() {
+            "identifier" => self.lookup(self.text(node)),
+            "selector_expression" => self.text(node).to_string(),
+            "call_expression" => self.router_expression(node).map(RouterRef::into_binding).unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    /// Resolve an expression that evaluates to a router, recording the
+    /// mounts it implies: `r.Group("/x")`, `r.PathPrefix("/x").Subrouter()`,
+    /// `r.With(mw)`, `newRouter()`, `routes.New()`.
+    fn router_expression(&mut self, node: Node) -> Option<RouterRef> {
+        match node.kind() {
+            "identifier" => return Some(RouterRef::Prefixed(self.lookup(self.text(node)))),
+            "selector_expression" => return Some(RouterRef::Prefixed(self.text(node).to_string())),
+            "call_expression" => {}
+            _ => return None,
+        }
+
+        let function = node.child_by_field_name("function")?;
+        let args = self.named_children(node.child_by_field_name("arguments")?);
+
+        if function.kind() == "identifier" {
+            let name = self.text(function);
+            if self.functions.contains(name) {
+                return Some(RouterRef::Returned(format!("{}:return", name)));
+            }
+            return None;
+        }
+
+        let operand = function.child_by_field_name("operand")?;
+        let field = self.text(function.child_by_field_name("field")?);
+
+        if !matches!(field, "Group" | "PathPrefix" | "Subrouter" | "With" | "Use") {
+            if let Some(package) = self.package_of(operand) {
+                let binding = format!("call@{}", node.start_byte());
+                self.module.imports.insert(binding.clone(), Import {
+                    source: package,
+                    name: Some(format!("{}:return", field)),
+                });
+                return Some(RouterRef::Returned(binding));
+            }
+        }
+
+        match field {
+            "Group" | "PathPrefix" => {
+                let prefix = self.string(*args.first()?)?;
+                let parent = self.owner(operand);
+                let group = format!("group@{}", node.start_byte());
+                self.module.mounts.push(Mount { parent, prefix, target: group.clone() });
+                Some(RouterRef::Prefixed(group))
+            }
+            "Subrouter" | "With" | "Use" => Some(RouterRef::Prefixed(self.owner(operand))),
+            _ => None,
+        }
+    }
+
+    fn push_endpoints(&mut self, owner: String, methods: Vec<HttpMethod>, path: String, handler: Option<Node>, at: Node) {
+        let pos = at.start_position();
+        let parameters = handler
+            .filter(|h| h.kind() == "func_literal")
+            .map(|h| self.handler_inputs(h))
+            .unwrap_or_default();
+        let handler = handler
+            .filter(|h| matches!(h.kind(), "identifier" | "selector_expression"))
+            .map(|h| self.text(h).to_string())
+            .unwrap_or_else(|| format!("{}:{}", pos.row + 1, pos.column + 1));
+
+        for method in methods {
+            self.module.endpoints.push(RoutedEndpoint {
+                owner: owner.clone(),
+                endpoint: Endpoint {
+                    method,
+                    path: path.clone(),
+                    handler: handler.clone(),
+                    line: pos.row + 1,
+                    column: pos.column + 1,
+                    documentation: None,
+                    parameters: parameters.clone(),
+                    tags: Vec::new(),
+                    middleware: Vec::new(),
+                    template: String::new(),
+                    body: None,
+                    responses: Vec::new(),
+                    requires_auth: false,
+                },
+            });
+        }
+    }
+
+    /// Query parameters and headers a handler reads: Gin's `c.Query("q")`,
+    /// `c.DefaultQuery("page", "1")` and `c.GetHeader("X-Tenant")`, Echo's
+    /// `c.QueryParam("q")`, Fiber's `c.Query("q", "all")` and `c.QueryInt("page", 1)`,
+    /// and net/http's `r.URL.Query().Get("q")` and `r.Header.Get("X-Tenant")`.
+    fn handler_inputs(&self, node: Node) -> Vec<Parameter> {
+        let mut found = Vec::new();
+        self.collect_inputs(node, &mut found);
+        let mut parameters = Vec::new();
+        add_inputs(&mut parameters, &found);
+        parameters
+    }
+
+    fn collect_inputs(&self, node: Node, found: &mut Vec<Parameter>) {
+        if let Some(parameter) = self.input(node) {
+            found.push(parameter);
+        }
+        for child in self.named_children(node) {
+            self.collect_inputs(child, found);
+        }
+    }
+
+    fn input(&self, call: Node) -> Option<Parameter> {
+        let function = call.child_by_field_name("function").filter(|_| call.kind() == "call_expression")?;
+        let (operand, field) = match (function.child_by_field_name("operand"), function.child_by_field_name("field")) {
+            (Some(operand), Some(field)) => (self.text(operand), self.text(field)),
+            _ => return None,
+        };
+        let args = self.named_children(call.child_by_field_name("arguments")?);
+        // `db.Query("SELECT ...")` shares the shape but not a parameter name
+        let name = args.first().and_then(|a| self.string(*a)).filter(|n| !n.is_empty() && !n.contains(char::is_whitespace))?;
+
+        let (location, param_type) = match field {
+            "Query" | "GetQuery" | "DefaultQuery" | "QueryParam" => (ParameterLocation::Query, None),
+            "QueryArray" | "GetQueryArray" => (ParameterLocation::Query, Some("array")),
+            "QueryInt" => (ParameterLocation::Query, Some("integer")),
+            "QueryFloat" => (ParameterLocation::Query, Some("number")),
+            "QueryBool" => (ParameterLocation::Query, Some("boolean")),
+            "GetHeader" => (ParameterLocation::Header, None),
+            "Get" if operand.ends_with("Query()") => (ParameterLocation::Query, None),
+            "Get" if operand.ends_with("Header") => (ParameterLocation::Header, None),
+            _ => return None,
+        };
+        let default = args.get(1).and_then(|value| match value.kind() {
+            "interpreted_string_literal" | "raw_string_literal" => self.string(*value),
+            "int_literal" | "float_literal" | "true" | "false" => Some(self.text(*value).to_string()),
+            _ => None,
+        });
+        let mut parameter = input(&name, location, default);
+        parameter.param_type = param_type.map(str::to_string);
+        Some(parameter)
+    }
+
+    /// Import path when `node` names an imported package rather than a variable.
+    fn package_of(&self, node: Node) -> Option<String> {
+        if node.kind() != "identifier" || self.lookup(self.text(node)) != self.text(node) {
+            return None;
+        }
+        self.packages.get(self.text(node)).cloned()
+    }
+
+    fn declare(&mut self, name: &str) -> String {
+        match self.scopes.last_mut() {
+            Some(scope) => {
+                let binding = format!("{}:{}", scope.function, name);
+                scope.bindings.insert(name.to_string(), binding.clone());
+                binding
+            }
+            None => name.to_string(),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> String {
+        self.scopes.iter()
+            .rev()
+            .find_map(|scope| scope.bindings.get(name).cloned())
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    fn current_function(&self) -> String {
+        self.scopes.last().map(|s| s.function.clone()).unwrap_or_default()
+    }
+
+    fn parameter_names(&self, parameters: Node) -> Vec<String> {
+        let mut names = Vec::new();
+        for declaration in self.named_children(parameters) {
+            let mut cursor = declaration.walk();
+            let declared: Vec<Node> = declaration.children_by_field_name("name", &mut cursor).collect();
+            if declared.is_empty() {
+                // Unnamed parameter still takes a position
+                names.push(String::from("_"));
+            }
+            for name in declared {
+                names.push(self.text(name).to_string());
+            }
+        }
+        names
+    }
+
+    fn string(&self, node: Node) -> Option<String> {
+        let text = self.text(node);
+        match node.kind() {
+            "interpreted_string_literal" => Some(text.trim_matches('"').to_string()),
+            "raw_string_literal" => Some(text.trim_matches('`').to_string()),
+            _ => None,
+        }
+    }
+
+    /// String elements of a composite literal such as `[]string{"GET", "POST"}`.
+    fn string_list(&self, node: Node) -> Vec<String> {
+        let body = match node.child_by_field_name("body") {
+            Some(body) => body,
+            None => return Vec::new(),
+        };
+        self.named_children(body)
+            .into_iter()
+            .flat_map(|element| {
+                let mut values = self.named_children(element);
+                values.push(element);
+                values
+            })
+            .filter_map(|n| self.string(n))
+            .collect()
+    }
+
+    fn named_children(&self, node: Node<'a>) -> Vec<Node<'a>> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).collect()
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+/// Split a Go 1.22 `ServeMux` pattern: `"GET /users/{id}"`, `"/users/"`,
+/// `"POST example.com/upload"` (the host is dropped).
+fn split_pattern(pattern: &str) -> (Option<HttpMethod>, &str) {
+    let (method, rest) = match pattern.split_once(' ') {
+        Some((method, rest)) => match parse_http_method(method) {
+            Ok(method) => (Some(method), rest.trim_start()),
+            Err(_) => (None, pattern),
+        },
+        None => (None, pattern),
+    };
+
+    match rest.find('/') {
+        Some(slash) => (method, &rest[slash..]),
+        None => (method, rest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(code: &str) -> Vec<(HttpMethod, String)> {
+        GoParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path))
+            .collect()
+    }
+
+    #[test]
+    fn test_supports_go_extension() {
+        assert!(GoParser.supports_extension("go"));
+        assert!(!GoParser.supports_extension("rs"));
+    }
+
+    #[test]
+    fn test_parse_net_http_patterns() {
+        let code = r#"
+package main
+
+import "net/http"
+
+func main() {
+    http.HandleFunc("/health", health)
+
+    mux := http.NewServeMux()
+    mux.HandleFunc("GET /users/{id}", getUser)
+    mux.HandleFunc("POST /users", createUser)
+    mux.Handle("DELETE example.com/users/{id}", http.HandlerFunc(deleteUser))
+
+    api := http.NewServeMux()
+    api.HandleFunc("GET /orders", listOrders)
+    mux.Handle("/api/", http.StripPrefix("/api", api))
+
+    http.ListenAndServe(":8080", mux)
+}
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/health".to_string()),
+            (HttpMethod::Get, "/users/{id}".to_string()),
+            (HttpMethod::Post, "/users".to_string()),
+            (HttpMethod::Delete, "/users/{id}".to_string()),
+            (HttpMethod::Get, "/api/orders".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_gin_groups() {
+        let code = r#"
+package main
+
+import "github.com/gin-gonic/gin"
+
+func main() {
+    r := gin.Default()
+    r.GET("/ping", ping)
+
+    api := r.Group("/api")
+    {
+        v1 := api.Group("/v1", authRequired())
+        v1.GET("/users/:id", handlers.GetUser)
+        v1.POST("/users", func(c *gin.Context) {
+            c.Get("user")
+            c.JSON(201, gin.H{})
+        })
+        registerOrders(v1)
+    }
+    r.Handle("PATCH", "/settings", updateSettings)
+    r.Any("/proxy", proxy)
+}
+
+func registerOrders(rg *gin.RouterGroup) {
+    orders := rg.Group("/orders")
+    orders.DELETE("/:id", deleteOrder)
+}
+"#;
+
+        let found = routes(code);
+        assert_eq!(found[..4], [
+            (HttpMethod::Get, "/ping".to_string()),
+            (HttpMethod::Get, "/api/v1/users/:id".to_string()),
+            (HttpMethod::Post, "/api/v1/users".to_string()),
+            (HttpMethod::Patch, "/settings".to_string()),
+        ]);
+        assert_eq!(found.iter().filter(|(_, p)| p == "/proxy").count(), 7);
+        assert!(found.contains(&(HttpMethod::Delete, "/api/v1/orders/:id".to_string())));
+        assert!(!found.iter().any(|(_, p)| p == "user"));
+    }
+
+    #[test]
+    fn test_group_names_are_scoped_per_function() {
+        let code = r#"
+package main
+
+func users(r *echo.Echo) {
+    g := r.Group("/users")
+    g.GET("/:id", getUser)
+}
+
+func orders(r *echo.Echo) {
+    g := r.Group("/orders")
+    g.GET("/:id", getOrder)
+}
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/users/:id".to_string()),
+            (HttpMethod::Get, "/orders/:id".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_chi_nesting() {
+        let code = r#"
+package main
+
+func main() {
+    r := chi.NewRouter()
+    r.Get("/", index)
+
+    r.Route("/articles", func(r chi.Router) {
+        r.With(paginate).Get("/", listArticles)
+        r.Route("/{articleID}", func(r chi.Router) {
+            r.Get("/", getArticle)
+            r.Put("/", updateArticle)
+        })
+    })
+
+    r.Mount("/admin", adminRouter())
+}
+
+func adminRouter() http.Handler {
+    r := chi.NewRouter()
+    r.Get("/stats", stats)
+    return r
+}
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/".to_string()),
+            (HttpMethod::Get, "/articles".to_string()),
+            (HttpMethod::Get, "/articles/{articleID}".to_string()),
+            (HttpMethod::Put, "/articles/{articleID}".to_string()),
+            (HttpMethod::Get, "/admin/stats".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_fiber_and_gorilla() {
+        let code = r#"
+package main
+
+func main() {
+    app := fiber.New()
+    api := app.Group("/api")
+    api.Get("/items", listItems)
+    api.Route("/v2", func(router fiber.Router) {
+        router.Post("/items", createItem)
+    })
+
+    r := mux.NewRouter()
+    s := r.PathPrefix("/products").Subrouter()
+    s.HandleFunc("/{key}", productHandler).Methods("GET", "PUT")
+}
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/api/items".to_string()),
+            (HttpMethod::Post, "/api/v2/items".to_string()),
+            (HttpMethod::Get, "/products/{key}".to_string()),
+            (HttpMethod::Put, "/products/{key}".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_query_parameters_and_headers_from_handlers() {
+        let code = r#"
+package main
+
+func main() {
+    r := gin.Default()
+    r.GET("/users", h.ListUsers)
+    r.GET("/search", func(c *gin.Context) {
+        c.JSON(200, search(c.Query("q"), c.GetHeader("X-Tenant")))
+    })
+}
+
+func (h *Handler) ListUsers(c *gin.Context) {
+    page := c.DefaultQuery("page", "1")
+    rows, _ := h.db.Query("SELECT * FROM users")
+    c.JSON(200, list(rows, page, c.QueryArray("role")))
+}
+"#;
+
+        let endpoints = GoParser.parse(code).unwrap();
+        let inputs: Vec<_> = endpoints[0].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.param_type.as_deref(), p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("page", ParameterLocation::Query, None, Some("1")),
+            ("role", ParameterLocation::Query, Some("array"), None),
+        ]);
+
+        let inputs: Vec<_> = endpoints[1].parameters.iter().map(|p| (p.name.as_str(), p.location.clone())).collect();
+        assert_eq!(inputs, vec![("q", ParameterLocation::Query), ("X-Tenant", ParameterLocation::Header)]);
+    }
+
+    #[test]
+    fn test_split_pattern() {
+        assert_eq!(split_pattern("GET /x").0, Some(HttpMethod::Get));
+        assert_eq!(split_pattern("GET /x").1, "/x");
+        assert_eq!(split_pattern("/static/").0, None);
+        assert_eq!(split_pattern("POST api.example.com/upload").1, "/upload");
+    }
+}
@@ -0,0 +1,754 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, Parameter, ParameterLocation, Response, Schema, parse_http_method};
+use crate::params::describe_path;
+use crate::project::join_route;
+use crate::responses::{add_response, response, status_code};
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use tree_sitter::{Node, Parser};
+
+/// Parser for Spring MVC / Spring Boot controllers.
+///
+/// Class-level `@RequestMapping` prefixes are combined with method-level
+/// `@GetMapping`-style or `@RequestMapping(method = ...)` annotations, and
+/// `@PathVariable`, `@RequestParam`, `@RequestHeader` and `@RequestBody`
+/// arguments become endpoint parameters.
+///
+/// Mapping paths may name `static final String` constants declared in the
+/// same file (`@GetMapping(BASE)`, `@RequestMapping(ApiPaths.USERS)`); a
+/// mapping whose path can't be resolved is skipped.
+pub struct JavaParser;
+
+impl LanguageParser for JavaParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_java::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse Java code"))?;
+
+        let constants = string_constants(tree.root_node(), content);
+        let mut endpoints = Vec::new();
+        collect_classes(tree.root_node(), content, &constants, &mut endpoints);
+        endpoints.iter_mut().for_each(describe_path);
+        Ok(endpoints)
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "java"
+    }
+}
+
+fn collect_classes(node: Node, content: &str, constants: &HashMap<String, String>, endpoints: &mut Vec<Endpoint>) {
+    if node.kind() == "class_declaration" {
+        parse_controller(node, content, constants, endpoints);
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_classes(child, content, constants, endpoints);
+    }
+}
+
+/// `static final String` fields (and interface constants) with a value that
+/// resolves to a string, keyed by their name and by `Class.NAME`.
+fn string_constants(root: Node, content: &str) -> HashMap<String, String> {
+    let mut declarations = Vec::new();
+    collect_constants(root, content, &mut declarations);
+
+    // Constants may be built from other constants declared further down
+    let mut constants = HashMap::new();
+    loop {
+        let resolved = constants.len();
+        for (names, value) in &declarations {
+            if let Some(value) = string_value(*value, content, &constants) {
+                for name in names {
+                    constants.entry(name.clone()).or_insert_with(|| value.clone());
+                }
+            }
+        }
+        if constants.len() == resolved {
+            return constants;
+        }
+    }
+}
+
+fn collect_constants<'a>(node: Node<'a>, content: &str, declarations: &mut Vec<(Vec<String>, Node<'a>)>) {
+    let constant = match node.kind() {
+        "constant_declaration" => true,
+        "field_declaration" => {
+            let mut cursor = node.walk();
+            let modifiers = node.named_children(&mut cursor).find(|n| n.kind() == "modifiers").map(|m| text(m, content)).unwrap_or_default();
+            let words: Vec<&str> = modifiers.split_whitespace().collect();
+            words.contains(&"static") && words.contains(&"final")
+        }
+        _ => false,
+    };
+    let string = node.child_by_field_name("type").map(|t| text(t, content) == "String").unwrap_or(false);
+
+    if constant && string {
+        let class = std::iter::successors(node.parent(), |n| n.parent())
+            .find(|n| matches!(n.kind(), "class_declaration" | "interface_declaration"))
+            .and_then(|n| n.child_by_field_name("name"))
+            .map(|n| text(n, content));
+        let mut cursor = node.walk();
+        for declarator in node.children_by_field_name("declarator", &mut cursor) {
+            if let (Some(name), Some(value)) = (declarator.child_by_field_name("name"), declarator.child_by_field_name("value")) {
+                let name = text(name, content);
+                let mut names = vec![name.to_string()];
+                names.extend(class.map(|class| format!("{}.{}", class, name)));
+                declarations.push((names, value));
+            }
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_constants(child, content, declarations);
+    }
+}
+
+fn parse_controller(class: Node, content: &str, constants: &HashMap<String, String>, endpoints: &mut Vec<Endpoint>) {
+    let annotations = annotations_of(class);
+    let class_mapping = annotations.iter().find(|a| annotation_name(**a, content) == "RequestMapping");
+    let is_controller = class_mapping.is_some()
+        || annotations.iter().any(|a| matches!(annotation_name(*a, content), "RestController" | "Controller"));
+    if !is_controller {
+        return;
+    }
+
+    let class_name = class.child_by_field_name("name").map(|n| text(n, content)).unwrap_or_default();
+    let prefixes = match class_mapping.map(|a| mapping_paths(*a, content, constants)) {
+        Some(Some(paths)) if !paths.is_empty() => paths,
+        // A prefix we can't resolve would put every route at the wrong path
+        Some(None) => return,
+        _ => vec![String::new()],
+    };
+
+    let body = match class.child_by_field_name("body") {
+        Some(body) => body,
+        None => return,
+    };
+
+    let mut cursor = body.walk();
+    for method in body.named_children(&mut cursor).filter(|n| n.kind() == "method_declaration") {
+        let method_name = method.child_by_field_name("name").map(|n| text(n, content)).unwrap_or_default();
+        let parameters = method_parameters(method, content, constants);
+        let responses = method_responses(method, content);
+
+        for annotation in annotations_of(method) {
+            let verbs = match annotation_name(annotation, content) {
+                "GetMapping" => vec![HttpMethod::Get],
+                "PostMapping" => vec![HttpMethod::Post],
+                "PutMapping" => vec![HttpMethod::Put],
+                "DeleteMapping" => vec![HttpMethod::Delete],
+                "PatchMapping" => vec![HttpMethod::Patch],
+                // Without `method = ...` Spring matches every verb; report it as GET
+                "RequestMapping" => match request_methods(annotation, content) {
+                    methods if methods.is_empty() => vec![HttpMethod::Get],
+                    methods => methods,
+                },
+                _ => continue,
+            };
+
+            let Some(mut paths) = mapping_paths(annotation, content, constants) else { continue };
+            if paths.is_empty() {
+                paths.push(String::new());
+            }
+
+            let pos = annotation.start_position();
+            for prefix in &prefixes {
+                for path in &paths {
+                    let full = join_route(prefix, path);
+                    let full = if full.starts_with('/') { full } else { format!("/{}", full) };
+
+                    for verb in &verbs {
+                        endpoints.push(Endpoint {
+                            method: verb.clone(),
+                            path: full.clone(),
+                            handler: format!("{}.{}", class_name, method_name),
+                            line: pos.row + 1,
+                            column: pos.column + 1,
+                            documentation: None,
+                            parameters: parameters.clone(),
+                            tags: Vec::new(),
+                            middleware: Vec::new(),
+                            template: String::new(),
+                            body: None,
+                            responses: responses.clone(),
+                            requires_auth: false,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `@PathVariable Long id`, `@RequestParam(defaultValue = "0") int page`,
+/// `@RequestHeader("X-Tenant") String tenant`, `@RequestBody @Valid CreateUser body`
+fn method_parameters(method: Node, content: &str, constants: &HashMap<String, String>) -> Vec<Parameter> {
+    let formal = match method.child_by_field_name("parameters") {
+        Some(formal) => formal,
+        None => return Vec::new(),
+    };
+
+    let mut parameters = Vec::new();
+    let mut cursor = formal.walk();
+    for param in formal.named_children(&mut cursor).filter(|n| n.kind() == "formal_parameter") {
+        let param_name = param.child_by_field_name("name").map(|n| text(n, content)).unwrap_or_default();
+        let param_type = param.child_by_field_name("type").map(|n| text(n, content).to_string());
+        let optional_type = param_type.as_deref().map(|t| t.starts_with("Optional<")).unwrap_or(false);
+
+        for annotation in annotations_of(param) {
+            let location = match annotation_name(annotation, content) {
+                "PathVariable" => ParameterLocation::Path,
+                "RequestParam" => ParameterLocation::Query,
+                "RequestHeader" => ParameterLocation::Header,
+                "RequestBody" => ParameterLocation::Body,
+                _ => continue,
+            };
+
+            let name = match location {
+                ParameterLocation::Body => param_name.to_string(),
+                _ => element(annotation, content, &["value", "name"])
+                    .and_then(|v| string_value(v, content, constants))
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or_else(|| param_name.to_string()),
+            };
+            let default = element(annotation, content, &["defaultValue"])
+                .and_then(|v| string_value(v, content, constants));
+            let required_attr = element(annotation, content, &["required"])
+                .map(|v| text(v, content) != "false")
+                .unwrap_or(true);
+
+            parameters.push(Parameter {
+                name,
+                location,
+                param_type: param_type.clone(),
+                required: required_attr && default.is_none() && !optional_type,
+                default,
+            });
+        }
+    }
+    parameters
+}
+
+/// Responses a handler method sends: its `@ResponseStatus` and return type
+/// (`ResponseEntity<User>`, `List<User>`), `ResponseEntity.status(HttpStatus.CREATED).body(user)`,
+/// `ResponseEntity.notFound().build()`, `new ResponseEntity<>(user, HttpStatus.CREATED)`
+/// and `throw new ResponseStatusException(HttpStatus.NOT_FOUND)`.
+fn method_responses(method: Node, content: &str) -> Vec<Response> {
+    let declared_status = annotations_of(method).into_iter()
+        .find(|a| annotation_name(*a, content) == "ResponseStatus")
+        .and_then(|a| element(a, content, &["value", "code"]))
+        .and_then(|status| status_code(text(status, content)));
+    let return_type = method.child_by_field_name("type").map(|t| text(t, content)).unwrap_or("void");
+    let (entity, body) = match return_type.strip_prefix("ResponseEntity") {
+        Some(rest) => (true, type_schema(rest.trim().trim_start_matches('<').trim_end_matches('>'))),
+        None => (false, type_schema(return_type)),
+    };
+
+    let mut responses = Vec::new();
+    if let Some(block) = method.child_by_field_name("body") {
+        collect_responses(block, &body, content, &mut responses);
+    }
+    // A plain return type is the body of the default (or declared) status;
+    // a `ResponseEntity` names its statuses where it is built
+    if !entity || responses.is_empty() {
+        let status = declared_status.unwrap_or(200);
+        let mut declared = vec![response(status, body)];
+        for sent in responses {
+            add_response(&mut declared, sent);
+        }
+        return declared;
+    }
+    responses
+}
+
+fn collect_responses(node: Node, body: &Option<Schema>, content: &str, responses: &mut Vec<Response>) {
+    match node.kind() {
+        // The outermost call of a `ResponseEntity.status(...).body(...)` chain
+        "method_invocation" if node.parent().and_then(|p| p.child_by_field_name("object")) != Some(node) => {
+            if let Some(sent) = entity_chain(node, body, content) {
+                add_response(responses, sent);
+            }
+        }
+        // `.map(ResponseEntity::ok)`
+        "method_reference" => {
+            if let Some(("ResponseEntity", builder)) = text(node, content).split_once("::") {
+                if let Some(status) = entity_status(builder.trim()) {
+                    add_response(responses, response(status, body.clone().filter(|_| status != 204)));
+                }
+            }
+        }
+        "object_creation_expression" => {
+            let created = node.child_by_field_name("type").map(|t| text(t, content)).unwrap_or_default();
+            let args = node.child_by_field_name("arguments").map(|a| {
+                let mut cursor = a.walk();
+                a.named_children(&mut cursor).collect::<Vec<_>>()
+            }).unwrap_or_default();
+            let status = args.iter().find_map(|arg| status_code(text(*arg, content)));
+            if created.starts_with("ResponseEntity") {
+                // `new ResponseEntity<>(HttpStatus.NO_CONTENT)` has no body
+                let sends_body = args.len() > 1;
+                add_response(responses, response(status.unwrap_or(200), body.clone().filter(|_| sends_body)));
+            } else if created == "ResponseStatusException" {
+                if let Some(status) = status {
+                    add_response(responses, response(status, None));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    for child in children {
+        collect_responses(child, body, content, responses);
+    }
+}
+
+/// The response a `ResponseEntity.ok(user)`-style chain builds.
+fn entity_chain(outer: Node, body: &Option<Schema>, content: &str) -> Option<Response> {
+    let mut calls = Vec::new();
+    let mut node = outer;
+    while node.kind() == "method_invocation" {
+        let name = text(node.child_by_field_name("name")?, content);
+        let args = node.child_by_field_name("arguments").map(|a| a.named_child_count()).unwrap_or(0);
+        calls.push((name, args, node));
+        node = node.child_by_field_name("object")?;
+    }
+    if text(node, content) != "ResponseEntity" {
+        return None;
+    }
+
+    let (first, first_args, first_node) = *calls.last()?;
+    let status = match first {
+        "status" => {
+            let args = first_node.child_by_field_name("arguments")?;
+            status_code(text(args.named_child(0)?, content))?
+        }
+        builder => entity_status(builder)?,
+    };
+    let sends_body = (first == "ok" && first_args > 0) || calls.iter().any(|(name, args, _)| *name == "body" && *args > 0);
+    Some(response(status, body.clone().filter(|_| sends_body)))
+}
+
+/// Status of the `ResponseEntity` builder shortcuts.
+fn entity_status(builder: &str) -> Option<u16> {
+    let status = match builder {
+        "ok" => 200,
+        "created" => 201,
+        "accepted" => 202,
+        "noContent" => 204,
+        "badRequest" => 400,
+        "notFound" => 404,
+        "unprocessableEntity" => 422,
+        "internalServerError" => 500,
+        _ => return None,
+    };
+    Some(status)
+}
+
+/// The schema of a Java type: primitives and their boxes, collections,
+/// and references to DTO classes. `void`, `Void` and `?` have no body.
+fn type_schema(java_type: &str) -> Option<Schema> {
+    let java_type = java_type.trim();
+    if let Some(element) = java_type.strip_suffix("[]") {
+        return Some(Schema::array(type_schema(element).unwrap_or_else(|| Schema::of_type("any"))));
+    }
+    let (head, argument) = match java_type.split_once('<') {
+        Some((head, rest)) => (head.trim(), Some(rest.strip_suffix('>').unwrap_or(rest))),
+        None => (java_type, None),
+    };
+    let inner = || argument.and_then(|a| type_schema(a.rsplit(',').next().unwrap_or(a)));
+    let schema = match head {
+        "" | "void" | "Void" | "?" => return None,
+        "Optional" | "Mono" | "CompletableFuture" | "Callable" | "ResponseEntity" => return inner(),
+        "List" | "Set" | "Collection" | "Iterable" | "Flux" | "Page" => Schema::array(inner().unwrap_or_else(|| Schema::of_type("any"))),
+        "Map" | "HashMap" | "JsonNode" | "ObjectNode" => Schema::of_type("object"),
+        "String" | "char" | "Character" | "UUID" => Schema::of_type("string"),
+        "int" | "Integer" | "long" | "Long" | "short" | "Short" | "byte" | "Byte" | "BigInteger" => Schema::of_type("integer"),
+        "double" | "Double" | "float" | "Float" | "BigDecimal" => Schema::of_type("number"),
+        "boolean" | "Boolean" => Schema::of_type("boolean"),
+        "Object" => Schema::of_type("any"),
+        name => Schema::reference(name),
+    };
+    Some(schema)
+}
+
+/// Paths from `@X("/a")`, `@X({"/a", "/b"})`, `@X(value = "/a")` or `@X(path = ...)`.
+/// `None` when a path is given but isn't a string the file declares.
+fn mapping_paths(annotation: Node, content: &str, constants: &HashMap<String, String>) -> Option<Vec<String>> {
+    match element(annotation, content, &["value", "path"]) {
+        Some(value) => string_values(value, content, constants),
+        None => Some(Vec::new()),
+    }
+}
+
+/// `method = RequestMethod.GET`, `method = {GET, POST}`
+fn request_methods(annotation: Node, content: &str) -> Vec<HttpMethod> {
+    let value = match element(annotation, content, &["method"]) {
+        Some(value) => value,
+        None => return Vec::new(),
+    };
+
+    let items = if value.kind() == "element_value_array_initializer" {
+        let mut cursor = value.walk();
+        value.named_children(&mut cursor).collect()
+    } else {
+        vec![value]
+    };
+
+    items.into_iter()
+        .filter_map(|item| {
+            let name = text(item, content);
+            parse_http_method(name.rsplit('.').next().unwrap_or(name)).ok()
+        })
+        .collect()
+}
+
+/// The value of a named annotation element; a lone positional argument
+/// counts as `value`.
+fn element<'a>(annotation: Node<'a>, content: &str, keys: &[&str]) -> Option<Node<'a>> {
+    let arguments = annotation.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let children: Vec<Node> = arguments.named_children(&mut cursor).collect();
+
+    for child in &children {
+        if child.kind() == "element_value_pair" {
+            let key = child.child_by_field_name("key").map(|k| text(k, content));
+            if key.map(|k| keys.contains(&k)).unwrap_or(false) {
+                return child.child_by_field_name("value");
+            }
+        }
+    }
+
+    match children.as_slice() {
+        [single] if single.kind() != "element_value_pair" && keys.contains(&"value") => Some(*single),
+        _ => None,
+    }
+}
+
+fn string_values(node: Node, content: &str, constants: &HashMap<String, String>) -> Option<Vec<String>> {
+    if node.kind() == "element_value_array_initializer" {
+        let mut cursor = node.walk();
+        return node.named_children(&mut cursor)
+            .map(|item| string_value(item, content, constants))
+            .collect();
+    }
+    string_value(node, content, constants).map(|value| vec![value])
+}
+
+/// A string literal, a string constant (`BASE`, `ApiPaths.USERS`), or a
+/// concatenation of them.
+fn string_value(node: Node, content: &str, constants: &HashMap<String, String>) -> Option<String> {
+    match node.kind() {
+        "string_literal" => {
+            let literal = text(node, content);
+            Some(unescape(literal.strip_prefix('"').and_then(|l| l.strip_suffix('"')).unwrap_or(literal)))
+        }
+        "identifier" => constants.get(text(node, content)).cloned(),
+        // `ApiPaths.USERS`, also when the class is written fully qualified
+        "field_access" => {
+            let class = text(node.child_by_field_name("object")?, content);
+            let field = text(node.child_by_field_name("field")?, content);
+            constants.get(&format!("{}.{}", class.rsplit('.').next().unwrap_or(class), field)).cloned()
+        }
+        "parenthesized_expression" => string_value(node.named_child(0)?, content, constants),
+        "binary_expression" => {
+            let left = string_value(node.child_by_field_name("left")?, content, constants)?;
+            let right = string_value(node.child_by_field_name("right")?, content, constants)?;
+            Some(left + &right)
+        }
+        _ => None,
+    }
+}
+
+/// Decode the escapes of a Java string literal's contents, so
+/// `"/{id:\\d+}"` becomes `/{id:\d+}`.
+fn unescape(literal: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('b') => unescaped.push('\u{8}'),
+            Some('f') => unescaped.push('\u{c}'),
+            Some('s') => unescaped.push(' '),
+            Some('u') => {
+                // `\uuu0041` is as valid as `\u0041`
+                while chars.peek() == Some(&'u') {
+                    chars.next();
+                }
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => unescaped.push(decoded),
+                    None => {
+                        unescaped.push_str("\\u");
+                        unescaped.push_str(&hex);
+                    }
+                }
+            }
+            Some(digit @ '0'..='7') => {
+                // Octal escapes take up to three digits, at most `\377`
+                let mut value = digit.to_digit(8).unwrap_or_default();
+                let max_digits = if digit <= '3' { 2 } else { 1 };
+                for _ in 0..max_digits {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(next) => {
+                            value = value * 8 + next;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                unescaped.extend(char::from_u32(value));
+            }
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+fn annotations_of(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    let modifiers = node.named_children(&mut cursor).find(|n| n.kind() == "modifiers");
+
+    match modifiers {
+        Some(modifiers) => {
+            let mut cursor = modifiers.walk();
+            modifiers.named_children(&mut cursor)
+                .filter(|n| matches!(n.kind(), "annotation" | "marker_annotation"))
+                .collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Simple name of an annotation: `org.springframework.web.bind.annotation.GetMapping` -> `GetMapping`.
+fn annotation_name<'a>(annotation: Node, content: &'a str) -> &'a str {
+    let name = annotation.child_by_field_name("name").map(|n| text(n, content)).unwrap_or_default();
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn text<'a>(node: Node, content: &'a str) -> &'a str {
+    &content[node.byte_range()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supports_java_extension() {
+        assert!(JavaParser.supports_extension("java"));
+        assert!(!JavaParser.supports_extension("kt"));
+    }
+
+    #[test]
+    fn test_parse_spring_controller() {
+        let code = r#"
+package com.example.demo;
+
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+
+    @GetMapping
+    public List<User> list() { return service.findAll(); }
+
+    @GetMapping("/{id}")
+    public User get(@PathVariable Long id) { return service.find(id); }
+
+    @PostMapping(value = "", consumes = "application/json")
+    public User create(@RequestBody @Valid CreateUserRequest request) { return service.create(request); }
+
+    @RequestMapping(path = "/{id}", method = {RequestMethod.PUT, RequestMethod.PATCH})
+    public User update(@PathVariable("id") Long userId, @RequestBody UserUpdate update) { return null; }
+
+    @DeleteMapping({"/{id}", "/remove/{id}"})
+    public void delete(@PathVariable Long id) {}
+
+    private void helper() {}
+}
+"#;
+
+        let endpoints = JavaParser.parse(code).unwrap();
+        let routes: Vec<_> = endpoints.iter().map(|e| (e.method.clone(), e.path.as_str())).collect();
+        assert_eq!(routes, vec![
+            (HttpMethod::Get, "/api/users"),
+            (HttpMethod::Get, "/api/users/{id}"),
+            (HttpMethod::Post, "/api/users"),
+            (HttpMethod::Put, "/api/users/{id}"),
+            (HttpMethod::Patch, "/api/users/{id}"),
+            (HttpMethod::Delete, "/api/users/{id}"),
+            (HttpMethod::Delete, "/api/users/remove/{id}"),
+        ]);
+        assert_eq!(endpoints[1].handler, "UserController.get");
+
+        let update = &endpoints[3].parameters;
+        assert_eq!(update[0].name, "id");
+        assert_eq!(update[0].location, ParameterLocation::Path);
+        assert_eq!(update[1].location, ParameterLocation::Body);
+        assert_eq!(update[1].param_type.as_deref(), Some("UserUpdate"));
+    }
+
+    #[test]
+    fn test_regex_constrained_mappings() {
+        let code = r#"
+@RestController
+@RequestMapping("/api/files")
+public class FileController {
+    @GetMapping("/{id:\\d+}")
+    public FileInfo get(@PathVariable Long id) { return null; }
+
+    @GetMapping("/{name:[a-z]+}\u002Eraw")
+    public byte[] raw(@PathVariable String name) { return null; }
+}
+"#;
+
+        let endpoints = JavaParser.parse(code).unwrap();
+        let paths: Vec<_> = endpoints.iter().map(|e| (e.path.as_str(), e.template.as_str())).collect();
+        assert_eq!(paths, vec![
+            ("/api/files/{id:\\d+}", "/api/files/{id}"),
+            ("/api/files/{name:[a-z]+}.raw", "/api/files/{name}.raw"),
+        ]);
+    }
+
+    #[test]
+    fn test_mapping_paths_from_constants() {
+        let code = r#"
+interface ApiPaths {
+    String API = "/api";
+    String USERS = API + "/users";
+}
+
+@RestController
+@RequestMapping(ApiPaths.USERS)
+public class UserController {
+    private static final String BY_ID = "/{id}";
+    static final String EXPORT = "/export";
+
+    @GetMapping(BY_ID)
+    public User get(@PathVariable Long id) { return null; }
+
+    @PostMapping({"", EXPORT})
+    public User create() { return null; }
+
+    @DeleteMapping(Routes.REMOVE)
+    public void delete() {}
+
+    @PutMapping(path = "/" + suffix())
+    public void update() {}
+}
+
+@RestController
+@RequestMapping(OtherPaths.ADMIN)
+public class AdminController {
+    @GetMapping("/stats")
+    public Stats stats() { return null; }
+}
+"#;
+
+        let endpoints = JavaParser.parse(code).unwrap();
+        let routes: Vec<_> = endpoints.iter().map(|e| (e.method.clone(), e.path.as_str())).collect();
+        assert_eq!(routes, vec![
+            (HttpMethod::Get, "/api/users/{id}"),
+            (HttpMethod::Post, "/api/users"),
+            (HttpMethod::Post, "/api/users/export"),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_request_params() {
+        let code = r#"
+@RestController
+public class SearchController {
+    @GetMapping("search")
+    public Page<Item> search(
+            @RequestParam String q,
+            @RequestParam(name = "page", defaultValue = "0") int page,
+            @RequestParam(required = false) String sort,
+            @RequestParam Optional<String> filter,
+            @RequestHeader("X-Tenant") String tenant) {
+        return null;
+    }
+}
+
+class NotAController {
+    @GetMapping("/ignored")
+    public void ignored() {}
+}
+"#;
+
+        let endpoints = JavaParser.parse(code).unwrap();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].path, "/search");
+
+        let params = &endpoints[0].parameters;
+        let summary: Vec<_> = params.iter()
+            .map(|p| (p.name.as_str(), p.required, p.default.as_deref()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("q", true, None),
+            ("page", false, Some("0")),
+            ("sort", false, None),
+            ("filter", false, None),
+            ("X-Tenant", true, None),
+        ]);
+        assert_eq!(params[4].location, ParameterLocation::Header);
+    }
+
+    #[test]
+    fn test_responses_from_response_entities() {
+        let code = r#"
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+
+    @GetMapping("/{id}")
+    public ResponseEntity<UserDto> get(@PathVariable Long id) {
+        return service.find(id)
+            .map(ResponseEntity::ok)
+            .orElseGet(() -> ResponseEntity.notFound().build());
+    }
+
+    @PostMapping
+    public ResponseEntity<UserDto> create(@RequestBody CreateUser request) {
+        return ResponseEntity.status(HttpStatus.CREATED).body(service.create(request));
+    }
+
+    @GetMapping
+    public List<UserDto> list() {
+        return service.findAll();
+    }
+
+    @DeleteMapping("/{id}")
+    @ResponseStatus(HttpStatus.NO_CONTENT)
+    public void delete(@PathVariable Long id) {
+        if (!service.exists(id)) {
+            throw new ResponseStatusException(HttpStatus.NOT_FOUND, "missing");
+        }
+    }
+}
+"#;
+
+        let endpoints = JavaParser.parse(code).unwrap();
+        let summary = |e: &Endpoint| -> Vec<(u16, Option<String>)> {
+            e.responses.iter().map(|r| (r.status, r.body.as_ref().map(|b| b.reference.clone().unwrap_or(b.schema_type.clone())))).collect()
+        };
+        assert_eq!(summary(&endpoints[0]), vec![(200, Some("UserDto".to_string())), (404, None)]);
+        assert_eq!(summary(&endpoints[1]), vec![(201, Some("UserDto".to_string()))]);
+        assert_eq!(summary(&endpoints[2]), vec![(200, Some("array".to_string()))]);
+        assert_eq!(summary(&endpoints[3]), vec![(204, None), (404, None)]);
+    }
+}
@@ -1,106 +1,919 @@
-use crate::{Endpoint, HttpMethod, LanguageParser};
+use crate::{Documentation, Endpoint, LanguageParser, parse_http_method};
+use crate::docs::parse_doc_block;
+use crate::languages::{file_routes, js_inputs, js_responses, js_schemas, nestjs, node_frameworks};
+use crate::project::{Import, ModuleRoutes, Mount, RoutedEndpoint, Use};
 use anyhow::{Result, anyhow};
-use tree_sitter::{Parser, Query, QueryCursor};
+use std::path::Path;
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, Tree};
 
 pub struct JavaScriptParser;
 
-impl LanguageParser for JavaScriptParser {
-    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+impl JavaScriptParser {
+    fn parse_tree(&self, content: &str) -> Result<Tree> {
         let mut parser = Parser::new();
         parser.set_language(tree_sitter_javascript::language())?;
-        
-        let tree = parser.parse(content, None)
-            .ok_or_else(|| anyhow!("Failed to parse JavaScript code"))?;
-        
-        let mut endpoints = Vec::new();
-        
-        // Query for Express.js style route definitions
-        let express_query = Query::new(
-            tree_sitter_javascript::language(),
-            r#"
-            (call_expression
-              function: (member_expression
-                object: (identifier) @app
-                property: (property_identifier) @method)
-              arguments: (arguments 
-                (string (string_fragment) @path)
-                . 
-                (arrow_function)?)
-            )
-            "#,
-        )?;
-
-        let mut cursor = QueryCursor::new();
-        let matches = cursor.matches(&express_query, tree.root_node(), content.as_bytes());
-
-        for m in matches {
-            let mut method_name = None;
-            let mut path_value = None;
-            let mut start_position = None;
-
-            for capture in m.captures {
-                let text = &content[capture.node.byte_range()];
-                let capture_name = &express_query.capture_names()[capture.index as usize];
-                match capture_name.as_str() {
-                    "method" => {
-                        method_name = Some(text.to_string());
-                        start_position = Some(capture.node.start_position());
-                    }
-                    "path" => {
-                        path_value = Some(text.to_string());
-                    }
-                    _ => {}
+
+        parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse JavaScript code"))
+    }
+}
+
+impl LanguageParser for JavaScriptParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        let tree = self.parse_tree(content)?;
+        Ok(extract_module(tree_sitter_javascript::language(), Path::new(""), &tree, content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        matches!(extension, "js" | "mjs")
+    }
+
+    fn parse_module(&self, path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let tree = self.parse_tree(content)?;
+        extract_module(tree_sitter_javascript::language(), path, &tree, content)
+    }
+}
+
+/// Extract routes and router wiring (mounts, imports, exports) from a parsed
+/// tree, plus Fastify/Koa/Hono/Hapi registrations, NestJS controllers and
+/// the routes implied by the file's location (Next.js API routes).
+///
+/// Shared with the TypeScript parser: the TS and TSX grammars use the same
+/// node names for calls, member expressions and strings as JavaScript.
+pub(crate) fn extract_module(language: Language, path: &Path, tree: &Tree, content: &str) -> Result<ModuleRoutes> {
+    let mut module = ModuleRoutes {
+        endpoints: extract_routes(language, tree, content)?,
+        ..Default::default()
+    };
+    collect_wiring(tree.root_node(), content, &mut module);
+    node_frameworks::collect(tree.root_node(), content, &mut module);
+    nestjs::collect(tree.root_node(), content, &mut module);
+    js_schemas::collect(tree.root_node(), content, &mut module);
+    file_routes::collect(path, tree.root_node(), content, &mut module);
+    Ok(module)
+}
+
+/// Extract Express-style route registrations, keyed by the object they were
+/// registered on.
+fn extract_routes(language: Language, tree: &Tree, content: &str) -> Result<Vec<RoutedEndpoint>> {
+    let mut endpoints = Vec::new();
+    
+    // Query for Express.js style route definitions
+    let express_query = Query::new(
+        language,
+        r#"
+        (call_expression
+          function: (member_expression
+            object: (identifier) @app
+            property: (property_identifier) @method)
+          arguments: (arguments 
+            (string) @path
+            . 
+            (arrow_function)?)
+        ) @call
+        "#,
+    )?;
+
+    let mut cursor = QueryCursor::new();
+    let matches = cursor.matches(&express_query, tree.root_node(), content.as_bytes());
+
+    for m in matches {
+        let mut owner = None;
+        let mut method_name = None;
+        let mut path_value = None;
+        let mut start_position = None;
+        let mut body = None;
+        let mut parameters = Vec::new();
+        let mut responses = Vec::new();
+        let mut documentation = None;
+        let mut middleware = Vec::new();
+
+        for capture in m.captures {
+            let text = &content[capture.node.byte_range()];
+            let capture_name = &express_query.capture_names()[capture.index as usize];
+            match capture_name.as_str() {
+                "app" => {
+                    owner = Some(owner_binding(capture.node, content));
+                }
+                "method" => {
+                    method_name = Some(text.to_string());
+                    start_position = Some(capture.node.start_position());
+                }
+                "path" => {
+                    path_value = string_value(capture.node, content);
+                }
+                "call" => {
+                    body = js_schemas::route_body(capture.node, content);
+                    parameters = js_inputs::route_inputs(capture.node, content);
+                    responses = js_responses::route_responses(capture.node, content);
+                    middleware = route_middleware(capture.node, content);
+                    documentation = jsdoc(capture.node, content).or_else(|| {
+                        js_inputs::route_handlers(capture.node, content).into_iter().find_map(|handler| jsdoc(handler, content))
+                    });
                 }
+                _ => {}
             }
+        }
+
+        // `form.get('id')` and `map.delete('key')` share the call shape
+        // but not the leading slash of a route path
+        let path_value = path_value.filter(|path| path.starts_with('/') || path == "*");
 
-            if let (Some(method), Some(path), Some(pos)) = (method_name, path_value, start_position) {
-                if let Ok(http_method) = parse_http_method(&method) {
-                    endpoints.push(Endpoint {
+        if let (Some(method), Some(path), Some(pos)) = (method_name, path_value, start_position) {
+            if let Ok(http_method) = parse_http_method(&method) {
+                endpoints.push(RoutedEndpoint {
+                    owner: owner.unwrap_or_default(),
+                    endpoint: Endpoint {
                         method: http_method,
                         path,
                         handler: format!("{}:{}", pos.row + 1, pos.column + 1),
                         line: pos.row + 1,
                         column: pos.column + 1,
-                        documentation: None,
+                        documentation,
+                        parameters,
+                        tags: Vec::new(),
+                        middleware,
+                        template: String::new(),
+                        body,
+                        responses,
+                        requires_auth: false,
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(endpoints)
+}
+
+/// Walk the tree recording `x.use(...)` mounts, `require`/`import` bindings
+/// and CommonJS/ES exports so routers can be linked across files.
+fn collect_wiring(node: Node, content: &str, module: &mut ModuleRoutes) {
+    match node.kind() {
+        "call_expression" => {
+            collect_mount(node, content, module);
+            collect_call_arguments(node, content, module);
+        }
+        "variable_declarator" => collect_require(node, content, module),
+        "import_statement" => collect_import(node, content, module),
+        "assignment_expression" => collect_commonjs_export(node, content, module),
+        "export_statement" => collect_export(node, content, module),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_wiring(child, content, module);
+    }
+}
+
+/// `app.use('/api', router)`, `router.use(auth, require('./users'))`
+fn collect_mount(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let function = match node.child_by_field_name("function") {
+        Some(function) if function.kind() == "member_expression" => function,
+        _ => return,
+    };
+    let (object, property) = match (function.child_by_field_name("object"), function.child_by_field_name("property")) {
+        (Some(object), Some(property)) => (object, property),
+        _ => return,
+    };
+    if text(property, content) != "use" || !is_binding_path(text(object, content)) {
+        return;
+    }
+    let arguments = match node.child_by_field_name("arguments") {
+        Some(arguments) => arguments,
+        None => return,
+    };
+
+    let mut cursor = arguments.walk();
+    let args: Vec<Node> = arguments.named_children(&mut cursor).collect();
+    let (prefix, targets) = match args.first().and_then(|arg| string_value(*arg, content)) {
+        Some(prefix) => (prefix, &args[1..]),
+        None => (String::new(), &args[..]),
+    };
+
+    let parent = owner_binding(object, content);
+    let mut handlers = Vec::new();
+    for arg in targets {
+        if let Some(target) = binding_for(*arg, content, module) {
+            module.mounts.push(Mount {
+                parent: parent.clone(),
+                prefix: prefix.clone(),
+                target: target.clone(),
+            });
+            if !is_function(arg.kind()) {
+                handlers.push(target);
+            }
+        } else {
+            handlers.extend(middleware_name(*arg, content));
+        }
+    }
+
+    let position = property.start_position();
+    module.uses.push(Use {
+        owner: parent,
+        line: position.row + 1,
+        column: position.column + 1,
+        prefix,
+        handlers,
+    });
+}
+
+/// The middleware passed between a route's path and its handler:
+/// `router.get('/me', auth, requireRole('admin'), handler)`.
+fn route_middleware(call: Node, content: &str) -> Vec<String> {
+    let args = js_schemas::arguments(call);
+    let Some((_, between)) = args.get(1..).and_then(|rest| rest.split_last()) else {
+        return Vec::new();
+    };
+    let mut middleware = Vec::new();
+    for arg in between {
+        // `router.get('/', [auth, admin], handler)`
+        if arg.kind() == "array" {
+            let mut cursor = arg.walk();
+            middleware.extend(arg.named_children(&mut cursor).filter_map(|item| middleware_name(item, content)));
+        } else {
+            middleware.extend(middleware_name(*arg, content));
+        }
+    }
+    middleware
+}
+
+/// How middleware is named in an endpoint's chain: its identifier or
+/// member path, or the call that builds it. Inline functions have no name.
+fn middleware_name(node: Node, content: &str) -> Option<String> {
+    match node.kind() {
+        "identifier" | "member_expression" | "call_expression" => {
+            Some(text(node, content).split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+        _ => None,
+    }
+}
+
+/// `registerRoutes(app)`, `require('./routes')(app)`: routes the callee
+/// registers on its parameters belong to the arguments passed in.
+fn collect_call_arguments(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let function = match node.child_by_field_name("function") {
+        Some(function) => function,
+        None => return,
+    };
+    let callee = match function.kind() {
+        "identifier" if text(function, content) != "require" => text(function, content).to_string(),
+        "call_expression" => match require_source(function, content) {
+            Some(source) => inline_require(source, None, module),
+            None => return,
+        },
+        _ => return,
+    };
+    let arguments = match node.child_by_field_name("arguments") {
+        Some(arguments) => arguments,
+        None => return,
+    };
+
+    let mut cursor = arguments.walk();
+    for (i, arg) in arguments.named_children(&mut cursor).enumerate() {
+        if arg.kind() == "identifier" {
+            module.mounts.push(Mount {
+                parent: owner_binding(arg, content),
+                prefix: String::new(),
+                target: format!("{}.#{}", callee, i),
+            });
+        }
+    }
+}
+
+/// `const users = require('./users')`, `const { router } = require('./x')`
+fn collect_require(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let (name, value) = match (node.child_by_field_name("name"), node.child_by_field_name("value")) {
+        (Some(name), Some(value)) => (name, value),
+        _ => return,
+    };
+
+    // `require('./x').router`
+    if value.kind() == "member_expression" && name.kind() == "identifier" {
+        let source = value.child_by_field_name("object").and_then(|o| require_source(o, content));
+        if let (Some(source), Some(property)) = (source, value.child_by_field_name("property")) {
+            module.imports.insert(text(name, content).to_string(), Import {
+                source,
+                name: Some(text(property, content).to_string()),
+            });
+        }
+        return;
+    }
+
+    let source = match require_source(value, content) {
+        Some(source) => source,
+        None => return,
+    };
+
+    match name.kind() {
+        "identifier" => {
+            module.imports.insert(text(name, content).to_string(), Import { source, name: None });
+        }
+        "object_pattern" => {
+            let mut cursor = name.walk();
+            for field in name.named_children(&mut cursor) {
+                let (imported, local) = match field.kind() {
+                    "shorthand_property_identifier_pattern" => (text(field, content), text(field, content)),
+                    "pair_pattern" => match (field.child_by_field_name("key"), field.child_by_field_name("value")) {
+                        (Some(key), Some(value)) if value.kind() == "identifier" => (text(key, content), text(value, content)),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
+                module.imports.insert(local.to_string(), Import {
+                    source: source.clone(),
+                    name: Some(imported.to_string()),
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `import users from './users'`, `import { router as r } from './x'`,
+/// `import * as routes from './routes'`, TS `import users = require('./users')`
+fn collect_import(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == "import_require_clause" {
+            let mut inner = child.walk();
+            let parts: Vec<Node> = child.named_children(&mut inner).collect();
+            if let (Some(local), Some(source)) = (
+                parts.iter().find(|n| n.kind() == "identifier"),
+                parts.iter().find_map(|n| string_value(*n, content)),
+            ) {
+                module.imports.insert(text(*local, content).to_string(), Import { source, name: None });
+            }
+        }
+    }
+
+    let source = match node.child_by_field_name("source").and_then(|s| string_value(s, content)) {
+        Some(source) => source,
+        None => return,
+    };
+
+    let mut cursor = node.walk();
+    let clause = match node.named_children(&mut cursor).find(|n| n.kind() == "import_clause") {
+        Some(clause) => clause,
+        None => return,
+    };
+
+    let mut cursor = clause.walk();
+    for part in clause.named_children(&mut cursor) {
+        match part.kind() {
+            "identifier" => {
+                module.imports.insert(text(part, content).to_string(), Import {
+                    source: source.clone(),
+                    name: None,
+                });
+            }
+            "namespace_import" => {
+                let mut inner = part.walk();
+                let local = part.named_children(&mut inner).find(|n| n.kind() == "identifier");
+                if let Some(local) = local {
+                    module.imports.insert(text(local, content).to_string(), Import {
+                        source: source.clone(),
+                        name: Some("*".to_string()),
+                    });
+                }
+            }
+            "named_imports" => {
+                let mut inner = part.walk();
+                for specifier in part.named_children(&mut inner) {
+                    let name = match specifier.child_by_field_name("name") {
+                        Some(name) => text(name, content),
+                        None => continue,
+                    };
+                    let local = specifier.child_by_field_name("alias")
+                        .map(|alias| text(alias, content))
+                        .unwrap_or(name);
+                    module.imports.insert(local.to_string(), Import {
+                        source: source.clone(),
+                        name: Some(name.to_string()),
                     });
                 }
             }
+            _ => {}
         }
+    }
+}
+
+/// `module.exports = router`, `module.exports = { router }`, `exports.router = router`
+fn collect_commonjs_export(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let (left, right) = match (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+        (Some(left), Some(right)) => (left, right),
+        _ => return,
+    };
 
-        Ok(endpoints)
+    let target = text(left, content);
+    if target == "module.exports" {
+        match right.kind() {
+            "identifier" => {
+                module.exports.insert("default".to_string(), text(right, content).to_string());
+            }
+            "object" => {
+                let mut cursor = right.walk();
+                for field in right.named_children(&mut cursor) {
+                    match field.kind() {
+                        "shorthand_property_identifier" => {
+                            let name = text(field, content).to_string();
+                            module.exports.insert(name.clone(), name);
+                        }
+                        "pair" => {
+                            if let (Some(key), Some(value)) = (field.child_by_field_name("key"), field.child_by_field_name("value")) {
+                                if let Some(value) = binding_for(value, content, module) {
+                                    module.exports.insert(text(key, content).to_string(), value);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {
+                if let Some(value) = binding_for(right, content, module) {
+                    module.exports.insert("default".to_string(), value);
+                }
+            }
+        }
+        return;
     }
 
-    fn supports_extension(&self, extension: &str) -> bool {
-        matches!(extension, "js" | "mjs" | "ts" | "tsx")
+    if let Some(name) = target.strip_prefix("module.exports.").or_else(|| target.strip_prefix("exports.")) {
+        if let Some(value) = binding_for(right, content, module) {
+            module.exports.insert(name.to_string(), value);
+        }
     }
 }
 
-fn parse_http_method(method_str: &str) -> Result<HttpMethod> {
-    match method_str.to_lowercase().as_str() {
-        "get" => Ok(HttpMethod::Get),
-        "post" => Ok(HttpMethod::Post),
-        "put" => Ok(HttpMethod::Put),
-        "delete" => Ok(HttpMethod::Delete),
-        "patch" => Ok(HttpMethod::Patch),
-        "head" => Ok(HttpMethod::Head),
-        "options" => Ok(HttpMethod::Options),
-        _ => Err(anyhow!("Unknown HTTP method: {}", method_str)),
+/// `export default router`, `export { router as users }`, `export class X`,
+/// `export { default as users } from './users'`
+fn collect_export(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let mut cursor = node.walk();
+    let is_default = node.children(&mut cursor).any(|c| c.kind() == "default");
+    let declaration = node.child_by_field_name("declaration");
+
+    // `export default function routes(fastify) {}`
+    if let (true, Some(name)) = (is_default, declaration.and_then(|d| d.child_by_field_name("name"))) {
+        module.exports.insert("default".to_string(), text(name, content).to_string());
+        return;
+    }
+
+    if let Some(value) = node.child_by_field_name("value") {
+        if let Some(value) = binding_for(value, content, module) {
+            module.exports.insert("default".to_string(), value);
+        }
+        return;
+    }
+
+    // `export class UsersModule {}`, `export const router = Router()`
+    if let Some(declaration) = declaration {
+        let mut names = Vec::new();
+        match declaration.kind() {
+            "lexical_declaration" | "variable_declaration" => {
+                let mut cursor = declaration.walk();
+                for declarator in declaration.named_children(&mut cursor) {
+                    names.extend(declarator.child_by_field_name("name").filter(|n| n.kind() == "identifier"));
+                }
+            }
+            _ => names.extend(declaration.child_by_field_name("name")),
+        }
+        for name in names {
+            let name = text(name, content).to_string();
+            module.exports.insert(name.clone(), name);
+        }
+        return;
+    }
+
+    let source = node.child_by_field_name("source").and_then(|s| string_value(s, content));
+
+    let mut cursor = node.walk();
+    let clause = match node.named_children(&mut cursor).find(|n| n.kind() == "export_clause") {
+        Some(clause) => clause,
+        None => return,
+    };
+
+    let mut cursor = clause.walk();
+    for specifier in clause.named_children(&mut cursor) {
+        let name = match specifier.child_by_field_name("name") {
+            Some(name) => text(name, content).to_string(),
+            None => continue,
+        };
+        let exported = specifier.child_by_field_name("alias")
+            .map(|alias| text(alias, content).to_string())
+            .unwrap_or_else(|| name.clone());
+
+        let local = match &source {
+            Some(source) => {
+                // Re-exports get a synthetic local binding pointing at the other module
+                let local = format!("export#{}", exported);
+                module.imports.insert(local.clone(), Import {
+                    source: source.clone(),
+                    name: if name == "default" { None } else { Some(name) },
+                });
+                local
+            }
+            None => name,
+        };
+        module.exports.insert(exported, local);
+    }
+}
+
+/// Name something that can be mounted or exported: an identifier, a dotted
+/// member path, a function, `router.routes()` (Koa), or an inline
+/// `require('./x')` (given a synthetic binding).
+pub(crate) fn binding_for(node: Node, content: &str, module: &mut ModuleRoutes) -> Option<String> {
+    match node.kind() {
+        "identifier" => Some(owner_binding(node, content)),
+        kind if is_function(kind) => Some(function_binding(node, content)),
+        "member_expression" => {
+            let object = node.child_by_field_name("object")?;
+            let property = node.child_by_field_name("property")?;
+            if let Some(source) = require_source(object, content) {
+                let local = inline_require(source, None, module);
+                Some(format!("{}.{}", local, text(property, content)))
+            } else if is_binding_path(text(node, content)) {
+                Some(text(node, content).to_string())
+            } else {
+                None
+            }
+        }
+        "call_expression" => {
+            if let Some(source) = require_source(node, content) {
+                return Some(inline_require(source, None, module));
+            }
+            let function = node.child_by_field_name("function")?;
+            let property = function.child_by_field_name("property")?;
+            if matches!(text(property, content), "routes" | "middleware") {
+                return binding_for(function.child_by_field_name("object")?, content, module);
+            }
+            None
+        }
+        _ => None,
     }
 }
 
+/// The binding routes registered on identifier `node` belong to: a parameter
+/// of an enclosing function is `fn.#i` (so callers can mount it), anything
+/// else is its own name.
+pub(crate) fn owner_binding(node: Node, content: &str) -> String {
+    let name = text(node, content);
+    if node.kind() != "identifier" {
+        return name.to_string();
+    }
+
+    let mut current = node.parent();
+    while let Some(scope) = current {
+        if is_function(scope.kind()) {
+            if let Some(i) = parameter_index(scope, name, content) {
+                return format!("{}.#{}", function_binding(scope, content), i);
+            }
+        }
+        current = scope.parent();
+    }
+    name.to_string()
+}
+
+fn is_function(kind: &str) -> bool {
+    matches!(
+        kind,
+        "function_declaration" | "generator_function_declaration" | "function" | "function_expression"
+            | "generator_function" | "arrow_function" | "method_definition"
+    )
+}
+
+fn parameter_index(function: Node, name: &str, content: &str) -> Option<usize> {
+    // `app => ...`
+    if let Some(param) = function.child_by_field_name("parameter") {
+        return (text(param, content) == name).then_some(0);
+    }
+    let params = function.child_by_field_name("parameters")?;
+    let mut cursor = params.walk();
+    let position = params.named_children(&mut cursor)
+        .filter(|p| p.kind() != "comment")
+        .position(|p| {
+            let binding = match p.kind() {
+                "required_parameter" | "optional_parameter" => p.child_by_field_name("pattern"),
+                "assignment_pattern" => p.child_by_field_name("left"),
+                _ => Some(p),
+            };
+            binding.map(|b| text(b, content) == name).unwrap_or(false)
+        });
+    position
+}
+
+/// Name of a function routes can be registered through: its declared name,
+/// the variable it's assigned to, `default` when it is the default export,
+/// else a synthetic name.
+fn function_binding(function: Node, content: &str) -> String {
+    if matches!(function.kind(), "function_declaration" | "generator_function_declaration") {
+        if let Some(name) = function.child_by_field_name("name") {
+            return text(name, content).to_string();
+        }
+    }
+
+    if let Some(parent) = function.parent() {
+        match parent.kind() {
+            "variable_declarator" => {
+                if let Some(name) = parent.child_by_field_name("name") {
+                    return text(name, content).to_string();
+                }
+            }
+            "export_statement" => return "default".to_string(),
+            "assignment_expression"
+                if parent.child_by_field_name("left").map(|l| text(l, content)) == Some("module.exports") =>
+            {
+                return "default".to_string();
+            }
+            _ => {}
+        }
+    }
+    format!("fn@{}", function.start_byte())
+}
+
+fn inline_require(source: String, name: Option<String>, module: &mut ModuleRoutes) -> String {
+    let local = format!("require#{}", module.imports.len());
+    module.imports.insert(local.clone(), Import { source, name });
+    local
+}
+
+/// The specifier of a `require('...')` call.
+fn require_source(node: Node, content: &str) -> Option<String> {
+    if node.kind() != "call_expression" {
+        return None;
+    }
+    let function = node.child_by_field_name("function")?;
+    if text(function, content) != "require" {
+        return None;
+    }
+    let arguments = node.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let first = arguments.named_children(&mut cursor).next()?;
+    string_value(first, content)
+}
+
+/// The contents of a plain string literal.
+pub(crate) fn string_value(node: Node, content: &str) -> Option<String> {
+    if node.kind() != "string" {
+        return None;
+    }
+    let mut value = String::new();
+    let mut cursor = node.walk();
+    for part in node.named_children(&mut cursor) {
+        match part.kind() {
+            // `'/:id(\\d+)'` is the regex `\d+`
+            "escape_sequence" => value.push_str(&text(part, content)[1..]),
+            _ => value.push_str(text(part, content)),
+        }
+    }
+    Some(value)
+}
+
+fn is_binding_path(text: &str) -> bool {
+    !text.is_empty() && text.split('.').all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    })
+}
+
+/// The `/** ... */` block above the statement or class member containing
+/// `node`, looking past decorators.
+pub(crate) fn jsdoc(node: Node, content: &str) -> Option<Documentation> {
+    let mut statement = node;
+    while let Some(parent) = statement.parent() {
+        if matches!(parent.kind(), "program" | "statement_block" | "class_body") {
+            break;
+        }
+        statement = parent;
+    }
+    let mut previous = statement.prev_sibling();
+    while let Some(decorator) = previous.filter(|p| p.kind() == "decorator") {
+        previous = decorator.prev_sibling();
+    }
+    let comment = text(previous.filter(|p| p.kind() == "comment")?, content);
+    comment.starts_with("/**").then(|| parse_doc_block(comment))
+}
+
+pub(crate) fn text<'a>(node: Node, content: &'a str) -> &'a str {
+    &content[node.byte_range()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ParameterLocation, Response};
 
     #[test]
     fn test_supports_javascript_extensions() {
         let parser = JavaScriptParser;
         assert!(parser.supports_extension("js"));
-        assert!(parser.supports_extension("ts"));
-        assert!(parser.supports_extension("tsx"));
+        assert!(parser.supports_extension("mjs"));
+        assert!(!parser.supports_extension("ts"));
         assert!(!parser.supports_extension("py"));
     }
 
+    #[test]
+    fn test_applies_mounts_within_a_file() {
+        let parser = JavaScriptParser;
+        let code = r#"
+const express = require('express');
+const app = express();
+const users = express.Router();
+
+users.get('/', (req, res) => res.json([]));
+users.delete('/:id', (req, res) => res.sendStatus(204));
+
+app.use('/api/users', authenticate, users);
+app.get('/health', (req, res) => res.send('ok'));
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let paths: Vec<_> = endpoints.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["/api/users", "/api/users/:id", "/health"]);
+    }
+
+    #[test]
+    fn test_request_bodies_from_validation_schemas() {
+        let parser = JavaScriptParser;
+        let code = r#"
+const address = z.object({ city: z.string(), zip: z.string().optional() });
+const createUser = z.object({
+  name: z.string().min(1),
+  email: z.string().email(),
+  age: z.number().int().optional(),
+  tags: z.array(z.string()),
+  address,
+});
+const updateUser = Joi.object({
+  name: Joi.string(),
+  role: Joi.string().valid('admin', 'user').required(),
+});
+
+function updateHandler(req, res) {
+  const data = updateUser.validate(req.body);
+  res.json(data);
+}
+
+app.post('/users', validate(createUser), (req, res) => res.json(req.body));
+app.put('/users/:id', updateHandler);
+app.get('/users', (req, res) => res.json([]));
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let body = endpoints[0].body.as_ref().unwrap();
+        assert_eq!(body.name.as_deref(), Some("createUser"));
+        let fields: Vec<_> = body.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str(), p.required)).collect();
+        assert_eq!(fields, vec![
+            ("name", "string", true),
+            ("email", "string", true),
+            ("age", "integer", false),
+            ("tags", "array", true),
+            ("address", "object", true),
+        ]);
+        assert!(!body.properties[4].schema.properties[1].required);
+
+        let body = endpoints[1].body.as_ref().unwrap();
+        let fields: Vec<_> = body.properties.iter().map(|p| (p.name.as_str(), p.required)).collect();
+        assert_eq!(fields, vec![("name", false), ("role", true)]);
+
+        assert!(endpoints[2].body.is_none());
+    }
+
+    #[test]
+    fn test_query_parameters_and_headers_from_handlers() {
+        let parser = JavaScriptParser;
+        let code = r#"
+function listUsers(req, res) {
+  const page = parseInt(req.query.page) || 1;
+  const { sort = 'name', order } = req.query;
+  const tenant = req.headers['x-tenant'];
+  res.json(db.query.users.findMany({ page, sort, order, tenant }));
+}
+
+app.get('/users', listUsers);
+app.get('/search', (req, res) => {
+  const term = req.query['q'] ?? '';
+  res.json({ term, auth: req.get('Authorization') });
+});
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let inputs: Vec<_> = endpoints[0].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.param_type.as_deref(), p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("page", ParameterLocation::Query, Some("integer"), Some("1")),
+            ("sort", ParameterLocation::Query, None, Some("name")),
+            ("order", ParameterLocation::Query, None, None),
+            ("x-tenant", ParameterLocation::Header, None, None),
+        ]);
+
+        let inputs: Vec<_> = endpoints[1].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("q", ParameterLocation::Query, Some("")),
+            ("Authorization", ParameterLocation::Header, None),
+        ]);
+    }
+
+    #[test]
+    fn test_responses_from_handlers() {
+        let parser = JavaScriptParser;
+        let code = r#"
+async function getUser(req, res) {
+  const user = await db.find(req.params.id);
+  if (!user) {
+    return res.status(404).json({ error: 'Not found', code: 404 });
+  }
+  const profile = await fetch(url).then((r) => r.json());
+  res.json({ id: user.id, tags: ['a'], profile });
+}
+
+app.get('/users/:id', getUser);
+app.delete('/users/:id', (req, res) => res.sendStatus(StatusCodes.NO_CONTENT));
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let statuses: Vec<_> = endpoints[0].responses.iter().map(|r| r.status).collect();
+        assert_eq!(statuses, vec![404, 200]);
+        let error = endpoints[0].responses[0].body.as_ref().unwrap();
+        let fields: Vec<_> = error.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str())).collect();
+        assert_eq!(fields, vec![("error", "string"), ("code", "integer")]);
+        let user = endpoints[0].responses[1].body.as_ref().unwrap();
+        let fields: Vec<_> = user.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str())).collect();
+        assert_eq!(fields, vec![("id", "any"), ("tags", "array"), ("profile", "any")]);
+
+        assert_eq!(endpoints[1].responses, vec![Response { status: 204, body: None }]);
+    }
+
+    #[test]
+    fn test_jsdoc_documentation() {
+        let parser = JavaScriptParser;
+        let code = r#"
+/**
+ * List users.
+ * @param {number} page - Page to return
+ */
+router.get('/users', listUsers);
+
+/**
+ * Remove a user.
+ * @deprecated Use DELETE /v2/users/:id
+ */
+function removeUser(req, res) {
+  res.sendStatus(204);
+}
+
+// Not a doc block
+router.delete('/users/:id', removeUser);
+router.get('/health', (req, res) => res.json({}));
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let list = endpoints[0].documentation.as_ref().unwrap();
+        assert_eq!(list.summary.as_deref(), Some("List users."));
+        assert_eq!(list.params[0].name, "page");
+
+        let remove = endpoints[1].documentation.as_ref().unwrap();
+        assert_eq!(remove.summary.as_deref(), Some("Remove a user."));
+        assert_eq!(remove.deprecated.as_deref(), Some("Use DELETE /v2/users/:id"));
+
+        assert!(endpoints[2].documentation.is_none());
+    }
+
+    #[test]
+    fn test_middleware_chains() {
+        let parser = JavaScriptParser;
+        let code = r#"
+const express = require('express');
+const passport = require('passport');
+const app = express();
+const router = express.Router();
+const admin = express.Router();
+
+app.use(express.json());
+router.post('/login', login);
+router.use(passport.authenticate('jwt', { session: false }));
+router.get('/me', [rateLimit, audit], (req, res) => res.json(req.user));
+admin.delete('/users/:id', requireRole('admin'), removeUser);
+app.use('/api', router);
+app.use('/admin', requireAuth, admin);
+app.get('/health', (req, res) => res.send('ok'));
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let chains: Vec<_> = endpoints.iter()
+            .map(|e| (e.path.as_str(), e.middleware.join(","), e.requires_auth))
+            .collect();
+        assert_eq!(chains, vec![
+            ("/api/login", "express.json()".to_string(), false),
+            ("/api/me", "express.json(),passport.authenticate('jwt', { session: false }),rateLimit,audit".to_string(), true),
+            ("/admin/users/:id", "express.json(),requireAuth,requireRole('admin')".to_string(), true),
+            ("/health", "express.json()".to_string(), false),
+        ]);
+    }
+
     #[test]
     fn test_parse_empty_content() {
         let parser = JavaScriptParser;
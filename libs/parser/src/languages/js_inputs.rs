@@ -0,0 +1,192 @@
+//! Query parameters and headers Express-style handlers read.
+//!
+//! Inputs come from `req.query.page`, `req.query['page']`,
+//! `const { page = 1 } = req.query`, `req.headers['x-tenant']`,
+//! `req.get('X-Tenant')` and Hono's `c.req.query('page')` /
+//! `c.req.header('X-Tenant')`. A literal after `||` or `??` is the default,
+//! and `parseInt`/`Number`/`Boolean` wrappers give the type.
+
+use crate::languages::javascript::{string_value, text};
+use crate::languages::js_schemas::{arguments, function_named, root};
+use crate::params::{add_inputs, input};
+use crate::{Parameter, ParameterLocation};
+use tree_sitter::Node;
+
+/// Inputs read by the handlers and middleware passed after the route path.
+pub(crate) fn route_inputs(call: Node, content: &str) -> Vec<Parameter> {
+    let mut parameters = Vec::new();
+    for function in route_handlers(call, content) {
+        let request = request_name(function, content);
+        let mut found = Vec::new();
+        collect(function, request.as_deref(), content, &mut found);
+        add_inputs(&mut parameters, &found);
+    }
+    parameters
+}
+
+/// The functions passed after the route path, looking up handlers passed
+/// by name in the same file.
+pub(crate) fn route_handlers<'t>(call: Node<'t>, content: &str) -> Vec<Node<'t>> {
+    arguments(call).into_iter().skip(1).filter_map(|arg| match arg.kind() {
+        "arrow_function" | "function_expression" | "function" => Some(arg),
+        "identifier" => function_named(root(arg), text(arg, content), content),
+        _ => None,
+    }).collect()
+}
+
+/// The handler's first parameter: `req` in `(req, res) => {}`.
+fn request_name(function: Node, content: &str) -> Option<String> {
+    if let Some(parameter) = function.child_by_field_name("parameter") {
+        return Some(text(parameter, content).to_string());
+    }
+    let parameters = function.child_by_field_name("parameters")?;
+    let mut cursor = parameters.walk();
+    let first = parameters.named_children(&mut cursor).next()?;
+    let name = match first.kind() {
+        "required_parameter" | "optional_parameter" => first.child_by_field_name("pattern")?,
+        _ => first,
+    };
+    (name.kind() == "identifier").then(|| text(name, content).to_string())
+}
+
+fn collect(node: Node, request: Option<&str>, content: &str, found: &mut Vec<Parameter>) {
+    match node.kind() {
+        "member_expression" | "subscript_expression" => {
+            if let Some((name, location)) = accessed(node, request, content) {
+                found.push(read(node, &name, location, content));
+            }
+        }
+        "call_expression" => {
+            if let Some((name, location)) = getter(node, request, content) {
+                found.push(read(node, &name, location, content));
+            }
+        }
+        "variable_declarator" => destructured(node, request, content, found),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    for child in children {
+        collect(child, request, content, found);
+    }
+}
+
+/// `req.query.page`, `req.query['page']`, `req.headers['x-tenant']`
+fn accessed(node: Node, request: Option<&str>, content: &str) -> Option<(String, ParameterLocation)> {
+    let location = source(node.child_by_field_name("object")?, request, content)?;
+    let name = match node.kind() {
+        "member_expression" => text(node.child_by_field_name("property")?, content).to_string(),
+        _ => string_value(node.child_by_field_name("index")?, content)?,
+    };
+    Some((name, location))
+}
+
+/// `req.get('X-Tenant')`, `req.header('X-Tenant')`, `c.req.query('page')`
+fn getter(call: Node, request: Option<&str>, content: &str) -> Option<(String, ParameterLocation)> {
+    let function = call.child_by_field_name("function").filter(|f| f.kind() == "member_expression")?;
+    let object = text(function.child_by_field_name("object")?, content);
+    let is_request = Some(object) == request || object.ends_with(".req");
+    if !is_request {
+        return None;
+    }
+    let location = match text(function.child_by_field_name("property")?, content) {
+        "query" => ParameterLocation::Query,
+        "get" | "header" => ParameterLocation::Header,
+        _ => return None,
+    };
+    let name = string_value(*arguments(call).first()?, content)?;
+    Some((name, location))
+}
+
+/// `const { page = 1, limit: max } = req.query`
+fn destructured(declarator: Node, request: Option<&str>, content: &str, found: &mut Vec<Parameter>) {
+    let (Some(pattern), Some(value)) = (declarator.child_by_field_name("name"), declarator.child_by_field_name("value")) else {
+        return;
+    };
+    let Some(location) = source(value, request, content).filter(|_| pattern.kind() == "object_pattern") else {
+        return;
+    };
+    let mut cursor = pattern.walk();
+    for property in pattern.named_children(&mut cursor) {
+        let parameter = match property.kind() {
+            "shorthand_property_identifier_pattern" => input(text(property, content), location.clone(), None),
+            "object_assignment_pattern" => {
+                let Some(left) = property.child_by_field_name("left") else { continue };
+                let default = property.child_by_field_name("right").and_then(|right| literal(right, content));
+                input(text(left, content), location.clone(), default)
+            }
+            "pair_pattern" => {
+                let Some(key) = property.child_by_field_name("key") else { continue };
+                let name = string_value(key, content).unwrap_or_else(|| text(key, content).to_string());
+                let default = property.child_by_field_name("value")
+                    .filter(|value| value.kind() == "assignment_pattern")
+                    .and_then(|value| value.child_by_field_name("right"))
+                    .and_then(|right| literal(right, content));
+                input(&name, location.clone(), default)
+            }
+            _ => continue,
+        };
+        found.push(parameter);
+    }
+}
+
+/// Where `req.query` / `req.headers` / `ctx.request.query` reads from.
+/// Other objects' `query` (`db.query.users`) aren't request inputs.
+fn source(node: Node, request: Option<&str>, content: &str) -> Option<ParameterLocation> {
+    if node.kind() != "member_expression" {
+        return None;
+    }
+    let object = text(node.child_by_field_name("object")?, content);
+    let is_request = Some(object) == request
+        || ["req", "request", "ctx"].contains(&object)
+        || object.ends_with(".req")
+        || object.ends_with(".request");
+    if !is_request {
+        return None;
+    }
+    match text(node.child_by_field_name("property")?, content) {
+        "query" => Some(ParameterLocation::Query),
+        "headers" => Some(ParameterLocation::Header),
+        _ => None,
+    }
+}
+
+/// The input read at `node`, with the type a conversion gives it and the
+/// literal it falls back to: `parseInt(req.query.page) || 1`.
+fn read(node: Node, name: &str, location: ParameterLocation, content: &str) -> Parameter {
+    let mut parameter = input(name, location, None);
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "arguments" | "parenthesized_expression" => {}
+            "call_expression" => {
+                let callee = parent.child_by_field_name("function").map(|f| text(f, content)).unwrap_or_default();
+                parameter.param_type = match callee {
+                    "parseInt" | "Number.parseInt" => Some("integer".to_string()),
+                    "Number" | "parseFloat" | "Number.parseFloat" => Some("number".to_string()),
+                    "Boolean" => Some("boolean".to_string()),
+                    _ => break,
+                };
+            }
+            "binary_expression" => {
+                let operator = parent.child_by_field_name("operator").map(|o| text(o, content));
+                if matches!(operator, Some("||" | "??")) && parent.child_by_field_name("left") == Some(current) {
+                    parameter.default = parent.child_by_field_name("right").and_then(|right| literal(right, content));
+                }
+                break;
+            }
+            _ => break,
+        }
+        current = parent;
+    }
+    parameter
+}
+
+fn literal(node: Node, content: &str) -> Option<String> {
+    match node.kind() {
+        "string" => string_value(node, content),
+        "number" | "true" | "false" => Some(text(node, content).to_string()),
+        _ => None,
+    }
+}
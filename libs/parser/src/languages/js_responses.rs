@@ -0,0 +1,123 @@
+//! Responses Express-style handlers send.
+//!
+//! `res.status(404).json({...})`, `res.json(users)`, `res.sendStatus(204)`,
+//! Fastify's `reply.code(201).send(user)` and Hono's `c.json({...}, 201)`.
+//! Bodies are described when they are literals; status codes may be
+//! numbers or named constants (`StatusCodes.CREATED`).
+
+use crate::languages::javascript::text;
+use crate::languages::js_inputs::route_handlers;
+use crate::languages::js_schemas::arguments;
+use crate::responses::{add_response, response, status_code};
+use crate::{Response, Schema};
+use tree_sitter::Node;
+
+/// Methods that send the response, and whether their first argument is the body.
+const SEND_METHODS: [(&str, bool); 6] = [("json", true), ("send", true), ("jsonp", true), ("end", false), ("sendStatus", false), ("text", true)];
+
+/// Responses sent by the handlers passed after the route path.
+pub(crate) fn route_responses(call: Node, content: &str) -> Vec<Response> {
+    let mut responses = Vec::new();
+    for function in route_handlers(call, content) {
+        let receivers = receivers(function, content);
+        collect(function, &receivers, content, &mut responses);
+    }
+    responses
+}
+
+/// The handler's parameter names: `(req, res)` or Hono's `(c)`.
+fn receivers(function: Node, content: &str) -> Vec<String> {
+    if let Some(parameter) = function.child_by_field_name("parameter") {
+        return vec![text(parameter, content).to_string()];
+    }
+    let Some(parameters) = function.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = parameters.walk();
+    let names = parameters.named_children(&mut cursor)
+        .map(|p| p.child_by_field_name("pattern").unwrap_or(p))
+        .filter(|p| p.kind() == "identifier")
+        .map(|p| text(p, content).to_string())
+        .collect();
+    names
+}
+
+fn collect(node: Node, receivers: &[String], content: &str, responses: &mut Vec<Response>) {
+    if node.kind() == "call_expression" {
+        if let Some(sent) = sent(node, receivers, content) {
+            add_response(responses, sent);
+        }
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    for child in children {
+        collect(child, receivers, content, responses);
+    }
+}
+
+/// The response a `res.status(201).json(body)`-style call sends.
+fn sent(call: Node, receivers: &[String], content: &str) -> Option<Response> {
+    let function = call.child_by_field_name("function").filter(|f| f.kind() == "member_expression")?;
+    let method = text(function.child_by_field_name("property")?, content);
+    let (_, has_body) = SEND_METHODS.iter().find(|(name, _)| *name == method)?;
+    let args = arguments(call);
+
+    // Walk `res.status(404).json` back to `res`, picking up the status
+    let mut status = None;
+    let mut object = function.child_by_field_name("object")?;
+    while object.kind() == "call_expression" {
+        let inner = object.child_by_field_name("function").filter(|f| f.kind() == "member_expression")?;
+        if matches!(inner.child_by_field_name("property").map(|p| text(p, content)), Some("status" | "code")) {
+            status = arguments(object).first().and_then(|s| status_code(text(*s, content)));
+        }
+        object = inner.child_by_field_name("object")?;
+    }
+    // `await fetch(url).then((r) => r.json())` isn't a response the handler sends
+    let receiver = text(object, content);
+    if !receivers.iter().any(|r| r == receiver) {
+        return None;
+    }
+
+    let status = match method {
+        "sendStatus" => status_code(text(*args.first()?, content))?,
+        // Hono: `c.json(body, 201)`
+        "json" | "text" if status.is_none() && args.len() > 1 => status_code(text(args[1], content)).unwrap_or(200),
+        _ => status.unwrap_or(200),
+    };
+    let body = args.first().filter(|_| *has_body).and_then(|body| value_schema(*body, content));
+    Some(response(status, body))
+}
+
+/// The shape of a literal body. Other expressions are left undescribed.
+fn value_schema(node: Node, content: &str) -> Option<Schema> {
+    let schema = match node.kind() {
+        "object" => {
+            let mut schema = Schema::of_type("object");
+            let mut cursor = node.walk();
+            for property in node.named_children(&mut cursor) {
+                let (key, value) = match property.kind() {
+                    "pair" => match (property.child_by_field_name("key"), property.child_by_field_name("value")) {
+                        (Some(key), Some(value)) => (text(key, content).trim_matches(['\'', '"']), value_schema(value, content)),
+                        _ => continue,
+                    },
+                    "shorthand_property_identifier" => (text(property, content), None),
+                    _ => continue,
+                };
+                schema.set_property(key, value.unwrap_or_else(|| Schema::of_type("any")), true);
+            }
+            schema
+        }
+        "array" => {
+            let mut cursor = node.walk();
+            let first = node.named_children(&mut cursor).next().and_then(|item| value_schema(item, content));
+            Schema::array(first.unwrap_or_else(|| Schema::of_type("any")))
+        }
+        "string" | "template_string" => Schema::of_type("string"),
+        "number" if text(node, content).contains('.') => Schema::of_type("number"),
+        "number" => Schema::of_type("integer"),
+        "true" | "false" => Schema::of_type("boolean"),
+        _ => return None,
+    };
+    Some(schema)
+}
@@ -0,0 +1,420 @@
+//! Request body shapes for the JavaScript and TypeScript parsers.
+//!
+//! Named shapes are zod, joi and yup schemas assigned to variables, and
+//! TypeScript classes, interfaces and object type aliases used as DTOs.
+//! Express routes take their body from validation middleware
+//! (`validate(createUserSchema)`, `celebrate({ body: ... })`) or from a
+//! `schema.parse(req.body)` call in the handler; NestJS handlers from the
+//! type of their `@Body()` parameter.
+
+use crate::Schema;
+use crate::languages::javascript::{string_value, text};
+use crate::project::ModuleRoutes;
+use tree_sitter::Node;
+
+/// Validation libraries and whether their fields are required by default.
+const LIBRARIES: [(&str, bool); 5] = [("z", true), ("Joi", false), ("joi", false), ("yup", false), ("Yup", false)];
+
+/// Methods that check a value against a schema: `schema.parse(req.body)`.
+const PARSE_METHODS: [&str; 8] = ["parse", "parseAsync", "safeParse", "safeParseAsync", "validate", "validateAsync", "validateSync", "cast"];
+
+/// Record the named body shapes declared in the tree.
+pub(crate) fn collect(node: Node, content: &str, module: &mut ModuleRoutes) {
+    match node.kind() {
+        // `const createUser = z.object({ ... })`
+        "variable_declarator" => {
+            if let (Some(name), Some(value)) = (node.child_by_field_name("name"), node.child_by_field_name("value")) {
+                if name.kind() == "identifier" && library(value, content).is_some() {
+                    if let Some((schema, _)) = validator(value, content, true) {
+                        let name = text(name, content);
+                        module.schemas.insert(name.to_string(), Schema { name: Some(name.to_string()), ..schema });
+                    }
+                }
+            }
+        }
+        "class_declaration" | "abstract_class_declaration" => collect_class(node, content, module),
+        // `interface CreateUser { ... }`, `type CreateUser = { ... }`
+        "interface_declaration" | "type_alias_declaration" => {
+            let body = node.child_by_field_name("body").or_else(|| node.child_by_field_name("value"));
+            if let (Some(name), Some(body)) = (node.child_by_field_name("name"), body) {
+                if matches!(body.kind(), "interface_body" | "object_type") {
+                    let name = text(name, content);
+                    let schema = Schema { name: Some(name.to_string()), ..type_schema(body, content).0 };
+                    module.schemas.insert(name.to_string(), schema);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(child, content, module);
+    }
+}
+
+/// DTO classes: public typed fields, with `?` or class-validator's
+/// `@IsOptional()` marking optional ones. A class without fields that
+/// extends another (`extends PartialType(CreateUserDto)`) refers to it.
+fn collect_class(class: Node, content: &str, module: &mut ModuleRoutes) {
+    let (name, body) = match (class.child_by_field_name("name"), class.child_by_field_name("body")) {
+        (Some(name), Some(body)) => (text(name, content), body),
+        _ => return,
+    };
+
+    let mut schema = Schema { name: Some(name.to_string()), ..Schema::of_type("object") };
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        if member.kind() != "public_field_definition" || has_child(member, "accessibility_modifier", content, &["private", "protected"]) {
+            continue;
+        }
+        let (field, annotation) = match (member.child_by_field_name("name"), member.child_by_field_name("type")) {
+            (Some(field), Some(annotation)) => (text(field, content), annotation),
+            _ => continue,
+        };
+        let (mut field_schema, mut optional) = type_schema(annotation, content);
+        optional |= has_child(member, "?", content, &["?"]);
+
+        let mut decorators = member.walk();
+        for decorator in member.children_by_field_name("decorator", &mut decorators) {
+            let name = decorator.named_child(0)
+                .map(|d| d.child_by_field_name("function").unwrap_or(d))
+                .map(|d| text(d, content))
+                .unwrap_or_default();
+            match name {
+                "IsOptional" => optional = true,
+                "IsInt" => field_schema.schema_type = "integer".to_string(),
+                "IsEmail" => field_schema.format = Some("email".to_string()),
+                "IsUUID" => field_schema.format = Some("uuid".to_string()),
+                "IsUrl" => field_schema.format = Some("uri".to_string()),
+                "IsDateString" => field_schema.format = Some("date-time".to_string()),
+                _ => {}
+            }
+        }
+        schema.set_property(field, field_schema, !optional);
+    }
+
+    if schema.properties.is_empty() {
+        match extended_class(class, content) {
+            Some(parent) => schema = Schema { name: Some(name.to_string()), ..Schema::reference(&parent) },
+            None => return,
+        }
+    }
+    module.schemas.insert(name.to_string(), schema);
+}
+
+/// `Base` in `class X extends Base` or `extends PartialType(Base)`.
+fn extended_class(class: Node, content: &str) -> Option<String> {
+    let mut cursor = class.walk();
+    let heritage = class.named_children(&mut cursor).find(|n| n.kind() == "class_heritage")?;
+    let mut cursor = heritage.walk();
+    let extends = heritage.named_children(&mut cursor).find(|n| n.kind() == "extends_clause")?;
+    let mut value = extends.child_by_field_name("value").or_else(|| extends.named_child(0))?;
+    // Mapped types: `PartialType(CreateUserDto)`, `OmitType(CreateUserDto, [...])`
+    while value.kind() == "call_expression" {
+        value = *arguments(value).first()?;
+    }
+    (value.kind() == "identifier").then(|| text(value, content).to_string())
+}
+
+/// The body an Express route validates, from its middleware and handler
+/// arguments. Handlers passed by name are looked up in the same file.
+pub(crate) fn route_body(call: Node, content: &str) -> Option<Schema> {
+    let args = arguments(call);
+    for arg in args.iter().skip(1) {
+        let found = match arg.kind() {
+            "call_expression" => middleware_schema(*arg, content),
+            "arrow_function" | "function_expression" | "function" => parsed_body(*arg, content),
+            "identifier" => function_named(root(*arg), text(*arg, content), content)
+                .and_then(|function| parsed_body(function, content)),
+            _ => None,
+        };
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// `validate(createUser)`, `validateBody(z.object(...))`,
+/// `celebrate({ body: Joi.object(...) })`, `celebrate({ [Segments.BODY]: schema })`
+fn middleware_schema(call: Node, content: &str) -> Option<Schema> {
+    let callee = text(call.child_by_field_name("function")?, content);
+    if !callee.to_lowercase().contains("valid") && callee != "celebrate" {
+        return None;
+    }
+    arguments(call).into_iter().find_map(|arg| match arg.kind() {
+        "object" => {
+            let mut cursor = arg.walk();
+            let pair = arg.named_children(&mut cursor).find(|pair| {
+                pair.kind() == "pair" && pair.child_by_field_name("key")
+                    .map(|k| text(k, content).trim_matches(|c| "[]'\"".contains(c)).to_lowercase().ends_with("body"))
+                    .unwrap_or(false)
+            })?;
+            validator(pair.child_by_field_name("value")?, content, true).map(|(schema, _)| schema)
+        }
+        _ => validator(arg, content, true).map(|(schema, _)| schema),
+    })
+}
+
+/// The schema a handler checks `req.body` against: `createUser.parse(req.body)`.
+fn parsed_body(node: Node, content: &str) -> Option<Schema> {
+    if node.kind() == "call_expression" {
+        let function = node.child_by_field_name("function").filter(|f| f.kind() == "member_expression");
+        let object = function.and_then(|f| f.child_by_field_name("object"));
+        let property = function.and_then(|f| f.child_by_field_name("property")).map(|p| text(p, content));
+        let reads_body = arguments(node).first().map(|a| text(*a, content).ends_with(".body")).unwrap_or(false);
+        if let (Some(object), Some(property)) = (object, property) {
+            if reads_body && PARSE_METHODS.contains(&property) {
+                if let Some((schema, _)) = validator(object, content, true) {
+                    return Some(schema);
+                }
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    children.into_iter().find_map(|child| parsed_body(child, content))
+}
+
+/// `function name() {}` or `const name = (req, res) => {}` anywhere in the tree.
+pub(crate) fn function_named<'t>(node: Node<'t>, name: &str, content: &str) -> Option<Node<'t>> {
+    let named = |field: &str| node.child_by_field_name(field).map(|n| text(n, content) == name).unwrap_or(false);
+    match node.kind() {
+        "function_declaration" if named("name") => return Some(node),
+        "variable_declarator" if named("name") => {
+            if let Some(value) = node.child_by_field_name("value") {
+                if matches!(value.kind(), "arrow_function" | "function_expression" | "function") {
+                    return Some(value);
+                }
+            }
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    children.into_iter().find_map(|child| function_named(child, name, content))
+}
+
+/// The schema a zod/joi/yup expression describes, and whether the field it
+/// defines is required. Other identifiers are references to named schemas.
+fn validator(node: Node, content: &str, required: bool) -> Option<(Schema, bool)> {
+    match node.kind() {
+        "identifier" => Some((Schema::reference(text(node, content)), required)),
+        "call_expression" => {
+            let function = node.child_by_field_name("function").filter(|f| f.kind() == "member_expression")?;
+            let (object, method) = (function.child_by_field_name("object")?, text(function.child_by_field_name("property")?, content));
+            let args = arguments(node);
+            let first = || args.first().and_then(|a| validator(*a, content, required)).map(|(s, _)| s);
+
+            if let Some(required) = library(object, content).filter(|_| object.kind() == "identifier") {
+                let schema = match method {
+                    "object" => {
+                        let mut schema = Schema::of_type("object");
+                        if let Some(shape) = args.first() {
+                            add_shape(&mut schema, *shape, content, required);
+                        }
+                        schema
+                    }
+                    "string" => Schema::of_type("string"),
+                    "number" => Schema::of_type("number"),
+                    "bigint" => Schema::of_type("integer"),
+                    "boolean" | "bool" => Schema::of_type("boolean"),
+                    "date" => Schema::formatted("string", "date-time"),
+                    "email" => Schema::formatted("string", "email"),
+                    "enum" | "nativeEnum" => Schema::of_type("string"),
+                    "array" => Schema::array(first().unwrap_or_else(|| Schema::of_type("any"))),
+                    "literal" => Schema::of_type(match args.first().map(|a| a.kind()) {
+                        Some("string") => "string",
+                        Some("number") => "number",
+                        Some("true" | "false") => "boolean",
+                        _ => "any",
+                    }),
+                    "any" | "unknown" | "mixed" | "union" | "alternatives" => Schema::of_type("any"),
+                    _ => return None,
+                };
+                return Some((schema, required));
+            }
+
+            let (mut schema, mut required) = validator(object, content, required)?;
+            match method {
+                "optional" | "nullish" | "default" | "notRequired" => required = false,
+                "required" | "defined" | "exist" | "nonempty" => required = true,
+                "int" | "integer" => schema.schema_type = "integer".to_string(),
+                "email" | "uuid" | "url" | "uri" | "datetime" | "isoDate" => {
+                    schema.format = Some(match method {
+                        "url" => "uri",
+                        "datetime" | "isoDate" => "date-time",
+                        format => format,
+                    }.to_string());
+                }
+                "items" | "of" | "element" => schema.items = first().map(Box::new),
+                "array" => schema = Schema::array(schema),
+                "keys" | "shape" | "extend" | "append" if schema.reference.is_none() => {
+                    if let Some(shape) = args.first() {
+                        schema.schema_type = "object".to_string();
+                        add_shape(&mut schema, *shape, content, required);
+                    }
+                }
+                "partial" => schema.properties.iter_mut().for_each(|p| p.required = false),
+                _ => {}
+            }
+            Some((schema, required))
+        }
+        _ => None,
+    }
+}
+
+/// Properties of `{ name: z.string(), address }`.
+fn add_shape(schema: &mut Schema, shape: Node, content: &str, required: bool) {
+    if shape.kind() != "object" {
+        return;
+    }
+    let mut cursor = shape.walk();
+    for pair in shape.named_children(&mut cursor) {
+        let (key, value) = match pair.kind() {
+            "pair" => match (pair.child_by_field_name("key"), pair.child_by_field_name("value")) {
+                (Some(key), Some(value)) => (string_value(key, content).unwrap_or_else(|| text(key, content).to_string()), value),
+                _ => continue,
+            },
+            "shorthand_property_identifier" => (text(pair, content).to_string(), pair),
+            _ => continue,
+        };
+        let field = match value.kind() {
+            "shorthand_property_identifier" => Some((Schema::reference(&key), required)),
+            _ => validator(value, content, required),
+        };
+        if let Some((field, field_required)) = field {
+            schema.set_property(&key, field, field_required);
+        }
+    }
+}
+
+/// The validation library at the root of a `z.object(...).strict()` chain,
+/// as its default for whether fields are required.
+fn library(node: Node, content: &str) -> Option<bool> {
+    let mut node = node;
+    loop {
+        node = match node.kind() {
+            "call_expression" => node.child_by_field_name("function")?,
+            "member_expression" => node.child_by_field_name("object")?,
+            "identifier" => {
+                let name = text(node, content);
+                return LIBRARIES.iter().find(|(library, _)| *library == name).map(|(_, required)| *required);
+            }
+            _ => return None,
+        };
+    }
+}
+
+/// The schema of a TypeScript type, and whether it admits `undefined`.
+pub(crate) fn type_schema(node: Node, content: &str) -> (Schema, bool) {
+    let schema = match node.kind() {
+        "type_annotation" | "parenthesized_type" | "readonly_type" => match node.named_child(0) {
+            Some(inner) => return type_schema(inner, content),
+            None => Schema::of_type("any"),
+        },
+        "predefined_type" => match text(node, content) {
+            "string" => Schema::of_type("string"),
+            "number" => Schema::of_type("number"),
+            "bigint" => Schema::of_type("integer"),
+            "boolean" => Schema::of_type("boolean"),
+            "object" => Schema::of_type("object"),
+            "undefined" | "void" | "null" => return (Schema::of_type("any"), true),
+            _ => Schema::of_type("any"),
+        },
+        "type_identifier" => match text(node, content) {
+            "Date" => Schema::formatted("string", "date-time"),
+            "String" => Schema::of_type("string"),
+            "Number" => Schema::of_type("number"),
+            "Boolean" => Schema::of_type("boolean"),
+            name => Schema::reference(name),
+        },
+        "nested_type_identifier" => Schema::reference(text(node, content).rsplit('.').next().unwrap_or_default()),
+        "array_type" => Schema::array(node.named_child(0).map(|n| type_schema(n, content).0).unwrap_or_default()),
+        "generic_type" => {
+            let name = node.child_by_field_name("name").map(|n| text(n, content)).unwrap_or_default();
+            let first = node.child_by_field_name("type_arguments")
+                .and_then(|args| args.named_child(0))
+                .map(|arg| type_schema(arg, content).0)
+                .unwrap_or_else(|| Schema::of_type("any"));
+            match name {
+                "Array" | "ReadonlyArray" | "Set" => Schema::array(first),
+                "Record" | "Map" => Schema::of_type("object"),
+                "Partial" | "Required" | "Readonly" | "Promise" => first,
+                name => Schema::reference(name.rsplit('.').next().unwrap_or(name)),
+            }
+        }
+        "literal_type" => match node.named_child(0).map(|n| n.kind()) {
+            Some("string") => Schema::of_type("string"),
+            Some("number" | "unary_expression") => Schema::of_type("number"),
+            Some("true" | "false") => Schema::of_type("boolean"),
+            _ => return (Schema::of_type("any"), true),
+        },
+        "union_type" => {
+            let mut members = Vec::new();
+            union_members(node, content, &mut members);
+            let optional = members.iter().any(|(_, optional)| *optional);
+            let types: Vec<Schema> = members.into_iter().filter(|(_, optional)| !optional).map(|(s, _)| s).collect();
+            let schema = match types.split_first() {
+                Some((first, rest)) if rest.iter().all(|s| s == first) => first.clone(),
+                _ => Schema::of_type("any"),
+            };
+            return (schema, optional);
+        }
+        "object_type" | "interface_body" => {
+            let mut schema = Schema::of_type("object");
+            let mut cursor = node.walk();
+            for member in node.named_children(&mut cursor) {
+                if member.kind() != "property_signature" {
+                    continue;
+                }
+                if let (Some(name), Some(annotation)) = (member.child_by_field_name("name"), member.child_by_field_name("type")) {
+                    let (field, optional) = type_schema(annotation, content);
+                    let optional = optional || has_child(member, "?", content, &["?"]);
+                    schema.set_property(text(name, content), field, !optional);
+                }
+            }
+            schema
+        }
+        _ => Schema::of_type("any"),
+    };
+    (schema, false)
+}
+
+fn union_members(node: Node, content: &str, members: &mut Vec<(Schema, bool)>) {
+    let mut cursor = node.walk();
+    for member in node.named_children(&mut cursor) {
+        if member.kind() == "union_type" {
+            union_members(member, content, members);
+        } else {
+            members.push(type_schema(member, content));
+        }
+    }
+}
+
+/// Whether `node` has an unnamed or named child of `kind` whose text is one of `texts`.
+fn has_child(node: Node, kind: &str, content: &str, texts: &[&str]) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|c| c.kind() == kind && texts.contains(&text(c, content)));
+    found
+}
+
+pub(crate) fn arguments(call: Node) -> Vec<Node> {
+    match call.child_by_field_name("arguments") {
+        Some(arguments) => {
+            let mut cursor = arguments.walk();
+            arguments.named_children(&mut cursor).collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+pub(crate) fn root(node: Node) -> Node {
+    let mut root = node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    root
+}
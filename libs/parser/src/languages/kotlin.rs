@@ -0,0 +1,1039 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, ModuleRoutes, Parameter, ParameterLocation, parse_http_method};
+use crate::project::{join_route, Import, Mount, RoutedEndpoint};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parser for Ktor routing and Spring controllers written in Kotlin.
+///
+/// The source is tokenized and walked by hand: tree-sitter-kotlin 0.2, the
+/// release that builds against our tree-sitter 0.20 bindings, fails on
+/// receiver functions such as `fun Route.userRoutes()`, where Ktor apps
+/// declare most of their routes, and on nested block comments.
+///
+/// Ktor's `routing { route("/api") { get("/x") { } } }` blocks add their
+/// path to the prefix of the routes nested in them. `fun Route.userRoutes()`
+/// extensions own their routes and are mounted wherever they are called, in
+/// this file or another one. Spring controllers are read the way
+/// `JavaParser` reads them.
+pub struct KotlinParser;
+
+impl LanguageParser for KotlinParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        Ok(self.parse_module(Path::new(""), content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "kt" || extension == "kts"
+    }
+
+    fn parse_module(&self, _path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let mut walker = RouteWalker {
+            content,
+            tokens: tokenize(content),
+            module: ModuleRoutes::default(),
+            resources: HashMap::new(),
+        };
+        walker.collect_declarations();
+        walker.walk(0, walker.tokens.len(), &RouteScope::default());
+        walker.import_mounted_functions();
+        Ok(walker.module)
+    }
+}
+
+const VERBS: [&str; 7] = ["get", "post", "put", "patch", "delete", "head", "options"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Name(String),
+    /// A string literal; `None` when it contains `$` templates.
+    Str(Option<String>),
+    Punct(&'static str),
+    Other,
+}
+
+struct Lexeme {
+    token: Token,
+    start: usize,
+    end: usize,
+}
+
+/// A call or annotation argument, reduced to the shapes routes use.
+#[derive(Debug, Clone)]
+enum Value {
+    Str(String),
+    /// A (dotted) name: `RequestMethod.GET`, `HttpMethod.Get`.
+    Name(String),
+    /// `[...]`, `arrayOf(...)`, `listOf(...)`
+    Array(Vec<Value>),
+    /// `value = [...]`
+    Named(String, Box<Value>),
+    Other,
+}
+
+struct Annotation {
+    name: String,
+    args: Vec<Value>,
+    start: usize,
+}
+
+/// Where routes declared in the current block end up.
+#[derive(Clone, Default)]
+struct RouteScope {
+    /// The `Route` extension function the block belongs to; empty at the top level.
+    owner: String,
+    prefix: String,
+    middleware: Vec<String>,
+    /// Inside `routing { }` or a `Route` extension, where DSL calls register routes.
+    routing: bool,
+}
+
+/// A Spring controller class being walked.
+struct Controller {
+    name: String,
+    prefixes: Vec<String>,
+    /// Token index after the class body.
+    end: usize,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    tokens: Vec<Lexeme>,
+    module: ModuleRoutes,
+    /// Ktor `@Resource("/articles") class Articles` paths, for `get<Articles> { }`.
+    resources: HashMap<String, String>,
+}
+
+impl<'a> RouteWalker<'a> {
+    /// Record imports and typed resources before walking, since both may be
+    /// used before they are declared.
+    fn collect_declarations(&mut self) {
+        let mut i = 0;
+        while i < self.tokens.len() {
+            if self.name_is(i, "import") {
+                let (path, next) = self.dotted(i + 1);
+                let (binding, next) = if self.name_is(next, "as") {
+                    (self.name(next + 1).unwrap_or_default().to_string(), next + 2)
+                } else {
+                    (path.rsplit('.').next().unwrap_or_default().to_string(), next)
+                };
+                if let Some((source, name)) = path.rsplit_once('.') {
+                    if name != "*" && !binding.is_empty() {
+                        self.module.imports.insert(binding, Import {
+                            source: source.to_string(),
+                            name: Some(name.to_string()),
+                        });
+                    }
+                }
+                i = next;
+                continue;
+            }
+
+            if self.is(i, "@") && self.name_is(i + 1, "Resource") && self.is(i + 2, "(") {
+                let (args, next) = self.list(i + 2, ")");
+                let mut j = next;
+                while j < self.tokens.len() && !self.name_is(j, "class") && !self.is(j, "@") {
+                    j += 1;
+                }
+                if let (Some(Value::Str(path)), Some(class)) = (args.first(), self.name(j + 1)) {
+                    self.resources.insert(class.to_string(), path.clone());
+                }
+                i = next;
+                continue;
+            }
+            i += 1;
+        }
+    }
+
+    /// Read routes and controllers in `tokens[start..end]`.
+    fn walk(&mut self, start: usize, end: usize, scope: &RouteScope) {
+        let mut annotations: Vec<Annotation> = Vec::new();
+        let mut controller: Option<Controller> = None;
+
+        let mut i = start;
+        while i < end {
+            if controller.as_ref().map(|c| i >= c.end).unwrap_or(false) {
+                controller = None;
+            }
+
+            match self.token(i) {
+                Some(Token::Punct("@")) => {
+                    let (annotation, next) = self.annotation(i);
+                    annotations.push(annotation);
+                    i = next;
+                    continue;
+                }
+                Some(Token::Name(keyword)) if keyword == "class" && !self.is(i.wrapping_sub(1), "::") => {
+                    if let (Some(name), Some(end)) = (self.name(i + 1), self.class_end(i + 2)) {
+                        if is_controller(&annotations) {
+                            controller = Some(Controller {
+                                name: name.to_string(),
+                                prefixes: annotations.iter()
+                                    .find(|a| a.name == "RequestMapping")
+                                    .map(|a| mapping_paths(&a.args))
+                                    .filter(|paths| !paths.is_empty())
+                                    .unwrap_or_else(|| vec![String::new()]),
+                                end,
+                            });
+                        }
+                    }
+                    annotations.clear();
+                    i += 2;
+                    continue;
+                }
+                Some(Token::Name(keyword)) if keyword == "fun" => {
+                    i = self.function(i, scope, controller.as_ref(), &annotations);
+                    annotations.clear();
+                    continue;
+                }
+                Some(Token::Name(_)) if self.is_dsl_call(i, scope) => {
+                    i = self.dsl_call(i, scope);
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    /// A `fun` declaration. `Route` extensions are walked as their own router;
+    /// controller methods register their mappings. Returns the index to resume at.
+    fn function(&mut self, i: usize, scope: &RouteScope, controller: Option<&Controller>, annotations: &[Annotation]) -> usize {
+        let mut j = i + 1;
+        if self.is(j, "<") {
+            j = self.skip_angle(j);
+        }
+        let (receiver, name, open) = match (self.name(j), self.is(j + 1, "."), self.name(j + 2)) {
+            (Some(receiver), true, Some(name)) if self.is(j + 3, "(") => (Some(receiver.to_string()), name.to_string(), j + 3),
+            (Some(name), false, _) if self.is(j + 1, "(") => (None, name.to_string(), j + 1),
+            _ => return i + 1,
+        };
+        let close = self.skip_group(open);
+
+        if matches!(receiver.as_deref(), Some("Route" | "Routing")) {
+            let inner = RouteScope { owner: name.clone(), routing: true, ..RouteScope::default() };
+            self.module.exports.insert(name.clone(), name);
+            return match self.function_body(close) {
+                Some((start, end, next)) => {
+                    self.walk(start, end, &inner);
+                    next
+                }
+                None => close,
+            };
+        }
+
+        if let Some(controller) = controller {
+            let parameters = self.parameters(open, close);
+            for annotation in annotations {
+                self.mapping(annotation, controller, &name, &parameters, scope);
+            }
+        }
+        close
+    }
+
+    /// Token range of a function body after its parameter list: `{ ... }`, or
+    /// the single call of an expression body `= route("/x") { ... }`.
+    fn function_body(&self, mut i: usize) -> Option<(usize, usize, usize)> {
+        while i < self.tokens.len() && !self.is(i, "{") && !self.is(i, "=") {
+            i += 1;
+        }
+        if self.is(i, "{") {
+            let end = self.skip_group(i);
+            return Some((i + 1, end - 1, end));
+        }
+
+        let start = i + 1;
+        let mut end = start + 1;
+        if self.is(end, "<") {
+            end = self.skip_angle(end);
+        }
+        for open in ["(", "{"] {
+            if self.is(end, open) {
+                end = self.skip_group(end);
+            }
+        }
+        Some((start, end, end))
+    }
+
+    /// An unqualified `name(...)`, `name { }` or `name<T> { }` inside routing.
+    fn is_dsl_call(&self, i: usize, scope: &RouteScope) -> bool {
+        let qualified = self.is(i.wrapping_sub(1), ".") || self.is(i.wrapping_sub(1), "?.") || self.is(i.wrapping_sub(1), "::");
+        let call = self.is(i + 1, "(") || self.is(i + 1, "{") || self.is(i + 1, "<");
+        !qualified && call && (scope.routing || self.name_is(i, "routing"))
+    }
+
+    /// Handle one Ktor DSL call and return the index after it.
+    fn dsl_call(&mut self, i: usize, scope: &RouteScope) -> usize {
+        let name = self.name(i).unwrap_or_default().to_string();
+        let offset = self.tokens[i].start;
+        let mut j = i + 1;
+
+        let type_argument = if self.is(j, "<") {
+            let end = self.skip_angle(j);
+            let text = self.content[self.tokens[j].end..self.tokens[end - 1].start].trim().to_string();
+            j = end;
+            Some(text)
+        } else {
+            None
+        };
+        let args = if self.is(j, "(") {
+            let (args, next) = self.list(j, ")");
+            j = next;
+            args
+        } else {
+            Vec::new()
+        };
+        let body = if self.is(j, "{") {
+            let end = self.skip_group(j);
+            let body = (j + 1, end - 1);
+            j = end;
+            Some(body)
+        } else {
+            None
+        };
+
+        let path = positional(&args).find_map(|arg| match arg {
+            Value::Str(path) => Some(path.clone()),
+            _ => None,
+        });
+        let method = positional(&args).find_map(|arg| match arg {
+            Value::Name(name) if name.starts_with("HttpMethod.") => parse_http_method(&name["HttpMethod.".len()..]).ok(),
+            _ => None,
+        });
+
+        match (name.as_str(), body) {
+            ("routing", Some((start, end))) => {
+                let inner = RouteScope { routing: true, ..scope.clone() };
+                self.walk(start, end, &inner);
+            }
+            ("route", Some((start, end))) => {
+                let path = join_route(&scope.prefix, &path.unwrap_or_default());
+                match method {
+                    // `route("/x", HttpMethod.Get) { handle { } }`
+                    Some(method) => self.push(offset, method, path, scope),
+                    None => {
+                        let inner = RouteScope { prefix: path, ..scope.clone() };
+                        self.walk(start, end, &inner);
+                    }
+                }
+            }
+            ("method", Some(_)) => {
+                if let Some(method) = method {
+                    self.push(offset, method, scope.prefix.clone(), scope);
+                }
+            }
+            ("authenticate", Some((start, end))) => {
+                let names: Vec<String> = positional(&args)
+                    .filter_map(|arg| match arg {
+                        Value::Str(name) => Some(name.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                let mut inner = scope.clone();
+                inner.middleware.push(if names.is_empty() {
+                    "authenticate".to_string()
+                } else {
+                    format!("authenticate:{}", names.join(","))
+                });
+                self.walk(start, end, &inner);
+            }
+            (verb, Some(_)) if VERBS.contains(&verb) => {
+                // Typed routes take their path from the `@Resource` class
+                let path = match (path, type_argument) {
+                    (Some(path), _) => Some(path),
+                    (None, Some(resource)) => self.resources.get(&resource).cloned(),
+                    (None, None) => Some(String::new()),
+                };
+                if let (Some(path), Ok(method)) = (path, parse_http_method(verb)) {
+                    self.push(offset, method, join_route(&scope.prefix, &path), scope);
+                }
+            }
+            (_, Some((start, end))) => self.walk(start, end, scope),
+            // `userRoutes()`: a `Route` extension mounted here
+            (_, None) if args.is_empty() && type_argument.is_none() && scope.routing => {
+                self.mount(offset, &name, scope);
+            }
+            _ => {}
+        }
+
+        j
+    }
+
+    /// Mount the routes of `target` at the current prefix. Middleware of the
+    /// enclosing `authenticate` blocks lives on a node between the two.
+    fn mount(&mut self, offset: usize, target: &str, scope: &RouteScope) {
+        if scope.middleware.is_empty() {
+            self.module.mounts.push(Mount {
+                parent: scope.owner.clone(),
+                prefix: scope.prefix.clone(),
+                target: target.to_string(),
+            });
+            return;
+        }
+
+        let call = format!("call@{}", offset);
+        self.module.middleware.insert(call.clone(), scope.middleware.clone());
+        self.module.mounts.push(Mount { parent: scope.owner.clone(), prefix: scope.prefix.clone(), target: call.clone() });
+        self.module.mounts.push(Mount { parent: call, prefix: String::new(), target: target.to_string() });
+    }
+
+    /// Mounted functions that are neither declared nor imported here live
+    /// elsewhere in the same package, which Kotlin doesn't import.
+    fn import_mounted_functions(&mut self) {
+        let targets: Vec<String> = self.module.mounts.iter()
+            .map(|mount| mount.target.clone())
+            .filter(|target| !target.starts_with("call@"))
+            .collect();
+        for target in targets {
+            if !self.module.exports.contains_key(&target) && !self.module.imports.contains_key(&target) {
+                self.module.imports.insert(target.clone(), Import { source: String::new(), name: Some(target) });
+            }
+        }
+    }
+
+    /// Spring `@GetMapping("/x")` / `@RequestMapping(method = [...])` on a controller method.
+    fn mapping(&mut self, annotation: &Annotation, controller: &Controller, method_name: &str, parameters: &[Parameter], scope: &RouteScope) {
+        let verbs = match annotation.name.as_str() {
+            "GetMapping" => vec![HttpMethod::Get],
+            "PostMapping" => vec![HttpMethod::Post],
+            "PutMapping" => vec![HttpMethod::Put],
+            "DeleteMapping" => vec![HttpMethod::Delete],
+            "PatchMapping" => vec![HttpMethod::Patch],
+            // Without `method = ...` Spring matches every verb; report it as GET
+            "RequestMapping" => {
+                let methods: Vec<HttpMethod> = named(&annotation.args, "method")
+                    .map(values)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|m| parse_http_method(m.rsplit('.').next().unwrap_or(m)).ok())
+                    .collect();
+                if methods.is_empty() { vec![HttpMethod::Get] } else { methods }
+            }
+            _ => return,
+        };
+
+        let mut paths = mapping_paths(&annotation.args);
+        if paths.is_empty() {
+            paths.push(String::new());
+        }
+
+        let (line, column) = self.line_column(annotation.start);
+        for prefix in &controller.prefixes {
+            for path in &paths {
+                let full = join_route(prefix, path);
+                let full = if full.starts_with('/') { full } else { format!("/{}", full) };
+                for verb in &verbs {
+                    self.module.endpoints.push(RoutedEndpoint {
+                        owner: scope.owner.clone(),
+                        endpoint: Endpoint {
+                            method: verb.clone(),
+                            path: full.clone(),
+                            handler: format!("{}.{}", controller.name, method_name),
+                            line,
+                            column,
+                            documentation: None,
+                            parameters: parameters.to_vec(),
+                            tags: Vec::new(),
+                            middleware: Vec::new(),
+                            template: String::new(),
+                            body: None,
+                            responses: Vec::new(),
+                            requires_auth: false,
+                        },
+                    });
+                }
+            }
+        }
+    }
+
+    /// `@PathVariable id: Long`, `@RequestParam(defaultValue = "0") page: Int`,
+    /// `@RequestHeader("X-Tenant") tenant: String?`, `@RequestBody body: CreateUser`
+    fn parameters(&self, open: usize, close: usize) -> Vec<Parameter> {
+        let mut parameters = Vec::new();
+        let mut i = open + 1;
+
+        while i < close - 1 {
+            let mut annotations = Vec::new();
+            while self.is(i, "@") {
+                let (annotation, next) = self.annotation(i);
+                annotations.push(annotation);
+                i = next;
+            }
+            while self.name_is(i, "val") || self.name_is(i, "var") || self.name_is(i, "vararg") {
+                i += 1;
+            }
+            let param_name = self.name(i).unwrap_or_default().to_string();
+
+            // Type runs up to `=` or the next top-level comma
+            let mut end = i + 2;
+            let type_start = end;
+            while end < close - 1 && !self.is(end, ",") && !self.is(end, "=") {
+                end = match self.token(end) {
+                    Some(Token::Punct("(" | "[" | "{")) => self.skip_group(end),
+                    Some(Token::Punct("<")) => self.skip_angle(end),
+                    _ => end + 1,
+                };
+            }
+            let param_type = (type_start < end)
+                .then(|| self.content[self.tokens[type_start].start..self.tokens[end - 1].end].to_string());
+            while end < close - 1 && !self.is(end, ",") {
+                end = match self.token(end) {
+                    Some(Token::Punct("(" | "[" | "{")) => self.skip_group(end),
+                    _ => end + 1,
+                };
+            }
+
+            for annotation in &annotations {
+                let location = match annotation.name.as_str() {
+                    "PathVariable" => ParameterLocation::Path,
+                    "RequestParam" => ParameterLocation::Query,
+                    "RequestHeader" => ParameterLocation::Header,
+                    "RequestBody" => ParameterLocation::Body,
+                    _ => continue,
+                };
+
+                let name = match location {
+                    ParameterLocation::Body => param_name.clone(),
+                    _ => named(&annotation.args, "value")
+                        .or_else(|| named(&annotation.args, "name"))
+                        .or_else(|| positional(&annotation.args).next())
+                        .and_then(string)
+                        .filter(|v| !v.is_empty())
+                        .unwrap_or_else(|| param_name.clone()),
+                };
+                let default = named(&annotation.args, "defaultValue").and_then(string);
+                let required_attr = !matches!(named(&annotation.args, "required"), Some(Value::Name(v)) if v == "false");
+                let nullable = param_type.as_deref().map(|t| t.ends_with('?')).unwrap_or(false);
+
+                parameters.push(Parameter {
+                    name,
+                    location,
+                    param_type: param_type.clone(),
+                    required: required_attr && default.is_none() && !nullable,
+                    default,
+                });
+            }
+            i = end + 1;
+        }
+        parameters
+    }
+
+    fn push(&mut self, offset: usize, method: HttpMethod, path: String, scope: &RouteScope) {
+        let (line, column) = self.line_column(offset);
+        // Extension routes stay relative until they are mounted
+        let path = if path.starts_with('/') || (path.is_empty() && !scope.owner.is_empty()) {
+            path
+        } else {
+            format!("/{}", path)
+        };
+        self.module.endpoints.push(RoutedEndpoint {
+            owner: scope.owner.clone(),
+            endpoint: Endpoint {
+                method,
+                path,
+                handler: format!("{}:{}", line, column),
+                line,
+                column,
+                documentation: None,
+                parameters: Vec::new(),
+                tags: Vec::new(),
+                middleware: scope.middleware.clone(),
+                template: String::new(),
+                body: None,
+                responses: Vec::new(),
+                requires_auth: false,
+            },
+        });
+    }
+
+    /// `@Name`, `@field:Name`, `@a.b.Name(args)` starting at the `@`.
+    fn annotation(&self, at: usize) -> (Annotation, usize) {
+        let mut i = at + 1;
+        if self.is(i + 1, ":") && self.name(i + 2).is_some() {
+            i += 2;
+        }
+        let (name, mut next) = self.dotted(i);
+        let args = if self.is(next, "(") {
+            let (args, after) = self.list(next, ")");
+            next = after;
+            args
+        } else {
+            Vec::new()
+        };
+        let name = name.rsplit('.').next().unwrap_or_default().to_string();
+        (Annotation { name, args, start: self.tokens[at].start }, next.max(at + 1))
+    }
+
+    /// Token index after the body of the class whose header starts at `i`;
+    /// `None` for classes without one.
+    fn class_end(&self, mut i: usize) -> Option<usize> {
+        while i < self.tokens.len() {
+            match self.token(i) {
+                Some(Token::Punct("{")) => return Some(self.skip_group(i)),
+                Some(Token::Punct("(")) => i = self.skip_group(i),
+                Some(Token::Punct("<")) => i = self.skip_angle(i),
+                Some(Token::Punct("@")) => return None,
+                Some(Token::Name(name)) if matches!(name.as_str(), "class" | "fun" | "val" | "var" | "object" | "interface") => return None,
+                _ => i += 1,
+            }
+        }
+        None
+    }
+
+    /// `a.b.c` starting at `i`, with the index after it.
+    fn dotted(&self, mut i: usize) -> (String, usize) {
+        let mut path = String::new();
+        while let Some(name) = self.name(i) {
+            path.push_str(name);
+            if self.is(i + 1, ".") && (self.name(i + 2).is_some() || self.is(i + 2, "*")) {
+                path.push('.');
+                if self.is(i + 2, "*") {
+                    path.push('*');
+                    return (path, i + 3);
+                }
+                i += 2;
+            } else {
+                return (path, i + 1);
+            }
+        }
+        (path, i)
+    }
+
+    /// Values up to `close`, returning the index after it.
+    fn list(&self, open: usize, close: &str) -> (Vec<Value>, usize) {
+        let mut values = Vec::new();
+        let mut i = open + 1;
+        while i < self.tokens.len() && !self.is(i, close) {
+            let (value, next) = match self.name(i) {
+                Some(name) if self.is(i + 1, "=") => {
+                    let (value, next) = self.value(i + 2);
+                    (Value::Named(name.to_string(), Box::new(value)), next)
+                }
+                _ => self.value(i),
+            };
+            values.push(value);
+            i = next;
+            if self.is(i, ",") {
+                i += 1;
+            }
+        }
+        (values, i + 1)
+    }
+
+    /// Parse one expression; anything that isn't a literal, name or array
+    /// is skipped as [`Value::Other`].
+    fn value(&self, i: usize) -> (Value, usize) {
+        let (value, next) = match self.token(i) {
+            Some(Token::Str(Some(text))) => (Value::Str(text.clone()), i + 1),
+            Some(Token::Punct("[")) => {
+                let (items, next) = self.list(i, "]");
+                (Value::Array(items), next)
+            }
+            Some(Token::Name(name)) if matches!(name.as_str(), "arrayOf" | "listOf" | "setOf") && self.is(i + 1, "(") => {
+                let (items, next) = self.list(i + 1, ")");
+                (Value::Array(items), next)
+            }
+            Some(Token::Name(_)) => {
+                let (name, next) = self.dotted(i);
+                (Value::Name(name), next)
+            }
+            _ => (Value::Other, i),
+        };
+
+        if self.at_expression_end(next) {
+            (value, next)
+        } else {
+            (Value::Other, self.skip_expression(next))
+        }
+    }
+
+    /// Index after the bracket that closes the one at `open`.
+    fn skip_group(&self, open: usize) -> usize {
+        let mut depth = 0;
+        let mut i = open;
+        while i < self.tokens.len() {
+            match self.token(i) {
+                Some(Token::Punct("(" | "[" | "{")) => depth += 1,
+                Some(Token::Punct(")" | "]" | "}")) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i + 1;
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        i
+    }
+
+    /// Index after the `>` closing type arguments at `open`.
+    fn skip_angle(&self, open: usize) -> usize {
+        let mut depth = 0;
+        let mut i = open;
+        while i < self.tokens.len() {
+            match self.token(i) {
+                Some(Token::Punct("<")) => depth += 1,
+                Some(Token::Punct(">")) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i + 1;
+                    }
+                }
+                Some(Token::Punct("{" | ";")) => return i,
+                _ => {}
+            }
+            i += 1;
+        }
+        i
+    }
+
+    fn skip_expression(&self, mut i: usize) -> usize {
+        while i < self.tokens.len() && !self.at_expression_end(i) {
+            i = match self.token(i) {
+                Some(Token::Punct("(" | "[" | "{")) => self.skip_group(i),
+                _ => i + 1,
+            };
+        }
+        i
+    }
+
+    fn at_expression_end(&self, i: usize) -> bool {
+        matches!(self.token(i), None | Some(Token::Punct("," | ")" | "]" | "}")))
+    }
+
+    fn line_column(&self, offset: usize) -> (usize, usize) {
+        let before = &self.content[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = offset - before.rfind('\n').map(|p| p + 1).unwrap_or(0) + 1;
+        (line, column)
+    }
+
+    fn token(&self, i: usize) -> Option<&Token> {
+        self.tokens.get(i).map(|l| &l.token)
+    }
+
+    fn name(&self, i: usize) -> Option<&str> {
+        match self.token(i) {
+            Some(Token::Name(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn is(&self, i: usize, punct: &str) -> bool {
+        matches!(self.token(i), Some(Token::Punct(p)) if *p == punct)
+    }
+
+    fn name_is(&self, i: usize, name: &str) -> bool {
+        self.name(i) == Some(name)
+    }
+}
+
+fn is_controller(annotations: &[Annotation]) -> bool {
+    annotations.iter().any(|a| matches!(a.name.as_str(), "RestController" | "Controller" | "RequestMapping"))
+}
+
+/// Paths from `@X("/a")`, `@X(["/a", "/b"])`, `@X(value = ["/a"])` or `@X(path = ...)`.
+fn mapping_paths(args: &[Value]) -> Vec<String> {
+    named(args, "value")
+        .or_else(|| named(args, "path"))
+        .or_else(|| positional(args).next())
+        .map(values)
+        .unwrap_or_default()
+}
+
+fn named<'v>(args: &'v [Value], name: &str) -> Option<&'v Value> {
+    args.iter().find_map(|arg| match arg {
+        Value::Named(key, value) if key == name => Some(value.as_ref()),
+        _ => None,
+    })
+}
+
+fn positional(args: &[Value]) -> impl Iterator<Item = &Value> {
+    args.iter().filter(|arg| !matches!(arg, Value::Named(..)))
+}
+
+fn string(value: &Value) -> Option<String> {
+    match value {
+        Value::Str(text) => Some(text.clone()),
+        _ => None,
+    }
+}
+
+/// Strings and names of a value or array: `"/a"`, `["/a", "/b"]`, `[RequestMethod.GET]`.
+fn values(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items.iter().flat_map(values).collect(),
+        Value::Str(text) | Value::Name(text) => vec![text.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Split Kotlin source into tokens. Comments are dropped, strings unescaped,
+/// and operators we don't care about become `Other`.
+fn tokenize(content: &str) -> Vec<Lexeme> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let token = match bytes[i] {
+            c if c.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = content[i..].find('\n').map(|p| i + p).unwrap_or(bytes.len());
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = block_comment_end(content, i);
+                continue;
+            }
+            b'"' if content[i..].starts_with("\"\"\"") => {
+                let end = content[i + 3..].find("\"\"\"").map(|p| i + 3 + p).unwrap_or(bytes.len());
+                let text = &content[i + 3..end];
+                i = (end + 3).min(bytes.len());
+                Token::Str((!text.contains('$')).then(|| text.to_string()))
+            }
+            b'"' => {
+                let (text, end) = string_literal(content, i);
+                i = end;
+                Token::Str(text)
+            }
+            b'\'' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'\'' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+                Token::Other
+            }
+            b'`' => {
+                let end = content[i + 1..].find('`').map(|p| i + 1 + p).unwrap_or(bytes.len());
+                i = (end + 1).min(bytes.len());
+                Token::Name(content[start + 1..end].to_string())
+            }
+            c if c.is_ascii_digit() => {
+                i = name_end(bytes, i);
+                Token::Other
+            }
+            c if is_name_byte(c) => {
+                i = name_end(bytes, i);
+                Token::Name(content[start..i].to_string())
+            }
+            _ => {
+                let punct = ["?.", "::", "->", "(", ")", "[", "]", "{", "}", ",", ".", ":", "=", "@", "<", ">", "*"]
+                    .into_iter()
+                    .find(|p| content[i..].starts_with(*p));
+                match punct {
+                    Some(punct) => {
+                        i += punct.len();
+                        Token::Punct(punct)
+                    }
+                    None => {
+                        i += content[i..].chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+                        Token::Other
+                    }
+                }
+            }
+        };
+        tokens.push(Lexeme { token, start, end: i });
+    }
+
+    tokens
+}
+
+fn is_name_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c >= 0x80
+}
+
+fn name_end(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && is_name_byte(bytes[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// Kotlin block comments nest.
+fn block_comment_end(content: &str, start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < content.len() {
+        if content[i..].starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if content[i..].starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += content[i..].chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+        }
+    }
+    content.len()
+}
+
+/// A `"..."` literal starting at `start`; the text is `None` when it has
+/// `$name` or `${...}` templates.
+fn string_literal(content: &str, start: usize) -> (Option<String>, usize) {
+    let mut text = String::new();
+    let mut templated = false;
+    let mut chars = content[start + 1..].char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    text.push(escaped);
+                }
+            }
+            '$' if chars.peek().map(|(_, n)| *n == '{' || n.is_alphabetic() || *n == '_').unwrap_or(false) => {
+                templated = true;
+                if chars.peek().map(|(_, n)| *n == '{').unwrap_or(false) {
+                    let mut depth = 0;
+                    for (_, n) in chars.by_ref() {
+                        match n {
+                            '{' => depth += 1,
+                            '}' => {
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            '"' => return ((!templated).then_some(text), start + 1 + offset + 1),
+            '\n' => break,
+            c => text.push(c),
+        }
+    }
+    (None, content.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(code: &str) -> Vec<(HttpMethod, String, String)> {
+        KotlinParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path, e.handler))
+            .collect()
+    }
+
+    #[test]
+    fn test_supports_kotlin_extensions() {
+        assert!(KotlinParser.supports_extension("kt"));
+        assert!(KotlinParser.supports_extension("kts"));
+        assert!(!KotlinParser.supports_extension("java"));
+    }
+
+    #[test]
+    fn test_parse_nested_ktor_routes() {
+        let code = r#"
+fun Application.module() {
+    routing {
+        get("/") { call.respondText("ok") }
+        route("/api") {
+            /* nested /* comment */ */
+            get("/status") {
+                val id = call.parameters.get("id")
+                call.respondText("up $id")
+            }
+            authenticate("auth-jwt") {
+                route("/users") {
+                    get { }
+                    post { }
+                    route("/{id}", HttpMethod.Delete) { handle { } }
+                }
+            }
+            userRoutes()
+        }
+    }
+}
+
+fun Route.userRoutes() {
+    route("profile") {
+        put { }
+    }
+}
+"#;
+
+        let endpoints = KotlinParser.parse(code).unwrap();
+        let summary: Vec<_> = endpoints.iter()
+            .map(|e| (e.method.clone(), e.path.as_str(), e.middleware.join(",")))
+            .collect();
+        assert_eq!(summary, vec![
+            (HttpMethod::Get, "/", String::new()),
+            (HttpMethod::Get, "/api/status", String::new()),
+            (HttpMethod::Get, "/api/users", "authenticate:auth-jwt".to_string()),
+            (HttpMethod::Post, "/api/users", "authenticate:auth-jwt".to_string()),
+            (HttpMethod::Delete, "/api/users/{id}", "authenticate:auth-jwt".to_string()),
+            (HttpMethod::Put, "/api/profile", String::new()),
+        ]);
+        assert_eq!(endpoints[1].handler, "7:13");
+    }
+
+    #[test]
+    fn test_parse_typed_resources() {
+        let code = r#"
+@Resource("/articles")
+class Articles(val sort: String? = "new")
+
+fun Application.module() {
+    routing {
+        get<Articles> { article -> call.respond(article) }
+    }
+}
+"#;
+
+        assert_eq!(routes(code), vec![(HttpMethod::Get, "/articles".to_string(), "7:9".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_spring_controller() {
+        let code = r#"
+@RestController
+@RequestMapping("/api/users")
+class UserController(private val service: UserService) {
+
+    @GetMapping("/{id}")
+    fun get(@PathVariable id: Long, @RequestHeader("X-Tenant") tenant: String?): User = service.find(id)
+
+    @PostMapping
+    fun create(@RequestBody @Valid body: CreateUser): User = service.create(body)
+
+    @RequestMapping(value = ["/search", "/find"], method = [RequestMethod.GET, RequestMethod.POST])
+    fun search(@RequestParam(defaultValue = "0") page: Int, @RequestParam(required = false) q: String?) = emptyList<User>()
+
+    private fun helper() = Unit
+}
+"#;
+
+        let endpoints = KotlinParser.parse(code).unwrap();
+        let summary: Vec<_> = endpoints.iter()
+            .map(|e| (e.method.clone(), e.path.as_str(), e.handler.as_str()))
+            .collect();
+        assert_eq!(summary, vec![
+            (HttpMethod::Get, "/api/users/{id}", "UserController.get"),
+            (HttpMethod::Post, "/api/users", "UserController.create"),
+            (HttpMethod::Get, "/api/users/search", "UserController.search"),
+            (HttpMethod::Post, "/api/users/search", "UserController.search"),
+            (HttpMethod::Get, "/api/users/find", "UserController.search"),
+            (HttpMethod::Post, "/api/users/find", "UserController.search"),
+        ]);
+
+        assert_eq!(endpoints[0].parameters, vec![
+            Parameter { name: "id".to_string(), location: ParameterLocation::Path, param_type: Some("Long".to_string()), required: true, default: None },
+            Parameter { name: "X-Tenant".to_string(), location: ParameterLocation::Header, param_type: Some("String?".to_string()), required: false, default: None },
+        ]);
+        assert_eq!(endpoints[2].parameters[0].default.as_deref(), Some("0"));
+        assert!(!endpoints[2].parameters[1].required);
+    }
+}
@@ -0,0 +1,614 @@
+//! NestJS decorator routing: `@Controller('users')` classes with `@Get(':id')`
+//! style method decorators. Shared by the JavaScript and TypeScript parsers.
+//!
+//! Controllers are linked like routers: each `@Module` mounts its
+//! `controllers` and `imports`, and `app.setGlobalPrefix('api')` mounts the
+//! module passed to `NestFactory.create` under the prefix. Versioned routes
+//! hang off a node per version, which only adds `/v2` to the path once
+//! `app.enableVersioning` turns on URI versioning.
+
+use crate::{Endpoint, HttpMethod, Parameter, ParameterLocation, Schema};
+use crate::languages::javascript::{jsdoc, string_value, text};
+use crate::languages::js_schemas::type_schema;
+use crate::project::{ModuleRoutes, Mount, RoutedEndpoint, join_route};
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+const ALL_METHODS: &[HttpMethod] = &[
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Delete,
+    HttpMethod::Patch,
+    HttpMethod::Options,
+    HttpMethod::Head,
+];
+
+/// Add controller routes and module wiring found in the tree.
+pub(crate) fn collect(root: Node, content: &str, module: &mut ModuleRoutes) {
+    let mut apps = HashMap::new();
+    visit(root, content, module, &mut apps);
+}
+
+fn visit(node: Node, content: &str, module: &mut ModuleRoutes, apps: &mut HashMap<String, String>) {
+    match node.kind() {
+        "class_declaration" | "abstract_class_declaration" | "class" => visit_class(node, content, module),
+        "variable_declarator" => {
+            // `const app = await NestFactory.create(AppModule)`
+            if let (Some(name), Some(value)) = (node.child_by_field_name("name"), node.child_by_field_name("value")) {
+                if let Some(app_module) = nest_factory_module(value, content) {
+                    apps.insert(text(name, content).to_string(), app_module);
+                }
+            }
+        }
+        "call_expression" => {
+            let function = node.child_by_field_name("function");
+            let object = function.and_then(|f| f.child_by_field_name("object"));
+            let property = function.and_then(|f| f.child_by_field_name("property"));
+            if let (Some(object), Some(property)) = (object, property) {
+                let app_module = apps.get(text(object, content));
+                let first = arguments(node).first().copied();
+                match (app_module, text(property, content)) {
+                    // `app.setGlobalPrefix('api')`
+                    (Some(app_module), "setGlobalPrefix") => {
+                        if let Some(prefix) = first.and_then(|a| string_value(a, content)) {
+                            module.mounts.push(Mount {
+                                parent: String::new(),
+                                prefix,
+                                target: app_module.clone(),
+                            });
+                        }
+                    }
+                    (Some(_), "enableVersioning") => {
+                        if let Some(prefix) = first.and_then(|options| uri_version_prefix(options, content)) {
+                            module.uri_versioning = Some(prefix);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        visit(child, content, module, apps);
+    }
+}
+
+/// The version prefix of `{ type: VersioningType.URI, prefix: 'v' }`, which
+/// defaults to `v`; `None` for header, media type and custom versioning.
+fn uri_version_prefix(options: Node, content: &str) -> Option<String> {
+    if options.kind() != "object" {
+        return None;
+    }
+    let kind = object_property(options, "type", content)?;
+    if text(kind, content).rsplit('.').next() != Some("URI") {
+        return None;
+    }
+    match object_property(options, "prefix", content) {
+        Some(prefix) if text(prefix, content) == "false" => Some(String::new()),
+        Some(prefix) => string_value(prefix, content),
+        None => Some("v".to_string()),
+    }
+}
+
+/// `NestFactory.create(AppModule)`, possibly awaited or with a type argument.
+fn nest_factory_module(node: Node, content: &str) -> Option<String> {
+    let call = match node.kind() {
+        "await_expression" => node.named_child(0)?,
+        _ => node,
+    };
+    if call.kind() != "call_expression" {
+        return None;
+    }
+    let function = text(call.child_by_field_name("function")?, content);
+    if !function.starts_with("NestFactory.create") {
+        return None;
+    }
+    let first = *arguments(call).first()?;
+    (first.kind() == "identifier").then(|| text(first, content).to_string())
+}
+
+fn visit_class(class: Node, content: &str, module: &mut ModuleRoutes) {
+    let name = match class.child_by_field_name("name") {
+        Some(name) => text(name, content).to_string(),
+        None => return,
+    };
+
+    // `@Controller() export class X` puts the decorators on the export
+    let mut decorators = field_nodes(class, "decorator");
+    if let Some(parent) = class.parent().filter(|p| p.kind() == "export_statement") {
+        decorators.extend(field_nodes(parent, "decorator"));
+    }
+
+    // Class-level guards run before every route of the controller
+    let guards = guards(&decorators, content);
+    if !guards.is_empty() {
+        module.middleware.insert(name.clone(), guards);
+    }
+
+    for decorator in decorators {
+        match decorator_name(decorator, content) {
+            Some("Module") => visit_module(&name, decorator, content, module),
+            Some("Controller") => visit_controller(&name, class, decorator, content, module),
+            _ => {}
+        }
+    }
+}
+
+/// `@Module({ imports: [UsersModule], controllers: [AppController] })`
+fn visit_module(name: &str, decorator: Node, content: &str, module: &mut ModuleRoutes) {
+    let options = match decorator_arguments(decorator).first() {
+        Some(options) if options.kind() == "object" => *options,
+        _ => return,
+    };
+
+    for key in ["imports", "controllers"] {
+        let list = match object_property(options, key, content) {
+            Some(list) if list.kind() == "array" => list,
+            _ => continue,
+        };
+        let mut cursor = list.walk();
+        for item in list.named_children(&mut cursor) {
+            if let Some(target) = module_reference(item, content) {
+                module.mounts.push(Mount {
+                    parent: name.to_string(),
+                    prefix: String::new(),
+                    target,
+                });
+            }
+        }
+    }
+}
+
+/// `UsersModule`, `forwardRef(() => UsersModule)`, `UsersModule.forRoot()`
+fn module_reference(node: Node, content: &str) -> Option<String> {
+    match node.kind() {
+        "identifier" => Some(text(node, content).to_string()),
+        "call_expression" => {
+            let function = node.child_by_field_name("function")?;
+            if text(function, content) == "forwardRef" {
+                let callback = *arguments(node).first()?;
+                return module_reference(callback.child_by_field_name("body")?, content);
+            }
+            module_reference(function.child_by_field_name("object")?, content)
+        }
+        _ => None,
+    }
+}
+
+fn visit_controller(name: &str, class: Node, decorator: Node, content: &str, module: &mut ModuleRoutes) {
+    // `@Controller('users')`, `@Controller(['users', 'people'])`,
+    // `@Controller({ path: 'users', version: '1' })`
+    let (paths, class_versions) = match decorator_arguments(decorator).first() {
+        Some(options) if options.kind() == "object" => (
+            object_property(*options, "path", content).map(|p| strings(p, content)).unwrap_or_default(),
+            object_property(*options, "version", content).map(|v| strings(v, content)).unwrap_or_default(),
+        ),
+        Some(path) => (strings(*path, content), Vec::new()),
+        None => (Vec::new(), Vec::new()),
+    };
+    let paths = if paths.is_empty() { vec![String::new()] } else { paths };
+
+    let body = match class.child_by_field_name("body") {
+        Some(body) => body,
+        None => return,
+    };
+
+    // Method decorators are the class body's children right before the method
+    let mut pending = Vec::new();
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "decorator" => pending.push(member),
+            "method_definition" => {
+                let decorators = std::mem::take(&mut pending);
+                visit_handler(name, member, &decorators, &paths, &class_versions, content, module);
+            }
+            _ => pending.clear(),
+        }
+    }
+}
+
+fn visit_handler(
+    class: &str,
+    method: Node,
+    decorators: &[Node],
+    prefixes: &[String],
+    class_versions: &[String],
+    content: &str,
+    module: &mut ModuleRoutes,
+) {
+    let method_name = method.child_by_field_name("name").map(|n| text(n, content)).unwrap_or_default();
+
+    let versions = decorators.iter()
+        .find(|d| decorator_name(**d, content) == Some("Version"))
+        .and_then(|d| decorator_arguments(*d).first().map(|v| strings(*v, content)))
+        .unwrap_or_else(|| class_versions.to_vec());
+    // Each version is its own node under the controller; the linker puts
+    // `v{version}` in front of the controller path for URI versioning
+    let owners: Vec<String> = if versions.is_empty() {
+        vec![class.to_string()]
+    } else {
+        versions.iter().map(|version| {
+            let owner = format!("{}@v{}", class, version);
+            if module.versions.insert(owner.clone(), version.clone()).is_none() {
+                module.mounts.push(Mount { parent: class.to_string(), prefix: String::new(), target: owner.clone() });
+            }
+            owner
+        }).collect()
+    };
+
+    let parameters = handler_parameters(method, content);
+    let body = request_body(method, content);
+    let documentation = jsdoc(method, content);
+    let middleware = guards(decorators, content);
+
+    for decorator in decorators {
+        let methods = match decorator_name(*decorator, content) {
+            Some("Get") => vec![HttpMethod::Get],
+            Some("Post") => vec![HttpMethod::Post],
+            Some("Put") => vec![HttpMethod::Put],
+            Some("Delete") => vec![HttpMethod::Delete],
+            Some("Patch") => vec![HttpMethod::Patch],
+            Some("Options") => vec![HttpMethod::Options],
+            Some("Head") => vec![HttpMethod::Head],
+            Some("All") => ALL_METHODS.to_vec(),
+            _ => continue,
+        };
+        let paths = decorator_arguments(*decorator)
+            .first()
+            .map(|p| strings(*p, content))
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| vec![String::new()]);
+
+        let pos = decorator.start_position();
+        for owner in &owners {
+            for prefix in prefixes {
+                for path in &paths {
+                    let full = join_route(prefix, path);
+                    let full = if full.starts_with('/') { full } else { format!("/{}", full) };
+
+                    for http_method in &methods {
+                        module.endpoints.push(RoutedEndpoint {
+                            owner: owner.clone(),
+                            endpoint: Endpoint {
+                                method: http_method.clone(),
+                                path: full.clone(),
+                                handler: format!("{}.{}", class, method_name),
+                                line: pos.row + 1,
+                                column: pos.column + 1,
+                                documentation: documentation.clone(),
+                                parameters: parameters.clone(),
+                                tags: Vec::new(),
+                                middleware: middleware.clone(),
+                                template: String::new(),
+                                body: body.clone(),
+                                responses: Vec::new(),
+                                requires_auth: false,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `@UseGuards(JwtAuthGuard, RolesGuard)`, `@UseGuards(AuthGuard('jwt'))`
+fn guards(decorators: &[Node], content: &str) -> Vec<String> {
+    decorators.iter()
+        .filter(|d| decorator_name(**d, content) == Some("UseGuards"))
+        .flat_map(|d| decorator_arguments(*d))
+        .map(|guard| text(guard, content).trim_start_matches("new ").to_string())
+        .collect()
+}
+
+/// `@Param('id') id: string`, `@Query('page') page?: number`,
+/// `@Headers('x-api-key') key: string`, `@Body() dto: CreateUserDto`
+fn handler_parameters(method: Node, content: &str) -> Vec<Parameter> {
+    let params = match method.child_by_field_name("parameters") {
+        Some(params) => params,
+        None => return Vec::new(),
+    };
+
+    let mut result = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        let param_name = param.child_by_field_name("pattern")
+            .or_else(|| param.child_by_field_name("name"))
+            .map(|n| text(n, content))
+            .unwrap_or_default();
+        let param_type = param.child_by_field_name("type")
+            .map(|t| text(t, content).trim_start_matches(':').trim().to_string());
+        let default = param.child_by_field_name("value")
+            .map(|v| string_value(v, content).unwrap_or_else(|| text(v, content).to_string()));
+        let required = param.kind() == "required_parameter" && default.is_none();
+
+        for decorator in field_nodes(param, "decorator") {
+            let location = match decorator_name(decorator, content) {
+                Some("Param") => ParameterLocation::Path,
+                Some("Query") => ParameterLocation::Query,
+                Some("Headers") => ParameterLocation::Header,
+                Some("Body") => ParameterLocation::Body,
+                _ => continue,
+            };
+            let name = decorator_arguments(decorator)
+                .first()
+                .and_then(|n| string_value(*n, content))
+                .unwrap_or_else(|| param_name.to_string());
+
+            result.push(Parameter {
+                name,
+                location,
+                param_type: param_type.clone(),
+                required,
+                default: default.clone(),
+            });
+        }
+    }
+    result
+}
+
+/// `@Body() dto: CreateUserDto` is the whole body; `@Body('name') name: string`
+/// is one field of it.
+fn request_body(method: Node, content: &str) -> Option<Schema> {
+    let params = method.child_by_field_name("parameters")?;
+
+    let mut fields = Schema::of_type("object");
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        for decorator in field_nodes(param, "decorator") {
+            if decorator_name(decorator, content) != Some("Body") {
+                continue;
+            }
+            let (schema, optional) = param.child_by_field_name("type")
+                .map(|t| type_schema(t, content))
+                .unwrap_or_else(|| (Schema::of_type("any"), false));
+            match decorator_arguments(decorator).first().and_then(|n| string_value(*n, content)) {
+                Some(name) => fields.set_property(&name, schema, param.kind() == "required_parameter" && !optional),
+                None => return Some(schema),
+            }
+        }
+    }
+    (!fields.properties.is_empty()).then_some(fields)
+}
+
+/// `Get` for `@Get(':id')` and `@Get`.
+fn decorator_name<'a>(decorator: Node, content: &'a str) -> Option<&'a str> {
+    let expression = decorator.named_child(0)?;
+    let callee = match expression.kind() {
+        "call_expression" => expression.child_by_field_name("function")?,
+        _ => expression,
+    };
+    let name = text(callee, content);
+    Some(name.rsplit('.').next().unwrap_or(name))
+}
+
+fn decorator_arguments(decorator: Node) -> Vec<Node> {
+    match decorator.named_child(0) {
+        Some(call) if call.kind() == "call_expression" => arguments(call),
+        _ => Vec::new(),
+    }
+}
+
+fn arguments(call: Node) -> Vec<Node> {
+    match call.child_by_field_name("arguments") {
+        Some(arguments) => {
+            let mut cursor = arguments.walk();
+            arguments.named_children(&mut cursor).collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+fn object_property<'a>(object: Node<'a>, key: &str, content: &str) -> Option<Node<'a>> {
+    let mut cursor = object.walk();
+    let pair = object.named_children(&mut cursor).find(|pair| {
+        pair.kind() == "pair"
+            && pair.child_by_field_name("key").map(|k| text(k, content).trim_matches(|c| c == '\'' || c == '"') == key).unwrap_or(false)
+    })?;
+    pair.child_by_field_name("value")
+}
+
+/// A string literal or an array of them.
+fn strings(node: Node, content: &str) -> Vec<String> {
+    if let Some(value) = string_value(node, content) {
+        return vec![value];
+    }
+    if node.kind() != "array" {
+        return Vec::new();
+    }
+    let mut cursor = node.walk();
+    let values = node.named_children(&mut cursor)
+        .filter_map(|item| string_value(item, content))
+        .collect();
+    values
+}
+
+fn field_nodes<'a>(node: Node<'a>, field: &str) -> Vec<Node<'a>> {
+    let mut cursor = node.walk();
+    let nodes = node.children_by_field_name(field, &mut cursor).collect();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageParser;
+    use crate::languages::typescript::TypeScriptParser;
+    use crate::{HttpMethod, ParameterLocation};
+
+    #[test]
+    fn test_parse_controller_with_versions_and_parameters() {
+        let code = r#"
+import { Body, Controller, Get, Param, Post, Query, Version } from '@nestjs/common';
+
+@Controller('users')
+export class UsersController {
+  constructor(private readonly users: UsersService) {}
+
+  @Get()
+  findAll(@Query('page') page?: number) {
+    return this.users.findAll(page);
+  }
+
+  @Get(':id')
+  @Version('2')
+  findOne(@Param('id') id: string) {
+    return this.users.findOne(id);
+  }
+
+  @Post()
+  create(@Body() dto: CreateUserDto) {
+    return this.users.create(dto);
+  }
+}
+
+@Controller({ path: 'health', version: ['1', '2'] })
+class HealthController {
+  @Get()
+  check() {}
+}
+"#;
+
+        // Without URI versioning the version isn't part of the path
+        let endpoints = TypeScriptParser::new().parse(code).unwrap();
+        let found: Vec<_> = endpoints.iter().map(|e| (e.method.clone(), e.path.as_str(), e.handler.as_str())).collect();
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/users", "UsersController.findAll"),
+            (HttpMethod::Get, "/users/:id", "UsersController.findOne"),
+            (HttpMethod::Post, "/users", "UsersController.create"),
+            (HttpMethod::Get, "/health", "HealthController.check"),
+        ]);
+
+        let bootstrap = r#"
+async function bootstrap() {
+  const app = await NestFactory.create(AppModule);
+  app.enableVersioning({ type: VersioningType.HEADER, header: 'X-Api-Version' });
+}
+"#;
+        let headers = TypeScriptParser::new().parse(&format!("{}{}", code, bootstrap)).unwrap();
+        assert!(headers.iter().all(|e| !e.path.contains("/v")));
+
+        let uri = bootstrap.replace("VersioningType.HEADER, header: 'X-Api-Version'", "VersioningType.URI, prefix: 'version'");
+        let versioned = TypeScriptParser::new().parse(&format!("{}{}", code, uri)).unwrap();
+        let found: Vec<_> = versioned.iter().map(|e| (e.method.clone(), e.path.as_str())).collect();
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/users"),
+            (HttpMethod::Get, "/version2/users/:id"),
+            (HttpMethod::Post, "/users"),
+            (HttpMethod::Get, "/version1/health"),
+            (HttpMethod::Get, "/version2/health"),
+        ]);
+
+        let page = &endpoints[0].parameters[0];
+        assert_eq!((page.name.as_str(), &page.location, page.required), ("page", &ParameterLocation::Query, false));
+        assert_eq!(endpoints[1].parameters[0].param_type.as_deref(), Some("string"));
+        assert_eq!(endpoints[2].parameters[0].location, ParameterLocation::Body);
+    }
+
+    #[test]
+    fn test_use_guards() {
+        let code = r#"
+@UseGuards(JwtAuthGuard)
+@Controller('orders')
+export class OrdersController {
+  @Get()
+  list() {}
+
+  @UseGuards(RolesGuard, new ThrottlerGuard())
+  @Delete(':id')
+  remove(@Param('id') id: string) {}
+}
+
+@Controller('status')
+export class StatusController {
+  @Get()
+  status() {}
+}
+"#;
+
+        let endpoints = TypeScriptParser::new().parse(code).unwrap();
+        let guards: Vec<_> = endpoints.iter()
+            .map(|e| (e.path.as_str(), e.middleware.clone(), e.requires_auth))
+            .collect();
+        assert_eq!(guards, vec![
+            ("/orders", vec!["JwtAuthGuard".to_string()], true),
+            ("/orders/:id", vec!["JwtAuthGuard".to_string(), "RolesGuard".to_string(), "ThrottlerGuard()".to_string()], true),
+            ("/status", Vec::new(), false),
+        ]);
+    }
+
+    #[test]
+    fn test_body_schema_from_dto_class() {
+        let code = r#"
+interface Address {
+  city: string;
+  zip?: string;
+}
+
+export class CreateUserDto {
+  @IsString()
+  name: string;
+
+  @IsEmail()
+  email: string;
+
+  @IsOptional()
+  @IsInt()
+  age: number;
+
+  roles: ('admin' | 'user')[];
+  address?: Address;
+}
+
+export class UpdateUserDto extends PartialType(CreateUserDto) {}
+
+@Controller('users')
+export class UsersController {
+  /**
+   * Create a user.
+   *
+   * Sends a welcome email.
+   */
+  @Post()
+  create(@Body() dto: CreateUserDto) {}
+
+  @Patch(':id')
+  update(@Param('id') id: string, @Body() dto: UpdateUserDto) {}
+
+  @Post(':id/rename')
+  rename(@Body('name') name: string, @Body('reason') reason?: string) {}
+}
+"#;
+
+        let endpoints = TypeScriptParser::new().parse(code).unwrap();
+        let documentation = endpoints[0].documentation.as_ref().unwrap();
+        assert_eq!(documentation.summary.as_deref(), Some("Create a user."));
+        assert_eq!(documentation.description.as_deref(), Some("Sends a welcome email."));
+        assert!(endpoints[1].documentation.is_none());
+
+        let body = endpoints[0].body.as_ref().unwrap();
+        let fields: Vec<_> = body.properties.iter()
+            .map(|p| (p.name.as_str(), p.schema.schema_type.as_str(), p.required))
+            .collect();
+        assert_eq!(fields, vec![
+            ("name", "string", true),
+            ("email", "string", true),
+            ("age", "integer", false),
+            ("roles", "array", true),
+            ("address", "object", false),
+        ]);
+        assert_eq!(body.properties[1].schema.format.as_deref(), Some("email"));
+        assert!(!body.properties[4].schema.properties[1].required);
+
+        assert_eq!(endpoints[1].body.as_ref().unwrap().name.as_deref(), Some("CreateUserDto"));
+
+        let fields: Vec<_> = endpoints[2].body.as_ref().unwrap().properties.iter()
+            .map(|p| (p.name.as_str(), p.required))
+            .collect();
+        assert_eq!(fields, vec![("name", true), ("reason", false)]);
+    }
+}
@@ -0,0 +1,422 @@
+//! Registration styles of Node frameworks beyond `app.get('/path', handler)`
+//! on a plain identifier: Fastify and Hapi route objects, Hono and
+//! Koa-router chains and prefixes, and Fastify plugin prefixes. Shared by the
+//! JavaScript and TypeScript parsers.
+
+use crate::{Endpoint, HttpMethod, parse_http_method};
+use crate::languages::javascript::{binding_for, owner_binding, string_value, text};
+use crate::project::{ModuleRoutes, Mount, RoutedEndpoint, join_route};
+use tree_sitter::Node;
+
+const ALL_METHODS: &[HttpMethod] = &[
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Delete,
+    HttpMethod::Patch,
+    HttpMethod::Options,
+    HttpMethod::Head,
+];
+
+const VERBS: &[&str] = &["get", "post", "put", "delete", "del", "patch", "head", "options", "all"];
+
+/// Add the routes, prefixes and plugin mounts found in the tree.
+pub(crate) fn collect(node: Node, content: &str, module: &mut ModuleRoutes) {
+    match node.kind() {
+        "call_expression" => visit_call(node, content, module),
+        "variable_declarator" => visit_declarator(node, content, module),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(child, content, module);
+    }
+}
+
+fn visit_call(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let function = match node.child_by_field_name("function") {
+        Some(function) if function.kind() == "member_expression" => function,
+        _ => return,
+    };
+    let (object, property) = match (function.child_by_field_name("object"), function.child_by_field_name("property")) {
+        (Some(object), Some(property)) => (object, text(property, content)),
+        _ => return,
+    };
+    let args = arguments(node);
+
+    match property {
+        // Fastify/Hapi `route({ method, url, handler })`, Hapi `route([...])`
+        // and Hono `app.route('/users', users)`
+        "route" => {
+            let (owner, prefix) = match chain(object, content) {
+                Some(chain) => chain,
+                None => return,
+            };
+            match args.first().map(|a| a.kind()) {
+                Some("object") => route_object(args[0], &owner, &prefix, content, module),
+                Some("array") => {
+                    let mut cursor = args[0].walk();
+                    for item in args[0].named_children(&mut cursor).filter(|i| i.kind() == "object") {
+                        route_object(item, &owner, &prefix, content, module);
+                    }
+                }
+                _ if args.len() == 2 => {
+                    let path = string_value(args[0], content);
+                    if let (Some(path), Some(target)) = (path, binding_for(args[1], content, module)) {
+                        module.mounts.push(Mount { parent: owner, prefix: join_route(&prefix, &path), target });
+                    }
+                }
+                _ => {}
+            }
+        }
+        // Fastify `register(plugin, { prefix: '/v1' })`
+        "register" => {
+            let plugin = match args.first().and_then(|p| binding_for(*p, content, module)) {
+                Some(plugin) => plugin,
+                None => return,
+            };
+            let prefix = args.get(1)
+                .filter(|o| o.kind() == "object")
+                .and_then(|o| object_property(*o, "prefix", content))
+                .and_then(|p| string_value(p, content))
+                .unwrap_or_default();
+            if let Some((owner, chain_prefix)) = chain(object, content) {
+                module.mounts.push(Mount {
+                    parent: owner,
+                    prefix: join_route(&chain_prefix, &prefix),
+                    target: format!("{}.#0", plugin),
+                });
+            }
+        }
+        // Hono `app.on('PURGE', '/cache', h)`, `app.on(['GET', 'POST'], ['/a', '/b'], h)`
+        "on" => {
+            let (owner, prefix) = match chain(object, content) {
+                Some(chain) => chain,
+                None => return,
+            };
+            let methods: Vec<HttpMethod> = args.first()
+                .map(|m| strings(*m, content))
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|m| parse_http_method(m).ok())
+                .collect();
+            let paths = args.get(1).map(|p| strings(*p, content)).unwrap_or_default();
+            for path in paths {
+                for method in &methods {
+                    push(module, &owner, method.clone(), join_route(&prefix, &path), anonymous(node), node);
+                }
+            }
+        }
+        // Koa-router `router.prefix('/users')`
+        "prefix" if object.kind() == "identifier" => {
+            if let Some(prefix) = args.first().and_then(|p| string_value(*p, content)) {
+                module.prefixes.insert(owner_binding(object, content), prefix);
+            }
+        }
+        verb if VERBS.contains(&verb) => visit_verb(node, object, verb, &args, content, module),
+        _ => {}
+    }
+}
+
+/// Verb calls the Express query doesn't cover: chained calls
+/// (`app.get(...).post(...)`, Express `app.route('/x').get(h)`), including
+/// Koa named routes (`router.get('user', '/users/:id', h)`).
+fn visit_verb(node: Node, object: Node, verb: &str, args: &[Node], content: &str, module: &mut ModuleRoutes) {
+    // Plain `app.get('/x')` is already an Express route; Koa's `del` isn't
+    if object.kind() == "identifier" && verb != "del" {
+        return;
+    }
+    let first = args.first().and_then(|a| string_value(*a, content));
+    let second = args.get(1).and_then(|a| string_value(*a, content));
+    let path = match (first, second) {
+        (Some(name), Some(path)) if !is_path(&name) && is_path(&path) => path,
+        (Some(path), _) if is_path(&path) => path,
+        (Some(_), _) => return,
+        (None, _) => String::new(),
+    };
+
+    let (owner, prefix) = match chain(object, content) {
+        Some(chain) => chain,
+        None => return,
+    };
+    if path.is_empty() && prefix.is_empty() {
+        return;
+    }
+
+    let methods = match verb {
+        "all" => ALL_METHODS.to_vec(),
+        "del" => vec![HttpMethod::Delete],
+        verb => match parse_http_method(verb) {
+            Ok(method) => vec![method],
+            Err(_) => return,
+        },
+    };
+    let handler = node.child_by_field_name("function")
+        .and_then(|f| f.child_by_field_name("property"))
+        .unwrap_or(node);
+    for method in methods {
+        push(module, &owner, method, join_route(&prefix, &path), anonymous(handler), handler);
+    }
+}
+
+/// The router a registration call chain starts from, and the path prefix
+/// the chain adds (`app.basePath('/api')`, Express `app.route('/book')`).
+fn chain(node: Node, content: &str) -> Option<(String, String)> {
+    match node.kind() {
+        "identifier" => Some((owner_binding(node, content), String::new())),
+        "member_expression" => Some((text(node, content).to_string(), String::new())),
+        "call_expression" => {
+            let function = node.child_by_field_name("function")?;
+            let object = function.child_by_field_name("object")?;
+            let property = text(function.child_by_field_name("property")?, content);
+            let args = arguments(node);
+            let (owner, prefix) = chain(object, content)?;
+
+            match property {
+                "basePath" => Some((owner, join_route(&prefix, &string_value(*args.first()?, content)?))),
+                "route" if args.len() == 1 => Some((owner, join_route(&prefix, &string_value(args[0], content)?))),
+                "on" | "use" | "route" => Some((owner, prefix)),
+                verb if VERBS.contains(&verb) => Some((owner, prefix)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `const router = new Router({ prefix: '/users' })`,
+/// `const api = new Hono().basePath('/api')`, `const v1 = app.basePath('/v1')`
+fn visit_declarator(node: Node, content: &str, module: &mut ModuleRoutes) {
+    let (name, value) = match (node.child_by_field_name("name"), node.child_by_field_name("value")) {
+        (Some(name), Some(value)) if name.kind() == "identifier" => (text(name, content).to_string(), value),
+        _ => return,
+    };
+
+    match value.kind() {
+        "new_expression" => {
+            let constructor = value.child_by_field_name("constructor").map(|c| text(c, content)).unwrap_or_default();
+            let options = value.child_by_field_name("arguments")
+                .and_then(|a| a.named_child(0))
+                .filter(|o| o.kind() == "object");
+            if constructor.ends_with("Router") {
+                if let Some(prefix) = options.and_then(|o| object_property(o, "prefix", content)).and_then(|p| string_value(p, content)) {
+                    module.prefixes.insert(name, prefix);
+                }
+            }
+        }
+        "call_expression" => {
+            let function = match value.child_by_field_name("function") {
+                Some(function) if function.kind() == "member_expression" => function,
+                _ => return,
+            };
+            let property = function.child_by_field_name("property").map(|p| text(p, content));
+            let object = function.child_by_field_name("object");
+            let path = arguments(value).first().and_then(|p| string_value(*p, content));
+            if let (Some("basePath"), Some(object), Some(path)) = (property, object, path) {
+                match chain(object, content) {
+                    Some((parent, prefix)) => module.mounts.push(Mount {
+                        parent,
+                        prefix: join_route(&prefix, &path),
+                        target: name,
+                    }),
+                    None => {
+                        module.prefixes.insert(name, path);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Fastify `{ method: ['GET', 'HEAD'], url: '/x', schema, handler }` and
+/// Hapi `{ method: '*', path: '/x', handler }`
+fn route_object(object: Node, owner: &str, prefix: &str, content: &str, module: &mut ModuleRoutes) {
+    let path = match ["url", "path"].iter()
+        .find_map(|key| object_property(object, key, content))
+        .and_then(|p| string_value(p, content))
+    {
+        Some(path) => path,
+        None => return,
+    };
+    let methods: Vec<HttpMethod> = object_property(object, "method", content)
+        .map(|m| strings(m, content))
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|m| match m.as_str() {
+            "*" => ALL_METHODS.to_vec(),
+            m => parse_http_method(m).into_iter().collect(),
+        })
+        .collect();
+
+    let handler = object_property(object, "handler", content)
+        .filter(|h| matches!(h.kind(), "identifier" | "member_expression"))
+        .map(|h| text(h, content).to_string())
+        .unwrap_or_else(|| anonymous(object));
+
+    for method in methods {
+        push(module, owner, method, join_route(prefix, &path), handler.clone(), object);
+    }
+}
+
+fn push(module: &mut ModuleRoutes, owner: &str, method: HttpMethod, path: String, handler: String, node: Node) {
+    let pos = node.start_position();
+    module.endpoints.push(RoutedEndpoint {
+        owner: owner.to_string(),
+        endpoint: Endpoint {
+            method,
+            path,
+            handler,
+            line: pos.row + 1,
+            column: pos.column + 1,
+            documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        },
+    });
+}
+
+fn anonymous(node: Node) -> String {
+    let pos = node.start_position();
+    format!("{}:{}", pos.row + 1, pos.column + 1)
+}
+
+fn is_path(value: &str) -> bool {
+    value.starts_with('/') || value == "*"
+}
+
+fn arguments(call: Node) -> Vec<Node> {
+    match call.child_by_field_name("arguments") {
+        Some(arguments) => {
+            let mut cursor = arguments.walk();
+            arguments.named_children(&mut cursor).collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+fn object_property<'a>(object: Node<'a>, key: &str, content: &str) -> Option<Node<'a>> {
+    let mut cursor = object.walk();
+    let pair = object.named_children(&mut cursor).find(|pair| {
+        pair.kind() == "pair"
+            && pair.child_by_field_name("key").map(|k| text(k, content).trim_matches(|c| c == '\'' || c == '"') == key).unwrap_or(false)
+    })?;
+    pair.child_by_field_name("value")
+}
+
+/// A string literal or an array of them.
+fn strings(node: Node, content: &str) -> Vec<String> {
+    if let Some(value) = string_value(node, content) {
+        return vec![value];
+    }
+    if node.kind() != "array" {
+        return Vec::new();
+    }
+    let mut cursor = node.walk();
+    let values = node.named_children(&mut cursor)
+        .filter_map(|item| string_value(item, content))
+        .collect();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LanguageParser;
+    use crate::HttpMethod;
+    use crate::languages::javascript::JavaScriptParser;
+    use crate::languages::typescript::TypeScriptParser;
+
+    fn routes(endpoints: Vec<crate::Endpoint>) -> Vec<(HttpMethod, String)> {
+        endpoints.into_iter().map(|e| (e.method, e.path)).collect()
+    }
+
+    #[test]
+    fn test_parse_fastify_route_objects_and_plugins() {
+        let code = r#"
+const fastify = require('fastify')({ logger: true });
+
+async function userRoutes(app, opts) {
+  app.route({
+    method: ['GET', 'HEAD'],
+    url: '/:id',
+    schema: { params: { type: 'object' } },
+    handler: getUser,
+  });
+  app.post('/', { schema: createSchema }, async (request, reply) => reply.code(201).send());
+}
+
+fastify.register(userRoutes, { prefix: '/users' });
+fastify.get('/health', async () => ({ ok: true }));
+"#;
+
+        let endpoints = JavaScriptParser.parse(code).unwrap();
+        assert_eq!(routes(endpoints), vec![
+            (HttpMethod::Post, "/users".to_string()),
+            (HttpMethod::Get, "/health".to_string()),
+            (HttpMethod::Get, "/users/:id".to_string()),
+            (HttpMethod::Head, "/users/:id".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_hono_chains_and_koa_router() {
+        let code = r#"
+import { Hono } from 'hono';
+import Router from '@koa/router';
+
+const app = new Hono().basePath('/api');
+app.get('/posts', list).post('/posts', create);
+app.on(['PUT', 'PATCH'], '/posts/:id', update);
+
+const books = new Hono();
+books.get('/', listBooks);
+app.route('/books', books);
+
+const router = new Router({ prefix: '/users' });
+router.get('user', '/:id', show).del('/:id', remove);
+router.del('/', clear);
+koa.use(router.routes());
+"#;
+
+        let endpoints = TypeScriptParser::new().parse(code).unwrap();
+        let mut found = routes(endpoints);
+        found.sort_by(|a, b| a.1.cmp(&b.1));
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/api/books".to_string()),
+            (HttpMethod::Get, "/api/posts".to_string()),
+            (HttpMethod::Post, "/api/posts".to_string()),
+            (HttpMethod::Put, "/api/posts/:id".to_string()),
+            (HttpMethod::Patch, "/api/posts/:id".to_string()),
+            (HttpMethod::Delete, "/users".to_string()),
+            (HttpMethod::Get, "/users/:id".to_string()),
+            (HttpMethod::Delete, "/users/:id".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_hapi_route_arrays() {
+        let code = r#"
+const server = Hapi.server({ port: 3000 });
+
+server.route([
+  { method: 'GET', path: '/todos', handler: listTodos },
+  { method: ['PUT', 'PATCH'], path: '/todos/{id}', handler: (request, h) => h.response() },
+]);
+server.route({ method: '*', path: '/{any*}', handler: notFound });
+"#;
+
+        let endpoints = JavaScriptParser.parse(code).unwrap();
+        assert_eq!(endpoints[0].handler, "listTodos");
+        assert_eq!(routes(endpoints)[..3], [
+            (HttpMethod::Get, "/todos".to_string()),
+            (HttpMethod::Put, "/todos/{id}".to_string()),
+            (HttpMethod::Patch, "/todos/{id}".to_string()),
+        ]);
+    }
+}
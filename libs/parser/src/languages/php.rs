@@ -1,6 +1,26 @@
-use crate::{Endpoint, HttpMethod, LanguageParser};
-use anyhow::Result;
+use crate::{Documentation, Endpoint, HttpMethod, LanguageParser, ModuleRoutes, Parameter, ParameterLocation, Response, Schema, parse_http_method};
+use crate::docs::parse_doc_block;
+use crate::params::{add_inputs, input, path_parameters, regex_type};
+use crate::project::{Handler, join_route};
+use crate::responses::{add_response, response, status_code};
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+use std::path::Path;
+use tree_sitter::{Node, Parser};
 
+/// Parser for Laravel, Slim and Symfony routes.
+///
+/// - Laravel `Route::` chains. A chain such as `Route::prefix('api')->middleware('auth')->group(fn)`
+///   builds up a scope for the routes declared in the closure, which makes
+///   multi-line calls and nested groups work the same as one-liners.
+/// - Slim (and Lumen) chains on the app from `AppFactory::create()` or
+///   `new App(...)` and on the parameter of each `group('/prefix', fn)`
+///   closure, where `group` takes the prefix directly.
+/// - Symfony `#[Route]` attributes and `@Route` docblock annotations on
+///   controller methods, under the class-level route prefix.
+///
+/// Paths are only read from literal strings; `"/users/{$id}"` and other
+/// interpolated strings are skipped.
 pub struct PhpParser;
 
 impl PhpParser {
@@ -8,89 +28,1239 @@ impl PhpParser {
         Ok(Self)
     }
 
-    fn parse_route_method(&self, method_name: &str) -> HttpMethod {
-        match method_name.to_lowercase().as_str() {
-            "get" => HttpMethod::Get,
-            "post" => HttpMethod::Post,
-            "put" => HttpMethod::Put,
-            "delete" => HttpMethod::Delete,
-            "patch" => HttpMethod::Patch,
-            "options" => HttpMethod::Options,
-            "head" => HttpMethod::Head,
-            "any" | "match" | "resource" | "apiresource" => HttpMethod::Get, // Default for complex routes
-            _ => HttpMethod::Get,
+    fn routes(&self, content: &str, scope: &RouteScope, routers: HashSet<String>) -> Result<ModuleRoutes> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_php::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse PHP code"))?;
+
+        let mut walker = RouteWalker { content, routers, endpoints: Vec::new() };
+        walker.collect_routers(tree.root_node());
+        walker.walk(tree.root_node(), scope, None);
+        let mut module = ModuleRoutes::from_endpoints(std::mem::take(&mut walker.endpoints));
+        walker.collect_handlers(tree.root_node(), &mut module);
+        Ok(module)
+    }
+}
+
+impl LanguageParser for PhpParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        Ok(self.routes(content, &RouteScope::default(), HashSet::new())?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "php"
+    }
+
+    /// `routes/api.php` is loaded under the `/api` prefix with the `api`
+    /// middleware group, `routes/web.php` with the `web` group.
+    fn parse_module(&self, path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let mut scope = RouteScope::default();
+        if path.ends_with("routes/api.php") {
+            scope.prefix = "api".to_string();
+            scope.middleware.push("api".to_string());
+        } else if path.ends_with("routes/web.php") {
+            scope.middleware.push("web".to_string());
         }
+        // Lumen requires its route files with the router in `$router`
+        let mut routers = HashSet::new();
+        if path.parent().is_some_and(|dir| dir.ends_with("routes")) {
+            routers.insert("router".to_string());
+        }
+        self.routes(content, &scope, routers)
     }
+}
+
+/// `Route::any` registers every verb.
+const ALL_METHODS: [HttpMethod; 7] = [
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Patch,
+    HttpMethod::Delete,
+    HttpMethod::Options,
+    HttpMethod::Head,
+];
+
+/// Routes generated by `Route::resource`, in `route:list` order. `{id}` is
+/// replaced by the singular resource name.
+const RESOURCE_ACTIONS: [(&str, HttpMethod, &str); 8] = [
+    ("index", HttpMethod::Get, ""),
+    ("create", HttpMethod::Get, "/create"),
+    ("store", HttpMethod::Post, ""),
+    ("show", HttpMethod::Get, "/{id}"),
+    ("edit", HttpMethod::Get, "/{id}/edit"),
+    ("update", HttpMethod::Put, "/{id}"),
+    ("update", HttpMethod::Patch, "/{id}"),
+    ("destroy", HttpMethod::Delete, "/{id}"),
+];
+
+/// Actions `apiResource` leaves out: the HTML form pages.
+const FORM_ACTIONS: [&str; 2] = ["create", "edit"];
+
+/// A call argument, reduced to the shapes route files use.
+#[derive(Debug, Clone)]
+enum Value<'t> {
+    Str(String),
+    /// `UserController::class`
+    Class(String),
+    /// Entries of `[...]` / `array(...)`, with their keys when present.
+    Array(Vec<(Option<Value<'t>>, Value<'t>)>),
+    /// Body of a closure or arrow function.
+    Closure(Node<'t>),
+    /// A PHP 8 named argument (`methods: ['GET']`) or annotation option (`methods={"GET"}`).
+    Named(String, Box<Value<'t>>),
+    Other,
+}
+
+/// One `name(args)` link of a `Route::a()->b()` chain.
+struct Call<'t> {
+    name: String,
+    args: Vec<Value<'t>>,
+}
+
+/// Parameter types of Slim route files and group closures.
+const ROUTER_TYPES: [&str; 3] = ["App", "RouteCollectorProxy", "RouteCollectorProxyInterface"];
+
+/// Attributes the enclosing groups apply to the routes declared inside them.
+#[derive(Clone, Default)]
+struct RouteScope {
+    prefix: String,
+    middleware: Vec<String>,
+    /// Controller from `Route::controller(X::class)->group(...)`.
+    controller: Option<String>,
+}
 
-    fn extract_string_content(&self, text: &str) -> Option<String> {
-        // Extract content from quoted strings
-        if text.len() >= 2 {
-            if (text.starts_with('"') && text.ends_with('"')) || 
-               (text.starts_with('\'') && text.ends_with('\'')) {
-                let inner = &text[1..text.len() - 1];
-                return Some(inner.to_string());
+/// A `Route::resource` declaration, expanded once the chain's `only`/`except` are known.
+struct Resource {
+    name: String,
+    controller: String,
+    api: bool,
+    only: Option<Vec<String>>,
+    except: Vec<String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    /// Variables, without the `$`, that Slim and Lumen routes are registered on.
+    routers: HashSet<String>,
+    endpoints: Vec<Endpoint>,
+}
+
+impl<'a> RouteWalker<'a> {
+    /// Read every route chain and controller route under `node`.
+    /// `controller` is the class whose body is being walked.
+    fn walk(&mut self, node: Node<'a>, scope: &RouteScope, controller: Option<&Controller<'a>>) {
+        if let Some((calls, facade)) = self.chain(node) {
+            let first = self.endpoints.len();
+            self.route_chain(node, &calls, scope, facade);
+            if facade {
+                self.document(first, node);
+            }
+            return;
+        }
+
+        let class = match node.kind() {
+            "class_declaration" => node.child_by_field_name("name").map(|name| Controller {
+                name: self.text(name).to_string(),
+                prefixes: self.declared_routes(node),
+            }),
+            "method_declaration" => {
+                if let Some(class) = controller {
+                    self.controller_routes(node, class, scope);
+                }
+                None
+            }
+            _ => None,
+        };
+        let controller = class.as_ref().or(controller);
+
+        let mut cursor = node.walk();
+        let children: Vec<Node<'a>> = node.named_children(&mut cursor).collect();
+        for child in children {
+            self.walk(child, scope, controller);
+        }
+    }
+
+    /// Record the Slim and Lumen router variables under `root`: apps created
+    /// with `AppFactory::create()` or `new App(...)`, the first parameter of
+    /// every `->group(...)` closure, and parameters typed as a Slim app or
+    /// route collector (`return function (App $app) { ... }`).
+    fn collect_routers(&mut self, root: Node<'a>) {
+        for node in descendants(root) {
+            let variable = match node.kind() {
+                "assignment_expression" => node.child_by_field_name("right")
+                    .filter(|right| self.creates_app(*right))
+                    .and_then(|_| node.child_by_field_name("left")),
+                "member_call_expression" if self.call_name(node) == Some("group") => self.call_arguments(node).into_iter()
+                    .filter(|argument| matches!(argument.kind(), "anonymous_function_creation_expression" | "arrow_function"))
+                    .find_map(|closure| closure.child_by_field_name("parameters")?.named_child(0)?.child_by_field_name("name")),
+                "simple_parameter" => node.child_by_field_name("type")
+                    .filter(|kind| ROUTER_TYPES.contains(&self.text(*kind).trim_start_matches('?').rsplit('\\').next().unwrap_or_default()))
+                    .and_then(|_| node.child_by_field_name("name")),
+                _ => None,
+            };
+            if let Some(variable) = variable.filter(|v| v.kind() == "variable_name") {
+                self.routers.insert(self.text(variable).trim_start_matches('$').to_string());
+            }
+        }
+    }
+
+    /// `AppFactory::create()`, `AppFactory::createFromContainer($c)` or `new \Slim\App(...)`.
+    fn creates_app(&self, node: Node<'a>) -> bool {
+        match node.kind() {
+            "scoped_call_expression" => {
+                let scope = node.child_by_field_name("scope").map(|scope| self.text(scope)).unwrap_or_default();
+                scope.rsplit('\\').next() == Some("AppFactory") && self.call_name(node).is_some_and(|name| name.starts_with("create"))
             }
+            "object_creation_expression" => self.created_class(node).is_some_and(|class| class.rsplit('\\').next() == Some("App")),
+            _ => false,
+        }
+    }
+
+    /// Laravel form requests become body schemas built from their `rules()`,
+    /// and controller methods record the body they validate (a form request
+    /// parameter, `$request->validate([...])` or `Validator::make($data, [...])`)
+    /// and the query parameters and headers they read, and the responses they send.
+    fn collect_handlers(&self, root: Node<'a>, module: &mut ModuleRoutes) {
+        for class in descendants(root).into_iter().filter(|n| n.kind() == "class_declaration") {
+            let (Some(name), Some(body)) = (class.child_by_field_name("name"), class.child_by_field_name("body")) else { continue };
+            let class_name = self.text(name).to_string();
+            let mut cursor = class.walk();
+            let form_request = class.named_children(&mut cursor)
+                .filter(|child| child.kind() == "base_clause")
+                .filter_map(|base| base.named_child(0))
+                .any(|base| self.text(base).rsplit('\\').next() == Some("FormRequest"));
+
+            let mut cursor = body.walk();
+            for method in body.named_children(&mut cursor).filter(|n| n.kind() == "method_declaration") {
+                let fields = ["name", "parameters", "body"].map(|field| method.child_by_field_name(field));
+                let [Some(name), Some(parameters), Some(block)] = fields else { continue };
+                let method_name = self.text(name);
+
+                if form_request && method_name == "rules" {
+                    if let Some(rules) = self.validation_rules(block, "return") {
+                        let schema = Schema { name: Some(class_name.clone()), ..rules_schema(&rules) };
+                        module.schemas.insert(class_name.clone(), schema);
+                    }
+                } else {
+                    let handler = Handler {
+                        body: self.validated_body(parameters, block),
+                        parameters: self.request_inputs(block),
+                        responses: self.responses(block),
+                        documentation: self.docblock(method),
+                    };
+                    let described = handler.body.is_some() || !handler.parameters.is_empty() || !handler.responses.is_empty();
+                    if described || handler.documentation.is_some() {
+                        module.handlers.insert(format!("{}@{}", class_name, method_name), handler);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The body a controller method validates: a `StoreUserRequest $request`
+    /// parameter, or the rules passed to `validate`/`Validator::make`.
+    fn validated_body(&self, parameters: Node<'a>, block: Node<'a>) -> Option<Schema> {
+        let mut cursor = parameters.walk();
+        for parameter in parameters.named_children(&mut cursor) {
+            let Some(declared) = parameter.child_by_field_name("type") else { continue };
+            let class = self.text(declared).trim_start_matches('?');
+            let class = class.rsplit('\\').next().unwrap_or(class);
+            if class.ends_with("Request") && !matches!(class, "Request" | "FormRequest" | "ServerRequestInterface") {
+                return Some(Schema::reference(class));
+            }
+        }
+        self.validation_rules(block, "validate")
+            .or_else(|| self.validation_rules(block, "make"))
+            .map(|rules| rules_schema(&rules))
+    }
+
+    /// Query parameters and headers read under `node`:
+    /// `$request->input('page', 1)`, `$request->integer('limit')`,
+    /// `request('q')`, `request()->query('sort')`, `$request->header('X-Tenant')`.
+    fn request_inputs(&self, node: Node<'a>) -> Vec<Parameter> {
+        let mut found = Vec::new();
+        for call in descendants(node) {
+            let method = match call.kind() {
+                "function_call_expression" if self.call_name(call) == Some("request") => "input",
+                "member_call_expression" | "nullsafe_member_call_expression" => {
+                    match (call.child_by_field_name("object"), self.call_name(call)) {
+                        (Some(object), Some(method)) if self.receives_request(object) => method,
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            let (location, param_type) = match method {
+                "input" | "query" | "get" | "string" | "str" | "has" | "filled" | "enum" | "date" => (ParameterLocation::Query, None),
+                "integer" => (ParameterLocation::Query, Some("integer")),
+                "boolean" => (ParameterLocation::Query, Some("boolean")),
+                "float" => (ParameterLocation::Query, Some("number")),
+                "header" | "hasHeader" => (ParameterLocation::Header, None),
+                _ => continue,
+            };
+            let args = self.call_arguments(call);
+            let Some(name) = args.first().and_then(|arg| self.string_literal(*arg)) else { continue };
+            let default = args.get(1).and_then(|arg| self.literal(*arg));
+            let mut parameter = input(&name, location, default);
+            parameter.param_type = param_type.map(str::to_string);
+            found.push(parameter);
+        }
+        let mut parameters = Vec::new();
+        add_inputs(&mut parameters, &found);
+        parameters
+    }
+
+    /// Responses sent under `node`: `response()->json($data, 201)`,
+    /// `response($content, 204)`, `response()->noContent()`,
+    /// `new JsonResponse($data, Response::HTTP_CREATED)`, `abort(404)` and
+    /// `abort_if($denied, 403)`. Bodies are described when they are array literals.
+    fn responses(&self, node: Node<'a>) -> Vec<Response> {
+        let mut responses = Vec::new();
+        for call in descendants(node) {
+            // Which arguments hold the body and the status, and the status when none is passed
+            let (body, status, default) = match (call.kind(), self.call_name(call)) {
+                ("member_call_expression", Some(method)) if call.child_by_field_name("object").map(|o| self.is_bare_call(o, "response")).unwrap_or(false) => {
+                    match method {
+                        "json" => (Some(0), 1, 200),
+                        "noContent" => (None, 0, 204),
+                        _ => (None, usize::MAX, 200),
+                    }
+                }
+                // `response()` itself is read with the method called on it
+                ("function_call_expression", Some("response")) if !self.is_bare_call(call, "response") || !is_chained(call) => (Some(0), 1, 200),
+                ("object_creation_expression", _) if self.created_class(call).map(|c| c.rsplit('\\').next() == Some("JsonResponse")).unwrap_or(false) => {
+                    (Some(0), 1, 200)
+                }
+                ("function_call_expression", Some("abort")) => (None, 0, 500),
+                ("function_call_expression", Some("abort_if" | "abort_unless")) => (None, 1, 500),
+                _ => continue,
+            };
+            let args = self.call_arguments(call);
+            let status = match args.get(status) {
+                Some(arg) => match status_code(self.text(*arg)) {
+                    Some(status) => status,
+                    None => continue,
+                },
+                None => default,
+            };
+            let body = body.and_then(|b| args.get(b)).and_then(|arg| value_schema(&self.value(*arg)));
+            add_response(&mut responses, response(status, body));
+        }
+        responses
+    }
+
+    /// The docblock above the declaration or statement at `node`.
+    fn docblock(&self, node: Node<'a>) -> Option<Documentation> {
+        self.doc_comment(node).map(parse_doc_block)
+    }
+
+    /// Text of the `/** ... */` comment right above `node`, looking past
+    /// other comments.
+    fn doc_comment(&self, node: Node<'a>) -> Option<&'a str> {
+        let mut sibling = node.prev_sibling();
+        while let Some(comment) = sibling.filter(|s| s.kind() == "comment") {
+            let text = self.text(comment);
+            if text.starts_with("/**") {
+                return Some(text);
+            }
+            sibling = comment.prev_sibling();
         }
         None
     }
-}
 
-impl LanguageParser for PhpParser {
-    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
-        let mut endpoints = Vec::new();
+    /// Give the routes registered by the chain at `node` (not those of the
+    /// groups it opens) the docblock above its statement.
+    fn document(&mut self, first: usize, node: Node<'a>) {
+        let statement = node.parent().filter(|p| p.kind() == "expression_statement").unwrap_or(node);
+        if let Some(documentation) = self.docblock(statement) {
+            let at = line_column(node);
+            for endpoint in self.endpoints[first..].iter_mut().filter(|e| (e.line, e.column) == at) {
+                endpoint.documentation = Some(documentation.clone());
+            }
+        }
+    }
+
+    /// `$request`, `$req` or `request()`.
+    fn receives_request(&self, node: Node<'a>) -> bool {
+        match node.kind() {
+            "variable_name" => matches!(self.text(node), "$request" | "$req"),
+            _ => self.is_bare_call(node, "request"),
+        }
+    }
+
+    /// A call to the function `name` without arguments.
+    fn is_bare_call(&self, node: Node<'a>, name: &str) -> bool {
+        node.kind() == "function_call_expression" && self.call_name(node) == Some(name) && self.call_arguments(node).is_empty()
+    }
+
+    /// The function or method a call expression invokes.
+    fn call_name(&self, call: Node<'a>) -> Option<&'a str> {
+        let name = match call.kind() {
+            "function_call_expression" => call.child_by_field_name("function")?,
+            "member_call_expression" | "nullsafe_member_call_expression" | "scoped_call_expression" => call.child_by_field_name("name")?,
+            _ => return None,
+        };
+        Some(self.text(name))
+    }
 
-        // Regex for Laravel Route facade calls
-        // Pattern: Route::method('path', handler)
-        let route_pattern = regex::Regex::new(
-            r#"Route::(get|post|put|delete|patch|options|head|any|match|resource|apiResource)\s*\(\s*(['"][^'"]*['"])\s*,([^)]*)\)"#
-        ).unwrap();
+    /// The class instantiated by `new Class(...)`.
+    fn created_class(&self, node: Node<'a>) -> Option<&'a str> {
+        let mut cursor = node.walk();
+        let class = node.named_children(&mut cursor).find(|c| matches!(c.kind(), "name" | "qualified_name"))?;
+        Some(self.text(class))
+    }
+
+    /// Argument expressions of a call or `new` expression.
+    fn call_arguments(&self, call: Node<'a>) -> Vec<Node<'a>> {
+        let mut cursor = call.walk();
+        let arguments = call.child_by_field_name("arguments")
+            .or_else(|| call.named_children(&mut cursor).find(|c| c.kind() == "arguments"));
+        arguments.map(|a| self.argument_list(a).into_iter().map(|(_, value)| value).collect()).unwrap_or_default()
+    }
+
+    /// A literal default argument: a string, number or boolean.
+    fn literal(&self, node: Node<'a>) -> Option<String> {
+        match node.kind() {
+            "string" | "encapsed_string" => self.string_literal(node),
+            "boolean" => Some(self.text(node).to_lowercase()),
+            "integer" | "float" => Some(self.text(node).to_string()),
+            _ => None,
+        }
+    }
 
-        for (line_num, line) in content.lines().enumerate() {
-            for caps in route_pattern.captures_iter(line) {
-                if let (Some(method_match), Some(path_match)) = (caps.get(1), caps.get(2)) {
-                    let method_name = method_match.as_str();
-                    let path_quoted = path_match.as_str();
-                    
-                    if let Some(path) = self.extract_string_content(path_quoted) {
-                        let normalized_path = if path.starts_with('/') { 
-                            path 
-                        } else { 
-                            format!("/{}", path) 
-                        };
+    /// The text of a quoted string, or `None` when it interpolates variables.
+    fn string_literal(&self, node: Node<'a>) -> Option<String> {
+        let quote = match node.kind() {
+            "string" => '\'',
+            "encapsed_string" => '"',
+            _ => return None,
+        };
+        let mut cursor = node.walk();
+        if node.named_children(&mut cursor).any(|part| !matches!(part.kind(), "string_value" | "escape_sequence")) {
+            return None;
+        }
+        let text = self.text(node);
+        let quoted = &text[text.find(quote)?..];
+        let inner = quoted.strip_prefix(quote)?.strip_suffix(quote)?;
+        Some(unescape(inner, quote))
+    }
 
-                        let handler = caps.get(3)
-                            .map(|m| m.as_str().trim().to_string())
-                            .unwrap_or_else(|| "anonymous".to_string());
+    /// The rules array after `keyword` under `node`: `return [...]`,
+    /// `->validate([...])` or `Validator::make($data, [...])`.
+    fn validation_rules(&self, node: Node<'a>, keyword: &str) -> Option<Vec<(Option<Value<'a>>, Value<'a>)>> {
+        descendants(node).into_iter().find_map(|n| {
+            let value = match n.kind() {
+                "return_statement" if keyword == "return" => self.value(n.named_child(0)?),
+                _ if keyword != "return" && self.call_name(n) == Some(keyword) => {
+                    self.call_arguments(n).into_iter()
+                        .map(|arg| self.value(arg))
+                        .find(|arg| matches!(arg, Value::Array(_)))?
+                }
+                _ => return None,
+            };
+            match value {
+                Value::Array(items) => Some(items),
+                _ => None,
+            }
+        })
+    }
 
-                        endpoints.push(Endpoint {
-                            method: self.parse_route_method(method_name),
-                            path: normalized_path,
-                            handler,
-                            line: line_num + 1,
-                            column: method_match.start(),
-                            documentation: None,
-                        });
+    /// Unwind `Route::a()->b()->c()` or `$app->a()->b()`, ending at `node`,
+    /// into its calls. The flag is true for chains on the `Route` facade.
+    fn chain(&self, node: Node<'a>) -> Option<(Vec<Call<'a>>, bool)> {
+        let mut links = Vec::new();
+        let mut current = node;
+        let facade = loop {
+            links.push((current.child_by_field_name("name")?, current.child_by_field_name("arguments")?));
+            match current.kind() {
+                "scoped_call_expression" => {
+                    let scope = current.child_by_field_name("scope")?;
+                    if !matches!(scope.kind(), "name" | "qualified_name") || !is_route_name(self.text(scope)) {
+                        return None;
                     }
+                    break true;
+                }
+                "member_call_expression" | "nullsafe_member_call_expression" => {
+                    let object = current.child_by_field_name("object")?;
+                    if object.kind() == "variable_name" {
+                        if !self.routers.contains(self.text(object).trim_start_matches('$')) {
+                            return None;
+                        }
+                        break false;
+                    }
+                    current = object;
+                }
+                _ => return None,
+            }
+        };
+
+        let calls = links.into_iter().rev()
+            .map(|(name, args)| Call { name: self.text(name).to_string(), args: self.arguments(args) })
+            .collect();
+        Some((calls, facade))
+    }
+
+    /// Register the routes of one chain. `facade` is false for Slim/Lumen
+    /// router variables, whose `group` takes the prefix as its first argument.
+    fn route_chain(&mut self, node: Node<'a>, calls: &[Call<'a>], scope: &RouteScope, facade: bool) {
+        let mut scope = scope.clone();
+        let mut routes: Vec<(Vec<HttpMethod>, String, String, Handler)> = Vec::new();
+        let mut resources: Vec<Resource> = Vec::new();
+        // `->middleware()` after the route itself applies to that route only
+        let mut trailing = Vec::new();
+        // Path parameter types from `->where()` and `->whereNumber()`
+        let mut constraints: Vec<Parameter> = Vec::new();
+
+        for (index, call) in calls.iter().enumerate() {
+            let args = &call.args;
+            let registered = !routes.is_empty() || !resources.is_empty();
+
+            match call.name.as_str() {
+                "prefix" => match args.first() {
+                    Some(Value::Str(prefix)) => scope.prefix = join_route(&scope.prefix, prefix),
+                    // The routes under an interpolated prefix can't be placed
+                    _ => return,
+                },
+                "middleware" | "add" => {
+                    let middleware = strings(args);
+                    if registered {
+                        trailing.extend(middleware);
+                    } else {
+                        scope.middleware.extend(middleware);
+                    }
+                }
+                "controller" => scope.controller = args.first().and_then(string),
+                "group" => {
+                    let mut inner = scope.clone();
+                    if !facade {
+                        // Slim: `$app->group('/api', fn)->add(Middleware::class)`
+                        match args.first() {
+                            Some(Value::Str(prefix)) => inner.prefix = join_route(&inner.prefix, prefix),
+                            Some(Value::Closure(_)) => {}
+                            _ => return,
+                        }
+                        for call in calls[index + 1..].iter().filter(|c| c.name == "add") {
+                            inner.middleware.extend(strings(&call.args));
+                        }
+                    }
+                    for arg in args {
+                        match arg {
+                            Value::Array(attributes) => inner.apply(attributes),
+                            Value::Closure(body) => self.walk(*body, &inner, None),
+                            _ => {}
+                        }
+                    }
+                }
+                "resource" | "apiResource" => {
+                    if let (Some(name), Some(controller)) = (args.first().and_then(string), args.get(1).and_then(string)) {
+                        let mut resource = Resource::new(name, controller, call.name == "apiResource");
+                        if let Some(Value::Array(options)) = args.get(2) {
+                            if let Some(only) = entry(options, "only") {
+                                resource.only = Some(strings(std::slice::from_ref(only)));
+                            }
+                            if let Some(except) = entry(options, "except") {
+                                resource.except = strings(std::slice::from_ref(except));
+                            }
+                        }
+                        resources.push(resource);
+                    }
+                }
+                "resources" | "apiResources" => {
+                    if let Some(Value::Array(items)) = args.first() {
+                        for (key, value) in items {
+                            if let (Some(name), Some(controller)) = (key.as_ref().and_then(string), string(value)) {
+                                resources.push(Resource::new(name, controller, call.name == "apiResources"));
+                            }
+                        }
+                    }
+                }
+                "only" => {
+                    for resource in resources.iter_mut() {
+                        resource.only = Some(strings(args));
+                    }
+                }
+                "except" => {
+                    for resource in resources.iter_mut() {
+                        resource.except = strings(args);
+                    }
+                }
+                "any" => {
+                    if let Some(path) = args.first().and_then(string) {
+                        routes.push((ALL_METHODS.to_vec(), path, self.handler(args.get(1), &scope, node), self.closure_handler(args.get(1))));
+                    }
+                }
+                "match" | "map" => {
+                    let methods: Vec<HttpMethod> = args.first()
+                        .map(|m| strings(std::slice::from_ref(m)))
+                        .unwrap_or_default()
+                        .iter()
+                        .filter_map(|m| parse_http_method(m).ok())
+                        .collect();
+                    if let Some(path) = args.get(1).and_then(string) {
+                        routes.push((methods, path, self.handler(args.get(2), &scope, node), self.closure_handler(args.get(2))));
+                    }
+                }
+                "where" => {
+                    let patterns: Vec<(String, String)> = match args.as_slice() {
+                        [Value::Str(name), Value::Str(pattern), ..] => vec![(name.clone(), pattern.clone())],
+                        [Value::Array(items), ..] => items.iter()
+                            .filter_map(|(key, value)| Some((key.as_ref().and_then(string)?, string(value)?)))
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    for (name, pattern) in patterns {
+                        constraints.push(path_constraint(name, regex_type(&pattern)));
+                    }
+                }
+                "whereNumber" | "whereAlpha" | "whereAlphaNumeric" | "whereUuid" | "whereUlid" | "whereIn" => {
+                    let param_type = match call.name.as_str() {
+                        "whereNumber" => "int",
+                        "whereUuid" => "uuid",
+                        "whereUlid" => "ulid",
+                        _ => "string",
+                    };
+                    let names = if call.name == "whereIn" { strings(&args[..args.len().min(1)]) } else { strings(args) };
+                    for name in names {
+                        constraints.push(path_constraint(name, Some(param_type.to_string())));
+                    }
+                }
+                "view" | "redirect" | "permanentRedirect" => {
+                    if let (Some(path), Some(target)) = (args.first().and_then(string), args.get(1).and_then(string)) {
+                        routes.push((vec![HttpMethod::Get], path, target, Handler::default()));
+                    }
+                }
+                verb => {
+                    if let (Ok(method), Some(path)) = (parse_http_method(verb), args.first().and_then(string)) {
+                        routes.push((vec![method], path, self.handler(args.get(1), &scope, node), self.closure_handler(args.get(1))));
+                    }
+                }
+            }
+        }
+
+        let mut middleware = scope.middleware.clone();
+        middleware.extend(trailing);
+
+        for (methods, path, handler, closure) in routes {
+            let path = join_route(&scope.prefix, &path);
+            for method in methods {
+                self.push(node, method, path.clone(), handler.clone(), &middleware, &constraints);
+                if let Some(endpoint) = self.endpoints.last_mut() {
+                    add_inputs(&mut endpoint.parameters, &closure.parameters);
+                    endpoint.responses = closure.responses.clone();
                 }
             }
         }
 
-        Ok(endpoints)
+        for resource in resources {
+            let base = join_route(&scope.prefix, &resource.base());
+            let param = format!("{{{}}}", resource.parameter());
+            for (action, method, suffix) in RESOURCE_ACTIONS {
+                if !resource.includes(action) {
+                    continue;
+                }
+                let path = format!("{}{}", base, suffix.replace("{id}", &param));
+                let handler = format!("{}@{}", resource.controller, action);
+                self.push(node, method, path, handler, &middleware, &constraints);
+            }
+        }
     }
 
-    fn supports_extension(&self, extension: &str) -> bool {
-        extension == "php"
+
+    /// Symfony `#[Route('/path', methods: ['GET'])]` on a controller method.
+    /// Routes without `methods` match any verb; they are reported as GET.
+    fn attribute_route(&mut self, node: Node<'a>, prefix: &str, args: &[Value<'a>], handler: &str, scope: &RouteScope) {
+        let path = match named(args, "path").or_else(|| positional(args).next()).and_then(string) {
+            Some(path) => path,
+            None => return,
+        };
+        let mut methods: Vec<HttpMethod> = named(args, "methods")
+            .map(|m| strings(std::slice::from_ref(m)))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|m| parse_http_method(m).ok())
+            .collect();
+        if methods.is_empty() {
+            methods.push(HttpMethod::Get);
+        }
+
+        let path = join_route(&join_route(&scope.prefix, prefix), &path);
+        for method in methods {
+            self.push(node, method, path.clone(), handler.to_string(), &scope.middleware, &[]);
+        }
+    }
+
+    /// Routes a controller method declares, under each class-level prefix.
+    /// They are reported at the `function` keyword, past any attributes.
+    fn controller_routes(&mut self, method: Node<'a>, class: &Controller<'a>, scope: &RouteScope) {
+        let Some(name) = method.child_by_field_name("name") else { return };
+        let handler = format!("{}::{}", class.name, self.text(name));
+        let mut cursor = method.walk();
+        let at = method.children(&mut cursor).find(|c| c.kind() == "function").unwrap_or(method);
+
+        let first = self.endpoints.len();
+        for route in self.declared_routes(method) {
+            for prefix in class.prefix_paths() {
+                self.attribute_route(at, &prefix, &route, &handler, scope);
+            }
+        }
+        if let Some(documentation) = self.docblock(method) {
+            for endpoint in &mut self.endpoints[first..] {
+                endpoint.documentation = Some(documentation.clone());
+            }
+        }
+    }
+
+    /// Arguments of the `@Route` annotations in the docblock above a class or
+    /// method, then of the `#[Route]` attributes on it.
+    fn declared_routes(&self, node: Node<'a>) -> Vec<Vec<Value<'a>>> {
+        let mut routes = self.doc_comment(node).map(annotations).unwrap_or_default();
+        if let Some(attributes) = node.child_by_field_name("attributes") {
+            for attribute in descendants(attributes).into_iter().filter(|n| n.kind() == "attribute") {
+                let is_route = attribute.named_child(0).map(|name| is_route_name(self.text(name))).unwrap_or(false);
+                if let (true, Some(args)) = (is_route, attribute.child_by_field_name("parameters")) {
+                    routes.push(self.arguments(args));
+                }
+            }
+        }
+        routes
+    }
+
+    /// `'UserController@index'`, `[UserController::class, 'index']`,
+    /// `InvokableController::class`, or a method of the group's controller.
+    /// Closures are named after their position.
+    fn handler(&self, action: Option<&Value<'a>>, scope: &RouteScope, node: Node<'a>) -> String {
+        match action {
+            Some(Value::Str(action)) => match &scope.controller {
+                Some(controller) if !action.contains('@') => format!("{}@{}", controller, action),
+                _ => action.clone(),
+            },
+            Some(Value::Class(class)) => class.clone(),
+            Some(Value::Array(items)) => match items.as_slice() {
+                [(None, Value::Class(class)), (None, Value::Str(method))] => format!("{}@{}", class, method),
+                _ => position(node),
+            },
+            _ => position(node),
+        }
+    }
+
+    /// What a route closure reads and sends; controller actions are
+    /// described by their method definitions.
+    fn closure_handler(&self, action: Option<&Value<'a>>) -> Handler {
+        match action {
+            Some(Value::Closure(body)) => Handler {
+                body: None,
+                parameters: self.request_inputs(*body),
+                responses: self.responses(*body),
+                documentation: None,
+            },
+            _ => Handler::default(),
+        }
+    }
+
+    fn push(&mut self, node: Node<'a>, method: HttpMethod, path: String, handler: String, middleware: &[String], parameters: &[Parameter]) {
+        let (line, column) = line_column(node);
+        let path = if path.starts_with('/') { path } else { format!("/{}", path) };
+        let names: Vec<String> = path_parameters(&path).into_iter().map(|p| p.name).collect();
+        self.endpoints.push(Endpoint {
+            method,
+            path,
+            handler,
+            line,
+            column,
+            documentation: None,
+            parameters: parameters.iter().filter(|p| names.contains(&p.name)).cloned().collect(),
+            tags: Vec::new(),
+            middleware: middleware.to_vec(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        });
+    }
+
+    /// Values of `(a, b, name: c)`.
+    fn arguments(&self, node: Node<'a>) -> Vec<Value<'a>> {
+        self.argument_list(node).into_iter()
+            .map(|(name, value)| match name {
+                Some(name) => Value::Named(self.text(name).to_string(), Box::new(self.value(value))),
+                None => self.value(value),
+            })
+            .collect()
+    }
+
+    /// The name (for PHP 8 named arguments) and expression of each argument.
+    fn argument_list(&self, node: Node<'a>) -> Vec<(Option<Node<'a>>, Node<'a>)> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .filter(|argument| argument.kind() == "argument")
+            .filter_map(|argument| {
+                let value = argument.named_child(argument.named_child_count().checked_sub(1)?)?;
+                Some((argument.child_by_field_name("name"), value))
+            })
+            .collect()
+    }
+
+    /// Reduce one expression to a [`Value`]. Anything that is not a plain
+    /// literal, class constant, array or closure is [`Value::Other`].
+    fn value(&self, node: Node<'a>) -> Value<'a> {
+        match node.kind() {
+            "string" | "encapsed_string" => self.string_literal(node).map(Value::Str).unwrap_or(Value::Other),
+            "array_creation_expression" => {
+                let mut cursor = node.walk();
+                let items = node.named_children(&mut cursor)
+                    .filter(|item| item.kind() == "array_element_initializer")
+                    .map(|item| self.array_item(item))
+                    .collect();
+                Value::Array(items)
+            }
+            "class_constant_access_expression" => match (node.named_child(0), node.named_child(1)) {
+                (Some(class), Some(constant)) if self.text(constant).eq_ignore_ascii_case("class") => {
+                    Value::Class(self.text(class).trim_start_matches('\\').to_string())
+                }
+                _ => Value::Other,
+            },
+            "object_creation_expression" => match self.created_class(node) {
+                Some(class) => Value::Class(class.trim_start_matches('\\').to_string()),
+                None => Value::Other,
+            },
+            "anonymous_function_creation_expression" | "arrow_function" => {
+                node.child_by_field_name("body").map(Value::Closure).unwrap_or(Value::Other)
+            }
+            _ => Value::Other,
+        }
+    }
+
+    /// One `[...]` entry with its optional `key =>`.
+    fn array_item(&self, item: Node<'a>) -> (Option<Value<'a>>, Value<'a>) {
+        let mut cursor = item.walk();
+        let parts: Vec<Node<'a>> = item.named_children(&mut cursor).filter(|n| n.kind() != "comment").collect();
+        match parts.as_slice() {
+            [key, value] => (Some(self.value(*key)), self.value(*value)),
+            [value] => (None, self.value(*value)),
+            _ => (None, Value::Other),
+        }
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+/// Whether `node` is the object of a method call: `response()->json(...)`.
+fn is_chained(node: Node) -> bool {
+    node.parent()
+        .filter(|parent| matches!(parent.kind(), "member_call_expression" | "nullsafe_member_call_expression"))
+        .and_then(|parent| parent.child_by_field_name("object"))
+        == Some(node)
+}
+
+/// `node` and everything under it, in source order.
+fn descendants(node: Node) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut cursor = node.walk();
+    loop {
+        nodes.push(cursor.node());
+        if cursor.goto_first_child() {
+            continue;
+        }
+        loop {
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            if !cursor.goto_parent() {
+                return nodes;
+            }
+        }
     }
 }
 
+fn position(node: Node) -> String {
+    let (line, column) = line_column(node);
+    format!("{}:{}", line, column)
+}
+
+fn line_column(node: Node) -> (usize, usize) {
+    let start = node.start_position();
+    (start.row + 1, start.column + 1)
+}
+
+impl RouteScope {
+    /// Apply `Route::group(['prefix' => ..., 'middleware' => ...], fn)` attributes.
+    fn apply(&mut self, attributes: &[(Option<Value>, Value)]) {
+        if let Some(prefix) = entry(attributes, "prefix").and_then(string) {
+            self.prefix = join_route(&self.prefix, &prefix);
+        }
+        if let Some(middleware) = entry(attributes, "middleware") {
+            self.middleware.extend(strings(std::slice::from_ref(middleware)));
+        }
+        if let Some(controller) = entry(attributes, "controller").and_then(string) {
+            self.controller = Some(controller);
+        }
+    }
+}
+
+impl Resource {
+    fn new(name: String, controller: String, api: bool) -> Self {
+        Self { name, controller, api, only: None, except: Vec::new() }
+    }
+
+    /// `photos.comments` -> `photos/{photo}/comments`
+    fn base(&self) -> String {
+        let segments: Vec<&str> = self.name.split('.').collect();
+        let mut base = String::new();
+        for (index, segment) in segments.iter().enumerate() {
+            base = join_route(&base, segment);
+            if index + 1 < segments.len() {
+                base = format!("{}/{{{}}}", base, parameter(segment));
+            }
+        }
+        base
+    }
+
+    fn parameter(&self) -> String {
+        parameter(self.name.rsplit('.').next().unwrap_or(&self.name))
+    }
+
+    fn includes(&self, action: &str) -> bool {
+        if self.api && FORM_ACTIONS.contains(&action) {
+            return false;
+        }
+        if let Some(only) = &self.only {
+            if !only.iter().any(|a| a == action) {
+                return false;
+            }
+        }
+        !self.except.iter().any(|a| a == action)
+    }
+}
+
+/// A class being walked, with the routes of its class-level `#[Route]`.
+struct Controller<'t> {
+    name: String,
+    prefixes: Vec<Vec<Value<'t>>>,
+}
+
+impl Controller<'_> {
+    /// Class-level prefixes; a class without one serves its routes as-is.
+    fn prefix_paths(&self) -> Vec<String> {
+        let paths: Vec<String> = self.prefixes.iter()
+            .filter_map(|args| named(args, "path").or_else(|| positional(args).next()).and_then(string))
+            .collect();
+        if paths.is_empty() { vec![String::new()] } else { paths }
+    }
+}
+
+/// `Route`, or a qualified name ending in it.
+fn is_route_name(name: &str) -> bool {
+    name == "Route" || name.ends_with("\\Route")
+}
+
+/// Arguments of every `@Route(...)` annotation in a docblock. Annotations
+/// are comment text rather than PHP, so their `name="x"` options and
+/// `{"GET", "POST"}` arrays are read by [`AnnotationReader`].
+fn annotations<'t>(doc: &str) -> Vec<Vec<Value<'t>>> {
+    // Drop the leading ` * ` of each docblock line
+    let text: String = doc.lines()
+        .map(|line| line.trim_start().trim_start_matches('*'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut routes = Vec::new();
+    let mut rest = text.as_str();
+    while let Some(at) = rest.find('@') {
+        rest = &rest[at + 1..];
+        let name_end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '\\')).unwrap_or(rest.len());
+        if !is_route_name(&rest[..name_end]) || !rest[name_end..].starts_with('(') {
+            continue;
+        }
+
+        let mut reader = AnnotationReader { rest: &rest[name_end + 1..] };
+        let args = reader.entries(')').into_iter()
+            .map(|(key, value)| match key {
+                Some(Value::Str(key)) => Value::Named(key, Box::new(value)),
+                _ => value,
+            })
+            .collect();
+        routes.push(args);
+    }
+    routes
+}
+
+/// Reads the options of one docblock annotation.
+struct AnnotationReader<'s> {
+    rest: &'s str,
+}
+
+impl AnnotationReader<'_> {
+    /// `key=value`, `key: value` and plain entries up to `close`.
+    fn entries<'t>(&mut self, close: char) -> Vec<(Option<Value<'t>>, Value<'t>)> {
+        let mut entries = Vec::new();
+        while !self.rest.trim_start().is_empty() && !self.eat(close) {
+            let entry = match self.key() {
+                Some(key) => (Some(Value::Str(key)), self.value()),
+                None => {
+                    let first = self.value();
+                    if self.eat('=') || self.eat(':') { (Some(first), self.value()) } else { (None, first) }
+                }
+            };
+            entries.push(entry);
+            self.eat(',');
+        }
+        entries
+    }
+
+    /// A bare option name and the `=` or `:` after it.
+    fn key(&mut self) -> Option<String> {
+        let rest = self.rest.trim_start();
+        let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+        let after = rest[end..].trim_start();
+        if end == 0 || !after.starts_with(['=', ':']) {
+            return None;
+        }
+        self.rest = &after[1..];
+        Some(rest[..end].to_string())
+    }
+
+    /// A `"string"` or `{...}` array. Constants and anything else are skipped
+    /// up to the next separator.
+    fn value<'t>(&mut self) -> Value<'t> {
+        self.rest = self.rest.trim_start();
+        if self.eat('{') {
+            return Value::Array(self.entries('}'));
+        }
+        if let Some(body) = self.rest.strip_prefix('"') {
+            let mut escaped = false;
+            let end = body.char_indices()
+                .find(|&(_, c)| {
+                    let closes = c == '"' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    closes
+                })
+                .map(|(i, _)| i)
+                .unwrap_or(body.len());
+            self.rest = body.get(end + 1..).unwrap_or_default();
+            return Value::Str(unescape(&body[..end], '"'));
+        }
+        let end = match self.rest.find([',', ')', '}', '=', ':']) {
+            Some(0) | None => self.rest.chars().next().map(char::len_utf8).unwrap_or(0),
+            Some(end) => end,
+        };
+        self.rest = &self.rest[end..];
+        Value::Other
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.trim_start().strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Route parameter for a resource segment: `blog-posts` -> `blog_post`.
+fn parameter(segment: &str) -> String {
+    singularize(segment).replace('-', "_")
+}
+
+/// Good enough for route params: `photos` -> `photo`, `categories` -> `category`.
+fn singularize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// A named argument.
+fn named<'v, 't>(args: &'v [Value<'t>], name: &str) -> Option<&'v Value<'t>> {
+    args.iter().find_map(|arg| match arg {
+        Value::Named(key, value) if key == name => Some(value.as_ref()),
+        _ => None,
+    })
+}
+
+fn positional<'v, 't>(args: &'v [Value<'t>]) -> impl Iterator<Item = &'v Value<'t>> {
+    args.iter().filter(|arg| !matches!(arg, Value::Named(..)))
+}
+
+/// Value of `'key' => value` in an array.
+fn entry<'v, 't>(items: &'v [(Option<Value<'t>>, Value<'t>)], key: &str) -> Option<&'v Value<'t>> {
+    items.iter()
+        .find(|(k, _)| matches!(k, Some(Value::Str(k)) if k == key))
+        .map(|(_, v)| v)
+}
+
+/// A path parameter typed by a `where` constraint.
+fn path_constraint(name: String, param_type: Option<String>) -> Parameter {
+    Parameter {
+        name,
+        location: ParameterLocation::Path,
+        param_type,
+        required: true,
+        default: None,
+    }
+}
+
+/// The shape of a literal response body: `['data' => $users, 'meta' => [...]]`.
+/// Other expressions are left undescribed.
+fn value_schema(value: &Value) -> Option<Schema> {
+    match value {
+        Value::Str(_) => Some(Schema::of_type("string")),
+        Value::Array(items) if items.iter().all(|(key, _)| key.is_none()) => {
+            let first = items.first().and_then(|(_, item)| value_schema(item));
+            Some(Schema::array(first.unwrap_or_else(|| Schema::of_type("any"))))
+        }
+        Value::Array(items) => {
+            let mut schema = Schema::of_type("object");
+            for (key, value) in items {
+                if let Some(key) = key.as_ref().and_then(string) {
+                    schema.set_property(&key, value_schema(value).unwrap_or_else(|| Schema::of_type("any")), true);
+                }
+            }
+            Some(schema)
+        }
+        _ => None,
+    }
+}
+
+/// A body schema from Laravel validation rules: `'name' => 'required|string'`,
+/// `'tags.*' => ['integer']` and `'address.city' => 'required'`.
+fn rules_schema(rules: &[(Option<Value>, Value)]) -> Schema {
+    let mut schema = Schema::of_type("object");
+    for (key, value) in rules {
+        let key = match key {
+            Some(Value::Str(key)) => key,
+            _ => continue,
+        };
+        let rules: Vec<String> = match value {
+            Value::Str(text) => text.split('|').map(str::to_string).collect(),
+            Value::Array(items) => items.iter().filter_map(|(_, rule)| match rule {
+                Value::Str(rule) => Some(rule.clone()),
+                _ => None,
+            }).collect(),
+            _ => Vec::new(),
+        };
+        let names: Vec<&str> = rules.iter().map(|r| r.split(':').next().unwrap_or_default().trim()).collect();
+        let field = names.iter().find_map(|rule| rule_type(rule)).unwrap_or_else(|| Schema::of_type("any"));
+        let required = names.contains(&"required") && !names.contains(&"sometimes");
+        let segments: Vec<&str> = key.split('.').collect();
+        insert_rule(&mut schema, &segments, field, required);
+    }
+    schema
+}
+
+fn rule_type(rule: &str) -> Option<Schema> {
+    Some(match rule {
+        "string" | "alpha" | "alpha_dash" | "alpha_num" | "json" => Schema::of_type("string"),
+        "email" => Schema::formatted("string", "email"),
+        "url" | "active_url" => Schema::formatted("string", "uri"),
+        "uuid" => Schema::formatted("string", "uuid"),
+        "date" | "date_format" => Schema::formatted("string", "date"),
+        "file" | "image" | "mimes" | "mimetypes" => Schema::formatted("string", "binary"),
+        "integer" | "digits" | "digits_between" => Schema::of_type("integer"),
+        "numeric" | "decimal" => Schema::of_type("number"),
+        "boolean" | "accepted" | "declined" => Schema::of_type("boolean"),
+        "array" | "list" => Schema::array(Schema::of_type("any")),
+        _ => return None,
+    })
+}
+
+/// Place the rule for a dotted key: `address.city` nests objects and
+/// `tags.*` describes the items of an array.
+fn insert_rule(schema: &mut Schema, segments: &[&str], field: Schema, required: bool) {
+    let (first, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    if *first == "*" {
+        schema.schema_type = "array".to_string();
+        schema.properties.clear();
+        let items = schema.items.get_or_insert_with(|| Box::new(Schema::of_type("any")));
+        if rest.is_empty() {
+            **items = field;
+        } else {
+            insert_rule(items, rest, field, required);
+        }
+        return;
+    }
+
+    if schema.schema_type != "object" {
+        schema.schema_type = "object".to_string();
+        schema.items = None;
+    }
+    match schema.properties.iter_mut().find(|p| p.name == *first) {
+        // `tags.*` after `tags` keeps the array's own rules
+        Some(property) if rest.is_empty() => property.required = required,
+        Some(property) => insert_rule(&mut property.schema, rest, field, required),
+        None if rest.is_empty() => schema.set_property(first, field, required),
+        None => {
+            let mut nested = Schema::of_type("object");
+            insert_rule(&mut nested, rest, field, required);
+            schema.set_property(first, nested, false);
+        }
+    }
+}
+
+fn string(value: &Value) -> Option<String> {
+    match value {
+        Value::Str(text) | Value::Class(text) => Some(text.clone()),
+        _ => None,
+    }
+}
+
+/// Flatten strings and arrays of strings: `'auth'`, `['auth', 'verified']`.
+fn strings(values: &[Value]) -> Vec<String> {
+    values.iter()
+        .flat_map(|value| match value {
+            Value::Array(items) => items.iter().filter_map(|(_, v)| string(v)).collect(),
+            other => string(other).into_iter().collect::<Vec<_>>(),
+        })
+        .collect()
+}
+
+/// Undo `\'` / `\"` and `\\` escapes; other escapes are kept as written.
+fn unescape(text: &str, quote: char) -> String {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if escaped == quote || escaped == '\\' => unescaped.push(escaped),
+                Some(escaped) => {
+                    unescaped.push('\\');
+                    unescaped.push(escaped);
+                }
+                None => unescaped.push('\\'),
+            },
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn routes(code: &str) -> Vec<(HttpMethod, String, String)> {
+        PhpParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path, e.handler))
+            .collect()
+    }
+
     #[test]
     fn test_parse_basic_routes() {
         let parser = PhpParser::new().unwrap();
-        
+
         let code = r#"
 <?php
 
@@ -102,16 +1272,16 @@ Route::delete('/users/{id}', 'UserController@destroy');
 
         let endpoints = parser.parse(code).unwrap();
         assert_eq!(endpoints.len(), 4);
-        
+
         assert_eq!(endpoints[0].method, HttpMethod::Get);
         assert_eq!(endpoints[0].path, "/users");
-        
+
         assert_eq!(endpoints[1].method, HttpMethod::Post);
         assert_eq!(endpoints[1].path, "/users");
-        
+
         assert_eq!(endpoints[2].method, HttpMethod::Put);
         assert_eq!(endpoints[2].path, "/users/{id}");
-        
+
         assert_eq!(endpoints[3].method, HttpMethod::Delete);
         assert_eq!(endpoints[3].path, "/users/{id}");
     }
@@ -119,7 +1289,7 @@ Route::delete('/users/{id}', 'UserController@destroy');
     #[test]
     fn test_parse_closure_routes() {
         let parser = PhpParser::new().unwrap();
-        
+
         let code = r#"
 <?php
 
@@ -133,4 +1303,342 @@ Route::get('/api/status', function () {
         assert_eq!(endpoints[0].method, HttpMethod::Get);
         assert_eq!(endpoints[0].path, "/api/status");
     }
+
+    #[test]
+    fn test_request_inputs_from_closures_and_controllers() {
+        let parser = PhpParser::new().unwrap();
+
+        let code = r#"<?php
+Route::get('/search', function (Request $request) {
+    $term = $request->input('q', '');
+    $limit = $request->integer('limit', 20);
+    return Post::search($term)->take($limit)->get();
+});
+Route::get('/posts', [PostController::class, 'index']);
+
+class PostController extends Controller
+{
+    public function index(Request $request)
+    {
+        $tenant = $request->header('X-Tenant');
+        return Post::where('tenant', $tenant)->paginate(request('per_page', 15));
+    }
+}
+        "#;
+
+        let endpoints = parser.parse(code).unwrap();
+        let inputs: Vec<_> = endpoints[0].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.param_type.as_deref(), p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("q", ParameterLocation::Query, None, Some("")),
+            ("limit", ParameterLocation::Query, Some("integer"), Some("20")),
+        ]);
+
+        let inputs: Vec<_> = endpoints[1].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("X-Tenant", ParameterLocation::Header, None),
+            ("per_page", ParameterLocation::Query, Some("15")),
+        ]);
+    }
+
+    #[test]
+    fn test_responses_from_closures_and_controllers() {
+        let code = r#"<?php
+Route::get('/status', function () {
+    return response()->json(['status' => 'ok', 'checks' => []]);
+});
+Route::post('/posts', [PostController::class, 'store']);
+
+class PostController extends Controller
+{
+    public function store(Request $request)
+    {
+        abort_if($request->user()->banned, 403);
+        $post = Post::create($request->all());
+        return response()->json($post, Response::HTTP_CREATED);
+    }
+}
+        "#;
+
+        let endpoints = PhpParser.parse(code).unwrap();
+        let status = endpoints[0].responses[0].body.as_ref().unwrap();
+        let fields: Vec<_> = status.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str())).collect();
+        assert_eq!(fields, vec![("status", "string"), ("checks", "array")]);
+
+        let statuses: Vec<_> = endpoints[1].responses.iter().map(|r| (r.status, r.body.is_some())).collect();
+        assert_eq!(statuses, vec![(403, false), (201, false)]);
+    }
+
+    #[test]
+    fn test_docblocks_on_routes_and_controller_methods() {
+        let code = r#"<?php
+/**
+ * Service health.
+ */
+Route::get('/health', fn () => response()->noContent());
+/** Grouped routes. */
+Route::prefix('admin')->group(function () {
+    Route::get('/posts', [PostController::class, 'index']);
+});
+
+class PostController extends Controller
+{
+    /**
+     * List posts.
+     *
+     * @param  Request  $request
+     * @return \Illuminate\Http\JsonResponse
+     */
+    #[Cache(ttl: 60)]
+    public function index(Request $request)
+    {
+        return Post::all();
+    }
+}
+        "#;
+
+        let endpoints = PhpParser.parse(code).unwrap();
+        assert_eq!(endpoints[0].documentation.as_ref().and_then(|d| d.summary.as_deref()), Some("Service health."));
+
+        let index = endpoints[1].documentation.as_ref().unwrap();
+        assert_eq!(index.summary.as_deref(), Some("List posts."));
+        assert_eq!(index.params[0].name, "request");
+        assert_eq!(index.returns.as_deref(), Some("\\Illuminate\\Http\\JsonResponse"));
+    }
+
+    #[test]
+    fn test_parse_nested_groups() {
+        let code = r#"<?php
+use App\Http\Controllers\OrderController;
+
+Route::prefix('api')
+    ->middleware('auth')
+    ->group(function () {
+        Route::get(
+            '/profile',
+            [ProfileController::class, 'show']
+        )->middleware('verified');
+
+        Route::group(['prefix' => 'admin', 'middleware' => ['can:admin']], function () {
+            Route::delete('users/{user}', 'Admin\UserController@destroy');
+        });
+
+        Route::controller(OrderController::class)->prefix('orders')->group(fn () => Route::post('/', 'store'));
+    });
+"#;
+
+        let endpoints = PhpParser.parse(code).unwrap();
+        let summary: Vec<_> = endpoints.iter()
+            .map(|e| (e.method.clone(), e.path.as_str(), e.handler.as_str(), e.middleware.join(",")))
+            .collect();
+        assert_eq!(summary, vec![
+            (HttpMethod::Get, "/api/profile", "ProfileController@show", "auth,verified".to_string()),
+            (HttpMethod::Delete, "/api/admin/users/{user}", "Admin\\UserController@destroy", "auth,can:admin".to_string()),
+            (HttpMethod::Post, "/api/orders", "OrderController@store", "auth".to_string()),
+        ]);
+        assert_eq!(endpoints[0].line, 7);
+        assert!(endpoints.iter().all(|e| e.requires_auth));
+    }
+
+    #[test]
+    fn test_expands_resources() {
+        let code = r#"<?php
+Route::resource('photos', PhotoController::class)->only(['index', 'show']);
+Route::apiResource('photos.comments', CommentController::class, ['except' => ['destroy']]);
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/photos".to_string(), "PhotoController@index".to_string()),
+            (HttpMethod::Get, "/photos/{photo}".to_string(), "PhotoController@show".to_string()),
+            (HttpMethod::Get, "/photos/{photo}/comments".to_string(), "CommentController@index".to_string()),
+            (HttpMethod::Post, "/photos/{photo}/comments".to_string(), "CommentController@store".to_string()),
+            (HttpMethod::Get, "/photos/{photo}/comments/{comment}".to_string(), "CommentController@show".to_string()),
+            (HttpMethod::Put, "/photos/{photo}/comments/{comment}".to_string(), "CommentController@update".to_string()),
+            (HttpMethod::Patch, "/photos/{photo}/comments/{comment}".to_string(), "CommentController@update".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_match_and_any_register_each_method() {
+        let code = r#"<?php
+// Route::get('/commented-out', 'Nope@index');
+Route::match(['get', 'post'], '/search', 'SearchController');
+Route::any('/webhook', WebhookController::class);
+"#;
+
+        let methods: Vec<_> = routes(code).into_iter().map(|(m, p, _)| (m, p)).collect();
+        assert_eq!(methods[..2], [
+            (HttpMethod::Get, "/search".to_string()),
+            (HttpMethod::Post, "/search".to_string()),
+        ]);
+        assert_eq!(methods.len(), 2 + ALL_METHODS.len());
+        assert!(methods[2..].iter().all(|(_, p)| p == "/webhook"));
+    }
+
+    #[test]
+    fn test_where_constraints_type_path_parameters() {
+        let code = r#"<?php
+Route::get('/users/{id}/{slug?}', [UserController::class, 'show'])
+    ->whereNumber('id')
+    ->where('slug', '[a-z]+');
+Route::get('/orders/{order}', 'OrderController@show')->where(['order' => '[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}']);
+"#;
+
+        let endpoints = PhpParser.parse(code).unwrap();
+        let params: Vec<_> = endpoints.iter()
+            .flat_map(|e| e.parameters.iter().map(|p| (p.name.as_str(), p.param_type.as_deref(), p.required)))
+            .collect();
+        assert_eq!(params, vec![
+            ("id", Some("int"), true),
+            ("slug", Some("string"), false),
+            ("order", Some("uuid"), true),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_slim_groups() {
+        let code = r#"<?php
+$app = AppFactory::create();
+
+$app->get('/', function (Request $request, Response $response) {
+    return $response;
+});
+
+$app->group('/api', function (RouteCollectorProxy $group) {
+    $group->map(['GET', 'POST'], '/items', ItemsAction::class);
+    $group->group('/admin', function (RouteCollectorProxy $group) {
+        $group->delete('/users/{id}', [UserController::class, 'delete']);
+    })->add(new AdminMiddleware());
+})->add(AuthMiddleware::class);
+
+$app->run();
+"#;
+
+        let endpoints = PhpParser.parse(code).unwrap();
+        let summary: Vec<_> = endpoints.iter()
+            .map(|e| (e.method.clone(), e.path.as_str(), e.handler.as_str(), e.middleware.join(",")))
+            .collect();
+        assert_eq!(summary, vec![
+            (HttpMethod::Get, "/", "4:1", String::new()),
+            (HttpMethod::Get, "/api/items", "ItemsAction", "AuthMiddleware".to_string()),
+            (HttpMethod::Post, "/api/items", "ItemsAction", "AuthMiddleware".to_string()),
+            (HttpMethod::Delete, "/api/admin/users/{id}", "UserController@delete", "AuthMiddleware,AdminMiddleware".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_slim_routes_on_any_app_variable() {
+        let code = r#"<?php
+$api = AppFactory::create();
+$api->get('/health', HealthAction::class);
+$api->group('/v1', function (RouteCollectorProxy $r) {
+    $r->post('/orders', CreateOrderAction::class);
+    $r->group('/admin', fn ($admin) => $admin->delete('/cache', ClearCacheAction::class));
+});
+
+$client->get('/not-a-route');
+
+return function (App $routes) {
+    $routes->put('/settings', SettingsAction::class);
+};
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/health".to_string(), "HealthAction".to_string()),
+            (HttpMethod::Post, "/v1/orders".to_string(), "CreateOrderAction".to_string()),
+            (HttpMethod::Delete, "/v1/admin/cache".to_string(), "ClearCacheAction".to_string()),
+            (HttpMethod::Put, "/settings".to_string(), "SettingsAction".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_symfony_attributes_and_annotations() {
+        let code = r#"<?php
+namespace App\Controller;
+
+use Symfony\Component\Routing\Attribute\Route;
+
+#[Route('/api/posts', name: 'api_posts_')]
+class PostController extends AbstractController
+{
+    #[Route('', name: 'index', methods: ['GET'])]
+    public function index(): Response {}
+
+    #[IsGranted('ROLE_ADMIN'), Route(path: '/{id}', methods: ['PUT', 'PATCH'])]
+    public function update(int $id): Response {}
+
+    private function helper() {}
+}
+
+/**
+ * @Route("/legacy")
+ */
+class LegacyController
+{
+    /**
+     * Lists things.
+     *
+     * @Route("/items/{id}", name="legacy_item", methods={"GET", "DELETE"})
+     */
+    public function item($id) {}
+}
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/api/posts".to_string(), "PostController::index".to_string()),
+            (HttpMethod::Put, "/api/posts/{id}".to_string(), "PostController::update".to_string()),
+            (HttpMethod::Patch, "/api/posts/{id}".to_string(), "PostController::update".to_string()),
+            (HttpMethod::Get, "/legacy/items/{id}".to_string(), "LegacyController::item".to_string()),
+            (HttpMethod::Delete, "/legacy/items/{id}".to_string(), "LegacyController::item".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_heredocs_do_not_hide_later_routes() {
+        let code = r#"<?php
+$t = <<<EOT
+it's
+EOT;
+$n = <<<'EOT'
+Route::get('/inside', 'Nope@index');
+EOT;
+Route::delete('/after', 'PostController@destroy');
+Route::get('/health', fn () => response()->noContent());
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Delete, "/after".to_string(), "PostController@destroy".to_string()),
+            (HttpMethod::Get, "/health".to_string(), "9:1".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_interpolated_paths_are_skipped() {
+        let code = r#"<?php
+Route::get("/str/{$var}", 'StrController@show');
+Route::get("/users/{id}", 'UserController@show');
+Route::prefix("v{$version}")->group(function () {
+    Route::get('/status', 'StatusController');
+});
+$app = AppFactory::create();
+$app->group("/{$tenant}", function (RouteCollectorProxy $group) {
+    $group->get('/items', ItemsAction::class);
+});
+"#;
+
+        assert_eq!(routes(code), vec![
+            (HttpMethod::Get, "/users/{id}".to_string(), "UserController@show".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_api_routes_file_is_prefixed() {
+        let module = PhpParser.parse_module(Path::new("routes/api.php"), "<?php Route::get('/users', 'UserController@index');").unwrap();
+        let endpoints = module.into_endpoints();
+        assert_eq!(endpoints[0].path, "/api/users");
+        assert_eq!(endpoints[0].middleware, vec!["api".to_string()]);
+    }
 }
@@ -1,171 +1,1272 @@
-use crate::{Endpoint, HttpMethod, LanguageParser};
+use crate::{Documentation, Endpoint, HttpMethod, LanguageParser, Parameter, ParameterLocation, Response, Schema, parse_http_method};
+use crate::docs::parse_docstring;
+use crate::languages::django;
+use crate::params::{add_inputs, input, path_parameters};
+use crate::project::{Handler, Import, ModuleRoutes, Mount, RoutedEndpoint};
+use crate::responses::{add_response, response, status_code};
 use anyhow::{Result, anyhow};
-use tree_sitter::{Parser, Query, QueryCursor};
+use std::collections::HashMap;
+use std::path::Path;
+use tree_sitter::{Node, Parser, Point};
 
+/// Parser for Python web frameworks: FastAPI (including `APIRouter`
+/// prefixes and `include_router` composition), Flask (blueprints,
+/// `add_url_rule` and class-based views), Flask-RESTful/Flask-Smorest
+/// resources, and Django/DRF URLconfs.
+///
+/// Routes are recorded against the router object they are registered on.
+/// Routers mounted with `include_router`/`register_blueprint` are linked
+/// across modules through Python imports, carrying their prefixes, tags and
+/// dependencies. View classes own their verb methods at an empty path and are
+/// mounted wherever they are registered.
 pub struct PythonParser;
 
 impl LanguageParser for PythonParser {
     fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        Ok(self.parse_module(Path::new(""), content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "py"
+    }
+
+    fn parse_module(&self, _path: &Path, content: &str) -> Result<ModuleRoutes> {
         let mut parser = Parser::new();
         parser.set_language(tree_sitter_python::language())?;
-        
+
         let tree = parser.parse(content, None)
             .ok_or_else(|| anyhow!("Failed to parse Python code"))?;
-        
-        let mut endpoints = Vec::new();
-        
-        // Query for Flask route decorators with methods parameter
-        let flask_methods_query = Query::new(
-            tree_sitter_python::language(),
-            r#"
-            (decorated_definition
-              (decorator 
-                (call
-                  function: (attribute
-                    object: (identifier) @app
-                    attribute: (identifier) @decorator_name)
-                  arguments: (argument_list
-                    (string (string_content) @path)
-                    (keyword_argument
-                      name: (identifier) @param_name
-                      value: (list (string (string_content) @http_method)*)))))
-              definition: (function_definition
-                name: (identifier) @handler))
-            "#,
-        )?;
-
-        // Query for simple route decorators (app.get, app.post, etc.)
-        let simple_route_query = Query::new(
-            tree_sitter_python::language(),
-            r#"
-            (decorated_definition
-              (decorator 
-                (call
-                  function: (attribute
-                    object: (identifier) @app
-                    attribute: (identifier) @method)
-                  arguments: (argument_list
-                    (string (string_content) @path))))
-              definition: (function_definition
-                name: (identifier) @handler))
-            "#,
-        )?;
-
-        let mut cursor = QueryCursor::new();
-        
-        // Parse routes with methods parameter
-        let matches = cursor.matches(&flask_methods_query, tree.root_node(), content.as_bytes());
-        for m in matches {
-            let mut path_value = None;
-            let mut handler_name = None;
-            let mut http_methods = Vec::new();
-            let mut start_position = None;
-
-            for capture in m.captures {
-                let text = &content[capture.node.byte_range()];
-                let capture_name = &flask_methods_query.capture_names()[capture.index as usize];
-                match capture_name.as_str() {
-                    "path" => {
-                        path_value = Some(text.to_string());
-                        start_position = Some(capture.node.start_position());
-                    }
-                    "handler" => {
-                        handler_name = Some(text.to_string());
+
+        let mut walker = RouteWalker::new(content);
+        walker.collect_constants(tree.root_node());
+        walker.visit(tree.root_node());
+        django::collect(tree.root_node(), content, &mut walker.module);
+        Ok(walker.module)
+    }
+}
+
+/// Options shared by router constructors, `include_router` and route
+/// decorators.
+struct RouterOptions {
+    prefix: Option<String>,
+    tags: Vec<String>,
+    dependencies: Vec<String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    module: ModuleRoutes,
+    /// Module-level string and string-list constants (`API_PREFIX = "/api"`,
+    /// `METHODS = ("GET", "POST")`).
+    constants: HashMap<String, Vec<String>>,
+}
+
+impl<'a> RouteWalker<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            module: ModuleRoutes::default(),
+            constants: HashMap::new(),
+        }
+    }
+
+    fn collect_constants(&mut self, root: Node) {
+        let mut cursor = root.walk();
+        for statement in root.named_children(&mut cursor) {
+            let assignment = match statement.named_child(0) {
+                Some(assignment) if statement.kind() == "expression_statement" && assignment.kind() == "assignment" => assignment,
+                _ => continue,
+            };
+            let (left, right) = match (assignment.child_by_field_name("left"), assignment.child_by_field_name("right")) {
+                (Some(left), Some(right)) if left.kind() == "identifier" => (left, right),
+                _ => continue,
+            };
+            let values = match right.kind() {
+                "list" | "tuple" => list_items(right).into_iter().map(|item| string_value(item, self.content)).collect(),
+                _ => string_value(right, self.content).map(|value| vec![value]),
+            };
+            if let Some(values) = values {
+                self.constants.insert(self.text(left).to_string(), values);
+            }
+        }
+    }
+
+    fn visit(&mut self, node: Node) {
+        match node.kind() {
+            "decorated_definition" => self.visit_decorated(node),
+            "function_definition" => self.visit_function(node),
+            "class_definition" => {
+                self.visit_model(node);
+                self.visit_class(node);
+            }
+            "assignment" => self.visit_assignment(node),
+            "call" => self.visit_call(node),
+            "import_statement" | "import_from_statement" => self.visit_import(node),
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child);
+        }
+    }
+
+    /// `@app.get("/x")`, `@router.post("/", tags=[...])`,
+    /// `@app.route("/x", methods=["GET", "POST"])`, and Flask-Smorest
+    /// `@blp.route("/x")` on a `MethodView` class
+    fn visit_decorated(&mut self, node: Node) {
+        let definition = match node.child_by_field_name("definition") {
+            Some(definition) => definition,
+            None => return,
+        };
+        let handler = match definition.child_by_field_name("name") {
+            Some(name) => self.text(name).to_string(),
+            None => return,
+        };
+        let is_class = definition.kind() == "class_definition";
+
+        let mut cursor = node.walk();
+        let decorators: Vec<Node> = node.named_children(&mut cursor).filter(|c| c.kind() == "decorator").collect();
+        for (i, decorator) in decorators.iter().copied().enumerate() {
+            let call = match decorator.named_child(0) {
+                Some(call) if call.kind() == "call" => call,
+                _ => continue,
+            };
+            let (object, attribute) = match self.method_call(call) {
+                Some(parts) => parts,
+                None => continue,
+            };
+            let args = arguments(call);
+            let path = match positional(&args, 0)
+                .or_else(|| keyword(&args, "path", self.content))
+                .or_else(|| keyword(&args, "rule", self.content))
+                .and_then(|p| self.string(p))
+            {
+                Some(path) => path,
+                None => continue,
+            };
+
+            if is_class {
+                if self.text(attribute) == "route" {
+                    self.module.mounts.push(Mount {
+                        parent: self.text(object).to_string(),
+                        prefix: path,
+                        target: handler.clone(),
+                    });
+                }
+                continue;
+            }
+
+            let methods = match self.text(attribute) {
+                "route" | "api_route" => self.methods(&args).unwrap_or_else(|| vec![HttpMethod::Get]),
+                verb => match parse_http_method(verb) {
+                    Ok(method) => vec![method],
+                    Err(_) => continue,
+                },
+            };
+
+            let owner = self.text(object).to_string();
+            let options = self.options(&args);
+            let mut parameters = self.path_hints(definition, &path);
+            add_inputs(&mut parameters, &self.signature_inputs(definition, &path));
+            add_inputs(&mut parameters, &self.body_inputs(definition));
+            let body = self.request_body(definition, &path);
+            let responses = self.route_responses(&args, definition);
+            let documentation = self.documentation(definition, &args);
+            // Route dependencies, then the view decorators below the route
+            // decorator (`@login_required`), then `Depends()` parameters
+            let mut middleware = options.dependencies.clone();
+            for name in self.view_decorators(&decorators[i + 1..]).into_iter().chain(self.signature_dependencies(definition)) {
+                if !middleware.contains(&name) {
+                    middleware.push(name);
+                }
+            }
+            let first = self.module.endpoints.len();
+            self.push(&owner, methods, path, handler.clone(), options, attribute);
+            for routed in &mut self.module.endpoints[first..] {
+                routed.endpoint.middleware = middleware.clone();
+                routed.endpoint.parameters = parameters.clone();
+                routed.endpoint.body = body.clone();
+                routed.endpoint.responses = responses.clone();
+                routed.endpoint.documentation = documentation.clone();
+            }
+        }
+    }
+
+    /// Flask `MethodView`/`View` and Flask-RESTful `Resource` classes: each
+    /// `get`/`post`/... method is an endpoint at the path the class is
+    /// registered under. A plain `View` serves its `methods` list through
+    /// `dispatch_request`. The class's `decorators = [login_required]`
+    /// (`method_decorators` in Flask-RESTful) wrap every one of them.
+    fn visit_class(&mut self, node: Node) {
+        let is_view = node.child_by_field_name("superclasses")
+            .map(|bases| arguments_of(bases).into_iter().any(|base| {
+                matches!(self.text(base).rsplit('.').next(), Some("MethodView" | "View" | "Resource"))
+            }))
+            .unwrap_or(false);
+        let (name, body) = match (node.child_by_field_name("name"), node.child_by_field_name("body")) {
+            (Some(name), Some(body)) if is_view => (self.text(name).to_string(), body),
+            _ => return,
+        };
+
+        let mut verbs = Vec::new();
+        let mut declared = None;
+        let mut middleware = Vec::new();
+        let mut cursor = body.walk();
+        for statement in body.named_children(&mut cursor) {
+            let statement = match statement.kind() {
+                "decorated_definition" => match statement.child_by_field_name("definition") {
+                    Some(definition) => definition,
+                    None => continue,
+                },
+                _ => statement,
+            };
+            match statement.kind() {
+                "function_definition" => {
+                    if let Some(method) = statement.child_by_field_name("name") {
+                        if let Ok(verb) = parse_http_method(self.text(method)) {
+                            verbs.push((verb, method));
+                        }
                     }
-                    "http_method" => {
-                        http_methods.push(text.to_string());
+                }
+                "expression_statement" => {
+                    let assignment = match statement.named_child(0) {
+                        Some(assignment) if assignment.kind() == "assignment" => assignment,
+                        _ => continue,
+                    };
+                    if let (Some(left), Some(right)) = (assignment.child_by_field_name("left"), assignment.child_by_field_name("right")) {
+                        match self.text(left) {
+                            "methods" => declared = Some(self.strings(right)),
+                            "decorators" | "method_decorators" if matches!(right.kind(), "list" | "tuple") => {
+                                middleware = list_items(right).into_iter().map(|d| self.text(d).to_string()).collect();
+                            }
+                            _ => {}
+                        }
                     }
-                    _ => {}
+                }
+                _ => {}
+            }
+        }
+
+        let pos = node.start_position();
+        if verbs.is_empty() {
+            let methods = declared.unwrap_or_else(|| vec!["GET".to_string()]);
+            for method in methods.iter().filter_map(|m| parse_http_method(m).ok()) {
+                let mut endpoint = endpoint(method, String::new(), format!("{}.dispatch_request", name), pos);
+                endpoint.middleware = middleware.clone();
+                self.module.endpoints.push(RoutedEndpoint { owner: name.clone(), endpoint });
+            }
+            return;
+        }
+        for (verb, method) in verbs {
+            let handler = format!("{}.{}", name, self.text(method));
+            let mut endpoint = endpoint(verb, String::new(), handler, method.start_position());
+            endpoint.middleware = middleware.clone();
+            if let Some(definition) = method.parent() {
+                endpoint.parameters = self.body_inputs(definition);
+                endpoint.responses = self.responses(definition, 200);
+                endpoint.documentation = self.documentation(definition, &[]);
+            }
+            self.module.endpoints.push(RoutedEndpoint { owner: name.clone(), endpoint });
+        }
+    }
+
+    /// Module-level view functions record the inputs they read, the
+    /// responses they send and their docstrings, for routes
+    /// that register them from a URLconf or `add_url_rule` elsewhere.
+    fn visit_function(&mut self, node: Node) {
+        let at_module_level = node.parent()
+            .map(|parent| parent.kind() == "module" || parent.parent().map(|p| p.kind() == "module").unwrap_or(false))
+            .unwrap_or(false);
+        let name = match node.child_by_field_name("name") {
+            Some(name) if at_module_level => self.text(name).to_string(),
+            _ => return,
+        };
+        let handler = Handler {
+            body: None,
+            parameters: self.body_inputs(node),
+            responses: self.responses(node, 200),
+            documentation: self.documentation(node, &[]),
+        };
+        let described = !handler.parameters.is_empty() || !handler.responses.is_empty();
+        if described || handler.documentation.is_some() {
+            self.module.handlers.insert(name, handler);
+        }
+    }
+
+    /// Pydantic models (`class UserCreate(BaseModel)`), and subclasses of
+    /// models declared earlier in the file, are recorded as body schemas.
+    fn visit_model(&mut self, node: Node) {
+        let (name, body) = match (node.child_by_field_name("name"), node.child_by_field_name("body")) {
+            (Some(name), Some(body)) => (self.text(name).to_string(), body),
+            _ => return,
+        };
+        let bases = node.child_by_field_name("superclasses").map(arguments_of).unwrap_or_default();
+
+        let mut schema = Schema { name: Some(name.clone()), ..Schema::of_type("object") };
+        let mut is_model = false;
+        for base in bases {
+            let base = self.text(base).rsplit('.').next().unwrap_or_default();
+            if MODEL_BASES.contains(&base) {
+                is_model = true;
+            } else if let Some(parent) = self.module.schemas.get(base) {
+                is_model = true;
+                schema.properties.extend(parent.properties.clone());
+            }
+        }
+        if !is_model {
+            return;
+        }
+
+        // `name: str`, `age: int = 0`, `email: EmailStr = Field(...)`
+        let mut cursor = body.walk();
+        for statement in body.named_children(&mut cursor) {
+            let assignment = match statement.named_child(0) {
+                Some(assignment) if statement.kind() == "expression_statement" && assignment.kind() == "assignment" => assignment,
+                _ => continue,
+            };
+            let (field, annotation) = match (assignment.child_by_field_name("left"), assignment.child_by_field_name("type")) {
+                (Some(field), Some(annotation)) if field.kind() == "identifier" => (self.text(field), self.text(annotation)),
+                _ => continue,
+            };
+            if annotation.starts_with("ClassVar") || field == "model_config" {
+                continue;
+            }
+            let (field_schema, optional) = annotation_schema(annotation);
+            let required = !optional && assignment.child_by_field_name("right")
+                .map(|default| self.required_marker(default))
+                .unwrap_or(true);
+            schema.set_property(field, field_schema, required);
+        }
+        self.module.schemas.insert(name, schema);
+    }
+
+    /// `router = APIRouter(prefix="/users", tags=["users"])`,
+    /// `app = FastAPI(dependencies=[Depends(verify_key)])`,
+    /// `bp = Blueprint("users", __name__, url_prefix="/users")`,
+    /// `api = Api(app, prefix="/v1")`
+    fn visit_assignment(&mut self, node: Node) {
+        let (left, right) = match (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+            (Some(left), Some(right)) if left.kind() == "identifier" && right.kind() == "call" => (left, right),
+            _ => return,
+        };
+        let constructor = match right.child_by_field_name("function") {
+            Some(function) => self.text(function).rsplit('.').next().unwrap_or_default(),
+            None => return,
+        };
+        let name = self.text(left).to_string();
+        let args = arguments(right);
+
+        match constructor {
+            "APIRouter" | "FastAPI" => {
+                let options = self.options(&args);
+                self.record_options(&name, options);
+            }
+            "Blueprint" => {
+                if let Some(prefix) = keyword(&args, "url_prefix", self.content).and_then(|p| self.string(p)) {
+                    self.module.prefixes.insert(name.clone(), prefix);
+                    self.module.default_prefixes.insert(name);
                 }
             }
+            // Flask-RESTful and Flask-Smorest serve resources on the app or
+            // blueprint they are created with
+            "Api" => {
+                if let Some(prefix) = keyword(&args, "prefix", self.content).and_then(|p| self.string(p)) {
+                    self.module.prefixes.insert(name.clone(), prefix);
+                }
+                if let Some(app) = positional(&args, 0).or_else(|| keyword(&args, "app", self.content)) {
+                    self.module.mounts.push(Mount {
+                        parent: self.text(app).to_string(),
+                        prefix: String::new(),
+                        target: name,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `app.include_router(users.router, prefix="/users")`,
+    /// `app.add_api_route("/x", handler, methods=["GET"])`,
+    /// `app.register_blueprint(bp, url_prefix="/api")`,
+    /// `app.add_url_rule("/x", view_func=UserView.as_view("users"))` and
+    /// `api.add_resource(UserResource, "/users/<int:id>")`
+    fn visit_call(&mut self, node: Node) {
+        let (object, attribute) = match self.method_call(node) {
+            Some(parts) => parts,
+            None => return,
+        };
+        let args = arguments(node);
+        let parent = self.text(object).to_string();
 
-            if let (Some(path), Some(handler), Some(pos)) = (path_value, handler_name, start_position) {
-                if http_methods.is_empty() {
-                    // Default to GET if no methods specified
-                    endpoints.push(Endpoint {
-                        method: HttpMethod::Get,
-                        path,
-                        handler: handler.clone(),
-                        line: pos.row + 1,
-                        column: pos.column + 1,
-                        documentation: None,
+        match self.text(attribute) {
+            "include_router" => {
+                let target = match positional(&args, 0)
+                    .or_else(|| keyword(&args, "router", self.content))
+                {
+                    Some(target) => self.text(target).to_string(),
+                    None => return,
+                };
+                let options = self.options(&args);
+                if options.tags.is_empty() && options.dependencies.is_empty() {
+                    self.module.mounts.push(Mount {
+                        parent,
+                        prefix: options.prefix.unwrap_or_default(),
+                        target,
                     });
-                } else {
-                    for method_str in http_methods {
-                        if let Ok(http_method) = parse_http_method(&method_str) {
-                            endpoints.push(Endpoint {
-                                method: http_method,
-                                path: path.clone(),
-                                handler: handler.clone(),
-                                line: pos.row + 1,
-                                column: pos.column + 1,
-                                documentation: None,
-                            });
-                        }
+                    return;
+                }
+
+                // Tags and dependencies apply to this inclusion only, so they
+                // live on a node between the parent and the router
+                let include = format!("include@{}", node.start_byte());
+                self.record_options(&include, options);
+                self.module.mounts.push(Mount { parent, prefix: String::new(), target: include.clone() });
+                self.module.mounts.push(Mount { parent: include, prefix: String::new(), target });
+            }
+            "add_api_route" => {
+                let path = match args.first().and_then(|p| self.string(*p)) {
+                    Some(path) => path,
+                    None => return,
+                };
+                let handler = match positional(&args, 1)
+                    .or_else(|| keyword(&args, "endpoint", self.content))
+                {
+                    Some(handler) => self.text(handler).to_string(),
+                    None => return,
+                };
+                let methods = self.methods(&args).unwrap_or_else(|| vec![HttpMethod::Get]);
+                let options = self.options(&args);
+                self.push(&parent, methods, path, handler, options, attribute);
+            }
+            // The url_prefix given here replaces the blueprint's own
+            "register_blueprint" => {
+                let target = match positional(&args, 0).or_else(|| keyword(&args, "blueprint", self.content)) {
+                    Some(target) => self.text(target).to_string(),
+                    None => return,
+                };
+                let prefix = keyword(&args, "url_prefix", self.content)
+                    .and_then(|p| self.string(p))
+                    .unwrap_or_default();
+                self.module.mounts.push(Mount { parent, prefix, target });
+            }
+            "init_app" => {
+                if let Some(app) = positional(&args, 0) {
+                    self.module.mounts.push(Mount {
+                        parent: self.text(app).to_string(),
+                        prefix: String::new(),
+                        target: parent,
+                    });
+                }
+            }
+            "add_url_rule" => {
+                let path = match positional(&args, 0)
+                    .or_else(|| keyword(&args, "rule", self.content))
+                    .and_then(|p| self.string(p))
+                {
+                    Some(path) => path,
+                    None => return,
+                };
+                let view = match positional(&args, 2).or_else(|| keyword(&args, "view_func", self.content)) {
+                    Some(view) => view,
+                    None => return,
+                };
+
+                // `UserView.as_view("users")` mounts the class's own endpoints
+                if let Some(class) = self.as_view(view) {
+                    self.module.mounts.push(Mount { parent, prefix: path, target: class });
+                    return;
+                }
+                let methods = self.methods(&args).unwrap_or_else(|| vec![HttpMethod::Get]);
+                let handler = self.text(view).to_string();
+                let options = self.options(&args);
+                self.push(&parent, methods, path, handler, options, attribute);
+            }
+            "add_resource" => {
+                let resource = match positional(&args, 0) {
+                    Some(resource) => self.text(resource).to_string(),
+                    None => return,
+                };
+                let urls: Vec<String> = args.iter().skip(1).filter_map(|url| self.string(*url)).collect();
+                for url in urls {
+                    self.module.mounts.push(Mount {
+                        parent: parent.clone(),
+                        prefix: url,
+                        target: resource.clone(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `from .routers import users`, `from app.api import router as api_router`,
+    /// `import app.routers.items as items`
+    fn visit_import(&mut self, node: Node) {
+        let source = node.child_by_field_name("module_name").map(|m| self.text(m).to_string());
+
+        let mut cursor = node.walk();
+        let names: Vec<Node> = node.children_by_field_name("name", &mut cursor).collect();
+        for name in names {
+            let (imported, local) = match name.kind() {
+                "aliased_import" => match (name.child_by_field_name("name"), name.child_by_field_name("alias")) {
+                    (Some(imported), Some(alias)) => (self.text(imported).to_string(), self.text(alias).to_string()),
+                    _ => continue,
+                },
+                _ => (self.text(name).to_string(), self.text(name).to_string()),
+            };
+
+            let import = match &source {
+                Some(source) => Import { source: source.clone(), name: Some(imported) },
+                // `import a.b` binds `a`, which doesn't name the module
+                None if imported.contains('.') && local == imported => continue,
+                None => Import { source: imported, name: Some("*".to_string()) },
+            };
+            self.module.imports.insert(local, import);
+        }
+    }
+
+    fn push(&mut self, owner: &str, methods: Vec<HttpMethod>, path: String, handler: String, options: RouterOptions, node: Node) {
+        for method in methods {
+            let mut endpoint = endpoint(method, path.clone(), handler.clone(), node.start_position());
+            endpoint.tags = options.tags.clone();
+            endpoint.middleware = options.dependencies.clone();
+            self.module.endpoints.push(RoutedEndpoint { owner: owner.to_string(), endpoint });
+        }
+    }
+
+    /// FastAPI types path parameters in the handler signature:
+    /// `def read_item(item_id: int)` or `item_id: Annotated[int, Path(ge=1)]`.
+    fn path_hints(&self, function: Node, path: &str) -> Vec<Parameter> {
+        let names: Vec<String> = path_parameters(path).into_iter().map(|p| p.name).collect();
+        let parameters = match function.child_by_field_name("parameters") {
+            Some(parameters) => parameters,
+            None => return Vec::new(),
+        };
+
+        let mut hints = Vec::new();
+        let mut cursor = parameters.walk();
+        for param in parameters.named_children(&mut cursor) {
+            let name = match param.kind() {
+                "typed_parameter" => param.named_child(0),
+                "typed_default_parameter" => param.child_by_field_name("name"),
+                _ => None,
+            };
+            let (name, param_type) = match (name, param.child_by_field_name("type")) {
+                (Some(name), Some(param_type)) => (self.text(name), self.text(param_type)),
+                _ => continue,
+            };
+            if !names.iter().any(|n| n == name) {
+                continue;
+            }
+            hints.push(Parameter {
+                name: name.to_string(),
+                location: ParameterLocation::Path,
+                param_type: Some(annotated_type(param_type).to_string()),
+                required: true,
+                default: None,
+            });
+        }
+        hints
+    }
+
+    /// FastAPI reads scalar handler parameters that aren't in the path from
+    /// the query string, and those declared with `Header()` from headers:
+    /// `q: str | None = None`, `limit: int = Query(20, alias="max")`,
+    /// `x_tenant: Annotated[str, Header()]`.
+    fn signature_inputs(&self, function: Node, path: &str) -> Vec<Parameter> {
+        let names: Vec<String> = path_parameters(path).into_iter().map(|p| p.name).collect();
+        let parameters = match function.child_by_field_name("parameters") {
+            Some(parameters) => parameters,
+            None => return Vec::new(),
+        };
+
+        let mut inputs = Vec::new();
+        let mut cursor = parameters.walk();
+        for param in parameters.named_children(&mut cursor) {
+            let (name, default) = match param.kind() {
+                "typed_parameter" => (param.named_child(0), None),
+                "typed_default_parameter" | "default_parameter" => (param.child_by_field_name("name"), param.child_by_field_name("value")),
+                _ => continue,
+            };
+            let name = match name {
+                Some(name) if name.kind() == "identifier" => self.text(name),
+                _ => continue,
+            };
+            if names.iter().any(|n| n == name) || matches!(name, "self" | "cls" | "request") {
+                continue;
+            }
+
+            let marker = default.filter(|d| d.kind() == "call").or_else(|| annotated_marker(param));
+            let marker_name = marker
+                .and_then(|m| m.child_by_field_name("function"))
+                .map(|f| self.text(f).rsplit('.').next().unwrap_or_default());
+            let location = match marker_name {
+                None | Some("Query") => ParameterLocation::Query,
+                Some("Header") => ParameterLocation::Header,
+                Some(_) => continue,
+            };
+            let annotation = param.child_by_field_name("type").map(|annotation| self.text(annotation));
+            let (schema, optional) = annotation
+                .map(annotation_schema)
+                .unwrap_or_else(|| (Schema::of_type("any"), true));
+            if schema.reference.is_some() {
+                continue;
+            }
+
+            let marker_args = marker.map(arguments).unwrap_or_default();
+            let alias = keyword(&marker_args, "alias", self.content).and_then(|a| self.string(a));
+            let name = match (alias, &location) {
+                (Some(alias), _) => alias,
+                (None, ParameterLocation::Header) => name.replace('_', "-"),
+                _ => name.to_string(),
+            };
+            // `= Query(20)` carries the default; `Annotated[int, Query()] = 20` doesn't
+            let in_default = marker.filter(|m| Some(*m) == default);
+            let default_value = match in_default {
+                Some(_) => positional(&marker_args, 0).or_else(|| keyword(&marker_args, "default", self.content)),
+                None => default,
+            };
+            let mut parameter = input(&name, location, default_value.and_then(|value| self.literal(value)));
+            parameter.param_type = annotation.map(|annotation| annotated_type(annotation).to_string());
+            parameter.required = !optional && match (in_default, default) {
+                (Some(marker), _) => self.required_marker(marker),
+                (None, default) => default.is_none(),
+            };
+            inputs.push(parameter);
+        }
+        inputs
+    }
+
+    /// Query parameters and headers read from the request object in a view
+    /// body: `request.args.get("page", 1, type=int)`, `request.args["q"]`,
+    /// `request.headers.get("X-Tenant")`, and Django's `request.GET` and
+    /// Starlette's `request.query_params`.
+    fn body_inputs(&self, function: Node) -> Vec<Parameter> {
+        let mut found = Vec::new();
+        if let Some(body) = function.child_by_field_name("body") {
+            self.collect_inputs(body, &mut found);
+        }
+        let mut inputs = Vec::new();
+        add_inputs(&mut inputs, &found);
+        inputs
+    }
+
+    fn collect_inputs(&self, node: Node, found: &mut Vec<Parameter>) {
+        match node.kind() {
+            "call" => {
+                let getter = self.method_call(node).filter(|(_, method)| matches!(self.text(*method), "get" | "getlist"));
+                if let Some((object, method)) = getter {
+                    let args = arguments(node);
+                    let name = positional(&args, 0).and_then(|n| string_value(n, self.content));
+                    if let (Some(location), Some(name)) = (request_source(self.text(object)), name) {
+                        let default = positional(&args, 1)
+                            .or_else(|| keyword(&args, "default", self.content))
+                            .and_then(|value| self.literal(value));
+                        let mut parameter = input(&name, location, default);
+                        parameter.param_type = match keyword(&args, "type", self.content).map(|t| self.text(t)) {
+                            _ if self.text(method) == "getlist" => Some("list".to_string()),
+                            Some(converter) => Some(converter.to_string()),
+                            None => None,
+                        };
+                        found.push(parameter);
                     }
                 }
             }
+            "subscript" => {
+                let object = node.child_by_field_name("value").map(|v| self.text(v)).unwrap_or_default();
+                let name = node.child_by_field_name("subscript").and_then(|n| string_value(n, self.content));
+                if let (Some(location), Some(name)) = (request_source(object), name) {
+                    let mut parameter = input(&name, location, None);
+                    parameter.required = true;
+                    found.push(parameter);
+                }
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_inputs(child, found);
+        }
+    }
+
+    /// FastAPI's declared responses (`status_code=201`, `response_model=User`
+    /// or the return annotation, `responses={404: {"model": Message}}`),
+    /// followed by those the handler body sends.
+    fn route_responses(&self, args: &[Node], function: Node) -> Vec<Response> {
+        let status = keyword(args, "status_code", self.content).and_then(|s| status_code(self.text(s)));
+        let model = keyword(args, "response_model", self.content)
+            .map(|m| self.text(m))
+            .or_else(|| function.child_by_field_name("return_type").map(|r| self.text(r)))
+            .filter(|model| *model != "None" && !model.ends_with("Response"))
+            .map(|model| annotation_schema(model).0);
+
+        let mut responses = Vec::new();
+        if status.is_some() || model.is_some() {
+            add_response(&mut responses, response(status.unwrap_or(200), model));
+        }
+        if let Some(declared) = keyword(args, "responses", self.content).filter(|r| r.kind() == "dictionary") {
+            let mut cursor = declared.walk();
+            for pair in declared.named_children(&mut cursor).filter(|p| p.kind() == "pair") {
+                let status = match pair.child_by_field_name("key").and_then(|k| status_code(self.text(k))) {
+                    Some(status) => status,
+                    None => continue,
+                };
+                let model = pair.child_by_field_name("value")
+                    .and_then(|v| self.dictionary_entry(v, "model"))
+                    .map(|m| annotation_schema(self.text(m)).0);
+                add_response(&mut responses, response(status, model));
+            }
+        }
+        for sent in self.responses(function, status.unwrap_or(200)) {
+            add_response(&mut responses, sent);
         }
+        responses
+    }
 
-        // Parse simple route decorators
-        let matches = cursor.matches(&simple_route_query, tree.root_node(), content.as_bytes());
-        for m in matches {
-            let mut method_name = None;
-            let mut path_value = None;
-            let mut handler_name = None;
-            let mut start_position = None;
+    /// Responses a view sends: `return JSONResponse(data, status_code=201)`,
+    /// Django's `JsonResponse(data, status=400)`, `raise HTTPException(404)`,
+    /// Flask's `abort(403)` and `return jsonify(user), 201`, and literal
+    /// dicts returned with the route's default status.
+    fn responses(&self, function: Node, default_status: u16) -> Vec<Response> {
+        let mut responses = Vec::new();
+        if let Some(body) = function.child_by_field_name("body") {
+            self.collect_responses(body, default_status, &mut responses);
+        }
+        responses
+    }
 
-            for capture in m.captures {
-                let text = &content[capture.node.byte_range()];
-                let capture_name = &simple_route_query.capture_names()[capture.index as usize];
-                match capture_name.as_str() {
-                    "method" => {
-                        method_name = Some(text.to_string());
-                        start_position = Some(capture.node.start_position());
+    fn collect_responses(&self, node: Node, default_status: u16, responses: &mut Vec<Response>) {
+        match node.kind() {
+            "call" => {
+                let callee = node.child_by_field_name("function").map(|f| self.text(f)).unwrap_or_default();
+                let callee = callee.rsplit('.').next().unwrap_or_default();
+                let args = arguments(node);
+                let status = keyword(&args, "status_code", self.content)
+                    .or_else(|| keyword(&args, "status", self.content))
+                    .and_then(|s| status_code(self.text(s)));
+                if callee.ends_with("Response") {
+                    let default = if callee == "RedirectResponse" { 307 } else { default_status };
+                    let body = positional(&args, 0)
+                        .or_else(|| keyword(&args, "content", self.content))
+                        .or_else(|| keyword(&args, "data", self.content))
+                        .and_then(|b| self.value_schema(b));
+                    add_response(responses, response(status.unwrap_or(default), body));
+                } else if matches!(callee, "HTTPException" | "abort") {
+                    let status = status.or_else(|| positional(&args, 0).and_then(|s| status_code(self.text(s))));
+                    if let Some(status) = status {
+                        let body = (callee == "HTTPException").then(|| {
+                            let mut detail = Schema::of_type("object");
+                            detail.set_property("detail", Schema::of_type("any"), true);
+                            detail
+                        });
+                        add_response(responses, response(status, body));
+                    }
+                }
+            }
+            "return_statement" => {
+                let returned = node.named_child(0);
+                let (body, status) = match returned.map(|r| (r.kind(), r)) {
+                    // `return jsonify(user), 201`
+                    Some(("expression_list" | "tuple", list)) => {
+                        let mut cursor = list.walk();
+                        let items: Vec<Node> = list.named_children(&mut cursor).collect();
+                        let status = items.get(1).and_then(|s| status_code(self.text(*s)));
+                        (items.first().copied(), status)
                     }
-                    "path" => {
-                        path_value = Some(text.to_string());
+                    _ => (returned, None),
+                };
+                let literal = body.filter(|b| match b.kind() {
+                    "dictionary" | "list" => true,
+                    "call" => b.child_by_field_name("function").map(|f| self.text(f).ends_with("jsonify")).unwrap_or(false),
+                    _ => false,
+                });
+                if literal.is_some() || status.is_some() {
+                    add_response(responses, response(status.unwrap_or(default_status), literal.and_then(|b| self.value_schema(b))));
+                }
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.collect_responses(child, default_status, responses);
+        }
+    }
+
+    /// The shape of a literal body, looking through `jsonify(...)`.
+    /// Other expressions are left undescribed.
+    fn value_schema(&self, node: Node) -> Option<Schema> {
+        let schema = match node.kind() {
+            "dictionary" => {
+                let mut schema = Schema::of_type("object");
+                let mut cursor = node.walk();
+                for pair in node.named_children(&mut cursor).filter(|p| p.kind() == "pair") {
+                    let key = pair.child_by_field_name("key").and_then(|k| string_value(k, self.content));
+                    if let Some(key) = key {
+                        let value = pair.child_by_field_name("value").and_then(|v| self.value_schema(v));
+                        schema.set_property(&key, value.unwrap_or_else(|| Schema::of_type("any")), true);
                     }
-                    "handler" => {
-                        handler_name = Some(text.to_string());
+                }
+                schema
+            }
+            "list" => {
+                let first = list_items(node).first().and_then(|item| self.value_schema(*item));
+                Schema::array(first.unwrap_or_else(|| Schema::of_type("any")))
+            }
+            "string" => Schema::of_type("string"),
+            "integer" => Schema::of_type("integer"),
+            "float" => Schema::of_type("number"),
+            "true" | "false" => Schema::of_type("boolean"),
+            "call" if node.child_by_field_name("function").map(|f| self.text(f).ends_with("jsonify")).unwrap_or(false) => {
+                let args = arguments(node);
+                match args.first() {
+                    Some(first) if first.kind() != "keyword_argument" => return self.value_schema(*first),
+                    _ => {
+                        let mut schema = Schema::of_type("object");
+                        for arg in args.iter().filter(|a| a.kind() == "keyword_argument") {
+                            if let Some(name) = arg.child_by_field_name("name") {
+                                let value = arg.child_by_field_name("value").and_then(|v| self.value_schema(v));
+                                schema.set_property(self.text(name), value.unwrap_or_else(|| Schema::of_type("any")), true);
+                            }
+                        }
+                        schema
                     }
-                    _ => {}
                 }
             }
+            _ => return None,
+        };
+        Some(schema)
+    }
 
-            if let (Some(method), Some(path), Some(handler), Some(pos)) = 
-                (method_name, path_value, handler_name, start_position) {
-                if let Ok(http_method) = parse_http_method(&method) {
-                    endpoints.push(Endpoint {
-                        method: http_method,
-                        path,
-                        handler,
-                        line: pos.row + 1,
-                        column: pos.column + 1,
-                        documentation: None,
-                    });
+    /// The value stored under a string `key` in a dict literal.
+    fn dictionary_entry<'t>(&self, dictionary: Node<'t>, key: &str) -> Option<Node<'t>> {
+        let mut cursor = dictionary.walk();
+        let pair = dictionary.named_children(&mut cursor).find(|pair| {
+            pair.kind() == "pair" && pair.child_by_field_name("key").and_then(|k| string_value(k, self.content)).as_deref() == Some(key)
+        })?;
+        pair.child_by_field_name("value")
+    }
+
+    /// The handler's docstring, with FastAPI's `summary=`, `description=`
+    /// and `deprecated=True` taking precedence.
+    fn documentation(&self, function: Node, args: &[Node]) -> Option<Documentation> {
+        let docstring = function.child_by_field_name("body")
+            .and_then(|body| body.named_child(0))
+            .filter(|statement| statement.kind() == "expression_statement")
+            .and_then(|statement| statement.named_child(0))
+            .and_then(|string| string_value(string, self.content));
+        let mut documentation = docstring.map(|docstring| parse_docstring(&docstring));
+
+        if let Some(summary) = keyword(args, "summary", self.content).and_then(|s| self.string(s)) {
+            documentation.get_or_insert_with(Documentation::default).summary = Some(summary);
+        }
+        if let Some(description) = keyword(args, "description", self.content).and_then(|d| self.string(d)) {
+            documentation.get_or_insert_with(Documentation::default).description = Some(description);
+        }
+        if keyword(args, "deprecated", self.content).map(|d| self.text(d) == "True").unwrap_or(false) {
+            documentation.get_or_insert_with(Documentation::default).deprecated.get_or_insert_with(String::new);
+        }
+        documentation
+    }
+
+    /// A literal default: a string, number or boolean.
+    fn literal(&self, node: Node) -> Option<String> {
+        match node.kind() {
+            "string" => string_value(node, self.content),
+            "integer" | "float" | "true" | "false" => Some(self.text(node).to_string()),
+            "unary_operator" if matches!(node.named_child(0).map(|n| n.kind()), Some("integer" | "float")) => Some(self.text(node).to_string()),
+            _ => None,
+        }
+    }
+
+    /// FastAPI reads parameters typed with a model, and those declared with
+    /// `Body()`, `Form()` or `File()`, from the request body. A lone model is
+    /// the body itself; several inputs are embedded under their names.
+    fn request_body(&self, function: Node, path: &str) -> Option<Schema> {
+        let names: Vec<String> = path_parameters(path).into_iter().map(|p| p.name).collect();
+        let parameters = function.child_by_field_name("parameters")?;
+
+        let mut fields = Vec::new();
+        let mut embed = false;
+        let mut cursor = parameters.walk();
+        for param in parameters.named_children(&mut cursor) {
+            let (name, default) = match param.kind() {
+                "typed_parameter" => (param.named_child(0), None),
+                "typed_default_parameter" => (param.child_by_field_name("name"), param.child_by_field_name("value")),
+                _ => continue,
+            };
+            let (name, annotation) = match (name, param.child_by_field_name("type")) {
+                (Some(name), Some(annotation)) => (self.text(name), self.text(annotation)),
+                _ => continue,
+            };
+            if names.iter().any(|n| n == name) || name == "self" {
+                continue;
+            }
+
+            // `= Body(...)` or `Annotated[X, Body()]`
+            let marker = default.filter(|d| d.kind() == "call").or_else(|| annotated_marker(param));
+            let marker_name = marker
+                .and_then(|m| m.child_by_field_name("function"))
+                .map(|f| self.text(f).rsplit('.').next().unwrap_or_default());
+            let explicit = matches!(marker_name, Some("Body" | "Form" | "File"));
+            let (schema, optional) = annotation_schema(annotation);
+            if marker_name.is_some() && !explicit {
+                continue;
+            }
+            match &schema.reference {
+                Some(reference) if !NOT_BODY_TYPES.contains(&reference.as_str()) => {}
+                _ if explicit => {}
+                _ => continue,
+            }
+
+            if let Some(marker) = marker.filter(|_| explicit) {
+                embed |= keyword(&arguments(marker), "embed", self.content).map(|e| self.text(e) == "True").unwrap_or(false)
+                    || marker_name != Some("Body");
+            }
+            let required = !optional && default.map(|d| self.required_marker(d)).unwrap_or(true);
+            fields.push((name, schema, required, explicit));
+        }
+
+        match fields.as_slice() {
+            [] => None,
+            [(_, schema, _, _)] if schema.reference.is_some() && !embed => Some(schema.clone()),
+            _ => {
+                let mut body = Schema::of_type("object");
+                for (name, schema, required, _) in fields {
+                    body.set_property(name, schema, required);
                 }
+                Some(body)
             }
         }
+    }
+
+    /// Whether a default keeps the field required: `Field(...)`,
+    /// `Body(embed=True)` and other markers without a default value.
+    fn required_marker(&self, default: Node) -> bool {
+        if default.kind() != "call" {
+            return false;
+        }
+        let args = arguments(default);
+        match positional(&args, 0) {
+            Some(first) => self.text(first) == "...",
+            None => keyword(&args, "default", self.content).is_none()
+                && keyword(&args, "default_factory", self.content).is_none(),
+        }
+    }
 
-        Ok(endpoints)
+    /// The class behind `UserView.as_view("users")`.
+    fn as_view(&self, node: Node) -> Option<String> {
+        let (object, attribute) = self.method_call(node).filter(|_| node.kind() == "call")?;
+        (self.text(attribute) == "as_view").then(|| self.text(object).to_string())
     }
 
-    fn supports_extension(&self, extension: &str) -> bool {
-        extension == "py"
+    fn record_options(&mut self, binding: &str, options: RouterOptions) {
+        if let Some(prefix) = options.prefix {
+            self.module.prefixes.insert(binding.to_string(), prefix);
+        }
+        if !options.tags.is_empty() {
+            self.module.tags.insert(binding.to_string(), options.tags);
+        }
+        if !options.dependencies.is_empty() {
+            self.module.middleware.insert(binding.to_string(), options.dependencies);
+        }
+    }
+
+    /// The receiver and attribute of `receiver.attribute(...)`.
+    fn method_call(&self, call: Node<'a>) -> Option<(Node<'a>, Node<'a>)> {
+        let function = call.child_by_field_name("function").filter(|f| f.kind() == "attribute")?;
+        Some((function.child_by_field_name("object")?, function.child_by_field_name("attribute")?))
+    }
+
+    fn options(&self, args: &[Node]) -> RouterOptions {
+        RouterOptions {
+            prefix: keyword(args, "prefix", self.content).and_then(|p| self.string(p)),
+            tags: keyword(args, "tags", self.content)
+                .map(|t| self.strings(t))
+                .unwrap_or_default(),
+            dependencies: keyword(args, "dependencies", self.content)
+                .map(|d| list_items(d).into_iter().map(|item| self.dependency(item)).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// `methods=["GET", "POST"]`, a tuple, or a module-level constant.
+    fn methods(&self, args: &[Node]) -> Option<Vec<HttpMethod>> {
+        let methods = keyword(args, "methods", self.content)?;
+        Some(self.strings(methods)
+            .iter()
+            .filter_map(|m| parse_http_method(m).ok())
+            .collect())
+    }
+
+    /// Decorators wrapping a view, such as `@login_required` or
+    /// `@roles_required("admin")`, leaving out further route decorators.
+    fn view_decorators(&self, decorators: &[Node]) -> Vec<String> {
+        decorators.iter()
+            .filter_map(|decorator| decorator.named_child(0))
+            .filter(|expression| {
+                let attribute = match expression.kind() {
+                    "call" => self.method_call(*expression).map(|(_, attribute)| self.text(attribute)),
+                    _ => None,
+                };
+                match attribute {
+                    Some("route" | "api_route" | "websocket") => false,
+                    Some(attribute) => parse_http_method(attribute).is_err(),
+                    None => true,
+                }
+            })
+            .map(|expression| self.text(expression).to_string())
+            .collect()
+    }
+
+    /// Dependencies declared in the handler signature:
+    /// `user: User = Depends(get_current_user)`,
+    /// `user: Annotated[User, Security(get_current_user)]`. `Depends()`
+    /// without a callable only parses parameters and is left out.
+    fn signature_dependencies(&self, function: Node) -> Vec<String> {
+        let Some(parameters) = function.child_by_field_name("parameters") else {
+            return Vec::new();
+        };
+        let mut cursor = parameters.walk();
+        let markers: Vec<Node> = parameters.named_children(&mut cursor)
+            .filter_map(|param| param.child_by_field_name("value").filter(|d| d.kind() == "call").or_else(|| annotated_marker(param)))
+            .collect();
+        markers.into_iter()
+            .filter(|marker| {
+                let callee = marker.child_by_field_name("function").map(|f| self.text(f));
+                matches!(callee, Some("Depends" | "Security" | "fastapi.Depends" | "fastapi.Security")) && !arguments(*marker).is_empty()
+            })
+            .map(|marker| self.dependency(marker))
+            .collect()
+    }
+
+    /// `Depends(verify_token)` -> `verify_token`
+    fn dependency(&self, node: Node) -> String {
+        if node.kind() == "call" {
+            let callee = node.child_by_field_name("function").map(|f| self.text(f));
+            if matches!(callee, Some("Depends" | "Security" | "fastapi.Depends" | "fastapi.Security")) {
+                if let Some(inner) = arguments(node).first() {
+                    return self.text(*inner).to_string();
+                }
+            }
+        }
+        self.text(node).to_string()
+    }
+
+    fn string(&self, node: Node) -> Option<String> {
+        string_value(node, self.content).or_else(|| match self.constants.get(self.text(node)) {
+            Some(values) if node.kind() == "identifier" && values.len() == 1 => Some(values[0].clone()),
+            _ => None,
+        })
+    }
+
+    fn strings(&self, node: Node) -> Vec<String> {
+        match node.kind() {
+            "list" | "tuple" | "set" => list_items(node).into_iter().filter_map(|item| self.string(item)).collect(),
+            "identifier" => self.constants.get(self.text(node)).cloned().unwrap_or_default(),
+            _ => self.string(node).into_iter().collect(),
+        }
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+/// Base classes whose subclasses are request models.
+const MODEL_BASES: [&str; 4] = ["BaseModel", "SQLModel", "Schema", "TypedDict"];
+
+/// Handler parameter types FastAPI injects rather than reading from the body.
+const NOT_BODY_TYPES: [&str; 9] = [
+    "Request", "Response", "BackgroundTasks", "WebSocket", "HTTPConnection",
+    "Session", "AsyncSession", "SecurityScopes", "HTTPAuthorizationCredentials",
+];
+
+/// Where `request.args`, `request.GET`, `request.query_params` or
+/// `request.headers` (also on `self.request`) reads from.
+fn request_source(object: &str) -> Option<ParameterLocation> {
+    let (request, source) = object.rsplit_once('.')?;
+    if request != "request" && !request.ends_with(".request") {
+        return None;
+    }
+    match source {
+        "args" | "GET" | "query_params" => Some(ParameterLocation::Query),
+        "headers" => Some(ParameterLocation::Header),
+        _ => None,
+    }
+}
+
+/// The type in `Annotated[int, Query()]`, or the annotation as written.
+fn annotated_type(annotation: &str) -> &str {
+    annotation.strip_prefix("Annotated[")
+        .and_then(|inner| split_top(inner, ',').first().copied())
+        .unwrap_or(annotation)
+        .trim()
+}
+
+/// The `Query()`/`Body()` call in `x: Annotated[int, Query()]`.
+fn annotated_marker(param: Node) -> Option<Node> {
+    let annotation = param.child_by_field_name("type")?.named_child(0)?;
+    let arguments: Vec<Node> = match annotation.kind() {
+        "generic_type" => {
+            let mut cursor = annotation.walk();
+            let parameters = annotation.named_children(&mut cursor).find(|n| n.kind() == "type_parameter")?;
+            let mut cursor = parameters.walk();
+            let arguments = parameters.named_children(&mut cursor).filter_map(|t| t.named_child(0)).collect();
+            arguments
+        }
+        "subscript" => {
+            let mut cursor = annotation.walk();
+            let arguments = annotation.children_by_field_name("subscript", &mut cursor).collect();
+            arguments
+        }
+        _ => return None,
+    };
+    arguments.into_iter().skip(1).find(|n| n.kind() == "call")
+}
+
+/// The schema of a type annotation, and whether it admits `None`.
+fn annotation_schema(annotation: &str) -> (Schema, bool) {
+    let annotation = annotation.trim().trim_matches(|c| c == '"' || c == '\'');
+    let members = split_top(annotation, '|');
+    if members.len() > 1 {
+        return union_schema(&members);
+    }
+
+    let (head, args) = match annotation.split_once('[') {
+        Some((head, rest)) => (head.trim(), split_top(rest.strip_suffix(']').unwrap_or(rest), ',')),
+        None => (annotation, Vec::new()),
+    };
+    let first = || args.first().map(|a| annotation_schema(a).0).unwrap_or_else(|| Schema::of_type("any"));
+    let schema = match head.rsplit('.').next().unwrap_or(head) {
+        "Optional" => return (first(), true),
+        "Union" => return union_schema(&args),
+        "Annotated" | "Required" | "NotRequired" => return args.first().map(|a| annotation_schema(a)).unwrap_or_default(),
+        "List" | "list" | "Sequence" | "Set" | "set" | "FrozenSet" | "frozenset" | "Tuple" | "tuple" | "Iterable" | "conlist" => Schema::array(first()),
+        "Dict" | "dict" | "Mapping" | "Json" => Schema::of_type("object"),
+        "str" | "constr" | "StrictStr" | "SecretStr" => Schema::of_type("string"),
+        "EmailStr" | "NameEmail" => Schema::formatted("string", "email"),
+        "HttpUrl" | "AnyUrl" | "AnyHttpUrl" => Schema::formatted("string", "uri"),
+        "UUID" | "UUID1" | "UUID4" => Schema::formatted("string", "uuid"),
+        "datetime" | "AwareDatetime" | "NaiveDatetime" => Schema::formatted("string", "date-time"),
+        "date" => Schema::formatted("string", "date"),
+        "time" => Schema::formatted("string", "time"),
+        "bytes" | "UploadFile" => Schema::formatted("string", "binary"),
+        "int" | "conint" | "StrictInt" | "PositiveInt" | "NegativeInt" | "NonNegativeInt" => Schema::of_type("integer"),
+        "float" | "Decimal" | "confloat" | "condecimal" | "StrictFloat" | "PositiveFloat" => Schema::of_type("number"),
+        "bool" | "StrictBool" => Schema::of_type("boolean"),
+        "Literal" => Schema::of_type(match args.first().map(|a| a.trim()) {
+            Some(value) if value.starts_with(['"', '\'']) => "string",
+            Some("True" | "False") => "boolean",
+            Some(value) if value.parse::<i64>().is_ok() => "integer",
+            _ => "any",
+        }),
+        "Any" | "object" => Schema::of_type("any"),
+        name => Schema::reference(name),
+    };
+    (schema, false)
+}
+
+/// `X | None` and `Union[X, None]`: the one non-`None` member, if there is one.
+fn union_schema(members: &[&str]) -> (Schema, bool) {
+    let rest: Vec<&str> = members.iter().copied().filter(|m| m.trim() != "None").collect();
+    let optional = rest.len() < members.len();
+    match rest.as_slice() {
+        [only] => {
+            let (schema, inner) = annotation_schema(only);
+            (schema, optional || inner)
+        }
+        _ => (Schema::of_type("any"), optional),
+    }
+}
+
+/// Split on `separator` outside brackets.
+fn split_top(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(text[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim());
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+pub(crate) fn arguments(call: Node) -> Vec<Node> {
+    match call.child_by_field_name("arguments") {
+        Some(arguments) => arguments_of(arguments),
+        None => Vec::new(),
+    }
+}
+
+pub(crate) fn arguments_of(argument_list: Node) -> Vec<Node> {
+    let mut cursor = argument_list.walk();
+    let arguments = argument_list.named_children(&mut cursor).collect();
+    arguments
+}
+
+/// The `index`th argument, when it is positional.
+pub(crate) fn positional<'t>(args: &[Node<'t>], index: usize) -> Option<Node<'t>> {
+    args.get(index).copied().filter(|arg| arg.kind() != "keyword_argument")
+}
+
+/// The value of keyword argument `name`.
+pub(crate) fn keyword<'t>(args: &[Node<'t>], name: &str, content: &str) -> Option<Node<'t>> {
+    args.iter()
+        .filter(|arg| arg.kind() == "keyword_argument")
+        .find(|arg| arg.child_by_field_name("name").map(|n| &content[n.byte_range()] == name).unwrap_or(false))
+        .and_then(|arg| arg.child_by_field_name("value"))
+}
+
+pub(crate) fn list_items(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    let items = node.named_children(&mut cursor).filter(|n| n.kind() != "comment").collect();
+    items
+}
+
+/// The literal value of a plain (non-f) string.
+pub(crate) fn string_value(node: Node, content: &str) -> Option<String> {
+    if node.kind() != "string" {
+        return None;
+    }
+    let quoted = &content[node.byte_range()];
+    if quoted.starts_with(['f', 'F']) {
+        return None;
     }
+    let mut cursor = node.walk();
+    let value = node.named_children(&mut cursor)
+        .filter(|n| n.kind() == "string_content")
+        .map(|n| &content[n.byte_range()])
+        .collect();
+    Some(value)
 }
 
-fn parse_http_method(method_str: &str) -> Result<HttpMethod> {
-    match method_str.to_lowercase().as_str() {
-        "get" | "route" => Ok(HttpMethod::Get), // Flask uses 'route', FastAPI uses 'get'
-        "post" => Ok(HttpMethod::Post),
-        "put" => Ok(HttpMethod::Put),
-        "delete" => Ok(HttpMethod::Delete),
-        "patch" => Ok(HttpMethod::Patch),
-        "head" => Ok(HttpMethod::Head),
-        "options" => Ok(HttpMethod::Options),
-        _ => Err(anyhow!("Unknown HTTP method: {}", method_str)),
+pub(crate) fn endpoint(method: HttpMethod, path: String, handler: String, pos: Point) -> Endpoint {
+    Endpoint {
+        method,
+        path,
+        handler,
+        line: pos.row + 1,
+        column: pos.column + 1,
+        documentation: None,
+        parameters: Vec::new(),
+        tags: Vec::new(),
+        middleware: Vec::new(),
+        template: String::new(),
+        body: None,
+        responses: Vec::new(),
+        requires_auth: false,
     }
 }
 
@@ -186,4 +1287,340 @@ mod tests {
         let result = parser.parse("").unwrap();
         assert_eq!(result.len(), 0);
     }
+
+    #[test]
+    fn test_parse_flask_route_methods() {
+        let code = r#"
+@app.route('/')
+def home():
+    return 'ok'
+
+@app.route('/users', methods=['GET', 'POST'])
+def users():
+    return []
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let routes: Vec<_> = endpoints.iter().map(|e| (e.method.clone(), e.path.as_str())).collect();
+        assert_eq!(routes, vec![
+            (HttpMethod::Get, "/"),
+            (HttpMethod::Get, "/users"),
+            (HttpMethod::Post, "/users"),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_fastapi_router_prefixes_and_dependencies() {
+        let code = r#"
+from fastapi import APIRouter, Depends, FastAPI
+
+API_PREFIX = "/api"
+
+app = FastAPI()
+router = APIRouter(prefix="/users", tags=["users"])
+
+@router.get("/{user_id}", dependencies=[Depends(rate_limit)])
+async def read_user(user_id: int):
+    return {}
+
+app.add_api_route("/health", health, methods=["GET", "HEAD"])
+app.include_router(router, prefix=API_PREFIX, dependencies=[Depends(verify_token)])
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        assert_eq!(endpoints.len(), 3);
+
+        let user = endpoints.iter().find(|e| e.handler == "read_user").unwrap();
+        assert_eq!(user.path, "/api/users/{user_id}");
+        assert_eq!(user.tags, vec!["users"]);
+        assert_eq!(user.middleware, vec!["verify_token", "rate_limit"]);
+
+        let health: Vec<_> = endpoints.iter().filter(|e| e.path == "/health").map(|e| e.method.clone()).collect();
+        assert_eq!(health, vec![HttpMethod::Get, HttpMethod::Head]);
+    }
+
+    #[test]
+    fn test_typed_path_parameters() {
+        let code = r#"
+from typing import Annotated
+from fastapi import FastAPI, Path
+
+app = FastAPI()
+
+@app.get("/items/{item_id}/files/{file_path:path}")
+async def read_file(item_id: Annotated[int, Path(ge=1)], file_path: str, q: str | None = None):
+    return {}
+
+@app.route("/users/<int:user_id>/<slug>")
+def user(user_id, slug):
+    return "ok"
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let types = |e: &Endpoint| -> Vec<(String, Option<String>)> {
+            e.parameters.iter().map(|p| (p.name.clone(), p.param_type.clone())).collect()
+        };
+        assert_eq!(types(&endpoints[0]), vec![
+            ("item_id".to_string(), Some("int".to_string())),
+            ("file_path".to_string(), Some("str".to_string())),
+            ("q".to_string(), Some("str | None".to_string())),
+        ]);
+        assert_eq!(types(&endpoints[1]), vec![
+            ("user_id".to_string(), Some("int".to_string())),
+            ("slug".to_string(), None),
+        ]);
+        assert!(endpoints[1].parameters.iter().all(|p| p.location == ParameterLocation::Path && p.required));
+    }
+
+    #[test]
+    fn test_pydantic_request_bodies() {
+        let code = r#"
+class Address(BaseModel):
+    city: str
+    zip: Optional[str] = None
+
+class UserCreate(BaseModel):
+    name: str
+    email: EmailStr
+    age: int = Field(..., ge=0)
+    tags: List[str] = []
+    address: Address
+
+class AdminCreate(UserCreate):
+    level: int = 1
+
+@app.post("/users/{org_id}")
+def create_user(org_id: int, user: UserCreate, db: Session = Depends(get_db)):
+    return user
+
+@app.post("/admins")
+def create_admin(admin: AdminCreate, note: Annotated[str, Body()], notify: bool = Query(False)):
+    return admin
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let body = endpoints[0].body.as_ref().unwrap();
+        assert_eq!(body.name.as_deref(), Some("UserCreate"));
+        let fields: Vec<_> = body.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str(), p.required)).collect();
+        assert_eq!(fields, vec![
+            ("name", "string", true),
+            ("email", "string", true),
+            ("age", "integer", true),
+            ("tags", "array", false),
+            ("address", "object", true),
+        ]);
+        assert_eq!(body.properties[1].schema.format.as_deref(), Some("email"));
+        let zip = &body.properties[4].schema.properties[1];
+        assert_eq!((zip.name.as_str(), zip.required), ("zip", false));
+
+        // Several body inputs are embedded under their parameter names
+        let body = endpoints[1].body.as_ref().unwrap();
+        let fields: Vec<_> = body.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str())).collect();
+        assert_eq!(fields, vec![("admin", "object"), ("note", "string")]);
+        assert_eq!(body.properties[0].schema.properties.last().unwrap().name, "level");
+    }
+
+    #[test]
+    fn test_query_parameters_and_headers() {
+        let code = r#"
+@app.get("/items/{item_id}")
+def read_items(
+    item_id: int,
+    q: str | None = None,
+    offset: Optional[int] = None,
+    limit: int = Query(20, alias="max"),
+    x_tenant: Annotated[str, Header()],
+    db: Session = Depends(get_db),
+):
+    return []
+
+@bp.route("/search")
+def search():
+    page = request.args.get("page", 1, type=int)
+    term = request.args["term"]
+    return find(term, page, request.headers.get("X-Api-Key"))
+
+def list_users(request):
+    return paginate(request.GET.get("cursor"))
+
+urlpatterns = [path("users/", list_users)]
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let inputs: Vec<_> = endpoints[0].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.param_type.as_deref(), p.required, p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("item_id", ParameterLocation::Path, Some("int"), true, None),
+            ("q", ParameterLocation::Query, Some("str | None"), false, None),
+            ("offset", ParameterLocation::Query, Some("Optional[int]"), false, None),
+            ("max", ParameterLocation::Query, Some("int"), false, Some("20")),
+            ("x-tenant", ParameterLocation::Header, Some("str"), true, None),
+        ]);
+
+        let inputs: Vec<_> = endpoints[1].parameters.iter()
+            .map(|p| (p.name.as_str(), p.location.clone(), p.param_type.as_deref(), p.required, p.default.as_deref()))
+            .collect();
+        assert_eq!(inputs, vec![
+            ("page", ParameterLocation::Query, Some("int"), false, Some("1")),
+            ("term", ParameterLocation::Query, None, true, None),
+            ("X-Api-Key", ParameterLocation::Header, None, false, None),
+        ]);
+
+        let inputs: Vec<_> = endpoints[2].parameters.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(inputs, vec!["cursor"]);
+    }
+
+    #[test]
+    fn test_responses() {
+        let code = r#"
+@app.post("/items", status_code=status.HTTP_201_CREATED, response_model=Item, responses={409: {"model": Conflict}})
+async def create_item(item: ItemCreate):
+    if exists(item):
+        raise HTTPException(status_code=409, detail="exists")
+    return save(item)
+
+@app.get("/health")
+def health() -> dict:
+    return JSONResponse({"status": "ok"}, status_code=200)
+
+@bp.route("/users/<int:user_id>")
+def user(user_id):
+    if not allowed():
+        abort(403)
+    return jsonify(id=user_id, name="x"), 200
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let summary = |e: &Endpoint| -> Vec<(u16, Option<String>)> {
+            e.responses.iter().map(|r| (r.status, r.body.as_ref().map(|b| b.reference.clone().unwrap_or(b.schema_type.clone())))).collect()
+        };
+        assert_eq!(summary(&endpoints[0]), vec![(201, Some("Item".to_string())), (409, Some("Conflict".to_string()))]);
+        assert_eq!(summary(&endpoints[1]), vec![(200, Some("object".to_string()))]);
+        assert_eq!(summary(&endpoints[2]), vec![(403, None), (200, Some("object".to_string()))]);
+
+        let body = endpoints[2].responses[1].body.as_ref().unwrap();
+        let fields: Vec<_> = body.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str())).collect();
+        assert_eq!(fields, vec![("id", "any"), ("name", "string")]);
+    }
+
+    #[test]
+    fn test_docstrings_and_route_summaries() {
+        let code = r#"
+@app.get("/items/{item_id}", summary="Read one item", deprecated=True)
+def read_item(item_id: int):
+    """Read an item.
+
+    Looks the item up by id.
+
+    Args:
+        item_id: The item to read.
+    """
+    return {}
+
+@app.get("/health")
+def health():
+    return {}
+
+def list_users(request):
+    """List users."""
+    return JsonResponse([])
+
+urlpatterns = [path("users/", list_users)]
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let read = endpoints[0].documentation.as_ref().unwrap();
+        assert_eq!(read.summary.as_deref(), Some("Read one item"));
+        assert_eq!(read.description.as_deref(), Some("Looks the item up by id."));
+        assert_eq!(read.params[0].name, "item_id");
+        assert_eq!(read.deprecated.as_deref(), Some(""));
+
+        assert!(endpoints[1].documentation.is_none());
+        assert_eq!(endpoints[2].documentation.as_ref().and_then(|d| d.summary.as_deref()), Some("List users."));
+    }
+
+    #[test]
+    fn test_view_decorators_and_dependencies() {
+        let code = r#"
+@app.route("/profile")
+@login_required
+@roles_required("admin")
+def profile():
+    return {}
+
+@app.route("/about")
+def about():
+    return {}
+
+@router.get("/me", dependencies=[Depends(rate_limit)])
+def read_me(db: Session = Depends(get_db), user: Annotated[User, Depends(get_current_user)], params: Params = Depends()):
+    return user
+
+@router.get("/items")
+def list_items(db: Session = Depends(get_db)):
+    return []
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let chains: Vec<_> = endpoints.iter()
+            .map(|e| (e.path.as_str(), e.middleware.join(","), e.requires_auth))
+            .collect();
+        assert_eq!(chains, vec![
+            ("/profile", "login_required,roles_required(\"admin\")".to_string(), true),
+            ("/about", String::new(), false),
+            ("/me", "rate_limit,get_db,get_current_user".to_string(), true),
+            ("/items", "get_db".to_string(), false),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_flask_blueprints_and_class_views() {
+        let code = r#"
+WRITE = ("POST", "PUT")
+
+bp = Blueprint("users", __name__, url_prefix="/users")
+
+@bp.route("/<int:user_id>", methods=WRITE)
+def update_user(user_id):
+    return ""
+
+class UserAPI(MethodView):
+    decorators = [login_required, cache.cached(timeout=60)]
+
+    def get(self):
+        return []
+
+    def post(self):
+        return {}
+
+class Ping(View):
+    methods = ["GET", "HEAD"]
+
+    def dispatch_request(self):
+        return "pong"
+
+bp.add_url_rule("/", view_func=UserAPI.as_view("user_api"))
+app.add_url_rule("/ping", "ping", Ping.as_view("ping"))
+app.register_blueprint(bp, url_prefix="/api")
+"#;
+
+        let endpoints = PythonParser.parse(code).unwrap();
+        let mut routes: Vec<_> = endpoints.iter().map(|e| (e.path.as_str(), e.method.clone(), e.handler.as_str())).collect();
+        routes.sort_by_key(|r| r.0);
+        assert_eq!(routes, vec![
+            ("/api", HttpMethod::Get, "UserAPI.get"),
+            ("/api", HttpMethod::Post, "UserAPI.post"),
+            ("/api/<int:user_id>", HttpMethod::Post, "update_user"),
+            ("/api/<int:user_id>", HttpMethod::Put, "update_user"),
+            ("/ping", HttpMethod::Get, "Ping.dispatch_request"),
+            ("/ping", HttpMethod::Head, "Ping.dispatch_request"),
+        ]);
+
+        let user_api = endpoints.iter().find(|e| e.handler == "UserAPI.get").unwrap();
+        assert_eq!(user_api.middleware, vec!["login_required", "cache.cached(timeout=60)"]);
+        assert!(user_api.requires_auth);
+        let ping = endpoints.iter().find(|e| e.handler == "Ping.dispatch_request").unwrap();
+        assert!(ping.middleware.is_empty());
+    }
 }
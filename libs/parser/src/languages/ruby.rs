@@ -0,0 +1,549 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, parse_http_method};
+use crate::params::describe_path;
+use crate::project::join_route;
+use anyhow::{Result, anyhow};
+use tree_sitter::{Node, Parser};
+
+/// Parser for Rails `config/routes.rb` and Sinatra applications.
+///
+/// Rails routes are only read inside a `routes.draw do ... end` block, where
+/// `namespace`, `scope`, `controller` and `resources` blocks build up the
+/// path prefix and controller. Outside of it, `get '/x' do ... end` blocks
+/// are read as Sinatra routes.
+pub struct RubyParser;
+
+const ALL_METHODS: [HttpMethod; 7] = [
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Delete,
+    HttpMethod::Patch,
+    HttpMethod::Head,
+    HttpMethod::Options,
+];
+
+/// The seven RESTful actions generated by `resources`, in `rails routes` order.
+const PLURAL_ACTIONS: [(&str, HttpMethod, &str); 7] = [
+    ("index", HttpMethod::Get, ""),
+    ("create", HttpMethod::Post, ""),
+    ("new", HttpMethod::Get, "/new"),
+    ("edit", HttpMethod::Get, "/:id/edit"),
+    ("show", HttpMethod::Get, "/:id"),
+    ("update", HttpMethod::Patch, "/:id"),
+    ("destroy", HttpMethod::Delete, "/:id"),
+];
+
+/// `resource` (singular) has no index and no id segment.
+const SINGULAR_ACTIONS: [(&str, HttpMethod, &str); 6] = [
+    ("create", HttpMethod::Post, ""),
+    ("new", HttpMethod::Get, "/new"),
+    ("edit", HttpMethod::Get, "/edit"),
+    ("show", HttpMethod::Get, ""),
+    ("update", HttpMethod::Patch, ""),
+    ("destroy", HttpMethod::Delete, ""),
+];
+
+impl LanguageParser for RubyParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_ruby::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse Ruby code"))?;
+
+        let mut walker = RouteWalker { content, endpoints: Vec::new() };
+        walker.visit(tree.root_node(), &RouteScope::default(), false);
+        walker.endpoints.iter_mut().for_each(describe_path);
+        Ok(walker.endpoints)
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "rb"
+    }
+}
+
+/// Where routes declared in the current block end up.
+#[derive(Clone, Default)]
+struct RouteScope {
+    path: String,
+    /// Controller module from `namespace`/`scope module:`, e.g. `admin/`.
+    module: String,
+    controller: Option<String>,
+    /// Member and collection paths of the enclosing `resources` block.
+    member: Option<String>,
+    collection: Option<String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    endpoints: Vec<Endpoint>,
+}
+
+impl<'a> RouteWalker<'a> {
+    fn visit(&mut self, node: Node, scope: &RouteScope, rails: bool) {
+        if node.kind() == "call" && self.visit_call(node, scope, rails) {
+            return;
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child, scope, rails);
+        }
+    }
+
+    /// Returns true when the call (and its block) has been handled.
+    fn visit_call(&mut self, node: Node, scope: &RouteScope, rails: bool) -> bool {
+        let method = match node.child_by_field_name("method") {
+            Some(method) => self.text(method),
+            None => return false,
+        };
+        let block = node.child_by_field_name("block");
+        let receiver = node.child_by_field_name("receiver");
+
+        if method == "draw" {
+            if let Some(block) = block {
+                self.visit_block(block, scope, true);
+            }
+            return true;
+        }
+        if receiver.is_some() {
+            return false;
+        }
+
+        let args = self.arguments(node);
+        let positional: Vec<Node> = args.iter().copied().filter(|a| a.kind() != "pair").collect();
+
+        if !rails {
+            return self.visit_sinatra(node, method, &positional, block, scope);
+        }
+
+        match method {
+            "namespace" => {
+                let name = match positional.first().and_then(|a| self.value(*a)) {
+                    Some(name) => name,
+                    None => return false,
+                };
+                let mut inner = scope.clone();
+                inner.path = join_route(&scope.path, &self.option(&args, "path").unwrap_or_else(|| name.clone()));
+                inner.module = format!("{}{}/", scope.module, self.option(&args, "module").unwrap_or(name));
+                inner.controller = None;
+                inner.member = None;
+                inner.collection = None;
+                if let Some(block) = block {
+                    self.visit_block(block, &inner, true);
+                }
+            }
+            "scope" => {
+                let mut inner = scope.clone();
+                if let Some(path) = positional.first().and_then(|a| self.value(*a)).or_else(|| self.option(&args, "path")) {
+                    inner.path = join_route(&scope.path, &path);
+                }
+                if let Some(module) = self.option(&args, "module") {
+                    inner.module = format!("{}{}/", scope.module, module);
+                }
+                if let Some(controller) = self.option(&args, "controller") {
+                    inner.controller = Some(controller);
+                }
+                if let Some(block) = block {
+                    self.visit_block(block, &inner, true);
+                }
+            }
+            "controller" => {
+                let mut inner = scope.clone();
+                inner.controller = positional.first().and_then(|a| self.value(*a));
+                if let Some(block) = block {
+                    self.visit_block(block, &inner, true);
+                }
+            }
+            "resources" | "resource" => {
+                let names: Vec<String> = positional.iter().filter_map(|a| self.value(*a)).collect();
+                for name in names {
+                    let inner = self.resources(node, &name, method == "resources", &args, scope);
+                    if let Some(block) = block {
+                        self.visit_block(block, &inner, true);
+                    }
+                }
+            }
+            "member" | "collection" => {
+                let mut inner = scope.clone();
+                let path = if method == "member" { &scope.member } else { &scope.collection };
+                if let Some(path) = path {
+                    inner.path = path.clone();
+                }
+                if let Some(block) = block {
+                    self.visit_block(block, &inner, true);
+                }
+            }
+            "root" => {
+                let target = positional.first()
+                    .and_then(|a| self.value(*a))
+                    .or_else(|| self.option(&args, "to"));
+                let handler = target.map(|t| self.qualify(&t, scope)).unwrap_or_default();
+                let path = join_route(&scope.path, "/");
+                self.push(node, HttpMethod::Get, path, handler);
+            }
+            "get" | "post" | "put" | "patch" | "delete" | "options" | "head" | "match" => {
+                self.verb_route(node, method, &args, scope);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// `get 'photos/search'`, `get :preview, on: :member`,
+    /// `get 'x' => 'c#a'`, `post 'x', to: 'c#a'`, `match 'x', to: 'c#a', via: [:get, :post]`
+    fn verb_route(&mut self, node: Node, verb: &str, args: &[Node], scope: &RouteScope) {
+        let first = match args.first() {
+            Some(first) => *first,
+            None => return,
+        };
+
+        // `get 'x' => 'c#a'` parses as a single pair argument
+        let (route, mut target) = if first.kind() == "pair" {
+            let key = first.child_by_field_name("key").and_then(|k| self.value(k));
+            let value = first.child_by_field_name("value").and_then(|v| self.value(v));
+            match key {
+                Some(key) => (key, value),
+                None => return,
+            }
+        } else {
+            match self.value(first) {
+                Some(route) => (route, None),
+                None => return,
+            }
+        };
+        if let Some(to) = self.option(args, "to") {
+            target = Some(to);
+        }
+
+        let base = match self.option(args, "on").as_deref() {
+            Some("member") => scope.member.clone().unwrap_or_else(|| scope.path.clone()),
+            Some("collection") => scope.collection.clone().unwrap_or_else(|| scope.path.clone()),
+            _ => scope.path.clone(),
+        };
+        let path = join_route(&base, &route);
+        let path = if path.starts_with('/') { path } else { format!("/{}", path) };
+
+        // Without `to:` Rails derives controller#action from the scope or the path
+        let handler = match target {
+            Some(target) => self.qualify(&target, scope),
+            None => {
+                let action = route.trim_matches('/').rsplit('/').next().unwrap_or_default().to_string();
+                match (&scope.controller, route.trim_matches('/').rsplit_once('/')) {
+                    (Some(controller), _) => format!("{}{}#{}", scope.module, controller, action),
+                    (None, Some((controller, action))) => format!("{}{}#{}", scope.module, controller, action),
+                    (None, None) => action,
+                }
+            }
+        };
+
+        let methods = match verb {
+            "match" => {
+                let via = args.iter()
+                    .filter(|a| a.kind() == "pair")
+                    .find(|a| a.child_by_field_name("key").and_then(|k| self.value(k)).as_deref() == Some("via"))
+                    .and_then(|a| a.child_by_field_name("value"));
+                match via {
+                    Some(via) => {
+                        let via = self.values(via);
+                        if via.iter().any(|v| v == "all") {
+                            ALL_METHODS.to_vec()
+                        } else {
+                            via.iter().filter_map(|v| parse_http_method(v).ok()).collect()
+                        }
+                    }
+                    None => vec![HttpMethod::Get],
+                }
+            }
+            other => match parse_http_method(other) {
+                Ok(method) => vec![method],
+                Err(_) => return,
+            },
+        };
+
+        for method in methods {
+            self.push(node, method, path.clone(), handler.clone());
+        }
+    }
+
+    /// Expand `resources :photos` / `resource :profile` and return the scope
+    /// for its block: nested routes go under `/photos/:photo_id`.
+    fn resources(&mut self, node: Node, name: &str, plural: bool, args: &[Node], scope: &RouteScope) -> RouteScope {
+        let segment = self.option(args, "path").unwrap_or_else(|| name.to_string());
+        let base = join_route(&scope.path, &segment);
+        let base = if base.starts_with('/') { base } else { format!("/{}", base) };
+        let controller = self.option(args, "controller")
+            .unwrap_or_else(|| if plural { name.to_string() } else { format!("{}s", name) });
+        let param = self.option(args, "param").unwrap_or_else(|| "id".to_string());
+
+        let only = self.option_values(args, "only");
+        let except = self.option_values(args, "except").unwrap_or_default();
+
+        let actions: &[(&str, HttpMethod, &str)] = if plural { &PLURAL_ACTIONS } else { &SINGULAR_ACTIONS };
+        for (action, method, suffix) in actions {
+            if only.as_ref().map(|only| !only.iter().any(|a| a == action)).unwrap_or(false)
+                || except.iter().any(|a| a == action)
+            {
+                continue;
+            }
+            let path = format!("{}{}", base, suffix.replace(":id", &format!(":{}", param)));
+            let handler = format!("{}{}#{}", scope.module, controller, action);
+            self.push(node, method.clone(), path, handler);
+        }
+
+        let member = if plural { format!("{}/:{}", base, param) } else { base.clone() };
+        RouteScope {
+            path: if plural { format!("{}/:{}_id", base, singularize(name)) } else { base.clone() },
+            module: scope.module.clone(),
+            controller: Some(controller),
+            member: Some(member),
+            collection: Some(base),
+        }
+    }
+
+    /// Sinatra: `get '/x' do ... end`, with sinatra-contrib `namespace '/api' do`.
+    fn visit_sinatra(&mut self, node: Node, method: &str, positional: &[Node], block: Option<Node>, scope: &RouteScope) -> bool {
+        let (path, block) = match (positional.first().and_then(|a| self.value(*a)), block) {
+            (Some(path), Some(block)) if path.starts_with('/') => (path, block),
+            _ => return false,
+        };
+
+        if method == "namespace" {
+            let mut inner = scope.clone();
+            inner.path = join_route(&scope.path, &path);
+            self.visit_block(block, &inner, false);
+            return true;
+        }
+
+        let methods = match method {
+            "any" => ALL_METHODS.to_vec(),
+            other => match parse_http_method(other) {
+                Ok(method) => vec![method],
+                Err(_) => return false,
+            },
+        };
+
+        let pos = node.start_position();
+        let handler = format!("{}:{}", pos.row + 1, pos.column + 1);
+        for method in methods {
+            self.push(node, method, join_route(&scope.path, &path), handler.clone());
+        }
+        true
+    }
+
+    fn visit_block(&mut self, block: Node, scope: &RouteScope, rails: bool) {
+        if let Some(body) = block.child_by_field_name("body") {
+            self.visit(body, scope, rails);
+        }
+    }
+
+    /// `'photos#show'` inside `namespace :admin` -> `admin/photos#show`
+    fn qualify(&self, target: &str, scope: &RouteScope) -> String {
+        match (target.contains('#'), &scope.controller) {
+            (true, _) => format!("{}{}", scope.module, target),
+            (false, Some(controller)) => format!("{}{}#{}", scope.module, controller, target),
+            (false, None) => target.to_string(),
+        }
+    }
+
+    fn push(&mut self, node: Node, method: HttpMethod, path: String, handler: String) {
+        let pos = node.start_position();
+        self.endpoints.push(Endpoint {
+            method,
+            path,
+            handler,
+            line: pos.row + 1,
+            column: pos.column + 1,
+            documentation: None,
+            parameters: Vec::new(),
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        });
+    }
+
+    fn arguments(&self, call: Node<'a>) -> Vec<Node<'a>> {
+        match call.child_by_field_name("arguments") {
+            Some(arguments) => {
+                let mut cursor = arguments.walk();
+                arguments.named_children(&mut cursor).collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Value of a `key: value` option.
+    fn option(&self, args: &[Node], key: &str) -> Option<String> {
+        args.iter()
+            .filter(|a| a.kind() == "pair")
+            .find(|a| a.child_by_field_name("key").and_then(|k| self.value(k)).as_deref() == Some(key))
+            .and_then(|a| a.child_by_field_name("value"))
+            .and_then(|v| self.value(v))
+    }
+
+    /// Values of a `key: [:a, :b]` / `key: %i[a b]` / `key: :a` option.
+    fn option_values(&self, args: &[Node], key: &str) -> Option<Vec<String>> {
+        args.iter()
+            .filter(|a| a.kind() == "pair")
+            .find(|a| a.child_by_field_name("key").and_then(|k| self.value(k)).as_deref() == Some(key))
+            .and_then(|a| a.child_by_field_name("value"))
+            .map(|v| self.values(v))
+    }
+
+    fn values(&self, node: Node) -> Vec<String> {
+        match node.kind() {
+            "array" | "symbol_array" | "string_array" => {
+                let mut cursor = node.walk();
+                node.named_children(&mut cursor).filter_map(|n| self.value(n)).collect()
+            }
+            _ => self.value(node).into_iter().collect(),
+        }
+    }
+
+    /// Text of a string or symbol literal.
+    fn value(&self, node: Node) -> Option<String> {
+        let text = self.text(node);
+        match node.kind() {
+            "string" | "delimited_symbol" => Some(text.trim_start_matches(':').trim_matches(|c| c == '\'' || c == '"').to_string()),
+            "simple_symbol" => Some(text.trim_start_matches(':').to_string()),
+            "hash_key_symbol" | "bare_symbol" | "bare_string" => Some(text.to_string()),
+            _ => None,
+        }
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+/// Good enough for route params: `photos` -> `photo`, `categories` -> `category`.
+fn singularize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(code: &str) -> Vec<(HttpMethod, String, String)> {
+        RubyParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path, e.handler))
+            .collect()
+    }
+
+    #[test]
+    fn test_supports_ruby_extension() {
+        assert!(RubyParser.supports_extension("rb"));
+        assert!(!RubyParser.supports_extension("py"));
+    }
+
+    #[test]
+    fn test_expands_resources() {
+        let code = r#"
+Rails.application.routes.draw do
+  resources :photos
+  resource :profile, only: [:show, :update]
+end
+"#;
+
+        let found = routes(code);
+        assert_eq!(found.len(), 9);
+        assert_eq!(found[..7], [
+            (HttpMethod::Get, "/photos".to_string(), "photos#index".to_string()),
+            (HttpMethod::Post, "/photos".to_string(), "photos#create".to_string()),
+            (HttpMethod::Get, "/photos/new".to_string(), "photos#new".to_string()),
+            (HttpMethod::Get, "/photos/:id/edit".to_string(), "photos#edit".to_string()),
+            (HttpMethod::Get, "/photos/:id".to_string(), "photos#show".to_string()),
+            (HttpMethod::Patch, "/photos/:id".to_string(), "photos#update".to_string()),
+            (HttpMethod::Delete, "/photos/:id".to_string(), "photos#destroy".to_string()),
+        ]);
+        assert_eq!(found[7], (HttpMethod::Get, "/profile".to_string(), "profiles#show".to_string()));
+        assert_eq!(found[8], (HttpMethod::Patch, "/profile".to_string(), "profiles#update".to_string()));
+    }
+
+    #[test]
+    fn test_parse_rails_scopes_and_blocks() {
+        let code = r#"
+Rails.application.routes.draw do
+  root 'pages#home'
+  get 'about' => 'pages#about'
+
+  namespace :admin do
+    resources :users, except: %i[new edit] do
+      member do
+        post :lock
+      end
+      collection do
+        get :search
+      end
+      resources :posts, only: :index
+      get :export, on: :collection
+    end
+  end
+
+  scope '/api', module: 'api' do
+    match 'ping', to: 'health#ping', via: [:get, :head]
+    get 'status/summary'
+  end
+end
+"#;
+
+        let found = routes(code);
+        let has = |method: HttpMethod, path: &str, handler: &str| {
+            found.contains(&(method, path.to_string(), handler.to_string()))
+        };
+
+        assert!(has(HttpMethod::Get, "/", "pages#home"));
+        assert!(has(HttpMethod::Get, "/about", "pages#about"));
+        assert!(has(HttpMethod::Get, "/admin/users", "admin/users#index"));
+        assert!(!has(HttpMethod::Get, "/admin/users/new", "admin/users#new"));
+        assert!(has(HttpMethod::Post, "/admin/users/:id/lock", "admin/users#lock"));
+        assert!(has(HttpMethod::Get, "/admin/users/search", "admin/users#search"));
+        assert!(has(HttpMethod::Get, "/admin/users/export", "admin/users#export"));
+        assert!(has(HttpMethod::Get, "/admin/users/:user_id/posts", "admin/posts#index"));
+        assert!(has(HttpMethod::Get, "/api/ping", "api/health#ping"));
+        assert!(has(HttpMethod::Head, "/api/ping", "api/health#ping"));
+        assert!(has(HttpMethod::Get, "/api/status/summary", "api/status#summary"));
+    }
+
+    #[test]
+    fn test_parse_sinatra_blocks() {
+        let code = r#"
+require 'sinatra'
+
+get '/' do
+  'Hello world!'
+end
+
+post '/items' do
+  params[:name]
+end
+
+class Api < Sinatra::Base
+  namespace '/v1' do
+    delete '/items/:id' do
+      halt 204
+    end
+  end
+end
+"#;
+
+        let found: Vec<_> = routes(code).into_iter().map(|(m, p, _)| (m, p)).collect();
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/".to_string()),
+            (HttpMethod::Post, "/items".to_string()),
+            (HttpMethod::Delete, "/v1/items/:id".to_string()),
+        ]);
+    }
+}
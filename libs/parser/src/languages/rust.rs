@@ -0,0 +1,773 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, parse_http_method};
+use crate::project::{Import, ModuleRoutes, Mount, RoutedEndpoint};
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+/// Parser for Rust web frameworks: Actix-web (attribute macros, `web::scope`,
+/// `web::resource`, `configure`), Axum (`Router::route`, `nest`, `merge`)
+/// and Rocket (attribute macros and `mount`).
+///
+/// Router builder chains are evaluated from their root (`App::new()`,
+/// `Router::new()`, `web::scope("/x")`, ...) so every `.route` lands on the
+/// right router, and `.nest`/`.service`/`.mount` become mounts. Handlers and
+/// router functions in other modules are linked through `use` paths.
+pub struct RustParser;
+
+const ALL_METHODS: [HttpMethod; 7] = [
+    HttpMethod::Get,
+    HttpMethod::Post,
+    HttpMethod::Put,
+    HttpMethod::Delete,
+    HttpMethod::Patch,
+    HttpMethod::Head,
+    HttpMethod::Options,
+];
+
+impl LanguageParser for RustParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        Ok(self.parse_module(Path::new(""), content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "rs"
+    }
+
+    fn parse_module(&self, _path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_rust::language())?;
+
+        let tree = parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse Rust code"))?;
+
+        let mut walker = RouteWalker::new(content);
+        walker.collect_declarations(tree.root_node());
+        walker.visit(tree.root_node());
+        Ok(walker.module)
+    }
+}
+
+/// A method router such as `get(h).post(h2)` or `web::get().to(h)`,
+/// flattened into (methods, handler) pairs.
+type MethodRoutes<'a> = Vec<(Vec<HttpMethod>, Option<Node<'a>>)>;
+
+struct Scope {
+    function: String,
+    bindings: HashMap<String, String>,
+}
+
+struct RouteWalker<'a> {
+    content: &'a str,
+    module: ModuleRoutes,
+    /// Functions declared in this file.
+    functions: HashSet<String>,
+    /// Name brought into scope by `use` -> full path.
+    uses: HashMap<String, String>,
+    /// Actix `web::resource` bindings, whose `.route(..)` takes no path.
+    resources: HashSet<String>,
+    scopes: Vec<Scope>,
+}
+
+impl<'a> RouteWalker<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            module: ModuleRoutes::default(),
+            functions: HashSet::new(),
+            uses: HashMap::new(),
+            resources: HashSet::new(),
+            scopes: Vec::new(),
+        }
+    }
+
+    fn collect_declarations(&mut self, node: Node) {
+        match node.kind() {
+            "function_item" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    self.functions.insert(self.text(name).to_string());
+                }
+            }
+            "use_declaration" => {
+                if let Some(argument) = node.child_by_field_name("argument") {
+                    self.collect_use("", argument);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        for child in self.named_children(node) {
+            self.collect_declarations(child);
+        }
+    }
+
+    fn collect_use(&mut self, prefix: &str, node: Node) {
+        let qualify = |path: &str| {
+            if prefix.is_empty() { path.to_string() } else { format!("{}::{}", prefix, path) }
+        };
+
+        match node.kind() {
+            "identifier" | "scoped_identifier" => {
+                let path = qualify(self.text(node));
+                let name = path.rsplit("::").next().unwrap_or(&path).to_string();
+                self.uses.insert(name, path);
+            }
+            "self" if !prefix.is_empty() => {
+                let name = prefix.rsplit("::").next().unwrap_or(prefix).to_string();
+                self.uses.insert(name, prefix.to_string());
+            }
+            "use_as_clause" => {
+                if let (Some(path), Some(alias)) = (node.child_by_field_name("path"), node.child_by_field_name("alias")) {
+                    self.uses.insert(self.text(alias).to_string(), qualify(self.text(path)));
+                }
+            }
+            "scoped_use_list" => {
+                let path = node.child_by_field_name("path")
+                    .map(|p| qualify(self.text(p)))
+                    .unwrap_or_else(|| prefix.to_string());
+                if let Some(list) = node.child_by_field_name("list") {
+                    self.collect_use(&path, list);
+                }
+            }
+            "use_list" => {
+                for child in self.named_children(node) {
+                    self.collect_use(prefix, child);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit(&mut self, node: Node) {
+        match node.kind() {
+            "function_item" => {
+                self.visit_function(node);
+                return;
+            }
+            "let_declaration" => {
+                self.visit_let(node);
+                return;
+            }
+            "return_expression" => {
+                if let Some(value) = node.named_child(0) {
+                    self.return_router(value);
+                }
+                return;
+            }
+            "call_expression" if self.router(node).is_some() => return,
+            _ => {}
+        }
+
+        for child in self.named_children(node) {
+            self.visit(child);
+        }
+    }
+
+    fn visit_function(&mut self, node: Node) {
+        let name = match node.child_by_field_name("name") {
+            Some(name) => self.text(name).to_string(),
+            None => return,
+        };
+
+        self.route_attributes(node, &name);
+
+        self.scopes.push(Scope { function: name.clone(), bindings: HashMap::new() });
+        self.module.exports.insert(name.clone(), name.clone());
+
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            let params: Vec<Node> = self.named_children(parameters)
+                .into_iter()
+                .filter(|p| p.kind() == "parameter")
+                .collect();
+            for (i, param) in params.into_iter().enumerate() {
+                let binding = match param.child_by_field_name("pattern").and_then(|p| self.pattern_name(p)) {
+                    Some(param) => self.declare(&param),
+                    None => continue,
+                };
+                // Positional alias so callers can hand a router in: `cfg.configure(config)`
+                let positional = format!("{}:#{}", name, i);
+                self.module.mounts.push(Mount {
+                    parent: positional.clone(),
+                    prefix: String::new(),
+                    target: binding,
+                });
+                self.module.exports.insert(positional.clone(), positional);
+            }
+        }
+        let returned = format!("{}:return", name);
+        self.module.exports.insert(returned.clone(), returned);
+
+        if let Some(body) = node.child_by_field_name("body") {
+            let children = self.named_children(body);
+            let tail = children.last()
+                .filter(|c| !matches!(c.kind(), "expression_statement" | "let_declaration" | "line_comment" | "block_comment")
+                    && !c.kind().ends_with("_item"))
+                .map(|c| c.id());
+            for child in children {
+                if Some(child.id()) == tail {
+                    self.return_router(child);
+                } else {
+                    self.visit(child);
+                }
+            }
+        }
+        self.scopes.pop();
+    }
+
+    /// `let app = Router::new()...;`, `let api = api_routes();`
+    fn visit_let(&mut self, node: Node) {
+        let value = match node.child_by_field_name("value") {
+            Some(value) => value,
+            None => return,
+        };
+        let name = node.child_by_field_name("pattern").and_then(|p| self.pattern_name(p));
+
+        let router = match self.router(value) {
+            Some(router) => Some(router),
+            None => {
+                self.visit(value);
+                self.returned_router(value)
+            }
+        };
+
+        if let (Some(name), Some(router)) = (name, router) {
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.bindings.insert(name, router);
+            }
+        }
+    }
+
+    fn return_router(&mut self, value: Node) {
+        let function = match self.scopes.first() {
+            Some(scope) => scope.function.clone(),
+            None => return,
+        };
+        match self.router_argument(value) {
+            Some(target) => self.module.mounts.push(Mount {
+                parent: format!("{}:return", function),
+                prefix: String::new(),
+                target,
+            }),
+            None => self.visit(value),
+        }
+    }
+
+    /// Actix/Rocket attribute macros on a handler function:
+    /// `#[get("/x")]`, `#[route("/x", method = "GET", method = "POST")]`,
+    /// `#[route(GET, uri = "/x")]`.
+    fn route_attributes(&mut self, function: Node, name: &str) {
+        let mut sibling = function.prev_named_sibling();
+        while let Some(item) = sibling {
+            match item.kind() {
+                "attribute_item" => {}
+                "line_comment" | "block_comment" => {
+                    sibling = item.prev_named_sibling();
+                    continue;
+                }
+                _ => break,
+            }
+            sibling = item.prev_named_sibling();
+
+            let attribute = match item.named_child(0) {
+                Some(attribute) if attribute.kind() == "attribute" => attribute,
+                _ => continue,
+            };
+            let (path, arguments) = match (attribute.named_child(0), attribute.child_by_field_name("arguments")) {
+                (Some(path), Some(arguments)) => (path, arguments),
+                _ => continue,
+            };
+            let macro_name = self.text(path).rsplit("::").next().unwrap_or_default();
+
+            let tokens = self.named_children(arguments);
+            let strings: Vec<String> = tokens.iter().filter_map(|t| self.string(*t)).collect();
+            let route = match strings.iter().find(|s| s.starts_with('/')) {
+                Some(route) => route.split('?').next().unwrap_or_default().to_string(),
+                None => continue,
+            };
+
+            let methods = match macro_name {
+                "route" => tokens.iter()
+                    .filter(|t| matches!(t.kind(), "identifier" | "string_literal"))
+                    .filter_map(|t| {
+                        let value = self.string(*t).unwrap_or_else(|| self.text(*t).to_string());
+                        // Only upper-case idents (`GET`); `method`/`uri` are keys
+                        if t.kind() == "identifier" && value != value.to_uppercase() {
+                            return None;
+                        }
+                        parse_http_method(&value).ok()
+                    })
+                    .collect(),
+                other => match parse_http_method(other) {
+                    Ok(method) => vec![method],
+                    Err(_) => continue,
+                },
+            };
+
+            let pos = item.start_position();
+            for method in methods {
+                self.push_endpoint(name.to_string(), method, route.clone(), name.to_string(), pos);
+            }
+        }
+    }
+
+    /// Evaluate a router builder chain, recording its routes and mounts, and
+    /// return the binding routes in it belong to. `None` means `node` is not a
+    /// router expression and nothing was recorded.
+    fn router(&mut self, node: Node) -> Option<String> {
+        if node.kind() != "call_expression" {
+            return None;
+        }
+        let function = node.child_by_field_name("function")?;
+        let args = self.named_children(node.child_by_field_name("arguments")?);
+
+        if function.kind() != "field_expression" {
+            return self.router_root(node, function, &args);
+        }
+
+        let receiver = function.child_by_field_name("value")?;
+        let method = self.text(function.child_by_field_name("field")?);
+        let router = match receiver.kind() {
+            "identifier" => self.lookup(self.text(receiver)),
+            _ => self.router(receiver)?,
+        };
+
+        match method {
+            // Axum `.route("/x", get(h))`, Actix `.route("/x", web::get().to(h))`
+            "route" if args.len() == 2 && self.string(args[0]).is_some() => {
+                let path = self.string(args[0]).unwrap_or_default();
+                for (methods, handler) in self.method_routes(args[1]) {
+                    self.push_routes(&router, methods, &path, handler, function);
+                }
+            }
+            // Actix `web::resource("/x").route(web::get().to(h))`
+            "route" if args.len() == 1 && self.resources.contains(&router) => {
+                for (methods, handler) in self.method_routes(args[0]) {
+                    self.push_routes(&router, methods, "", handler, function);
+                }
+            }
+            "to" if args.len() == 1 && self.resources.contains(&router) => {
+                self.push_routes(&router, vec![HttpMethod::Get], "", Some(args[0]), function);
+            }
+            "nest" | "nest_service" | "mount" if args.len() == 2 => {
+                if let Some(prefix) = self.string(args[0]) {
+                    for target in self.mount_targets(args[1]) {
+                        self.module.mounts.push(Mount { parent: router.clone(), prefix: prefix.clone(), target });
+                    }
+                }
+            }
+            "merge" | "service" if args.len() == 1 => {
+                for target in self.mount_targets(args[0]) {
+                    self.module.mounts.push(Mount { parent: router.clone(), prefix: String::new(), target });
+                }
+            }
+            "configure" if args.len() == 1 => {
+                if let Some(target) = self.function_binding(args[0], ":#0") {
+                    self.module.mounts.push(Mount { parent: router.clone(), prefix: String::new(), target });
+                }
+            }
+            // Everything else (`.layer`, `.wrap`, `.with_state`, ...) keeps the router
+            _ => {
+                for arg in args {
+                    self.visit(arg);
+                }
+            }
+        }
+
+        Some(router)
+    }
+
+    /// `Router::new()`, `App::new()`, `rocket::build()`, `web::scope("/x")`,
+    /// `web::resource("/x")`.
+    fn router_root(&mut self, node: Node, function: Node, args: &[Node]) -> Option<String> {
+        let path = strip_generics(self.text(function));
+        let binding = format!("router@{}", node.start_byte());
+
+        if ["Router::new", "App::new", "rocket::build", "rocket::custom"].iter().any(|root| path.ends_with(root)) {
+            return Some(binding);
+        }
+
+        let prefix = args.first().and_then(|a| self.string(*a))?;
+        if path.ends_with("scope") || path.ends_with("Scope::new") {
+            self.module.prefixes.insert(binding.clone(), prefix);
+            return Some(binding);
+        }
+        if path.ends_with("resource") || path.ends_with("Resource::new") {
+            self.module.prefixes.insert(binding.clone(), prefix);
+            self.resources.insert(binding.clone());
+            return Some(binding);
+        }
+
+        None
+    }
+
+    /// Targets of `.nest`/`.service`/`.mount`: a router expression, a
+    /// function returning one, a handler function, a tuple of services or
+    /// Rocket's `routes![a, b]`.
+    fn mount_targets(&mut self, node: Node) -> Vec<String> {
+        match node.kind() {
+            "tuple_expression" => self.named_children(node)
+                .into_iter()
+                .flat_map(|element| self.mount_targets(element))
+                .collect(),
+            "macro_invocation" => {
+                let is_routes = node.child_by_field_name("macro")
+                    .map(|m| self.text(m).ends_with("routes"))
+                    .unwrap_or(false);
+                let tokens = match node.named_children(&mut node.walk()).find(|c| c.kind() == "token_tree") {
+                    Some(tokens) if is_routes => self.text(tokens),
+                    _ => return Vec::new(),
+                };
+                tokens.trim_matches(|c| matches!(c, '[' | ']' | '(' | ')'))
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|path| !path.is_empty())
+                    .map(|path| self.path_binding(path, "", node.start_byte()))
+                    .collect()
+            }
+            _ => self.router_argument(node).into_iter().collect(),
+        }
+    }
+
+    /// A router passed by value: a variable, a builder chain or a call to a
+    /// function returning a router. Bare paths name handler functions.
+    fn router_argument(&mut self, node: Node) -> Option<String> {
+        match node.kind() {
+            "identifier" if self.is_local(self.text(node)) => Some(self.lookup(self.text(node))),
+            "identifier" | "scoped_identifier" => Some(self.path_binding(self.text(node), "", node.start_byte())),
+            "call_expression" => match self.router(node) {
+                Some(router) => Some(router),
+                None => {
+                    self.visit(node);
+                    self.returned_router(node)
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// The `:return` binding of a plain function call, e.g. `api::routes()`.
+    fn returned_router(&mut self, node: Node) -> Option<String> {
+        if node.kind() != "call_expression" {
+            return None;
+        }
+        let function = node.child_by_field_name("function")?;
+        self.function_binding(function, ":return")
+    }
+
+    fn function_binding(&mut self, function: Node, suffix: &str) -> Option<String> {
+        match function.kind() {
+            "identifier" | "scoped_identifier" => Some(self.path_binding(self.text(function), suffix, function.start_byte())),
+            _ => None,
+        }
+    }
+
+    /// Binding for a function path: local functions are referenced by name,
+    /// anything else through an import resolved by module path.
+    fn path_binding(&mut self, path: &str, suffix: &str, offset: usize) -> String {
+        let path: String = path.split_whitespace().collect();
+        let mut segments: Vec<&str> = path.split("::").collect();
+        let name = segments.pop().unwrap_or_default().to_string();
+
+        if segments.is_empty() && self.functions.contains(&name) {
+            return format!("{}{}", name, suffix);
+        }
+
+        // Expand the head through `use` declarations: `users::config` with `use crate::routes::users`
+        let source = match segments.split_first() {
+            Some((head, rest)) => {
+                let head = self.uses.get(*head).cloned().unwrap_or_else(|| head.to_string());
+                std::iter::once(head.as_str()).chain(rest.iter().copied()).collect::<Vec<_>>().join("::")
+            }
+            None => self.uses.get(&name)
+                .and_then(|full| full.rsplit_once("::").map(|(module, _)| module.to_string()))
+                .unwrap_or_default(),
+        };
+
+        let binding = format!("use@{}{}", offset, suffix.replace(':', "#"));
+        self.module.imports.insert(binding.clone(), Import {
+            source,
+            name: Some(format!("{}{}", name, suffix)),
+        });
+        binding
+    }
+
+    /// Flatten a method router: `get(h).post(h2)`, `axum::routing::any(h)`,
+    /// `on(MethodFilter::PUT, h)`, `web::get().to(h)`,
+    /// `web::route().method(Method::PATCH).to(h)`.
+    fn method_routes(&self, node: Node<'a>) -> MethodRoutes<'a> {
+        if node.kind() != "call_expression" {
+            return Vec::new();
+        }
+        let (function, args) = match (node.child_by_field_name("function"), node.child_by_field_name("arguments")) {
+            (Some(function), Some(arguments)) => (function, self.named_children(arguments)),
+            _ => return Vec::new(),
+        };
+
+        let (mut routes, name) = match function.kind() {
+            "field_expression" => match (function.child_by_field_name("value"), function.child_by_field_name("field")) {
+                (Some(receiver), Some(field)) => (self.method_routes(receiver), self.text(field)),
+                _ => return Vec::new(),
+            },
+            "identifier" | "scoped_identifier" => {
+                let path = self.text(function);
+                (Vec::new(), path.rsplit("::").next().unwrap_or(path))
+            }
+            _ => return Vec::new(),
+        };
+
+        let handler = args.last().copied();
+        match name {
+            "any" | "any_service" => routes.push((ALL_METHODS.to_vec(), handler)),
+            "to" => {
+                for route in routes.iter_mut().filter(|r| r.1.is_none()) {
+                    route.1 = handler;
+                }
+            }
+            "on" | "method" => {
+                let method = args.first()
+                    .map(|m| self.text(*m))
+                    .and_then(|m| parse_http_method(m.rsplit("::").next().unwrap_or(m)).ok());
+                if let Some(method) = method {
+                    let handler = if name == "on" { handler } else { None };
+                    routes.push((vec![method], handler));
+                }
+            }
+            other => {
+                let verb = other.strip_suffix("_service").unwrap_or(other);
+                if let Ok(method) = parse_http_method(verb) {
+                    routes.push((vec![method], handler));
+                }
+            }
+        }
+        routes
+    }
+
+    fn push_routes(&mut self, owner: &str, methods: Vec<HttpMethod>, path: &str, handler: Option<Node>, at: Node) {
+        let pos = at.end_position();
+        let handler = handler
+            .filter(|h| matches!(h.kind(), "identifier" | "scoped_identifier" | "field_expression"))
+            .map(|h| self.text(h).to_string())
+            .unwrap_or_else(|| format!("{}:{}", pos.row + 1, pos.column + 1));
+
+        for method in methods {
+            self.push_endpoint(owner.to_string(), method, path.to_string(), handler.clone(), pos);
+        }
+    }
+
+    fn push_endpoint(&mut self, owner: String, method: HttpMethod, path: String, handler: String, pos: tree_sitter::Point) {
+        self.module.endpoints.push(RoutedEndpoint {
+            owner,
+            endpoint: Endpoint {
+                method,
+                path,
+                handler,
+                line: pos.row + 1,
+                column: pos.column + 1,
+                documentation: None,
+                parameters: Vec::new(),
+                tags: Vec::new(),
+                middleware: Vec::new(),
+                template: String::new(),
+                body: None,
+                responses: Vec::new(),
+                requires_auth: false,
+            },
+        });
+    }
+
+    fn declare(&mut self, name: &str) -> String {
+        match self.scopes.last_mut() {
+            Some(scope) => {
+                let binding = format!("{}:{}", scope.function, name);
+                scope.bindings.insert(name.to_string(), binding.clone());
+                binding
+            }
+            None => name.to_string(),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> String {
+        self.scopes.iter()
+            .rev()
+            .find_map(|scope| scope.bindings.get(name).cloned())
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    fn is_local(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| scope.bindings.contains_key(name))
+    }
+
+    fn pattern_name(&self, pattern: Node) -> Option<String> {
+        match pattern.kind() {
+            "identifier" => Some(self.text(pattern).to_string()),
+            "mut_pattern" => self.named_children(pattern)
+                .into_iter()
+                .find_map(|child| self.pattern_name(child)),
+            _ => None,
+        }
+    }
+
+    fn string(&self, node: Node) -> Option<String> {
+        let text = self.text(node);
+        match node.kind() {
+            "string_literal" => Some(text.trim_matches('"').to_string()),
+            "raw_string_literal" => Some(text.trim_start_matches('r').trim_matches('#').trim_matches('"').to_string()),
+            _ => None,
+        }
+    }
+
+    fn named_children(&self, node: Node<'a>) -> Vec<Node<'a>> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).collect()
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.content[node.byte_range()]
+    }
+}
+
+/// `Router::<AppState>::new` -> `Router::new`
+fn strip_generics(path: &str) -> String {
+    let mut out = String::new();
+    let mut depth = 0;
+    for c in path.chars() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            _ if depth == 0 => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("::::", "::").split_whitespace().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(code: &str) -> Vec<(HttpMethod, String, String)> {
+        RustParser.parse(code).unwrap()
+            .into_iter()
+            .map(|e| (e.method, e.path, e.handler))
+            .collect()
+    }
+
+    #[test]
+    fn test_supports_rust_extension() {
+        assert!(RustParser.supports_extension("rs"));
+        assert!(!RustParser.supports_extension("go"));
+    }
+
+    #[test]
+    fn test_parse_actix_attributes_and_scopes() {
+        let code = r#"
+use actix_web::{get, post, route, web, App, HttpServer, Responder};
+
+#[get("/users/{id}")]
+async fn get_user(path: web::Path<u32>) -> impl Responder { "" }
+
+#[post("/users")]
+async fn create_user() -> impl Responder { "" }
+
+#[route("/ping", method = "GET", method = "HEAD")]
+async fn ping() -> impl Responder { "" }
+
+fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/items")
+            .route(web::get().to(list_items))
+            .route(web::post().to(create_item)),
+    );
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    HttpServer::new(|| {
+        App::new()
+            .service(ping)
+            .service(web::scope("/api").service(get_user).service(create_user))
+            .service(web::scope("/v2").configure(config))
+            .route("/health", web::get().to(health))
+    })
+    .bind(("127.0.0.1", 8080))?
+    .run()
+    .await
+}
+"#;
+
+        let found = routes(code);
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/api/users/{id}".to_string(), "get_user".to_string()),
+            (HttpMethod::Post, "/api/users".to_string(), "create_user".to_string()),
+            (HttpMethod::Get, "/ping".to_string(), "ping".to_string()),
+            (HttpMethod::Head, "/ping".to_string(), "ping".to_string()),
+            (HttpMethod::Get, "/v2/items".to_string(), "list_items".to_string()),
+            (HttpMethod::Post, "/v2/items".to_string(), "create_item".to_string()),
+            (HttpMethod::Get, "/health".to_string(), "health".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_axum_router() {
+        let code = r#"
+use axum::{routing::{get, post}, Router};
+
+fn user_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_users).post(create_user))
+        .route("/:id", get(handlers::get_user).delete(delete_user))
+}
+
+#[tokio::main]
+async fn main() {
+    let api = Router::new()
+        .nest("/users", user_routes())
+        .route("/ping", axum::routing::any(ping));
+
+    let app = Router::new()
+        .route("/", get(|| async { "Hello" }))
+        .nest("/api", api)
+        .with_state(state);
+
+    axum::serve(listener, app).await.unwrap();
+}
+"#;
+
+        let found: Vec<_> = routes(code).into_iter().map(|(m, p, _)| (m, p)).collect();
+        assert_eq!(found[..4], [
+            (HttpMethod::Get, "/api/users".to_string()),
+            (HttpMethod::Post, "/api/users".to_string()),
+            (HttpMethod::Get, "/api/users/:id".to_string()),
+            (HttpMethod::Delete, "/api/users/:id".to_string()),
+        ]);
+        assert_eq!(found.iter().filter(|(_, p)| p == "/api/ping").count(), 7);
+        assert_eq!(found.last(), Some(&(HttpMethod::Get, "/".to_string())));
+    }
+
+    #[test]
+    fn test_parse_rocket_mounts() {
+        let code = r#"
+#[macro_use] extern crate rocket;
+
+#[get("/hello/<name>?<greeting>")]
+fn hello(name: &str, greeting: Option<&str>) -> String { String::new() }
+
+#[route(POST, uri = "/upload", data = "<file>")]
+fn upload(file: Data<'_>) {}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![upload])
+        .mount("/api", routes![hello])
+}
+"#;
+
+        let found = routes(code);
+        assert_eq!(found, vec![
+            (HttpMethod::Get, "/api/hello/<name>".to_string(), "hello".to_string()),
+            (HttpMethod::Post, "/upload".to_string(), "upload".to_string()),
+        ]);
+    }
+}
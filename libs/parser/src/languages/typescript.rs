@@ -0,0 +1,135 @@
+use crate::{Endpoint, LanguageParser};
+use crate::languages::javascript::extract_module;
+use crate::project::ModuleRoutes;
+use anyhow::{Result, anyhow};
+use std::path::Path;
+use tree_sitter::{Language, Parser, Tree};
+
+/// Parser for TypeScript sources.
+///
+/// `.ts` and `.tsx` need different grammars: the TSX grammar accepts JSX but
+/// rejects `<Type>value` casts, so the dialect is fixed at construction time.
+pub struct TypeScriptParser {
+    tsx: bool,
+}
+
+impl TypeScriptParser {
+    pub fn new() -> Self {
+        Self { tsx: false }
+    }
+
+    pub fn tsx() -> Self {
+        Self { tsx: true }
+    }
+
+    fn language(&self) -> Language {
+        if self.tsx {
+            tree_sitter_typescript::language_tsx()
+        } else {
+            tree_sitter_typescript::language_typescript()
+        }
+    }
+
+    fn parse_tree(&self, content: &str) -> Result<Tree> {
+        let mut parser = Parser::new();
+        parser.set_language(self.language())?;
+
+        parser.parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse TypeScript code"))
+    }
+}
+
+impl Default for TypeScriptParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageParser for TypeScriptParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        let tree = self.parse_tree(content)?;
+        Ok(extract_module(self.language(), Path::new(""), &tree, content)?.into_endpoints())
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        if self.tsx {
+            extension == "tsx"
+        } else {
+            extension == "ts"
+        }
+    }
+
+    fn parse_module(&self, path: &Path, content: &str) -> Result<ModuleRoutes> {
+        let tree = self.parse_tree(content)?;
+        extract_module(self.language(), path, &tree, content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HttpMethod;
+
+    #[test]
+    fn test_supports_typescript_extensions() {
+        assert!(TypeScriptParser::new().supports_extension("ts"));
+        assert!(!TypeScriptParser::new().supports_extension("tsx"));
+        assert!(TypeScriptParser::tsx().supports_extension("tsx"));
+        assert!(!TypeScriptParser::tsx().supports_extension("js"));
+    }
+
+    #[test]
+    fn test_parse_typed_express_routes() {
+        let parser = TypeScriptParser::new();
+        let code = r#"
+import express, { Request, Response, Router } from 'express';
+
+interface User { id: string; name: string }
+
+const app = express();
+const router: Router = express.Router();
+
+app.get('/users/:id', async (req: Request<{ id: string }>, res: Response<User>) => {
+    const id = req.params.id as string;
+    res.json({ id, name: <string>'x' });
+});
+
+router.post<{}, User, Omit<User, 'id'>>('/users', (req, res) => {
+    res.status(201).json(req.body as User);
+});
+
+app.delete('/users/:id', handler);
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        assert_eq!(endpoints.len(), 3);
+
+        assert_eq!(endpoints[0].method, HttpMethod::Get);
+        assert_eq!(endpoints[0].path, "/users/:id");
+        assert_eq!(endpoints[1].method, HttpMethod::Post);
+        assert_eq!(endpoints[1].path, "/users");
+        assert_eq!(endpoints[2].method, HttpMethod::Delete);
+    }
+
+    #[test]
+    fn test_parse_tsx_routes() {
+        let parser = TypeScriptParser::tsx();
+        let code = r#"
+const Page = (): JSX.Element => <div className="status">ok</div>;
+
+app.get('/status', (req: Request, res: Response) => {
+    res.send(renderToString(<Page />));
+});
+"#;
+
+        let endpoints = parser.parse(code).unwrap();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].path, "/status");
+    }
+
+    #[test]
+    fn test_parse_empty_content() {
+        let parser = TypeScriptParser::new();
+        assert_eq!(parser.parse("").unwrap().len(), 0);
+    }
+}
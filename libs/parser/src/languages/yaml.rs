@@ -0,0 +1,199 @@
+use crate::{Endpoint, HttpMethod, LanguageParser, parse_http_method};
+use crate::params::describe_path;
+use anyhow::Result;
+use std::collections::HashMap;
+
+/// Parser for Symfony YAML route files (`config/routes.yaml`, `config/routes/*.yaml`).
+///
+/// Only the route-definition subset of YAML is read: top-level route names
+/// mapping to `path`, `controller` (or `defaults: { _controller: ... }`) and
+/// `methods`. Entries without both a path and a controller, such as
+/// `resource:` imports, and unrelated YAML files produce nothing.
+pub struct YamlParser;
+
+impl LanguageParser for YamlParser {
+    fn parse(&self, content: &str) -> Result<Vec<Endpoint>> {
+        let mut endpoints = Vec::new();
+
+        for definition in definitions(content) {
+            let path = match definition.options.get("path") {
+                Some(path) => path,
+                None => continue,
+            };
+            let controller = match definition.options.get("controller").or_else(|| definition.options.get("defaults._controller")) {
+                Some(controller) => controller,
+                None => continue,
+            };
+
+            // Symfony matches any verb when `methods` is left out; report it as GET
+            let mut methods: Vec<HttpMethod> = definition.options.get("methods")
+                .map(|m| list(m))
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|m| parse_http_method(m).ok())
+                .collect();
+            if methods.is_empty() {
+                methods.push(HttpMethod::Get);
+            }
+
+            for method in methods {
+                endpoints.push(Endpoint {
+                    method,
+                    path: if path.starts_with('/') { path.clone() } else { format!("/{}", path) },
+                    handler: controller.clone(),
+                    line: definition.line,
+                    column: 1,
+                    documentation: None,
+                    parameters: Vec::new(),
+                    tags: Vec::new(),
+                    middleware: Vec::new(),
+                    template: String::new(),
+                    body: None,
+                    responses: Vec::new(),
+                    requires_auth: false,
+                });
+            }
+        }
+
+        endpoints.iter_mut().for_each(describe_path);
+        Ok(endpoints)
+    }
+
+    fn supports_extension(&self, extension: &str) -> bool {
+        extension == "yaml" || extension == "yml"
+    }
+}
+
+/// A top-level key and its options, nested keys flattened as `defaults._controller`.
+struct Definition {
+    line: usize,
+    options: HashMap<String, String>,
+}
+
+fn definitions(content: &str) -> Vec<Definition> {
+    let mut definitions: Vec<Definition> = Vec::new();
+    // Nested mapping (`defaults:`) and its indentation
+    let mut parent: Option<(String, usize)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let line = strip_comment(line);
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('-') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let (key, value) = match trimmed.split_once(':') {
+            Some((key, value)) => (unquote(key), value.trim()),
+            None => continue,
+        };
+
+        if indent == 0 {
+            definitions.push(Definition { line: index + 1, options: HashMap::new() });
+            parent = None;
+            continue;
+        }
+        let definition = match definitions.last_mut() {
+            Some(definition) => definition,
+            None => continue,
+        };
+
+        let key = match &parent {
+            Some((name, parent_indent)) if indent > *parent_indent => format!("{}.{}", name, key),
+            _ => {
+                parent = None;
+                key
+            }
+        };
+
+        if value.is_empty() {
+            parent = Some((key, indent));
+        } else if let Some(map) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+            // `defaults: { _controller: App\Controller\BlogController::list }`
+            for entry in map.split(',') {
+                if let Some((name, value)) = entry.split_once(':') {
+                    definition.options.insert(format!("{}.{}", key, unquote(name)), unquote(value));
+                }
+            }
+        } else {
+            definition.options.insert(key, unquote(value));
+        }
+    }
+
+    definitions
+}
+
+/// `GET|HEAD`, `[GET, HEAD]` or `GET`
+fn list(value: &str) -> Vec<String> {
+    value.trim_start_matches('[')
+        .trim_end_matches(']')
+        .split([',', '|'])
+        .map(unquote)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches(|c| c == '\'' || c == '"').to_string()
+}
+
+/// Drop a trailing `# comment`, ignoring `#` inside quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (c, quote) {
+            ('\'' | '"', None) => quote = Some(c),
+            (c, Some(open)) if c == open => quote = None,
+            ('#', None) if i == 0 || line[..i].ends_with(char::is_whitespace) => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_symfony_routes() {
+        let code = r#"
+# config/routes.yaml
+controllers:
+    resource: ../src/Controller/
+    type: attribute
+
+blog_list:
+    path: /blog/{page}
+    controller: App\Controller\BlogController::list
+    methods: GET|HEAD
+
+blog_create:
+    path: '/blog'   # form post
+    methods: [POST]
+    defaults: { _controller: 'App\Controller\BlogController::create' }
+
+blog_legacy:
+    path: /legacy
+    defaults:
+        _controller: App\Controller\LegacyController::index
+"#;
+
+        let endpoints = YamlParser.parse(code).unwrap();
+        let routes: Vec<_> = endpoints.iter()
+            .map(|e| (e.method.clone(), e.path.as_str(), e.handler.as_str()))
+            .collect();
+        assert_eq!(routes, vec![
+            (HttpMethod::Get, "/blog/{page}", "App\\Controller\\BlogController::list"),
+            (HttpMethod::Head, "/blog/{page}", "App\\Controller\\BlogController::list"),
+            (HttpMethod::Post, "/blog", "App\\Controller\\BlogController::create"),
+            (HttpMethod::Get, "/legacy", "App\\Controller\\LegacyController::index"),
+        ]);
+        assert_eq!(endpoints[0].line, 7);
+    }
+
+    #[test]
+    fn test_ignores_unrelated_yaml() {
+        let code = "services:\n  web:\n    image: nginx\n    ports:\n      - \"80:80\"\n";
+        assert!(YamlParser.parse(code).unwrap().is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -13,6 +13,20 @@ pub enum HttpMethod {
     Head,
 }
 
+/// `GET`, `get` or `Get` as an [`HttpMethod`].
+pub(crate) fn parse_http_method(method_str: &str) -> Result<HttpMethod> {
+    match method_str.to_lowercase().as_str() {
+        "get" => Ok(HttpMethod::Get),
+        "post" => Ok(HttpMethod::Post),
+        "put" => Ok(HttpMethod::Put),
+        "delete" => Ok(HttpMethod::Delete),
+        "patch" => Ok(HttpMethod::Patch),
+        "head" => Ok(HttpMethod::Head),
+        "options" => Ok(HttpMethod::Options),
+        _ => Err(anyhow!("Unknown HTTP method: {}", method_str)),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Endpoint {
     pub method: HttpMethod,
@@ -20,35 +34,236 @@ pub struct Endpoint {
     pub handler: String,
     pub line: usize,
     pub column: usize,
-    pub documentation: Option<String>,
+    /// JSDoc, docstring or PHPDoc written on the route or its handler.
+    pub documentation: Option<Documentation>,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+    /// Grouping tags (FastAPI `tags=`), outermost router first.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Middleware, guards and dependencies applied to the route, outermost first.
+    #[serde(default)]
+    pub middleware: Vec<String>,
+    /// Canonical form of `path` with OpenAPI-style `{name}` placeholders,
+    /// see [`params::route_template`].
+    #[serde(default)]
+    pub template: String,
+    /// Shape of the request body, when the handler declares one.
+    #[serde(default)]
+    pub body: Option<Schema>,
+    /// Status codes the handler responds with and the bodies it sends,
+    /// in order of appearance.
+    #[serde(default)]
+    pub responses: Vec<Response>,
+    /// Whether any of `middleware` authenticates the caller, see
+    /// [`auth::requires_auth`].
+    #[serde(default)]
+    pub requires_auth: bool,
+}
+
+/// Documentation written on a handler, split into its parts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Documentation {
+    /// The first paragraph.
+    pub summary: Option<String>,
+    /// The prose after the summary.
+    pub description: Option<String>,
+    /// `@param`, `:param` and `Args:` entries, in order.
+    #[serde(default)]
+    pub params: Vec<ParamDoc>,
+    pub returns: Option<String>,
+    /// Set when the handler is marked deprecated, to the note given (possibly empty).
+    pub deprecated: Option<String>,
+}
+
+/// One documented handler parameter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamDoc {
+    pub name: String,
+    pub param_type: Option<String>,
+    pub description: String,
+}
+
+/// Where a request parameter is read from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ParameterLocation {
+    Path,
+    Query,
+    Header,
+    Body,
+}
+
+/// A request input declared by the handler.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Parameter {
+    pub name: String,
+    pub location: ParameterLocation,
+    /// Type as written in the source (`Long`, `CreateUserRequest`), when declared.
+    pub param_type: Option<String>,
+    pub required: bool,
+    pub default: Option<String>,
+}
+
+/// A JSON-Schema-like description of a request body, as far as the source
+/// declares it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Schema {
+    /// `object`, `array`, `string`, `integer`, `number`, `boolean` or `any`.
+    /// Empty while the schema is only a `reference`.
+    #[serde(rename = "type")]
+    pub schema_type: String,
+    /// The model, DTO or schema variable the shape was declared as.
+    pub name: Option<String>,
+    /// `email`, `uuid`, `date-time`, ...
+    pub format: Option<String>,
+    /// Object fields in declaration order.
+    #[serde(default)]
+    pub properties: Vec<Property>,
+    /// Element shape of an array.
+    pub items: Option<Box<Schema>>,
+    /// A named shape declared elsewhere (`CreateUserDto`). Linking replaces
+    /// it with the definition when one is found in the project.
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+}
+
+/// One response an endpoint can send.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    pub status: u16,
+    /// Shape of the response body; `None` when it is empty or unknown.
+    pub body: Option<Schema>,
+}
+
+/// One field of an object [`Schema`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Property {
+    pub name: String,
+    pub schema: Schema,
+    pub required: bool,
+}
+
+impl Schema {
+    pub fn of_type(schema_type: &str) -> Self {
+        Self { schema_type: schema_type.to_string(), ..Default::default() }
+    }
+
+    /// A `string` with a format such as `email`.
+    pub fn formatted(schema_type: &str, format: &str) -> Self {
+        Self { format: Some(format.to_string()), ..Self::of_type(schema_type) }
+    }
+
+    pub fn array(items: Schema) -> Self {
+        Self { items: Some(Box::new(items)), ..Self::of_type("array") }
+    }
+
+    pub fn reference(name: &str) -> Self {
+        Self { reference: Some(name.to_string()), ..Default::default() }
+    }
+
+    /// Add or replace the property `name`.
+    pub fn set_property(&mut self, name: &str, schema: Schema, required: bool) {
+        let property = Property { name: name.to_string(), schema, required };
+        match self.properties.iter_mut().find(|p| p.name == name) {
+            Some(existing) => *existing = property,
+            None => self.properties.push(property),
+        }
+    }
+}
+
+impl Endpoint {
+    /// The canonical template, derived from `path` when it wasn't filled in
+    /// (endpoints built by hand or loaded from older state).
+    pub fn route_template(&self) -> String {
+        if self.template.is_empty() {
+            params::route_template(&self.path)
+        } else {
+            self.template.clone()
+        }
+    }
 }
 
 pub trait LanguageParser: Send + Sync {
     fn parse(&self, content: &str) -> Result<Vec<Endpoint>>;
     fn supports_extension(&self, extension: &str) -> bool;
+
+    /// Parse a file into endpoints plus the router wiring needed to resolve
+    /// prefixes across files. Parsers without cross-file support keep the default.
+    fn parse_module(&self, _path: &Path, content: &str) -> Result<ModuleRoutes> {
+        Ok(ModuleRoutes::from_endpoints(self.parse(content)?))
+    }
 }
 
 pub fn detect_language(path: &Path) -> Option<&'static str> {
     match path.extension()?.to_str()? {
         "js" | "mjs" => Some("javascript"),
-        "ts" | "tsx" => Some("typescript"),
+        "ts" => Some("typescript"),
+        "tsx" => Some("tsx"),
         "py" => Some("python"),
         "go" => Some("go"),
         "rs" => Some("rust"),
         "rb" => Some("ruby"),
         "php" => Some("php"),
         "java" => Some("java"),
+        "cs" => Some("csharp"),
+        "kt" | "kts" => Some("kotlin"),
+        "ex" | "exs" => Some("elixir"),
+        "yaml" | "yml" => Some("yaml"),
+        _ => None,
+    }
+}
+
+/// Build the parser for a language name returned by [`detect_language`].
+pub fn parser_for_language(language: &str) -> Option<Box<dyn LanguageParser>> {
+    use languages::{csharp::CSharpParser, elixir::ElixirParser, go::GoParser, java::JavaParser, javascript::JavaScriptParser, kotlin::KotlinParser, php::PhpParser, python::PythonParser, ruby::RubyParser, rust::RustParser, typescript::TypeScriptParser, yaml::YamlParser};
+
+    match language {
+        "javascript" => Some(Box::new(JavaScriptParser)),
+        "typescript" => Some(Box::new(TypeScriptParser::new())),
+        "tsx" => Some(Box::new(TypeScriptParser::tsx())),
+        "python" => Some(Box::new(PythonParser)),
+        "go" => Some(Box::new(GoParser)),
+        "rust" => Some(Box::new(RustParser)),
+        "ruby" => Some(Box::new(RubyParser)),
+        "java" => Some(Box::new(JavaParser)),
+        "csharp" => Some(Box::new(CSharpParser)),
+        "kotlin" => Some(Box::new(KotlinParser)),
+        "elixir" => Some(Box::new(ElixirParser)),
+        "php" => PhpParser::new().ok().map(|p| Box::new(p) as Box<dyn LanguageParser>),
+        "yaml" => Some(Box::new(YamlParser)),
         _ => None,
     }
 }
 
 pub mod languages {
     pub mod javascript;
+    pub mod typescript;
     pub mod python;
+    pub mod go;
+    pub mod rust;
+    pub mod ruby;
     pub mod php;
+    pub mod java;
+    pub mod csharp;
+    pub mod kotlin;
+    pub mod elixir;
+    pub mod yaml;
+    pub(crate) mod django;
+    pub(crate) mod file_routes;
+    pub(crate) mod js_inputs;
+    pub(crate) mod js_responses;
+    pub(crate) mod js_schemas;
+    pub(crate) mod nestjs;
+    pub(crate) mod node_frameworks;
 }
 
+pub mod auth;
 pub mod config;
+pub mod docs;
 pub mod incremental;
+pub mod params;
+pub mod project;
+pub mod responses;
 
 pub use incremental::{IncrementalParser, EndpointChanges, EndpointChange, ChangeType};
+pub use project::{ModuleRoutes, ProjectEndpoint};
@@ -0,0 +1,595 @@
+use crate::{Endpoint, Parameter, ParameterLocation};
+
+/// Path parameters declared in a route path, in order of appearance.
+///
+/// Every placeholder syntax the parsers emit is understood:
+///
+/// - `:id`, `:id?`, `:id(\d+)`, `:id{[0-9]+}`, `:path*` (Express, Hono, Rails, Gin)
+/// - `*path` and a bare `*` wildcard; `(/:id)`, `{/:id}` and FastRoute's `[/{id}]` optional groups
+/// - `{id}`, `{id?}`, `{id:int}`, `{id:[0-9]+}`, `{id<\d+>}`, `{*slug}`, `{path...}`, `{path*}`
+/// - `<id>`, `<int:id>`, `<path..>` (Flask, Django, Rocket) and `(?P<id>\d+)` regex groups
+/// - `[id]`, `[[id]]`, `[...slug]`, `[[...slug]]`, `[id=integer]` (file-based routers)
+/// - `$id` and a bare `$` splat (Remix)
+///
+/// Types come from converters and constraints (`<int:id>` -> `int`,
+/// `{id:guid}` -> `guid`), or are inferred from simple regexes (`\d+` -> `int`).
+/// Wildcards that match several segments have type `path`.
+pub fn path_parameters(path: &str) -> Vec<Parameter> {
+    let mut parameters: Vec<Parameter> = Vec::new();
+    scan(path, false, &mut parameters, &mut String::new());
+
+    let mut seen = Vec::new();
+    parameters.retain(|p| {
+        let first = !seen.contains(&p.name);
+        seen.push(p.name.clone());
+        first
+    });
+    parameters
+}
+
+/// The framework-neutral form of a route path, so the same route compares
+/// equal whichever framework declares it.
+///
+/// Parameters are written OpenAPI-style as `{name}`; whether one is optional
+/// or spans segments is kept on its [`Parameter`], and a bare `*` or `$`
+/// splat is named `wildcard`. Regex constraints, converters and optional-group
+/// brackets are dropped. Regex anchors and any trailing slash are removed:
+/// `<int:user_id>/`, `:user_id(\d+)` and `{user_id:int}` all become `{user_id}`.
+pub fn route_template(path: &str) -> String {
+    let mut template = String::new();
+    scan(path, false, &mut Vec::new(), &mut template);
+
+    let template = template.trim_end_matches('$').trim_end_matches('/');
+    if template.starts_with('/') {
+        template.to_string()
+    } else {
+        format!("/{}", template)
+    }
+}
+
+/// Fill in what `endpoint.path` implies: its [`route_template`] and
+/// path parameters.
+///
+/// Parameters the parser already declared from the handler signature keep
+/// their place and type; the path fills in a missing type, default or
+/// optionality. Undeclared path parameters are appended in path order.
+pub fn describe_path(endpoint: &mut Endpoint) {
+    endpoint.template = route_template(&endpoint.path);
+
+    for param in path_parameters(&endpoint.path) {
+        let declared = endpoint.parameters.iter_mut()
+            .find(|p| p.location == ParameterLocation::Path && p.name == param.name);
+        match declared {
+            Some(declared) => {
+                if declared.param_type.is_none() {
+                    declared.param_type = param.param_type;
+                }
+                if declared.default.is_none() {
+                    declared.default = param.default;
+                }
+                declared.required &= param.required;
+            }
+            None => endpoint.parameters.push(param),
+        }
+    }
+}
+
+/// A query parameter or header a handler reads, with the literal default it
+/// falls back to when the client leaves it out.
+pub fn input(name: &str, location: ParameterLocation, default: Option<String>) -> Parameter {
+    Parameter {
+        name: name.to_string(),
+        location,
+        param_type: None,
+        required: false,
+        default,
+    }
+}
+
+/// Add the inputs a handler reads that the endpoint doesn't declare yet.
+/// Header names compare case-insensitively; a declared input without a
+/// default takes the one the handler falls back to.
+pub fn add_inputs(parameters: &mut Vec<Parameter>, inputs: &[Parameter]) {
+    for input in inputs {
+        let same = |p: &Parameter| {
+            p.location == input.location
+                && match input.location {
+                    ParameterLocation::Header => p.name.eq_ignore_ascii_case(&input.name),
+                    _ => p.name == input.name,
+                }
+        };
+        match parameters.iter_mut().find(|p| same(p)) {
+            Some(declared) => {
+                if declared.param_type.is_none() {
+                    declared.param_type = input.param_type.clone();
+                }
+                if declared.default.is_none() {
+                    declared.default = input.default.clone();
+                }
+                declared.required |= input.required;
+            }
+            None => parameters.push(input.clone()),
+        }
+    }
+}
+
+/// Name of a bare `*` or `$` splat, which the route doesn't name itself.
+const WILDCARD: &str = "wildcard";
+
+/// Record `param` and write its placeholder into the template.
+fn emit(param: Parameter, parameters: &mut Vec<Parameter>, template: &mut String) {
+    template.push_str(&format!("{{{}}}", param.name));
+    parameters.push(param);
+}
+
+fn scan(path: &str, optional: bool, parameters: &mut Vec<Parameter>, template: &mut String) {
+    let chars: Vec<char> = path.chars().collect();
+    // Depth of `(...)` optional groups
+    let mut group: usize = 0;
+    let mut i = 0;
+
+    // Django `re_path` patterns only name parameters through `(?P<name>...)`
+    let regex = path.starts_with('^');
+
+    while i < chars.len() {
+        let segment_start = i == 0 || matches!(chars[i - 1], '/' | '(' | '.' | '-');
+        if regex && !starts_with(&chars[i..], "(?P<") {
+            if i > 0 || chars[i] != '^' {
+                template.push(chars[i]);
+            }
+            i += 1;
+            continue;
+        }
+        match chars[i] {
+            '(' if starts_with(&chars[i..], "(?P<") => {
+                let close = matching(&chars, i, '(', ')');
+                let group_text: String = chars[i + 4..close.saturating_sub(1).max(i + 4)].iter().collect();
+                if let Some((name, pattern)) = group_text.split_once('>') {
+                    emit(parameter(name, regex_type(pattern), optional || group > 0, None), parameters, template);
+                }
+                i = close;
+                continue;
+            }
+            '(' => group += 1,
+            ')' => group = group.saturating_sub(1),
+            ':' if segment_start && chars.get(i + 1).map(|c| is_name_start(*c)).unwrap_or(false) => {
+                let end = name_end(&chars, i + 1);
+                let name: String = chars[i + 1..end].iter().collect();
+                let mut i_next = end;
+                let mut param_type = None;
+
+                // `:id(\d+)` and Hono's `:id{[0-9]+}`
+                if let Some(&open @ ('(' | '{')) = chars.get(i_next) {
+                    let close = matching(&chars, i_next, open, if open == '(' { ')' } else { '}' });
+                    let pattern: String = chars[i_next + 1..close.saturating_sub(1).max(i_next + 1)].iter().collect();
+                    param_type = regex_type(&pattern);
+                    i_next = close;
+                }
+
+                let mut required = !optional && group == 0;
+                match chars.get(i_next) {
+                    Some('?') => {
+                        required = false;
+                        i_next += 1;
+                    }
+                    Some('*') => {
+                        required = false;
+                        param_type = Some("path".to_string());
+                        i_next += 1;
+                    }
+                    Some('+') => {
+                        param_type = Some("path".to_string());
+                        i_next += 1;
+                    }
+                    _ => {}
+                }
+                emit(parameter(&name, param_type, !required, None), parameters, template);
+                i = i_next;
+                continue;
+            }
+            '*' if segment_start => {
+                let end = name_end(&chars, i + 1);
+                let name: String = chars[i + 1..end].iter().collect();
+                let name = if name.is_empty() { WILDCARD.to_string() } else { name };
+                emit(parameter(&name, Some("path".to_string()), optional || group > 0, None), parameters, template);
+                i = end;
+                continue;
+            }
+            '$' if segment_start => {
+                let end = name_end(&chars, i + 1);
+                let name: String = chars[i + 1..end].iter().collect();
+                let (name, param_type) = if name.is_empty() { (WILDCARD.to_string(), Some("path".to_string())) } else { (name, None) };
+                emit(parameter(&name, param_type, optional || group > 0, None), parameters, template);
+                i = end;
+                continue;
+            }
+            '{' => {
+                let close = matching(&chars, i, '{', '}');
+                let inner: String = chars[i + 1..close.saturating_sub(1).max(i + 1)].iter().collect();
+                if inner.starts_with('/') || inner.starts_with('.') {
+                    // path-to-regexp optional group: `{/:id}`
+                    scan(&inner, true, parameters, template);
+                } else if let Some(param) = brace_parameter(&inner, optional || group > 0) {
+                    emit(param, parameters, template);
+                } else {
+                    template.extend(&chars[i..close]);
+                }
+                i = close;
+                continue;
+            }
+            '<' => {
+                let close = chars[i..].iter().position(|c| *c == '>').map(|p| i + p + 1).unwrap_or(chars.len());
+                let inner: String = chars[i + 1..close.saturating_sub(1).max(i + 1)].iter().collect();
+                let (converter, name) = match inner.split_once(':') {
+                    Some((converter, name)) => (Some(converter.to_string()), name),
+                    None => (None, inner.as_str()),
+                };
+                // Rocket `<path..>` segments
+                let (name, converter) = match name.strip_suffix("..") {
+                    Some(name) => (name, Some("path".to_string())),
+                    None => (name, converter),
+                };
+                if !name.is_empty() && name.chars().all(is_name_char) {
+                    emit(parameter(name, converter, optional || group > 0, None), parameters, template);
+                } else {
+                    template.extend(&chars[i..close]);
+                }
+                i = close;
+                continue;
+            }
+            // FastRoute/Slim optional group: `/users[/{id:[0-9]+}]`
+            '[' if chars.get(i + 1) == Some(&'/') => {
+                let close = matching(&chars, i, '[', ']');
+                let inner: String = chars[i + 1..close.saturating_sub(1).max(i + 1)].iter().collect();
+                scan(&inner, true, parameters, template);
+                i = close;
+                continue;
+            }
+            '[' => {
+                let double = chars.get(i + 1) == Some(&'[');
+                let (open_len, close_text) = if double { (2, "]]") } else { (1, "]") };
+                let rest: String = chars[i + open_len..].iter().collect();
+                if let Some(end) = rest.find(close_text) {
+                    let inner = &rest[..end];
+                    let (inner, catch_all) = match inner.strip_prefix("...") {
+                        Some(name) => (name, true),
+                        None => (inner, false),
+                    };
+                    // SvelteKit matchers: `[id=integer]`
+                    let (name, matcher) = match inner.split_once('=') {
+                        Some((name, matcher)) => (name, Some(matcher.to_string())),
+                        None => (inner, None),
+                    };
+                    if !name.is_empty() && name.chars().all(is_name_char) {
+                        let param_type = if catch_all { Some("path".to_string()) } else { matcher };
+                        emit(parameter(name, param_type, double || optional || group > 0, None), parameters, template);
+                    } else {
+                        template.push_str(&chars[i..i + open_len].iter().collect::<String>());
+                        template.push_str(&rest[..end]);
+                        template.push_str(close_text);
+                    }
+                    i += open_len + rest[..end].chars().count() + close_text.len();
+                    continue;
+                }
+                template.push('[');
+            }
+            c => template.push(c),
+        }
+        i += 1;
+    }
+}
+
+/// The inside of `{...}`: `id`, `id?`, `id:int`, `id:[0-9]+`, `id<\d+>`,
+/// `page?1`, `id=5`, `*slug`, `**slug`, `path...`, `path*`.
+fn brace_parameter(inner: &str, optional: bool) -> Option<Parameter> {
+    let (inner, catch_all) = match inner.trim_start_matches('*') {
+        stripped if stripped.len() != inner.len() => (stripped, true),
+        _ => (inner, false),
+    };
+    let chars: Vec<char> = inner.chars().collect();
+    let end = name_end(&chars, 0);
+    if end == 0 {
+        return None;
+    }
+    let name: String = chars[..end].iter().collect();
+    let mut rest: &str = &inner[name.len()..];
+
+    let mut param_type = catch_all.then(|| "path".to_string());
+    let mut required = !optional;
+    let mut default = None;
+
+    if let Some(pattern) = rest.strip_prefix('<').and_then(|r| r.split_once('>')) {
+        // Symfony requirement: `{id<\d+>}`
+        param_type = regex_type(pattern.0);
+        rest = pattern.1;
+    }
+
+    if let Some(wildcard) = rest.strip_prefix("...") {
+        param_type = Some("path".to_string());
+        rest = wildcard;
+    } else if let Some(wildcard) = rest.strip_prefix('*') {
+        // Hapi `{path*}` matches zero or more segments
+        param_type = Some("path".to_string());
+        required = false;
+        rest = wildcard;
+    } else if let Some(constraint) = rest.strip_prefix(':') {
+        let (constraint, tail) = split_constraint(constraint);
+        param_type = constraint_type(&constraint);
+        rest = tail;
+    }
+
+    if let Some(tail) = rest.strip_prefix('?') {
+        required = false;
+        if !tail.is_empty() {
+            default = Some(tail.to_string());
+        }
+    } else if let Some(value) = rest.strip_prefix('=') {
+        required = false;
+        default = Some(value.to_string());
+    }
+
+    Some(parameter(&name, param_type, !required, default))
+}
+
+/// Split `int:min(1)?` into the constraint text and a trailing `?`/`=default`.
+fn split_constraint(constraint: &str) -> (String, &str) {
+    let mut depth = 0;
+    for (i, c) in constraint.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '?' | '=' if depth == 0 && constraint[..i].chars().all(|c| c.is_ascii_alphanumeric() || "():,".contains(c)) => {
+                return (constraint[..i].to_string(), &constraint[i..]);
+            }
+            _ => {}
+        }
+    }
+    (constraint.to_string(), "")
+}
+
+/// ASP.NET route constraints (`int`, `guid`, `min(1)`, `regex(...)`), or a
+/// regex as used by chi, gorilla/mux and Actix.
+fn constraint_type(constraint: &str) -> Option<String> {
+    let first = constraint.split(':').next().unwrap_or_default();
+    let word: String = first.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+    let is_asp = !word.is_empty() && (first.len() == word.len() || first[word.len()..].starts_with('('));
+    if !is_asp {
+        return regex_type(constraint);
+    }
+
+    match word.as_str() {
+        "min" | "max" | "range" => Some("int".to_string()),
+        "length" | "minlength" | "maxlength" | "alpha" => Some("string".to_string()),
+        "regex" => regex_type(first.trim_start_matches("regex(").trim_end_matches(')')),
+        "required" => None,
+        _ => Some(word),
+    }
+}
+
+/// A type for simple constraint regexes: digits are `int`, letters are
+/// `string`, hex groups are `uuid`, and `.*`-style patterns span segments.
+pub(crate) fn regex_type(pattern: &str) -> Option<String> {
+    let pattern = pattern.trim_start_matches('^').trim_end_matches('$');
+    if pattern.is_empty() {
+        return None;
+    }
+    let lower = pattern.to_lowercase();
+    if lower.contains("[0-9a-f]{8}") || lower.contains("[a-f0-9]{8}") || lower.contains("[\\da-f]{8}") {
+        return Some("uuid".to_string());
+    }
+
+    let quantifiers = |rest: &str| rest.chars().all(|c| "+*?{},0123456789".contains(c));
+    let without = |classes: &[&str]| classes.iter().fold(pattern.to_string(), |p, class| p.replace(class, ""));
+
+    let digits = without(&["\\d", "[0-9]"]);
+    if digits.len() < pattern.len() && quantifiers(&digits) {
+        return Some("int".to_string());
+    }
+    let letters = without(&["[a-zA-Z]", "[A-Za-z]", "[a-z]", "[A-Z]", "\\w", "[\\w-]", "[a-zA-Z0-9_-]", "[a-z0-9-]"]);
+    if letters.len() < pattern.len() && quantifiers(&letters) {
+        return Some("string".to_string());
+    }
+    if matches!(pattern, ".*" | ".+" | ".*?" | ".+?") {
+        return Some("path".to_string());
+    }
+    None
+}
+
+fn parameter(name: &str, param_type: Option<String>, optional: bool, default: Option<String>) -> Parameter {
+    Parameter {
+        name: name.to_string(),
+        location: ParameterLocation::Path,
+        param_type,
+        required: !optional,
+        default,
+    }
+}
+
+/// Index after the bracket that closes the one at `open`.
+fn matching(chars: &[char], open: usize, left: char, right: char) -> usize {
+    let mut depth = 0;
+    let mut i = open;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if chars[i] == left {
+            depth += 1;
+        } else if chars[i] == right {
+            depth -= 1;
+            if depth == 0 {
+                return i + 1;
+            }
+        }
+        i += 1;
+    }
+    chars.len()
+}
+
+fn starts_with(chars: &[char], prefix: &str) -> bool {
+    prefix.chars().enumerate().all(|(i, c)| chars.get(i) == Some(&c))
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+fn name_end(chars: &[char], mut i: usize) -> usize {
+    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(path: &str) -> Vec<(String, Option<String>, bool)> {
+        path_parameters(path)
+            .into_iter()
+            .map(|p| (p.name, p.param_type, p.required))
+            .collect()
+    }
+
+    fn param(name: &str, param_type: Option<&str>, required: bool) -> (String, Option<String>, bool) {
+        (name.to_string(), param_type.map(str::to_string), required)
+    }
+
+    #[test]
+    fn test_colon_parameters() {
+        assert_eq!(summary("/users/:id/posts/:postId?"), vec![
+            param("id", None, true),
+            param("postId", None, false),
+        ]);
+        assert_eq!(summary("/files/:id(\\d+)/:rest*"), vec![
+            param("id", Some("int"), true),
+            param("rest", Some("path"), false),
+        ]);
+        assert_eq!(summary("/posts/:slug{[a-z-]+}"), vec![param("slug", None, true)]);
+        assert_eq!(summary("/photos(/:id)(.:format)"), vec![
+            param("id", None, false),
+            param("format", None, false),
+        ]);
+        assert_eq!(summary("/assets/*filepath"), vec![param("filepath", Some("path"), true)]);
+        assert_eq!(summary("/users{/:id}"), vec![param("id", None, false)]);
+    }
+
+    #[test]
+    fn test_brace_parameters() {
+        assert_eq!(summary("/users/{user}/posts/{post?}"), vec![
+            param("user", None, true),
+            param("post", None, false),
+        ]);
+        assert_eq!(summary("/orders/{id:int}/{code:regex(^\\d{{3}}$)}"), vec![
+            param("id", Some("int"), true),
+            param("code", Some("int"), true),
+        ]);
+        assert_eq!(summary("/items/{id:[0-9]+}/{*slug}"), vec![
+            param("id", Some("int"), true),
+            param("slug", Some("path"), true),
+        ]);
+        assert_eq!(summary("/blog/{page<\\d+>?1}"), vec![param("page", Some("int"), false)]);
+        assert_eq!(path_parameters("/blog/{page<\\d+>?1}")[0].default.as_deref(), Some("1"));
+        assert_eq!(summary("/static/{path...}"), vec![param("path", Some("path"), true)]);
+        assert_eq!(summary("/docs/{path*}"), vec![param("path", Some("path"), false)]);
+        assert_eq!(summary("/users[/{id:[0-9]+}[/{name}]]"), vec![
+            param("id", Some("int"), false),
+            param("name", None, false),
+        ]);
+    }
+
+    #[test]
+    fn test_angle_and_bracket_parameters() {
+        assert_eq!(summary("/users/<int:user_id>/files/<path:name>"), vec![
+            param("user_id", Some("int"), true),
+            param("name", Some("path"), true),
+        ]);
+        assert_eq!(summary("/hello/<name>/<rest..>"), vec![
+            param("name", None, true),
+            param("rest", Some("path"), true),
+        ]);
+        assert_eq!(summary("^articles/(?P<year>[0-9]{4})/$"), vec![param("year", Some("int"), true)]);
+        assert_eq!(summary("/api/posts/[id=integer]/[[...slug]]"), vec![
+            param("id", Some("integer"), true),
+            param("slug", Some("path"), false),
+        ]);
+        assert_eq!(summary("/api/[category]/[...rest]"), vec![
+            param("category", None, true),
+            param("rest", Some("path"), true),
+        ]);
+        assert_eq!(summary("/api/users/$id/$"), vec![
+            param("id", None, true),
+            param("wildcard", Some("path"), true),
+        ]);
+    }
+
+    #[test]
+    fn test_route_templates() {
+        for path in ["/users/<int:user_id>", "/users/:user_id(\\d+)", "/users/{user_id:int}", "/users/[user_id]/", "^users/(?P<user_id>[0-9]+)/$"] {
+            assert_eq!(route_template(path), "/users/{user_id}", "{}", path);
+        }
+        assert_eq!(route_template("/photos(/:id)(.:format)"), "/photos/{id}.{format}");
+        assert_eq!(route_template("/users{/:id}"), "/users/{id}");
+        assert_eq!(route_template("/users[/{id:[0-9]+}]"), "/users/{id}");
+        assert_eq!(route_template("/blog/{page<\\d+>?1}"), "/blog/{page}");
+        assert_eq!(route_template("/assets/*filepath"), "/assets/{filepath}");
+        assert_eq!(route_template("/docs/[[...slug]]"), "/docs/{slug}");
+        assert_eq!(route_template("/api/users/$id/$"), "/api/users/{id}/{wildcard}");
+        assert_eq!(route_template("/hello/<rest..>"), "/hello/{rest}");
+        assert_eq!(route_template("users"), "/users");
+        assert_eq!(route_template("/"), "/");
+    }
+
+    #[test]
+    fn test_describe_path_keeps_declared_types() {
+        let mut endpoint = Endpoint {
+            method: crate::HttpMethod::Get,
+            path: "/users/{id}/posts/{post}".to_string(),
+            handler: "show".to_string(),
+            line: 1,
+            column: 1,
+            documentation: None,
+            parameters: vec![
+                Parameter { name: "page".to_string(), location: ParameterLocation::Query, param_type: None, required: false, default: None },
+                Parameter { name: "id".to_string(), location: ParameterLocation::Path, param_type: Some("Long".to_string()), required: true, default: None },
+            ],
+            tags: Vec::new(),
+            middleware: Vec::new(),
+            template: String::new(),
+            body: None,
+            responses: Vec::new(),
+            requires_auth: false,
+        };
+
+        describe_path(&mut endpoint);
+        describe_path(&mut endpoint);
+        assert_eq!(endpoint.template, "/users/{id}/posts/{post}");
+
+        let names: Vec<_> = endpoint.parameters.iter().map(|p| (p.name.as_str(), p.param_type.as_deref())).collect();
+        assert_eq!(names, vec![("page", None), ("id", Some("Long")), ("post", None)]);
+    }
+
+    #[test]
+    fn test_add_inputs_merges_repeated_reads() {
+        let mut parameters = vec![input("page", ParameterLocation::Query, None)];
+        add_inputs(&mut parameters, &[
+            input("page", ParameterLocation::Query, Some("1".to_string())),
+            input("X-Tenant", ParameterLocation::Header, None),
+            input("x-tenant", ParameterLocation::Header, None),
+            input("page", ParameterLocation::Header, None),
+        ]);
+
+        let inputs: Vec<_> = parameters.iter().map(|p| (p.name.as_str(), p.location.clone(), p.default.as_deref())).collect();
+        assert_eq!(inputs, vec![
+            ("page", ParameterLocation::Query, Some("1")),
+            ("X-Tenant", ParameterLocation::Header, None),
+            ("page", ParameterLocation::Header, None),
+        ]);
+    }
+}
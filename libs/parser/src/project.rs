@@ -0,0 +1,897 @@
+use crate::auth::requires_auth;
+use crate::{detect_language, parser_for_language, Documentation, Endpoint, Parameter, Response, Schema};
+use crate::params::{add_inputs, describe_path};
+use crate::responses::add_response;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Maximum number of import/export hops followed when resolving a binding.
+const MAX_RESOLVE_DEPTH: usize = 16;
+
+/// Route facts extracted from a single source file, before cross-file linking.
+///
+/// Endpoints are recorded against the local binding they were registered on
+/// (`router` in `router.get(...)`), and mounts describe how those bindings are
+/// attached to each other. Linking resolves bindings through imports and
+/// exports and prepends every mount prefix on the way to the root.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleRoutes {
+    pub endpoints: Vec<RoutedEndpoint>,
+    pub mounts: Vec<Mount>,
+    /// Local binding -> where it was imported from.
+    pub imports: HashMap<String, Import>,
+    /// Exported name (`default` for default exports) -> local binding.
+    pub exports: HashMap<String, String>,
+    /// Local binding -> prefix the router applies to its own routes.
+    pub prefixes: HashMap<String, String>,
+    /// Local bindings whose prefix is only used when they are mounted
+    /// without one: a Flask blueprint's `url_prefix` is replaced by the one
+    /// passed to `register_blueprint`.
+    pub default_prefixes: HashSet<String>,
+    /// Local binding -> API version its routes are served under, which only
+    /// shows in the path when the app versions by URI (NestJS `@Version('2')`).
+    pub versions: HashMap<String, String>,
+    /// Segment before the version number (`v` in `/v2/users`) when this file
+    /// turns on URI versioning: NestJS `app.enableVersioning({ type: VersioningType.URI })`.
+    pub uri_versioning: Option<String>,
+    /// Local binding -> tags the router adds to its own routes.
+    pub tags: HashMap<String, Vec<String>>,
+    /// Local binding -> middleware the router runs before its own routes.
+    pub middleware: HashMap<String, Vec<String>>,
+    /// Express-style `x.use(...)` calls, in source order.
+    pub uses: Vec<Use>,
+    /// Named body shapes declared in this file: Pydantic models, DTO
+    /// classes, zod/joi/yup schemas and Laravel form requests.
+    pub schemas: HashMap<String, Schema>,
+    /// `Controller@method` -> what the method's definition declares, for
+    /// routes registered in another file.
+    pub handlers: HashMap<String, Handler>,
+}
+
+/// Facts read from a handler's definition rather than its route registration.
+#[derive(Debug, Clone, Default)]
+pub struct Handler {
+    pub body: Option<Schema>,
+    /// Query parameters and headers the handler reads.
+    pub parameters: Vec<Parameter>,
+    /// Responses the handler sends.
+    pub responses: Vec<Response>,
+    /// Docblock or docstring on the handler's definition.
+    pub documentation: Option<Documentation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RoutedEndpoint {
+    pub owner: String,
+    pub endpoint: Endpoint,
+}
+
+/// `owner.use(...)`: middleware run by the routes and routers registered on
+/// `owner` after it, under `prefix`.
+///
+/// Routers passed to `use` are mounts as well, so which arguments are
+/// middleware is only known once the project is linked.
+#[derive(Debug, Clone)]
+pub struct Use {
+    pub owner: String,
+    pub line: usize,
+    pub column: usize,
+    pub prefix: String,
+    /// Each argument after the prefix: its binding, or its source text
+    /// when it can't be mounted (`passport.authenticate('jwt')`).
+    pub handlers: Vec<String>,
+}
+
+/// `parent` serves everything registered on `target` under `prefix`.
+#[derive(Debug, Clone)]
+pub struct Mount {
+    pub parent: String,
+    pub prefix: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Import {
+    /// Module specifier as written in the source (`./routes/users`).
+    pub source: String,
+    /// Imported name; `None` is the default export, `*` the whole module.
+    pub name: Option<String>,
+}
+
+/// What a route inherits from the routers it is served through.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Inherited {
+    prefix: String,
+    tags: Vec<String>,
+    middleware: Vec<String>,
+}
+
+/// An endpoint together with the file it was declared in.
+#[derive(Debug, Clone)]
+pub struct ProjectEndpoint {
+    pub file: PathBuf,
+    pub endpoint: Endpoint,
+}
+
+impl ModuleRoutes {
+    /// Wrap endpoints from a parser that knows nothing about routers.
+    pub fn from_endpoints(endpoints: Vec<Endpoint>) -> Self {
+        Self {
+            endpoints: endpoints
+                .into_iter()
+                .map(|endpoint| RoutedEndpoint { owner: String::new(), endpoint })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Resolve mounts declared within this file only.
+    pub fn into_endpoints(self) -> Vec<Endpoint> {
+        link(&[(PathBuf::new(), self)])
+            .into_iter()
+            .map(|e| e.endpoint)
+            .collect()
+    }
+}
+
+/// Endpoints found across a project, and the files that could not be parsed.
+#[derive(Debug, Default)]
+pub struct ProjectParse {
+    pub endpoints: Vec<ProjectEndpoint>,
+    /// Files left out of linking because their parser failed, with the error.
+    pub failures: Vec<(PathBuf, anyhow::Error)>,
+}
+
+/// Parse a set of files together so router mounts can be followed across modules.
+///
+/// Files in languages without a parser are skipped, and so are files that
+/// fail to parse: the rest of the project is still linked.
+pub fn parse_project(files: &[(PathBuf, String)]) -> ProjectParse {
+    let mut project = ProjectModules::default();
+    let failures = project.add_files(files);
+
+    ProjectParse {
+        endpoints: project.link(),
+        failures,
+    }
+}
+
+/// The parsed modules of a project, kept so a changed file can be parsed
+/// again and linked against the rest without re-reading the project.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectModules {
+    modules: Vec<(PathBuf, ModuleRoutes)>,
+    index: HashMap<PathBuf, usize>,
+}
+
+impl ProjectModules {
+    /// Parse `files` into the project, returning the ones that failed.
+    pub fn add_files(&mut self, files: &[(PathBuf, String)]) -> Vec<(PathBuf, anyhow::Error)> {
+        files
+            .iter()
+            .filter_map(|(path, content)| self.update(path, content).err().map(|err| (path.clone(), err)))
+            .collect()
+    }
+
+    /// Parse `content` as the current version of `path`, replacing the
+    /// module parsed from it before. Files without a parser are ignored.
+    pub fn update(&mut self, path: &Path, content: &str) -> anyhow::Result<()> {
+        let parser = match detect_language(path).and_then(parser_for_language) {
+            Some(parser) => parser,
+            None => return Ok(()),
+        };
+        let module = parser.parse_module(path, content)?;
+
+        match self.index.get(path) {
+            Some(&i) => self.modules[i].1 = module,
+            None => {
+                self.index.insert(path.to_path_buf(), self.modules.len());
+                self.modules.push((path.to_path_buf(), module));
+            }
+        }
+        Ok(())
+    }
+
+    /// Link every module of the project.
+    pub fn link(&self) -> Vec<ProjectEndpoint> {
+        link(&self.modules)
+    }
+
+    /// Endpoints declared in `path`, linked against the rest of the project.
+    pub fn endpoints_in(&self, path: &Path) -> Vec<Endpoint> {
+        self.link()
+            .into_iter()
+            .filter(|e| e.file == path)
+            .map(|e| e.endpoint)
+            .collect()
+    }
+}
+
+/// [`parse_project`], keeping only the endpoints.
+pub fn parse_files(files: &[(PathBuf, String)]) -> Vec<ProjectEndpoint> {
+    parse_project(files).endpoints
+}
+
+/// Join a mount prefix and a route path, leaving the path untouched when
+/// there is no prefix.
+pub fn join_route(prefix: &str, path: &str) -> String {
+    let trimmed = prefix.trim_end_matches('/');
+    if trimmed.is_empty() {
+        // Mounting at "/" serves an empty path at the root
+        if path.is_empty() && !prefix.is_empty() {
+            return "/".to_string();
+        }
+        return path.to_string();
+    }
+    let prefix = trimmed;
+
+    let prefix = if prefix.starts_with('/') {
+        prefix.to_string()
+    } else {
+        format!("/{}", prefix)
+    };
+
+    let path = path.trim_start_matches('/');
+    if path.is_empty() {
+        prefix
+    } else {
+        format!("{}/{}", prefix, path)
+    }
+}
+
+type Node = (usize, String);
+
+enum Resolved {
+    Module(usize),
+    Binding(usize, String),
+}
+
+struct Linker<'m> {
+    modules: &'m [(PathBuf, ModuleRoutes)],
+    index: HashMap<PathBuf, usize>,
+    /// Mount prefix and middleware of each mount of a node, by node.
+    incoming: HashMap<Node, Vec<(Node, String, Vec<String>)>>,
+    /// Nodes with routes or mounts of their own.
+    routers: HashSet<Node>,
+    /// Version prefix when some module turns on URI versioning.
+    uri_versioning: Option<String>,
+}
+
+fn link(modules: &[(PathBuf, ModuleRoutes)]) -> Vec<ProjectEndpoint> {
+    let index = modules
+        .iter()
+        .enumerate()
+        .map(|(i, (path, _))| (normalize(path), i))
+        .collect();
+
+    let uri_versioning = modules.iter().find_map(|(_, module)| module.uri_versioning.clone());
+    let mut linker = Linker {
+        modules,
+        index,
+        incoming: HashMap::new(),
+        routers: HashSet::new(),
+        uri_versioning,
+    };
+
+    let mut routers = HashSet::new();
+    for (i, (_, module)) in linker.modules.iter().enumerate() {
+        routers.extend(module.endpoints.iter().map(|routed| linker.canonical(i, &routed.owner)));
+        routers.extend(module.mounts.iter().map(|mount| linker.canonical(i, &mount.parent)));
+    }
+    linker.routers = routers;
+
+    let mut incoming: HashMap<Node, Vec<(Node, String, Vec<String>)>> = HashMap::new();
+    for (i, (_, module)) in linker.modules.iter().enumerate() {
+        for mount in &module.mounts {
+            let parent = linker.canonical(i, &mount.parent);
+            if let Some(target) = linker.resolve(i, &mount.target) {
+                let middleware = linker.mount_middleware(i, mount);
+                incoming.entry(target).or_default().push((parent, mount.prefix.clone(), middleware));
+            }
+        }
+    }
+    linker.incoming = incoming;
+
+    let mut endpoints = Vec::new();
+    // A route reached twice the same way, like one declared for two API
+    // versions when versions don't show in the path, is served once
+    let mut seen = HashSet::new();
+    for (i, (path, module)) in linker.modules.iter().enumerate() {
+        for routed in &module.endpoints {
+            let node = linker.canonical(i, &routed.owner);
+            for inherited in linker.inherited(&node, &mut Vec::new()) {
+                let mut endpoint = routed.endpoint.clone();
+                endpoint.path = join_route(&inherited.prefix, &endpoint.path);
+                // Routes that never receive a path (view classes nobody
+                // registers) are not served
+                if endpoint.path.is_empty() {
+                    continue;
+                }
+                if !seen.insert((i, format!("{:?}", endpoint.method), endpoint.path.clone(), endpoint.line, endpoint.column)) {
+                    continue;
+                }
+                endpoint.tags = concat(&inherited.tags, &endpoint.tags);
+                let used = linker.used_before(i, &routed.owner, (endpoint.line, endpoint.column), &routed.endpoint.path);
+                endpoint.middleware = concat(&inherited.middleware, &concat(&used, &endpoint.middleware));
+                endpoint.requires_auth = requires_auth(&endpoint.middleware);
+                describe_path(&mut endpoint);
+                linker.describe_handler(i, &mut endpoint);
+                endpoints.push(ProjectEndpoint {
+                    file: path.clone(),
+                    endpoint,
+                });
+            }
+        }
+    }
+
+    endpoints
+}
+
+impl Linker<'_> {
+    /// Middleware `use`d on `owner` in `module` before `position`, for
+    /// routes at `path` on it.
+    fn used_before(&self, module: usize, owner: &str, position: (usize, usize), path: &str) -> Vec<String> {
+        let mut middleware = Vec::new();
+        for registered in &self.modules[module].1.uses {
+            if registered.owner == owner && (registered.line, registered.column) < position && serves(&registered.prefix, path) {
+                middleware.extend(registered.handlers.iter().filter(|h| self.is_middleware(module, h)).cloned());
+            }
+        }
+        middleware
+    }
+
+    /// Middleware a router runs through when mounted: what its parent
+    /// `use`d earlier, then the arguments before it in the same call
+    /// (`app.use('/api', auth, router)`).
+    fn mount_middleware(&self, module: usize, mount: &Mount) -> Vec<String> {
+        let registered = self.modules[module].1.uses.iter().find(|u| {
+            u.owner == mount.parent && u.prefix == mount.prefix && u.handlers.contains(&mount.target)
+        });
+        let Some(registered) = registered else {
+            return Vec::new();
+        };
+        let mut middleware = self.used_before(module, &mount.parent, (registered.line, registered.column), &mount.prefix);
+        for handler in registered.handlers.iter().take_while(|h| **h != mount.target) {
+            if self.is_middleware(module, handler) && !middleware.contains(handler) {
+                middleware.push(handler.clone());
+            }
+        }
+        middleware
+    }
+
+    /// Whether a `use` argument is middleware rather than a router. Names
+    /// imported from packages are middleware; ones imported from files that
+    /// weren't parsed may be routers and are left out.
+    fn is_middleware(&self, module: usize, name: &str) -> bool {
+        match self.resolve(module, name) {
+            Some(node) => !self.routers.contains(&node),
+            None => {
+                let head = name.split('.').next().unwrap_or(name);
+                self.modules[module].1.imports.get(head).is_none_or(|import| !import.source.starts_with('.'))
+            }
+        }
+    }
+
+    /// Take the body, the inputs the handler reads, the responses it sends
+    /// and its documentation from its definition when the route didn't
+    /// declare them, and expand the named shapes they refer to.
+    fn describe_handler(&self, module: usize, endpoint: &mut Endpoint) {
+        let definition = self.handler(module, &endpoint.handler);
+        let mut module = module;
+        if let Some((defined_in, handler)) = definition {
+            add_inputs(&mut endpoint.parameters, &handler.parameters);
+            for response in &handler.responses {
+                add_response(&mut endpoint.responses, response.clone());
+            }
+            if endpoint.body.is_none() {
+                module = defined_in;
+                endpoint.body = handler.body.clone();
+            }
+            if endpoint.documentation.is_none() {
+                endpoint.documentation = handler.documentation.clone();
+            }
+        }
+        let schemas = endpoint.body.iter_mut()
+            .chain(endpoint.responses.iter_mut().filter_map(|r| r.body.as_mut()));
+        for schema in schemas {
+            self.expand(module, schema, &mut Vec::new());
+        }
+    }
+
+    /// The definition of the handler a route in `module` names: in the same
+    /// file, then through its imports (`views.list_users`). Laravel's
+    /// `PostController@index` names its controller class rather than
+    /// importing it, and Go shares functions across the files of a package,
+    /// so those are also looked up in PHP files and in the Go package.
+    fn handler(&self, module: usize, handler: &str) -> Option<(usize, &Handler)> {
+        let lookup = |i: usize, name: &str| {
+            let short = name.rsplit(['\\', '.']).next().unwrap_or(name);
+            let handlers = &self.modules[i].1.handlers;
+            handlers.get(name).or_else(|| handlers.get(short)).map(|h| (i, h))
+        };
+        if let Some(found) = lookup(module, handler) {
+            return Some(found);
+        }
+        if let Some((target, name)) = self.resolve(module, handler).filter(|(target, _)| *target != module) {
+            if let Some(found) = lookup(target, &name) {
+                return Some(found);
+            }
+        }
+
+        let path = normalize(&self.modules[module].0);
+        let related: Box<dyn Fn(&Path) -> bool> = match detect_language(&path)? {
+            "php" => Box::new(|other: &Path| detect_language(other) == Some("php")),
+            "go" => {
+                // `h.ListUsers` in this package, `handlers.GetUser` in package `handlers`
+                let qualifier = handler.split_once('.').map(|(qualifier, _)| qualifier.to_string());
+                Box::new(move |other: &Path| {
+                    let dir = other.parent();
+                    detect_language(other) == Some("go")
+                        && (dir == path.parent() || dir.and_then(Path::file_name).and_then(|n| n.to_str()) == qualifier.as_deref())
+                })
+            }
+            _ => return None,
+        };
+        self.modules.iter().enumerate()
+            .filter(|(i, (other, _))| *i != module && related(&normalize(other)))
+            .find_map(|(i, _)| lookup(i, handler))
+    }
+
+    /// Replace `$ref`s with the shapes they name, leaving recursive and
+    /// unknown references in place.
+    fn expand(&self, module: usize, schema: &mut Schema, expanding: &mut Vec<String>) {
+        if let Some(name) = schema.reference.clone() {
+            if expanding.contains(&name) || expanding.len() >= MAX_RESOLVE_DEPTH {
+                return;
+            }
+            let (defined_in, definition) = match self.schema(module, &name) {
+                Some(found) => found,
+                None => return,
+            };
+            *schema = definition.clone();
+            expanding.push(name);
+            self.expand(defined_in, schema, expanding);
+            expanding.pop();
+            return;
+        }
+        self.expand_children(module, schema, expanding);
+    }
+
+    fn expand_children(&self, module: usize, schema: &mut Schema, expanding: &mut Vec<String>) {
+        for property in &mut schema.properties {
+            self.expand(module, &mut property.schema, expanding);
+        }
+        if let Some(items) = &mut schema.items {
+            self.expand(module, items, expanding);
+        }
+    }
+
+    /// The schema `name` refers to from `module`: a local or imported
+    /// definition, or else the project's only schema of that name.
+    fn schema(&self, module: usize, name: &str) -> Option<(usize, &Schema)> {
+        if let Some((target, binding)) = self.resolve(module, name) {
+            if let Some(schema) = self.modules[target].1.schemas.get(&binding) {
+                return Some((target, schema));
+            }
+        }
+        let short = name.rsplit(['.', '\\']).next().unwrap_or(name);
+        if let Some(schema) = self.modules[module].1.schemas.get(short) {
+            return Some((module, schema));
+        }
+        // Names whose import wasn't followed (PHP `use`, Java packages) only
+        // resolve when a single file in the project defines them
+        let mut definitions = self.modules.iter().enumerate().filter_map(|(i, (_, m))| m.schemas.get(short).map(|s| (i, s)));
+        match (definitions.next(), definitions.next()) {
+            (Some(definition), None) => Some(definition),
+            _ => None,
+        }
+    }
+
+    fn canonical(&self, module: usize, binding: &str) -> Node {
+        self.resolve(module, binding)
+            .unwrap_or_else(|| (module, binding.to_string()))
+    }
+
+    /// Follow imports until `binding` names something defined in a parsed file.
+    fn resolve(&self, mut module: usize, binding: &str) -> Option<Node> {
+        let mut binding = binding.to_string();
+
+        for _ in 0..MAX_RESOLVE_DEPTH {
+            let (head, rest) = match binding.split_once('.') {
+                Some((head, rest)) => (head.to_string(), Some(rest.to_string())),
+                None => (binding.clone(), None),
+            };
+
+            let import = match self.modules[module].1.imports.get(&head) {
+                Some(import) => import,
+                None => return Some((module, binding)),
+            };
+
+            match (self.resolve_import(module, import)?, rest) {
+                (Resolved::Binding(target, name), None) => {
+                    module = target;
+                    binding = name;
+                }
+                (Resolved::Binding(target, name), Some(rest)) => {
+                    module = target;
+                    binding = format!("{}.{}", name, rest);
+                }
+                (Resolved::Module(target), Some(rest)) => {
+                    let exports = &self.modules[target].1.exports;
+                    let (first, tail) = match rest.split_once('.') {
+                        Some((first, tail)) => (first, Some(tail)),
+                        None => (rest.as_str(), None),
+                    };
+                    let first = exports.get(first).map(String::as_str).unwrap_or(first);
+                    module = target;
+                    binding = match tail {
+                        Some(tail) => format!("{}.{}", first, tail),
+                        None => first.to_string(),
+                    };
+                }
+                (Resolved::Module(_), None) => return None,
+            }
+        }
+
+        None
+    }
+
+    fn resolve_import(&self, module: usize, import: &Import) -> Option<Resolved> {
+        let path = &self.modules[module].0;
+        let target = match detect_language(path)? {
+            "javascript" | "typescript" | "tsx" => self.resolve_js_module(path, &import.source)?,
+            "go" => return self.resolve_go_import(path, import),
+            "rust" => return self.resolve_rust_import(import),
+            "csharp" => return self.resolve_csharp_import(import),
+            "python" => return self.resolve_python_import(path, import),
+            "kotlin" => return self.resolve_kotlin_import(path, import),
+            _ => return None,
+        };
+
+        let exports = &self.modules[target].1.exports;
+        Some(match import.name.as_deref() {
+            Some("*") => Resolved::Module(target),
+            None => match exports.get("default") {
+                Some(local) => Resolved::Binding(target, local.clone()),
+                None => Resolved::Module(target),
+            },
+            Some(name) => Resolved::Binding(
+                target,
+                exports.get(name).cloned().unwrap_or_else(|| name.to_string()),
+            ),
+        })
+    }
+
+    /// Resolve a relative `require`/`import` specifier the way Node and
+    /// bundlers do: exact file, then known extensions, then `index` files.
+    fn resolve_js_module(&self, from: &Path, source: &str) -> Option<usize> {
+        if !source.starts_with('.') {
+            return None;
+        }
+
+        let base = normalize(&from.parent()?.join(source));
+        if let Some(&i) = self.index.get(&base) {
+            return Some(i);
+        }
+
+        const EXTENSIONS: [&str; 6] = ["js", "ts", "mjs", "cjs", "tsx", "jsx"];
+        for ext in EXTENSIONS {
+            let file = PathBuf::from(format!("{}.{}", base.display(), ext));
+            if let Some(&i) = self.index.get(&file) {
+                return Some(i);
+            }
+        }
+        for ext in EXTENSIONS {
+            if let Some(&i) = self.index.get(&base.join(format!("index.{}", ext))) {
+                return Some(i);
+            }
+        }
+
+        None
+    }
+
+    /// Go imports name a package (a directory), not a file: look the name up in
+    /// the exports of every file in that package. An empty source is the
+    /// importing file's own package, falling back to any package for method
+    /// calls on values whose type is declared elsewhere.
+    fn resolve_go_import(&self, from: &Path, import: &Import) -> Option<Resolved> {
+        let name = import.name.as_deref()?;
+        let dir = if import.source.is_empty() {
+            normalize(from.parent()?)
+        } else {
+            self.go_package_dir(&import.source)?
+        };
+
+        let find = |same_package: bool| {
+            self.modules.iter().enumerate().find_map(|(i, (path, module))| {
+                let path = normalize(path);
+                if path.extension().and_then(|e| e.to_str()) != Some("go")
+                    || (same_package && path.parent() != Some(dir.as_path()))
+                {
+                    return None;
+                }
+                module.exports.get(name).map(|local| Resolved::Binding(i, local.clone()))
+            })
+        };
+
+        find(true).or_else(|| if import.source.is_empty() { find(false) } else { None })
+    }
+
+    /// Kotlin imports name a package and a declaration, and functions from the
+    /// importing file's own package need no import at all: look the name up in
+    /// the exports of Kotlin files in the package directory, then anywhere.
+    fn resolve_kotlin_import(&self, from: &Path, import: &Import) -> Option<Resolved> {
+        let name = import.name.as_deref()?;
+        let package: PathBuf = import.source.split('.').collect();
+        let is_kotlin = |path: &Path| matches!(path.extension().and_then(|e| e.to_str()), Some("kt" | "kts"));
+
+        let find = |in_package: &dyn Fn(&Path) -> bool| {
+            self.modules.iter().enumerate().find_map(|(i, (path, module))| {
+                let path = normalize(path);
+                if !is_kotlin(&path) || !in_package(path.parent()?) {
+                    return None;
+                }
+                module.exports.get(name).map(|local| Resolved::Binding(i, local.clone()))
+            })
+        };
+
+        let same_dir = normalize(from.parent()?);
+        if import.source.is_empty() {
+            find(&|dir: &Path| dir == same_dir).or_else(|| find(&|_: &Path| true))
+        } else {
+            find(&|dir: &Path| dir.ends_with(&package)).or_else(|| find(&|_: &Path| true))
+        }
+    }
+
+    /// Map a Go import path (`example.com/app/internal/routes`) to a parsed
+    /// directory by the longest matching trailing path segments; the module
+    /// path from go.mod is not needed.
+    fn go_package_dir(&self, import_path: &str) -> Option<PathBuf> {
+        let segments: Vec<&str> = import_path.split('/').collect();
+        let dirs: Vec<PathBuf> = self.index.keys()
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("go"))
+            .filter_map(|path| path.parent().map(Path::to_path_buf))
+            .collect();
+
+        (0..segments.len()).find_map(|skip| {
+            let suffix: PathBuf = segments[skip..].iter().collect();
+            dirs.iter().find(|dir| dir.ends_with(&suffix)).cloned()
+        })
+    }
+
+    /// Rust paths name modules, which map to files by convention
+    /// (`crate::routes::users` -> `src/routes/users.rs` or `.../users/mod.rs`).
+    /// Pick the file exporting the name whose module path matches the most
+    /// trailing segments of the source path.
+    fn resolve_rust_import(&self, import: &Import) -> Option<Resolved> {
+        let name = import.name.as_deref()?;
+        let segments: Vec<&str> = import.source
+            .split("::")
+            .filter(|s| !matches!(*s, "" | "crate" | "self" | "super"))
+            .collect();
+
+        self.modules.iter()
+            .enumerate()
+            .filter(|(_, (path, _))| path.extension().and_then(|e| e.to_str()) == Some("rs"))
+            .filter_map(|(i, (path, module))| {
+                let local = module.exports.get(name)?;
+                let mut module_path: Vec<String> = normalize(&path.with_extension(""))
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().to_string())
+                    .collect();
+                if matches!(module_path.last().map(String::as_str), Some("mod" | "lib" | "main")) {
+                    module_path.pop();
+                }
+                let matched = segments.iter()
+                    .rev()
+                    .zip(module_path.iter().rev())
+                    .take_while(|(segment, component)| **segment == component.as_str())
+                    .count();
+                Some((matched, i, local.clone()))
+            })
+            .max_by_key(|(matched, i, _)| (*matched, std::cmp::Reverse(*i)))
+            .map(|(_, i, local)| Resolved::Binding(i, local))
+    }
+
+    /// C# namespaces are not tied to file paths, so a name resolves to the
+    /// first `.cs` file that exports it.
+    fn resolve_csharp_import(&self, import: &Import) -> Option<Resolved> {
+        let name = import.name.as_deref()?;
+        self.modules.iter().enumerate().find_map(|(i, (path, module))| {
+            if path.extension().and_then(|e| e.to_str()) != Some("cs") {
+                return None;
+            }
+            module.exports.get(name).map(|local| Resolved::Binding(i, local.clone()))
+        })
+    }
+
+    /// `from app.routers import users` may name a submodule (`users.py`) or a
+    /// name defined in the package; module-level Python names need no export.
+    fn resolve_python_import(&self, from: &Path, import: &Import) -> Option<Resolved> {
+        match import.name.as_deref() {
+            Some("*") => self.python_module(from, &import.source).map(Resolved::Module),
+            Some(name) => {
+                let submodule = if import.source.ends_with('.') {
+                    format!("{}{}", import.source, name)
+                } else {
+                    format!("{}.{}", import.source, name)
+                };
+                if let Some(target) = self.python_module(from, &submodule) {
+                    return Some(Resolved::Module(target));
+                }
+                let target = self.python_module(from, &import.source)?;
+                let exports = &self.modules[target].1.exports;
+                Some(Resolved::Binding(target, exports.get(name).cloned().unwrap_or_else(|| name.to_string())))
+            }
+            None => None,
+        }
+    }
+
+    /// Map a dotted module path to a parsed `.py` file or package
+    /// `__init__.py`. Relative paths (`.users`, `..core`) start from the
+    /// importing file; absolute ones match trailing path segments, since the
+    /// package root is not known.
+    fn python_module(&self, from: &Path, source: &str) -> Option<usize> {
+        let dotted = source.trim_start_matches('.');
+        let segments: PathBuf = dotted.split('.').filter(|s| !s.is_empty()).collect();
+        let candidates = [segments.with_extension("py"), segments.join("__init__.py")];
+
+        let levels = source.len() - dotted.len();
+        if levels > 0 {
+            let mut base = normalize(from.parent()?);
+            for _ in 1..levels {
+                base.pop();
+            }
+            return candidates.iter().find_map(|candidate| self.index.get(&base.join(candidate)).copied());
+        }
+
+        candidates.iter().find_map(|candidate| {
+            self.index.iter()
+                .filter(|(path, _)| path.ends_with(candidate))
+                .map(|(_, &i)| i)
+                .min()
+        })
+    }
+
+    /// Every full prefix under which routes registered on `node` are served,
+    /// with the tags and middleware collected on the way down from the root.
+    fn inherited(&self, node: &Node, stack: &mut Vec<Node>) -> Vec<Inherited> {
+        let module = &self.modules[node.0].1;
+        let version = module.versions.get(&node.1)
+            .zip(self.uri_versioning.as_ref())
+            .map(|(version, prefix)| format!("/{}{}", prefix, version));
+        let own = Inherited {
+            prefix: version.or_else(|| module.prefixes.get(&node.1).cloned()).unwrap_or_default(),
+            tags: module.tags.get(&node.1).cloned().unwrap_or_default(),
+            middleware: module.middleware.get(&node.1).cloned().unwrap_or_default(),
+        };
+
+        let mut inherited = Vec::new();
+        if let Some(parents) = self.incoming.get(node) {
+            stack.push(node.clone());
+            for (parent, mount_prefix, mount_middleware) in parents {
+                if stack.contains(parent) {
+                    continue;
+                }
+                let own_prefix = if !mount_prefix.is_empty() && module.default_prefixes.contains(&node.1) { "" } else { own.prefix.as_str() };
+                for outer in self.inherited(parent, stack) {
+                    let scope = Inherited {
+                        prefix: join_route(&join_route(&outer.prefix, mount_prefix), own_prefix),
+                        tags: concat(&outer.tags, &own.tags),
+                        middleware: concat(&concat(&outer.middleware, mount_middleware), &own.middleware),
+                    };
+                    if !inherited.contains(&scope) {
+                        inherited.push(scope);
+                    }
+                }
+            }
+            stack.pop();
+        }
+
+        if inherited.is_empty() {
+            inherited.push(own);
+        }
+        inherited
+    }
+}
+
+/// Whether middleware `use`d under `prefix` runs for `path`: Express matches
+/// whole segments, Hono writes `/api/*`.
+fn serves(prefix: &str, path: &str) -> bool {
+    let prefix = prefix.trim_end_matches('*').trim_end_matches('/');
+    prefix.is_empty() || path == prefix || path.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// `outer` followed by the items of `inner` not already in it.
+fn concat(outer: &[String], inner: &[String]) -> Vec<String> {
+    let mut items = outer.to_vec();
+    for item in inner {
+        if !items.contains(item) {
+            items.push(item.clone());
+        }
+    }
+    items
+}
+
+/// Lexically normalise a path so `a/./b/../c` and `a/c` compare equal.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_route() {
+        assert_eq!(join_route("", "/users"), "/users");
+        assert_eq!(join_route("/api", "/users"), "/api/users");
+        assert_eq!(join_route("/api/", "users"), "/api/users");
+        assert_eq!(join_route("/api", "/"), "/api");
+        assert_eq!(join_route("api", ""), "/api");
+        assert_eq!(join_route("/", ""), "/");
+    }
+
+    #[test]
+    fn test_handlers_are_not_taken_from_unrelated_files() {
+        let files = vec![
+            (PathBuf::from("app/main.py"), r#"
+@app.post("/items")
+def create(limit: int = 10):
+    """Create an item."""
+    return {}
+"#.to_string()),
+            (PathBuf::from("src/cats.controller.ts"), r#"
+@Controller('cats')
+export class CatsController {
+  @Post()
+  create() {}
+}
+"#.to_string()),
+        ];
+
+        let endpoints = parse_files(&files);
+        let cats = endpoints.iter().find(|e| e.endpoint.path == "/cats").unwrap();
+        assert!(cats.endpoint.documentation.is_none());
+        assert!(cats.endpoint.parameters.is_empty());
+
+        let items = endpoints.iter().find(|e| e.endpoint.path == "/items").unwrap();
+        assert_eq!(items.endpoint.documentation.as_ref().and_then(|d| d.summary.as_deref()), Some("Create an item."));
+    }
+
+    #[test]
+    fn test_ambiguous_schema_names_stay_references() {
+        let model = |field: &str| format!("class Item(BaseModel):\n    {}: str\n", field);
+        let route = "@app.post(\"/items\")\ndef create(item: Item):\n    return {}\n";
+        let files = vec![
+            (PathBuf::from("orders/api.py"), route.to_string()),
+            (PathBuf::from("orders/models.py"), model("sku")),
+            (PathBuf::from("billing/models.py"), model("invoice")),
+        ];
+
+        let endpoints = parse_files(&files);
+        let body = endpoints[0].endpoint.body.as_ref().unwrap();
+        assert_eq!(body.reference.as_deref(), Some("Item"));
+
+        let endpoints = parse_files(&files[..2]);
+        let body = endpoints[0].endpoint.body.as_ref().unwrap();
+        assert_eq!(body.properties[0].name, "sku");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(Path::new("./src/./routes/../app.js")), PathBuf::from("src/app.js"));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{Response, Schema};
+
+/// Reason phrases of the status codes handlers name through constants.
+const STATUS_NAMES: [(u16, &str); 30] = [
+    (200, "OK"),
+    (201, "CREATED"),
+    (202, "ACCEPTED"),
+    (204, "NO_CONTENT"),
+    (301, "MOVED_PERMANENTLY"),
+    (302, "FOUND"),
+    (303, "SEE_OTHER"),
+    (304, "NOT_MODIFIED"),
+    (307, "TEMPORARY_REDIRECT"),
+    (308, "PERMANENT_REDIRECT"),
+    (400, "BAD_REQUEST"),
+    (401, "UNAUTHORIZED"),
+    (402, "PAYMENT_REQUIRED"),
+    (403, "FORBIDDEN"),
+    (404, "NOT_FOUND"),
+    (405, "METHOD_NOT_ALLOWED"),
+    (406, "NOT_ACCEPTABLE"),
+    (408, "REQUEST_TIMEOUT"),
+    (409, "CONFLICT"),
+    (410, "GONE"),
+    (412, "PRECONDITION_FAILED"),
+    (413, "PAYLOAD_TOO_LARGE"),
+    (415, "UNSUPPORTED_MEDIA_TYPE"),
+    (422, "UNPROCESSABLE_ENTITY"),
+    (429, "TOO_MANY_REQUESTS"),
+    (500, "INTERNAL_SERVER_ERROR"),
+    (501, "NOT_IMPLEMENTED"),
+    (502, "BAD_GATEWAY"),
+    (503, "SERVICE_UNAVAILABLE"),
+    (504, "GATEWAY_TIMEOUT"),
+];
+
+/// The status code an expression names.
+///
+/// Numbers (`404`) and the constants frameworks provide are understood:
+/// `status.HTTP_404_NOT_FOUND` (FastAPI/DRF), `HttpStatus.NOT_FOUND` (Spring),
+/// `Response::HTTP_NOT_FOUND` (Symfony/Laravel), `StatusCodes.NOT_FOUND`
+/// (http-status-codes), `HTTPStatus.NOT_FOUND` and `http.StatusNotFound` (Go).
+pub fn status_code(expression: &str) -> Option<u16> {
+    let expression = expression.trim();
+    if let Ok(code) = expression.parse::<u16>() {
+        return (100..600).contains(&code).then_some(code);
+    }
+
+    let name = expression.rsplit(['.', ':']).next()?;
+    let name = name.strip_prefix("HTTP_").or_else(|| name.strip_prefix("Status")).unwrap_or(name);
+    let digits: String = name.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if !digits.is_empty() {
+        return status_code(&digits);
+    }
+
+    let normalized: String = name.chars().filter(|c| *c != '_').collect::<String>().to_uppercase();
+    STATUS_NAMES.iter()
+        .find(|(_, reason)| reason.replace('_', "") == normalized)
+        .map(|(code, _)| *code)
+}
+
+/// A response with the given status and body.
+pub fn response(status: u16, body: Option<Schema>) -> Response {
+    Response { status, body }
+}
+
+/// Add `response` unless its status is already listed; a listed status
+/// without a body takes the one found here.
+pub fn add_response(responses: &mut Vec<Response>, response: Response) {
+    match responses.iter_mut().find(|r| r.status == response.status) {
+        Some(listed) => {
+            if listed.body.is_none() {
+                listed.body = response.body;
+            }
+        }
+        None => responses.push(response),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_codes_from_constants() {
+        assert_eq!(status_code("404"), Some(404));
+        assert_eq!(status_code("status.HTTP_201_CREATED"), Some(201));
+        assert_eq!(status_code("HttpStatus.NO_CONTENT"), Some(204));
+        assert_eq!(status_code("Response::HTTP_UNPROCESSABLE_ENTITY"), Some(422));
+        assert_eq!(status_code("http.StatusNotFound"), Some(404));
+        assert_eq!(status_code("StatusCodes.OK"), Some(200));
+        assert_eq!(status_code("code"), None);
+        assert_eq!(status_code("42"), None);
+    }
+
+    #[test]
+    fn test_add_response_keeps_first_status() {
+        let mut responses = vec![response(404, None)];
+        add_response(&mut responses, response(200, Some(Schema::of_type("object"))));
+        add_response(&mut responses, response(404, Some(Schema::of_type("object"))));
+        add_response(&mut responses, response(200, Some(Schema::of_type("array"))));
+
+        let statuses: Vec<_> = responses.iter().map(|r| (r.status, r.body.as_ref().map(|b| b.schema_type.as_str()))).collect();
+        assert_eq!(statuses, vec![(404, Some("object")), (200, Some("object"))]);
+    }
+}
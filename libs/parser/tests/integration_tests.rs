@@ -6,11 +6,32 @@ mod issues; // Issue reproduction tests
 
 // Test modules are organized as follows:
 // - unit/: Framework-specific unit tests with isolated fixtures
-//   - unit::nextjs: Next.js framework detection and config generation
+//   - unit::nextjs: Next.js framework detection, config generation and file-based routes
 //   - unit::express: Express.js framework detection and patterns
-//   - unit::flask: Flask framework detection and patterns
-//   - unit::fastapi: FastAPI framework detection and patterns  
+//   - unit::nestjs: NestJS detection, controllers and module-linked global prefixes
+//   - unit::flask: Flask detection, blueprints and class-based views
+//   - unit::django: Django detection, URLconf includes and DRF routers
+//   - unit::fastapi: FastAPI detection and APIRouter/include_router resolution
 //   - unit::laravel: Laravel framework detection and patterns
+//   - unit::symfony: Symfony detection, #[Route] attributes, @Route annotations and routes.yaml
+//   - unit::slim: Slim detection and route groups
+//   - unit::gin: Gin detection and Go route group resolution
+//   - unit::echo: Echo framework detection
+//   - unit::actix: Actix-web detection and scope resolution
+//   - unit::axum: Axum detection and nested router resolution
+//   - unit::spring_boot: Spring Boot detection and controller mappings in Java and Kotlin
+//   - unit::ktor: Ktor detection, nested route blocks and Route extension functions
+//   - unit::rails: Rails detection and routes.rb expansion
+//   - unit::sinatra: Sinatra detection and route blocks
+//   - unit::phoenix: Phoenix detection, router scopes, pipelines and resources
+//   - unit::aspnetcore: ASP.NET Core detection, minimal API groups and controllers
+//   - unit::sveltekit: SvelteKit detection and +server.ts routes
+//   - unit::remix: Remix detection and flat-route loaders/actions
+//   - unit::nuxt: Nuxt detection and server/api routes
+//   - unit::fastify: Fastify detection, route objects and plugin prefixes
+//   - unit::koa: Koa detection and koa-router prefixes
+//   - unit::hono: Hono detection, basePath and chained routes
+//   - unit::hapi: Hapi detection and server.route arrays
 //   - unit::core: Core parsing functionality tests
 //
 // - projects/: Full project integration tests with realistic scenarios
@@ -0,0 +1,73 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:12:29.566651831+00:00
+// Version: 1.0.0
+// Debug Mode: false
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:12:29.566651831+00:00",
+    lastModified: "2026-10-17T04:12:29.566651831+00:00",
+    debugMode: false,
+  },
+
+  // Global configuration
+  debugMode: false,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 2894,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/actix/fixtures/basic_app",
+      framework: "actix",
+      confidence: 1,
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "actix.attributes",
+          files: "src/**/*.rs",
+          routes: ["#[{method}(\"{path}\")]"],
+          confidence: 0.95,
+        },
+        {
+          name: "actix.builder",
+          files: "src/**/*.rs",
+          routes: ["web::scope(\"{prefix}\").route(\"{path}\", web::{method}().to({handler}))", "web::resource(\"{path}\").route(web::{method}().to({handler}))"],
+          confidence: 0.9,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
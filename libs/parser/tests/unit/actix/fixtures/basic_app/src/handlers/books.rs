@@ -0,0 +1,21 @@
+use actix_web::{delete, get, web, HttpResponse, Responder};
+
+#[get("/books/{id}")]
+async fn get_book(path: web::Path<u32>) -> impl Responder {
+    HttpResponse::Ok().finish()
+}
+
+#[delete("/books/{id}")]
+async fn delete_book(path: web::Path<u32>) -> impl Responder {
+    HttpResponse::NoContent().finish()
+}
+
+async fn list_books() -> impl Responder {
+    HttpResponse::Ok().finish()
+}
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_book)
+        .service(delete_book)
+        .route("/books", web::get().to(list_books));
+}
@@ -0,0 +1,22 @@
+use actix_web::{web, App, HttpServer};
+
+mod handlers;
+
+use handlers::books;
+
+#[actix_web::get("/health")]
+async fn health() -> &'static str {
+    "ok"
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    HttpServer::new(|| {
+        App::new()
+            .service(health)
+            .service(web::scope("/api").configure(books::config))
+    })
+    .bind(("127.0.0.1", 8080))?
+    .run()
+    .await
+}
@@ -0,0 +1,15 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn resolves_scopes_through_configure_across_modules() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/books".to_string()),
+        (HttpMethod::Get, "/api/books/{id}".to_string()),
+        (HttpMethod::Delete, "/api/books/{id}".to_string()),
+        (HttpMethod::Get, "/health".to_string()),
+    ]);
+}
@@ -0,0 +1,80 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:23:08.600559423+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:23:08.600559423+00:00",
+    lastModified: "2026-10-17T04:23:08.600559423+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 6,
+    totalSize: 4918,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/aspnetcore/fixtures/basic_app",
+      framework: "aspnetcore",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: ".csproj", value: "Microsoft.NET.Sdk.Web project found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/aspnetcore/fixtures/basic_app/Api.csproj" },
+        { type: "code_pattern", value: "ASP.NET Core host setup found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/aspnetcore/fixtures/basic_app/Program.cs" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "aspnetcore.minimal",
+          files: "**/*.cs",
+          routes: ["app.Map{Method}(\"{path}\", {handler})", "app.MapGroup(\"{prefix}\")"],
+          confidence: 0.95,
+        },
+        {
+          name: "aspnetcore.controllers",
+          files: "Controllers/**/*.cs",
+          routes: ["[Http{Method}(\"{path}\")]"],
+          convention: "[Route(\"api/[controller]\")] -> /api/{name without Controller suffix}",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
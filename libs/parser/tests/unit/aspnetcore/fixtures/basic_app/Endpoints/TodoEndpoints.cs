@@ -0,0 +1,19 @@
+namespace Api.Endpoints;
+
+public static class TodoEndpoints
+{
+    public static RouteGroupBuilder MapTodoEndpoints(this RouteGroupBuilder api)
+    {
+        var todos = api.MapGroup("/todos").WithTags("Todos");
+
+        todos.MapGet("/", GetAll);
+        todos.MapPost("/", Create);
+        todos.MapDelete("/{id}", (int id) => Results.NoContent());
+
+        return api;
+    }
+
+    static IResult GetAll() => Results.Ok();
+
+    static IResult Create(Todo todo) => Results.Created($"/api/todos/{todo.Id}", todo);
+}
@@ -0,0 +1,14 @@
+using Api.Endpoints;
+
+var builder = WebApplication.CreateBuilder(args);
+builder.Services.AddControllers();
+
+var app = builder.Build();
+
+app.MapGet("/health", () => Results.Ok());
+
+var api = app.MapGroup("/api");
+api.MapTodoEndpoints();
+
+app.MapControllers();
+app.Run();
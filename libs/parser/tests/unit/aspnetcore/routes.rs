@@ -0,0 +1,18 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn links_minimal_api_groups_and_controllers() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/Orders".to_string()),
+        (HttpMethod::Post, "/api/Orders".to_string()),
+        (HttpMethod::Get, "/api/Orders/{id:int}".to_string()),
+        (HttpMethod::Get, "/api/todos".to_string()),
+        (HttpMethod::Post, "/api/todos".to_string()),
+        (HttpMethod::Delete, "/api/todos/{id}".to_string()),
+        (HttpMethod::Get, "/health".to_string()),
+    ]);
+}
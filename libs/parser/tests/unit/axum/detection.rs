@@ -0,0 +1,68 @@
+use super::fixtures;
+use pinpath_parser::config::ConfigDiscovery;
+use std::fs;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn detects_axum_from_cargo_toml() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(false);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    assert_eq!(config.frameworks.len(), 1);
+    assert_eq!(config.frameworks[0].framework, "axum");
+    assert!(config.frameworks[0].confidence > 0.9);
+    
+    let signal_types: std::collections::HashSet<_> = config.frameworks[0].signals.iter()
+        .map(|s| s.signal_type.as_str())
+        .collect();
+    
+    assert!(signal_types.contains("Cargo.toml"));
+}
+
+#[tokio::test]
+async fn ignores_axum_outside_cargo_dependencies() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir(temp_dir.path().join("src")).unwrap();
+    fs::write(temp_dir.path().join("Cargo.toml"), r#"[package]
+name = "axum-to-rocket"
+description = "Port of our axum service"
+
+[dependencies]
+rocket = "0.5"
+axum-extra = "0.9"
+
+[dev-dependencies]
+axum = "0.7"
+"#).unwrap();
+    fs::write(temp_dir.path().join("src/main.rs"), r#"#[macro_use] extern crate rocket;
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+}
+"#).unwrap();
+
+    let discovery = ConfigDiscovery::new(false);
+    let config = discovery.discover(temp_dir.path()).await.unwrap();
+
+    let frameworks: Vec<_> = config.frameworks.iter().map(|f| f.framework.as_str()).collect();
+    assert_eq!(frameworks, vec!["rocket"]);
+}
+
+#[tokio::test]
+async fn detects_workspace_and_table_dependencies() {
+    for manifest in [
+        "[package]\nname = \"api\"\n\n[dependencies.axum]\nversion = \"0.7\"\n",
+        "[package]\nname = \"api\"\n\n[target.'cfg(unix)'.dependencies]\naxum.workspace = true\n",
+    ] {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("Cargo.toml"), manifest).unwrap();
+
+        let discovery = ConfigDiscovery::new(false);
+        let config = discovery.discover(temp_dir.path()).await.unwrap();
+
+        assert_eq!(config.frameworks.len(), 1, "{}", manifest);
+        assert_eq!(config.frameworks[0].framework, "axum");
+    }
+}
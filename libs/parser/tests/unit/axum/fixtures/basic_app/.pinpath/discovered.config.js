@@ -0,0 +1,67 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:12:29.594072223+00:00
+// Version: 1.0.0
+// Debug Mode: false
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:12:29.594072223+00:00",
+    lastModified: "2026-10-17T04:12:29.594072223+00:00",
+    debugMode: false,
+  },
+
+  // Global configuration
+  debugMode: false,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 2481,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/axum/fixtures/basic_app",
+      framework: "axum",
+      confidence: 1,
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "axum.router",
+          files: "src/**/*.rs",
+          routes: ["Router::new().route(\"{path}\", {method}({handler}))", "Router::new().nest(\"{prefix}\", {router})"],
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,7 @@
+use axum::Router;
+
+mod todos;
+
+pub fn api() -> Router {
+    Router::new().nest("/todos", todos::routes())
+}
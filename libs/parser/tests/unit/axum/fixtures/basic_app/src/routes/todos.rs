@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/", get(list_todos).post(create_todo))
+        .route("/:id", get(get_todo).patch(update_todo).delete(delete_todo))
+}
+
+async fn list_todos() {}
+async fn create_todo() {}
+async fn get_todo() {}
+async fn update_todo() {}
+async fn delete_todo() {}
@@ -0,0 +1,17 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn resolves_nested_routers_across_modules() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/todos".to_string()),
+        (HttpMethod::Post, "/api/todos".to_string()),
+        (HttpMethod::Get, "/api/todos/:id".to_string()),
+        (HttpMethod::Patch, "/api/todos/:id".to_string()),
+        (HttpMethod::Delete, "/api/todos/:id".to_string()),
+        (HttpMethod::Get, "/health".to_string()),
+    ]);
+}
@@ -0,0 +1,32 @@
+use super::fixtures;
+use pinpath_parser::config::ConfigDiscovery;
+
+#[tokio::test]
+async fn detects_django_from_manage_py() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(false);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    assert_eq!(config.frameworks.len(), 1);
+    assert_eq!(config.frameworks[0].framework, "django");
+    assert!(config.frameworks[0].confidence > 0.9);
+}
+
+#[tokio::test]
+async fn finds_manifest_and_settings_signals() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(true);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    let detection = config.frameworks.iter()
+        .find(|f| f.framework == "django")
+        .unwrap();
+    
+    let signal_types: std::collections::HashSet<_> = detection.signals.iter()
+        .map(|s| s.signal_type.as_str())
+        .collect();
+    
+    assert!(signal_types.contains("config_file"));
+    assert!(signal_types.contains("requirements.txt"));
+    assert!(signal_types.contains("code_pattern"));
+}
@@ -0,0 +1,75 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:55:30.662636142+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:55:30.662636142+00:00",
+    lastModified: "2026-10-17T04:55:30.662636142+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 13,
+    totalSize: 5740,
+    roots: ["./api"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/django/fixtures/basic_app",
+      framework: "django",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "config_file", value: "manage.py found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/django/fixtures/basic_app/manage.py" },
+        { type: "requirements.txt", value: "django dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/django/fixtures/basic_app/requirements.txt" },
+        { type: "code_pattern", value: "INSTALLED_APPS found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/django/fixtures/basic_app/.pinpath/discovered.config.json" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "django.urlpatterns",
+          files: "**/urls.py",
+          routes: ["path('{path}', {view})", "path('{prefix}', include('{module}.urls'))", "router.register(r'{prefix}', {ViewSet})"],
+          convention: "ROOT_URLCONF urlpatterns + include() prefixes",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,14 @@
+from rest_framework import viewsets
+from rest_framework.decorators import action, api_view
+from rest_framework.response import Response
+
+
+class UserViewSet(viewsets.ModelViewSet):
+    @action(detail=True, methods=["post"], url_path="set-password")
+    def set_password(self, request, pk=None):
+        return Response({"status": "password set"})
+
+
+@api_view(["GET"])
+def status(request):
+    return Response({"status": "ok"})
@@ -0,0 +1,12 @@
+from django.urls import path, re_path
+
+from . import views
+
+app_name = "blog"
+
+urlpatterns = [
+    path("", views.index),
+    path("<int:pk>/", views.PostDetail.as_view()),
+    re_path(r"^archive/(?P<year>[0-9]{4})/$", views.archive),
+    path("contact/", views.contact),
+]
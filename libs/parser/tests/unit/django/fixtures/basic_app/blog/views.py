@@ -0,0 +1,24 @@
+from django.shortcuts import redirect, render
+from django.views.decorators.http import require_GET
+from django.views.generic import DetailView
+
+from .models import Post
+
+
+def index(request):
+    return render(request, "blog/index.html")
+
+
+class PostDetail(DetailView):
+    model = Post
+
+
+@require_GET
+def archive(request, year):
+    return render(request, "blog/archive.html", {"year": year})
+
+
+def contact(request):
+    if request.method == "POST":
+        return redirect("blog:index")
+    return render(request, "blog/contact.html")
@@ -0,0 +1,13 @@
+SECRET_KEY = "not-a-secret"
+DEBUG = True
+
+INSTALLED_APPS = [
+    "django.contrib.admin",
+    "django.contrib.auth",
+    "django.contrib.contenttypes",
+    "rest_framework",
+    "blog",
+    "api",
+]
+
+ROOT_URLCONF = "mysite.urls"
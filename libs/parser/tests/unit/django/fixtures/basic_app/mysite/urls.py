@@ -0,0 +1,11 @@
+from django.contrib import admin
+from django.urls import include, path
+
+from blog.views import index
+
+urlpatterns = [
+    path("", index),
+    path("admin/", admin.site.urls),
+    path("blog/", include("blog.urls")),
+    path("api/v1/", include("api.urls")),
+]
@@ -0,0 +1,25 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn follows_includes_and_expands_router_viewsets() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/api/v1/status".to_string()),
+        (HttpMethod::Get, "/api/v1/users".to_string()),
+        (HttpMethod::Post, "/api/v1/users".to_string()),
+        (HttpMethod::Get, "/api/v1/users/<pk>".to_string()),
+        (HttpMethod::Put, "/api/v1/users/<pk>".to_string()),
+        (HttpMethod::Patch, "/api/v1/users/<pk>".to_string()),
+        (HttpMethod::Delete, "/api/v1/users/<pk>".to_string()),
+        (HttpMethod::Post, "/api/v1/users/<pk>/set-password".to_string()),
+        (HttpMethod::Get, "/blog".to_string()),
+        (HttpMethod::Get, "/blog/<int:pk>".to_string()),
+        (HttpMethod::Get, "/blog/archive/(?P<year>[0-9]{4})".to_string()),
+        (HttpMethod::Get, "/blog/contact".to_string()),
+        (HttpMethod::Post, "/blog/contact".to_string()),
+    ]);
+}
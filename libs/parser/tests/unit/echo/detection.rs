@@ -0,0 +1,13 @@
+use super::fixtures;
+use pinpath_parser::config::ConfigDiscovery;
+
+#[tokio::test]
+async fn detects_echo_with_cmd_entrypoint() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(false);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    assert_eq!(config.frameworks.len(), 1);
+    assert_eq!(config.frameworks[0].framework, "echo");
+    assert!(config.frameworks[0].confidence > 0.9);
+}
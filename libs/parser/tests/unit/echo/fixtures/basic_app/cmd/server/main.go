@@ -0,0 +1,25 @@
+package main
+
+import (
+	"net/http"
+
+	"github.com/labstack/echo/v4"
+)
+
+func main() {
+	e := echo.New()
+	e.GET("/notes", listNotes)
+
+	admin := e.Group("/admin")
+	admin.DELETE("/notes/:id", deleteNote)
+
+	e.Logger.Fatal(e.Start(":1323"))
+}
+
+func listNotes(c echo.Context) error {
+	return c.JSON(http.StatusOK, []string{})
+}
+
+func deleteNote(c echo.Context) error {
+	return c.NoContent(http.StatusNoContent)
+}
@@ -0,0 +1,5 @@
+module example.com/notes
+
+go 1.21
+
+require github.com/labstack/echo/v4 v4.11.4
@@ -0,0 +1,13 @@
+pub mod detection;
+
+use std::path::{Path, PathBuf};
+
+/// Echo-specific test fixtures
+pub mod fixtures {
+    use super::*;
+    
+    pub fn basic_app() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/unit/echo/fixtures/basic_app")
+    }
+}
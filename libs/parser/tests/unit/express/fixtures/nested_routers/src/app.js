@@ -0,0 +1,14 @@
+const express = require('express');
+const api = require('./routes');
+
+const app = express();
+
+app.use(express.json());
+
+app.get('/health', (req, res) => {
+    res.json({ status: 'ok' });
+});
+
+app.use('/api', api);
+
+app.listen(3000);
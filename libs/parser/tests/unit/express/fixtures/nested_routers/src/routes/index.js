@@ -0,0 +1,6 @@
+const router = require('express').Router();
+const { v1 } = require('./v1');
+
+router.use('/v1', v1);
+
+module.exports = router;
@@ -0,0 +1,10 @@
+import { Router } from 'express';
+import users from './users';
+import * as orders from './orders';
+
+const v1: Router = Router();
+
+v1.use('/users', users);
+v1.use('/orders', orders.router);
+
+export { v1 };
@@ -0,0 +1,7 @@
+import { Router, Request, Response } from 'express';
+
+export const router = Router();
+
+router.post('/', (req: Request, res: Response) => {
+    res.status(201).json(req.body);
+});
@@ -1,6 +1,7 @@
 pub mod detection;
 pub mod patterns;
 pub mod config_generation;
+pub mod router_mounts;
 
 use std::path::{Path, PathBuf};
 
@@ -13,6 +14,11 @@ pub mod fixtures {
             .join("tests/unit/express/fixtures/basic_app")
     }
     
+    pub fn nested_routers() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/unit/express/fixtures/nested_routers")
+    }
+    
     pub fn monorepo() -> PathBuf {
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("tests/unit/express/fixtures/monorepo")
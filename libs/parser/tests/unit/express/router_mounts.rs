@@ -0,0 +1,99 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::project::parse_files;
+use pinpath_parser::{HttpMethod, ParameterLocation};
+use std::path::PathBuf;
+
+#[test]
+fn resolves_nested_router_prefixes_across_files() {
+    let routes = discover(&fixtures::nested_routers());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Post, "/api/v1/orders".to_string()),
+        (HttpMethod::Get, "/api/v1/users".to_string()),
+        (HttpMethod::Get, "/api/v1/users/:id".to_string()),
+        (HttpMethod::Get, "/health".to_string()),
+    ]);
+}
+
+#[test]
+fn unmounted_router_keeps_its_own_paths() {
+    let root = fixtures::nested_routers().join("src/routes/v1");
+    let routes = discover(&root);
+
+    // Without app.js and routes/index.js the /api/v1 prefix is unknown
+    assert!(routes.contains(&(HttpMethod::Get, "/users/:id".to_string())));
+    assert!(routes.contains(&(HttpMethod::Post, "/orders".to_string())));
+}
+
+#[test]
+fn path_parameters_include_mount_prefix() {
+    let files = vec![
+        (PathBuf::from("src/app.js"), r#"
+const express = require('express');
+const members = require('./members');
+const app = express();
+app.use('/orgs/:orgId', members);
+"#.to_string()),
+        (PathBuf::from("src/members.js"), r#"
+const router = require('express').Router({ mergeParams: true });
+router.get('/members/:id(\\d+)/:tab?', (req, res) => res.json({}));
+module.exports = router;
+"#.to_string()),
+    ];
+
+    let endpoints = parse_files(&files);
+    let params: Vec<_> = endpoints[0].endpoint.parameters.iter()
+        .map(|p| (p.name.as_str(), &p.location, p.param_type.as_deref(), p.required))
+        .collect();
+    assert_eq!(params, vec![
+        ("orgId", &ParameterLocation::Path, None, true),
+        ("id", &ParameterLocation::Path, Some("int"), true),
+        ("tab", &ParameterLocation::Path, None, false),
+    ]);
+}
+
+#[test]
+fn middleware_chains_follow_mounts_across_files() {
+    let files = vec![
+        (PathBuf::from("src/app.js"), r#"
+const express = require('express');
+const helmet = require('helmet');
+const auth = require('./middleware/auth');
+const users = require('./routes/users');
+const status = require('./routes/status');
+const app = express();
+app.use(helmet());
+app.use(status);
+app.use('/users', auth, users);
+app.get('/health', (req, res) => res.send('ok'));
+"#.to_string()),
+        (PathBuf::from("src/middleware/auth.js"), r#"
+module.exports = (req, res, next) => next();
+"#.to_string()),
+        (PathBuf::from("src/routes/status.js"), r#"
+const router = require('express').Router();
+router.get('/status', (req, res) => res.json({}));
+module.exports = router;
+"#.to_string()),
+        (PathBuf::from("src/routes/users.js"), r#"
+const router = require('express').Router();
+router.get('/', (req, res) => res.json([]));
+router.use(audit);
+router.delete('/:id', (req, res) => res.sendStatus(204));
+module.exports = router;
+"#.to_string()),
+    ];
+
+    let endpoints = parse_files(&files);
+    let mut chains: Vec<_> = endpoints.iter()
+        .map(|e| (e.endpoint.path.clone(), e.endpoint.middleware.join(","), e.endpoint.requires_auth))
+        .collect();
+    chains.sort();
+    assert_eq!(chains, vec![
+        ("/health".to_string(), "helmet()".to_string(), false),
+        ("/status".to_string(), "helmet()".to_string(), false),
+        ("/users".to_string(), "helmet(),auth".to_string(), true),
+        ("/users/:id".to_string(), "helmet(),auth,audit".to_string(), true),
+    ]);
+}
@@ -0,0 +1,11 @@
+from fastapi import APIRouter
+
+from ..routers.users import router as users_router
+
+api_router = APIRouter(prefix="/v1")
+api_router.include_router(users_router)
+
+
+@api_router.get("/status")
+async def status():
+    return {"status": "ok"}
@@ -0,0 +1,13 @@
+from fastapi import APIRouter
+
+router = APIRouter()
+
+
+@router.get("/")
+async def list_items():
+    return []
+
+
+@router.api_route("/{item_id}", methods=["PUT", "PATCH"])
+async def update_item(item_id: str):
+    return {"item_id": item_id}
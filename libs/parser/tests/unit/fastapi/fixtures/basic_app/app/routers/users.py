@@ -0,0 +1,13 @@
+from fastapi import APIRouter
+
+router = APIRouter(prefix="/accounts", tags=["users"])
+
+
+@router.get("/")
+async def list_accounts():
+    return []
+
+
+@router.post("/", status_code=201)
+async def create_account():
+    return {}
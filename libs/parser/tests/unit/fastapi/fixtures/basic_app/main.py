@@ -1,4 +1,8 @@
-from fastapi import FastAPI
+from fastapi import Depends, FastAPI
+
+from app.api import v1
+from app.dependencies import verify_token
+from app.routers import items, users
 
 app = FastAPI()
 
@@ -9,3 +13,7 @@ async def read_root():
 @app.get("/users/{user_id}")
 async def read_user(user_id: int):
     return {"user_id": user_id}
+
+app.include_router(users.router)
+app.include_router(items.router, prefix="/items", tags=["items"], dependencies=[Depends(verify_token)])
+app.include_router(v1.api_router, prefix="/api")
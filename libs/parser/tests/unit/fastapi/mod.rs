@@ -1,5 +1,6 @@
 // FastAPI unit tests module
 pub mod detection;
+pub mod routes;
 
 use std::path::{Path, PathBuf};
 
@@ -0,0 +1,40 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::project::parse_files;
+use pinpath_parser::HttpMethod;
+use std::fs;
+
+#[test]
+fn resolves_router_prefixes_across_modules() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/accounts".to_string()),
+        (HttpMethod::Post, "/accounts".to_string()),
+        (HttpMethod::Get, "/api/v1/accounts".to_string()),
+        (HttpMethod::Post, "/api/v1/accounts".to_string()),
+        (HttpMethod::Get, "/api/v1/status".to_string()),
+        (HttpMethod::Get, "/items".to_string()),
+        (HttpMethod::Put, "/items/{item_id}".to_string()),
+        (HttpMethod::Patch, "/items/{item_id}".to_string()),
+        (HttpMethod::Get, "/users/{user_id}".to_string()),
+    ]);
+}
+
+#[test]
+fn applies_include_router_tags_and_dependencies() {
+    let root = fixtures::basic_app();
+    let files: Vec<_> = ["main.py", "app/dependencies.py", "app/routers/items.py"]
+        .iter()
+        .map(|file| (root.join(file), fs::read_to_string(root.join(file)).unwrap()))
+        .collect();
+
+    let endpoints = parse_files(&files);
+    let update = endpoints.iter()
+        .find(|e| e.endpoint.handler == "update_item")
+        .unwrap();
+
+    assert_eq!(update.endpoint.tags, vec!["items"]);
+    assert_eq!(update.endpoint.middleware, vec!["verify_token"]);
+}
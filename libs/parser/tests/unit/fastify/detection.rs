@@ -0,0 +1,31 @@
+use super::fixtures;
+use pinpath_parser::config::ConfigDiscovery;
+
+#[tokio::test]
+async fn detects_fastify_from_package_json() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(false);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    assert_eq!(config.frameworks.len(), 1);
+    assert_eq!(config.frameworks[0].framework, "fastify");
+    assert!(config.frameworks[0].confidence > 0.9);
+}
+
+#[tokio::test]
+async fn finds_dependency_and_code_pattern_signals() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(true);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    let detection = config.frameworks.iter()
+        .find(|f| f.framework == "fastify")
+        .unwrap();
+    
+    let signal_types: std::collections::HashSet<_> = detection.signals.iter()
+        .map(|s| s.signal_type.as_str())
+        .collect();
+    
+    assert!(signal_types.contains("package.json"));
+    assert!(signal_types.contains("code_pattern"));
+}
@@ -0,0 +1,9 @@
+async function getUser(request, reply) {
+  return { id: request.params.id };
+}
+
+async function createUser(request, reply) {
+  reply.code(201).send(request.body);
+}
+
+module.exports = { getUser, createUser };
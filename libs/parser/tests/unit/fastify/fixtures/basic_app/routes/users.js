@@ -0,0 +1,19 @@
+const { getUser, createUser } = require('../handlers/users');
+
+const userSchema = {
+  params: {
+    type: 'object',
+    properties: { id: { type: 'string' } },
+  },
+};
+
+module.exports = async function (app, opts) {
+  app.route({
+    method: ['GET', 'HEAD'],
+    url: '/:id',
+    schema: userSchema,
+    handler: getUser,
+  });
+
+  app.post('/', { schema: { body: { type: 'object' } } }, createUser);
+};
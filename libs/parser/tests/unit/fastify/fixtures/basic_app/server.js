@@ -0,0 +1,7 @@
+const fastify = require('fastify')({ logger: true });
+
+fastify.register(require('./routes/users'), { prefix: '/users' });
+
+fastify.get('/health', async () => ({ status: 'ok' }));
+
+fastify.listen({ port: 3000 });
@@ -0,0 +1,14 @@
+pub mod detection;
+pub mod routes;
+
+use std::path::{Path, PathBuf};
+
+/// Fastify-specific test fixtures
+pub mod fixtures {
+    use super::*;
+    
+    pub fn basic_app() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/unit/fastify/fixtures/basic_app")
+    }
+}
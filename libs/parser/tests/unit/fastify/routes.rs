@@ -0,0 +1,15 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn applies_plugin_prefix_to_route_objects() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/health".to_string()),
+        (HttpMethod::Post, "/users".to_string()),
+        (HttpMethod::Get, "/users/:id".to_string()),
+        (HttpMethod::Head, "/users/:id".to_string()),
+    ]);
+}
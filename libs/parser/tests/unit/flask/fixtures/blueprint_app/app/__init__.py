@@ -0,0 +1,21 @@
+from flask import Blueprint, Flask
+from flask_restful import Api
+
+from .pets import blp as pets_blp
+from .resources import Todo, TodoList
+from .users import bp as users_bp
+
+
+def create_app():
+    app = Flask(__name__)
+
+    app.register_blueprint(users_bp, url_prefix="/api/users")
+    app.register_blueprint(pets_blp)
+
+    api_bp = Blueprint("api", __name__, url_prefix="/v1")
+    api = Api(api_bp)
+    api.add_resource(TodoList, "/todos")
+    api.add_resource(Todo, "/todos/<int:todo_id>")
+    app.register_blueprint(api_bp)
+
+    return app
@@ -0,0 +1,15 @@
+from flask.views import MethodView
+from flask_smorest import Blueprint
+
+blp = Blueprint("pets", __name__, url_prefix="/pets", description="Operations on pets")
+
+
+@blp.route("/")
+class Pets(MethodView):
+    @blp.response(200)
+    def get(self):
+        return []
+
+    @blp.response(201)
+    def post(self, new_pet):
+        return new_pet
@@ -0,0 +1,17 @@
+from flask_restful import Resource
+
+
+class TodoList(Resource):
+    def get(self):
+        return []
+
+    def post(self):
+        return {}, 201
+
+
+class Todo(Resource):
+    def get(self, todo_id):
+        return {}
+
+    def delete(self, todo_id):
+        return "", 204
@@ -0,0 +1,27 @@
+from flask import Blueprint, jsonify
+from flask.views import MethodView
+
+ITEM_METHODS = ("GET", "DELETE")
+
+bp = Blueprint("users", __name__, url_prefix="/users")
+
+
+@bp.get("/")
+def list_users():
+    return jsonify([])
+
+
+@bp.route("/<int:user_id>", methods=ITEM_METHODS)
+def user(user_id):
+    return jsonify({"id": user_id})
+
+
+class ProfileAPI(MethodView):
+    def get(self, user_id):
+        return jsonify({})
+
+    def put(self, user_id):
+        return jsonify({})
+
+
+bp.add_url_rule("/<int:user_id>/profile", view_func=ProfileAPI.as_view("profile"))
@@ -1,6 +1,7 @@
 pub mod detection;
 pub mod patterns;
 pub mod config_generation;
+pub mod routes;
 
 use std::path::{Path, PathBuf};
 
@@ -12,4 +13,9 @@ pub mod fixtures {
         Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("tests/unit/flask/fixtures/basic_app")
     }
+
+    pub fn blueprint_app() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/unit/flask/fixtures/blueprint_app")
+    }
 }
@@ -0,0 +1,34 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn discovers_decorator_routes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/users".to_string()),
+        (HttpMethod::Post, "/users".to_string()),
+        (HttpMethod::Get, "/users/<int:user_id>".to_string()),
+    ]);
+}
+
+#[test]
+fn applies_blueprint_prefixes_to_views_and_resources() {
+    let routes = discover(&fixtures::blueprint_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/users".to_string()),
+        (HttpMethod::Get, "/api/users/<int:user_id>".to_string()),
+        (HttpMethod::Delete, "/api/users/<int:user_id>".to_string()),
+        (HttpMethod::Get, "/api/users/<int:user_id>/profile".to_string()),
+        (HttpMethod::Put, "/api/users/<int:user_id>/profile".to_string()),
+        (HttpMethod::Get, "/pets".to_string()),
+        (HttpMethod::Post, "/pets".to_string()),
+        (HttpMethod::Get, "/v1/todos".to_string()),
+        (HttpMethod::Post, "/v1/todos".to_string()),
+        (HttpMethod::Get, "/v1/todos/<int:todo_id>".to_string()),
+        (HttpMethod::Delete, "/v1/todos/<int:todo_id>".to_string()),
+    ]);
+}
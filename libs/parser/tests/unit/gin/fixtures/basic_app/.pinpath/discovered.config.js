@@ -0,0 +1,73 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:07:47.420651831+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:07:47.420651831+00:00",
+    lastModified: "2026-10-17T04:07:47.420651831+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 7,
+    totalSize: 3835,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/gin/fixtures/basic_app",
+      framework: "gin",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "go.mod", value: "github.com/gin-gonic/gin dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/gin/fixtures/basic_app/go.mod" },
+        { type: "code_pattern", value: "gin.Default() or gin.New() found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/gin/fixtures/basic_app/main.go" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "gin.routes",
+          files: "**/*.go",
+          routes: ["r.{METHOD}(\"{path}\", {handler})", "r.Group(\"{prefix}\").{METHOD}(\"{path}\", {handler})"],
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,9 @@
+package main
+
+import "github.com/gin-gonic/gin"
+
+func setupHealth(r *gin.Engine) {
+	r.GET("/healthz", func(c *gin.Context) {
+		c.JSON(200, gin.H{"status": "ok"})
+	})
+}
@@ -0,0 +1,10 @@
+package routes
+
+import "github.com/gin-gonic/gin"
+
+func registerOrders(rg *gin.RouterGroup) {
+	orders := rg.Group("/orders")
+	orders.POST("", createOrder)
+}
+
+func createOrder(c *gin.Context) {}
@@ -0,0 +1,15 @@
+package routes
+
+import "github.com/gin-gonic/gin"
+
+func Register(rg *gin.RouterGroup) {
+	users := rg.Group("/users")
+	users.GET("", listUsers)
+	users.GET("/:id", getUser)
+
+	registerOrders(rg)
+}
+
+func listUsers(c *gin.Context) {}
+
+func getUser(c *gin.Context) {}
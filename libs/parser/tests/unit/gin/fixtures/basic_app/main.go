@@ -0,0 +1,17 @@
+package main
+
+import (
+	"github.com/gin-gonic/gin"
+
+	"example.com/shop/internal/routes"
+)
+
+func main() {
+	r := gin.Default()
+	setupHealth(r)
+
+	api := r.Group("/api")
+	routes.Register(api)
+
+	r.Run(":8080")
+}
@@ -0,0 +1,15 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn resolves_groups_across_files_and_packages() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Post, "/api/orders".to_string()),
+        (HttpMethod::Get, "/api/users".to_string()),
+        (HttpMethod::Get, "/api/users/:id".to_string()),
+        (HttpMethod::Get, "/healthz".to_string()),
+    ]);
+}
@@ -0,0 +1,73 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:44:55.445911150+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:44:55.445911150+00:00",
+    lastModified: "2026-10-17T04:44:55.445911150+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 6,
+    totalSize: 3784,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/hapi/fixtures/basic_app",
+      framework: "hapi",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "@hapi/hapi dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/hapi/fixtures/basic_app/package.json" },
+        { type: "code_pattern", value: "Hapi.server( or Hapi.Server( found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/hapi/fixtures/basic_app/server.js" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "hapi.routes",
+          files: "**/*.{js,ts}",
+          routes: ["server.route({ method, path, handler })"],
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,5 @@
+module.exports = {
+  list: () => [],
+  create: (request, h) => h.response(request.payload).code(201),
+  update: (request, h) => h.response().code(204),
+};
@@ -0,0 +1,9 @@
+const handlers = require('../handlers/todos');
+
+module.exports = function (server) {
+  server.route([
+    { method: 'GET', path: '/todos', handler: handlers.list },
+    { method: 'POST', path: '/todos', handler: handlers.create },
+    { method: ['PUT', 'PATCH'], path: '/todos/{id}', handler: handlers.update },
+  ]);
+};
@@ -0,0 +1,18 @@
+const Hapi = require('@hapi/hapi');
+const registerTodos = require('./routes/todos');
+
+const init = async () => {
+  const server = Hapi.server({ port: 3000, host: 'localhost' });
+
+  server.route({
+    method: 'GET',
+    path: '/health',
+    handler: () => ({ status: 'ok' }),
+  });
+
+  registerTodos(server);
+
+  await server.start();
+};
+
+init();
@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn collects_route_arrays_registered_through_functions() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/health".to_string()),
+        (HttpMethod::Get, "/todos".to_string()),
+        (HttpMethod::Post, "/todos".to_string()),
+        (HttpMethod::Put, "/todos/{id}".to_string()),
+        (HttpMethod::Patch, "/todos/{id}".to_string()),
+    ]);
+}
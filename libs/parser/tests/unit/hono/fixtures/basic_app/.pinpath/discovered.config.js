@@ -0,0 +1,74 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:44:55.515073874+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:44:55.515073874+00:00",
+    lastModified: "2026-10-17T04:44:55.515073874+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 3619,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/hono/fixtures/basic_app",
+      framework: "hono",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "hono dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/hono/fixtures/basic_app/package.json" },
+        { type: "code_pattern", value: "new Hono( found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/hono/fixtures/basic_app/src/index.ts" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "hono.routes",
+          files: "**/*.{js,ts}",
+          routes: ["app.{method}('{path}', {handler})", "app.on('{method}', '{path}', {handler})"],
+          convention: "basePath('{prefix}') + app.route('{prefix}', {subapp})",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
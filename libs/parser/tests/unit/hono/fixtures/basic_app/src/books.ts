@@ -0,0 +1,11 @@
+import { Hono } from 'hono';
+
+const books = new Hono();
+
+books
+  .get('/', (c) => c.json([]))
+  .post('/', async (c) => c.json(await c.req.json(), 201));
+
+books.on(['PUT', 'PATCH'], '/:id', (c) => c.body(null, 204));
+
+export default books;
@@ -0,0 +1,9 @@
+import { Hono } from 'hono';
+import books from './books';
+
+const app = new Hono().basePath('/api');
+
+app.get('/health', (c) => c.json({ status: 'ok' }));
+app.route('/books', books);
+
+export default app;
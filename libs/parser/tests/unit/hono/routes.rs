@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn joins_base_path_and_mounted_sub_apps() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/books".to_string()),
+        (HttpMethod::Post, "/api/books".to_string()),
+        (HttpMethod::Put, "/api/books/:id".to_string()),
+        (HttpMethod::Patch, "/api/books/:id".to_string()),
+        (HttpMethod::Get, "/api/health".to_string()),
+    ]);
+}
@@ -0,0 +1,74 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:44:55.601615315+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:44:55.601615315+00:00",
+    lastModified: "2026-10-17T04:44:55.601615315+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 3927,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/koa/fixtures/basic_app",
+      framework: "koa",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "koa dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/koa/fixtures/basic_app/package.json" },
+        { type: "code_pattern", value: "new Koa( or new Router( found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/koa/fixtures/basic_app/src/index.js" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "koa.router",
+          files: "**/*.{js,ts}",
+          routes: ["router.{method}('{path}', {handler})", "router.{method}('{name}', '{path}', {handler})"],
+          convention: "new Router({ prefix }) or router.prefix('{prefix}')",
+          confidence: 0.9,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
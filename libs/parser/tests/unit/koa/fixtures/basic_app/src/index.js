@@ -0,0 +1,15 @@
+const Koa = require('koa');
+const Router = require('@koa/router');
+const users = require('./routes/users');
+
+const app = new Koa();
+const router = new Router();
+
+router.get('/status', (ctx) => {
+  ctx.body = { status: 'ok' };
+});
+
+app.use(router.routes());
+app.use(users.routes()).use(users.allowedMethods());
+
+app.listen(3000);
@@ -0,0 +1,17 @@
+const Router = require('@koa/router');
+
+const router = new Router({ prefix: '/users' });
+
+router.get('/', async (ctx) => {
+  ctx.body = [];
+});
+
+router.get('user', '/:id', async (ctx) => {
+  ctx.body = { id: ctx.params.id };
+});
+
+router
+  .put('/:id', async (ctx) => { ctx.status = 204; })
+  .del('/:id', async (ctx) => { ctx.status = 204; });
+
+module.exports = router;
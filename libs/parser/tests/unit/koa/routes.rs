@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn applies_router_prefix_to_named_and_chained_routes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/status".to_string()),
+        (HttpMethod::Get, "/users".to_string()),
+        (HttpMethod::Get, "/users/:id".to_string()),
+        (HttpMethod::Put, "/users/:id".to_string()),
+        (HttpMethod::Delete, "/users/:id".to_string()),
+    ]);
+}
@@ -0,0 +1,74 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T05:16:40.742993282+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T05:16:40.742993282+00:00",
+    lastModified: "2026-10-17T05:16:40.742993282+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 4481,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/ktor/fixtures/basic_app",
+      framework: "ktor",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "build.gradle.kts", value: "io.ktor dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/ktor/fixtures/basic_app/build.gradle.kts" },
+        { type: "code_pattern", value: "io.ktor.server import found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/ktor/fixtures/basic_app/src/main/kotlin/com/example/Application.kt" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "ktor.routing",
+          files: "src/main/kotlin/**/*.kt",
+          routes: ["{method}(\"{path}\") { }", "route(\"{path}\") { }", "route(\"{path}\", HttpMethod.{Method}) { }"],
+          convention: "nested route(\"/prefix\") blocks and Route extension functions",
+          confidence: 0.9,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,27 @@
+package com.example
+
+import com.example.routes.userRoutes
+import io.ktor.server.application.*
+import io.ktor.server.auth.*
+import io.ktor.server.engine.*
+import io.ktor.server.netty.*
+import io.ktor.server.response.*
+import io.ktor.server.routing.*
+
+fun main() {
+    embeddedServer(Netty, port = 8080) { module() }.start(wait = true)
+}
+
+fun Application.module() {
+    routing {
+        get("/") {
+            call.respondText("Hello")
+        }
+        route("/api") {
+            get("/health") { call.respondText("ok") }
+            authenticate("auth-jwt") {
+                userRoutes()
+            }
+        }
+    }
+}
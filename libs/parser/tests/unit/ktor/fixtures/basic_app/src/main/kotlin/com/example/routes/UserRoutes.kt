@@ -0,0 +1,18 @@
+package com.example.routes
+
+import io.ktor.server.application.*
+import io.ktor.server.response.*
+import io.ktor.server.routing.*
+
+fun Route.userRoutes() {
+    route("/users") {
+        get {
+            call.respond(emptyList<String>())
+        }
+        post { }
+        route("/{id}") {
+            get { call.respondText("user ${call.parameters["id"]}") }
+            delete { }
+        }
+    }
+}
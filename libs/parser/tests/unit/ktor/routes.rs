@@ -0,0 +1,35 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::project::parse_files;
+use pinpath_parser::HttpMethod;
+use std::fs;
+
+#[test]
+fn composes_nested_route_prefixes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/api/health".to_string()),
+        (HttpMethod::Get, "/api/users".to_string()),
+        (HttpMethod::Post, "/api/users".to_string()),
+        (HttpMethod::Get, "/api/users/{id}".to_string()),
+        (HttpMethod::Delete, "/api/users/{id}".to_string()),
+    ]);
+}
+
+#[test]
+fn applies_authenticate_to_mounted_routes() {
+    let root = fixtures::basic_app().join("src/main/kotlin/com/example");
+    let files: Vec<_> = ["Application.kt", "routes/UserRoutes.kt"].iter()
+        .map(|file| (root.join(file), fs::read_to_string(root.join(file)).unwrap()))
+        .collect();
+    let endpoints: Vec<_> = parse_files(&files).into_iter().map(|e| e.endpoint).collect();
+
+    let users = endpoints.iter().find(|e| e.path == "/api/users").unwrap();
+    assert_eq!(users.middleware, vec!["authenticate:auth-jwt".to_string()]);
+    assert_eq!(users.handler, "9:9");
+
+    let health = endpoints.iter().find(|e| e.path == "/api/health").unwrap();
+    assert!(health.middleware.is_empty());
+}
@@ -0,0 +1,30 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Http\Requests\StorePostRequest;
+use Illuminate\Http\Request;
+
+class PostController extends Controller
+{
+    public function index()
+    {
+        return Post::paginate();
+    }
+
+    public function store(StorePostRequest $request)
+    {
+        return Post::create($request->validated());
+    }
+
+    public function update(Request $request, Post $post)
+    {
+        $data = $request->validate([
+            'title' => 'sometimes|required|string',
+            'pinned' => 'boolean',
+        ]);
+
+        $post->update($data);
+        return $post;
+    }
+}
@@ -0,0 +1,25 @@
+<?php
+
+namespace App\Http\Requests;
+
+use Illuminate\Foundation\Http\FormRequest;
+
+class StorePostRequest extends FormRequest
+{
+    public function authorize(): bool
+    {
+        return true;
+    }
+
+    public function rules(): array
+    {
+        return [
+            'title' => 'required|string|max:255',
+            'body' => ['required', 'string'],
+            'published_at' => 'nullable|date',
+            'tags' => 'array',
+            'tags.*' => 'string',
+            'author.email' => 'required|email',
+        ];
+    }
+}
@@ -0,0 +1,27 @@
+<?php
+
+use App\Http\Controllers\OrderController;
+use App\Http\Controllers\PostController;
+use App\Http\Controllers\ProfileController;
+use Illuminate\Support\Facades\Route;
+
+Route::get('/health', function () {
+    return ['status' => 'ok'];
+});
+
+Route::middleware('auth:sanctum')->group(function () {
+    Route::get(
+        '/profile',
+        [ProfileController::class, 'show']
+    );
+
+    Route::apiResource('posts', PostController::class)
+        ->except(['destroy']);
+
+    Route::prefix('orders')
+        ->controller(OrderController::class)
+        ->group(function () {
+            Route::get('/', 'index');
+            Route::match(['put', 'patch'], '/{order}', 'update');
+        });
+});
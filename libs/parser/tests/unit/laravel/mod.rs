@@ -1,5 +1,6 @@
 // Laravel unit tests module  
 pub mod detection;
+pub mod routes;
 
 use std::path::{Path, PathBuf};
 
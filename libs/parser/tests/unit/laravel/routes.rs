@@ -0,0 +1,59 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::project::parse_files;
+use pinpath_parser::HttpMethod;
+use std::fs;
+
+#[test]
+fn discovers_grouped_and_resource_routes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/api/health".to_string()),
+        (HttpMethod::Get, "/api/orders".to_string()),
+        (HttpMethod::Put, "/api/orders/{order}".to_string()),
+        (HttpMethod::Patch, "/api/orders/{order}".to_string()),
+        (HttpMethod::Get, "/api/posts".to_string()),
+        (HttpMethod::Post, "/api/posts".to_string()),
+        (HttpMethod::Get, "/api/posts/{post}".to_string()),
+        (HttpMethod::Put, "/api/posts/{post}".to_string()),
+        (HttpMethod::Patch, "/api/posts/{post}".to_string()),
+        (HttpMethod::Get, "/api/profile".to_string()),
+        (HttpMethod::Get, "/users".to_string()),
+        (HttpMethod::Post, "/users".to_string()),
+    ]);
+}
+
+#[test]
+fn reads_request_bodies_from_form_requests_and_validate_calls() {
+    let root = fixtures::basic_app();
+    let files: Vec<_> = ["routes/api.php", "app/Http/Controllers/PostController.php", "app/Http/Requests/StorePostRequest.php"].iter()
+        .map(|file| (root.join(file), fs::read_to_string(root.join(file)).unwrap()))
+        .collect();
+    let endpoints: Vec<_> = parse_files(&files).into_iter().map(|e| e.endpoint).collect();
+
+    let store = endpoints.iter().find(|e| e.method == HttpMethod::Post && e.path == "/api/posts").unwrap();
+    let body = store.body.as_ref().unwrap();
+    assert_eq!(body.name.as_deref(), Some("StorePostRequest"));
+    let fields: Vec<_> = body.properties.iter().map(|p| (p.name.as_str(), p.schema.schema_type.as_str(), p.required)).collect();
+    assert_eq!(fields, vec![
+        ("title", "string", true),
+        ("body", "string", true),
+        ("published_at", "string", false),
+        ("tags", "array", false),
+        ("author", "object", false),
+    ]);
+    assert_eq!(body.properties[3].schema.items.as_ref().unwrap().schema_type, "string");
+    let email = &body.properties[4].schema.properties[0];
+    assert_eq!((email.name.as_str(), email.schema.format.as_deref(), email.required), ("email", Some("email"), true));
+
+    let update = endpoints.iter().find(|e| e.method == HttpMethod::Put && e.path == "/api/posts/{post}").unwrap();
+    let fields: Vec<_> = update.body.as_ref().unwrap().properties.iter()
+        .map(|p| (p.name.as_str(), p.schema.schema_type.as_str(), p.required))
+        .collect();
+    assert_eq!(fields, vec![("title", "string", false), ("pinned", "boolean", false)]);
+
+    let index = endpoints.iter().find(|e| e.method == HttpMethod::Get && e.path == "/api/posts").unwrap();
+    assert!(index.body.is_none());
+}
@@ -1,13 +1,36 @@
 // Unit tests - framework-specific testing with dedicated fixtures
 pub mod express;
+pub mod nestjs;
 pub mod nextjs;
 pub mod flask;
+pub mod django;
 pub mod fastapi;
 pub mod laravel;
+pub mod symfony;
+pub mod slim;
+pub mod gin;
+pub mod echo;
+pub mod actix;
+pub mod axum;
+pub mod spring_boot;
+pub mod ktor;
+pub mod rails;
+pub mod sinatra;
+pub mod phoenix;
+pub mod aspnetcore;
+pub mod sveltekit;
+pub mod remix;
+pub mod nuxt;
+pub mod fastify;
+pub mod koa;
+pub mod hono;
+pub mod hapi;
 pub mod core;
 
+use pinpath_parser::project::parse_files;
+use pinpath_parser::HttpMethod;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Helper function to create test file structures (shared across unit tests)
 #[allow(dead_code)]
@@ -20,3 +43,29 @@ pub fn create_test_structure<P: AsRef<Path>>(base_path: P, files: Vec<(&str, &st
         fs::write(full_path, content).unwrap();
     }
 }
+
+fn read_sources(dir: &Path, files: &mut Vec<(PathBuf, String)>) {
+    for entry in fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            read_sources(&path, files);
+        } else if let Ok(content) = fs::read_to_string(&path) {
+            files.push((path, content));
+        }
+    }
+}
+
+/// Parse every file under `root` as one project, sorted by path
+#[allow(dead_code)]
+pub fn discover_routes(root: &Path) -> Vec<(HttpMethod, String)> {
+    let mut files = Vec::new();
+    read_sources(root, &mut files);
+    files.sort();
+
+    let mut routes: Vec<_> = parse_files(&files)
+        .into_iter()
+        .map(|e| (e.endpoint.method, e.endpoint.path))
+        .collect();
+    routes.sort_by(|a, b| a.1.cmp(&b.1));
+    routes
+}
@@ -0,0 +1,75 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:30:47.539414360+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:30:47.539414360+00:00",
+    lastModified: "2026-10-17T04:30:47.539414360+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 10,
+    totalSize: 6012,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/nestjs/fixtures/basic_app",
+      framework: "nestjs",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "@nestjs/core dependency found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/nestjs/fixtures/basic_app/package.json" },
+        { type: "config_file", value: "nest-cli.json found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/nestjs/fixtures/basic_app/nest-cli.json" },
+        { type: "code_pattern", value: "NestFactory.create found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/nestjs/fixtures/basic_app/src/main.ts" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "nestjs.controllers",
+          files: "src/**/*.controller.ts",
+          routes: ["@Controller('{prefix}')", "@{Method}('{path}')"],
+          convention: "global prefix + @Controller prefix + method decorator path",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,26 @@
+import { Injectable } from '@nestjs/common';
+
+@Injectable()
+export class UsersService {
+  private readonly users = new Map<string, unknown>();
+
+  findAll(role?: string) {
+    return [...this.users.values()];
+  }
+
+  findOne(id: string) {
+    return this.users.get(id);
+  }
+
+  create(user: unknown) {
+    return user;
+  }
+
+  update(id: string, user: unknown) {
+    return user;
+  }
+
+  remove(id: string) {
+    this.users.delete(id);
+  }
+}
@@ -0,0 +1,18 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn applies_global_prefix_through_module_imports() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/health".to_string()),
+        (HttpMethod::Get, "/api/users".to_string()),
+        (HttpMethod::Post, "/api/users".to_string()),
+        (HttpMethod::Get, "/api/users/:id".to_string()),
+        (HttpMethod::Patch, "/api/users/:id".to_string()),
+        (HttpMethod::Delete, "/api/users/:id".to_string()),
+        (HttpMethod::Get, "/api/v2/users/:id".to_string()),
+    ]);
+}
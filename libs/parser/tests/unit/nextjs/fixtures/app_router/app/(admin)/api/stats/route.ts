@@ -0,0 +1,3 @@
+const handler = async () => Response.json({ users: 0 });
+
+export { handler as GET, handler as HEAD };
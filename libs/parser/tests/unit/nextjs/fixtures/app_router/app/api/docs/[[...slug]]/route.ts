@@ -0,0 +1,3 @@
+export function GET(request: Request, { params }: { params: { slug?: string[] } }) {
+  return Response.json({ path: params.slug ?? [] });
+}
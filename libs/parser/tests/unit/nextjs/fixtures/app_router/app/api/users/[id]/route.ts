@@ -0,0 +1,12 @@
+import { NextRequest } from 'next/server';
+
+export async function GET(request: NextRequest, { params }: { params: { id: string } }) {
+  return Response.json({ id: params.id });
+}
+
+export async function PATCH(request: NextRequest, { params }: { params: { id: string } }) {
+  const body = await request.json();
+  return Response.json({ id: params.id, ...body });
+}
+
+export const DELETE = async () => new Response(null, { status: 204 });
@@ -0,0 +1,6 @@
+export const db = {
+  posts: {
+    findMany: async () => [],
+    create: async (data: unknown) => data,
+  },
+};
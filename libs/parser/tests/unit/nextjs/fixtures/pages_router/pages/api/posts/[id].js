@@ -0,0 +1,13 @@
+const handler = async (req, res) => {
+  const { method } = req;
+
+  if (method === 'DELETE') {
+    return res.status(204).end();
+  }
+  if (['PUT', 'PATCH'].includes(method)) {
+    return res.json({ id: req.query.id, ...req.body });
+  }
+  res.status(405).end();
+};
+
+export default handler;
@@ -0,0 +1,14 @@
+import type { NextApiRequest, NextApiResponse } from 'next';
+import { db } from '../_lib/db';
+
+export default async function handler(req: NextApiRequest, res: NextApiResponse) {
+  switch (req.method) {
+    case 'GET':
+      return res.json(await db.posts.findMany());
+    case 'POST':
+      return res.status(201).json(await db.posts.create(req.body));
+    default:
+      res.setHeader('Allow', ['GET', 'POST']);
+      return res.status(405).end();
+  }
+}
@@ -1,6 +1,7 @@
 pub mod detection;
 pub mod patterns;
 pub mod config_generation;
+pub mod routes;
 
 use std::path::{Path, PathBuf};
 
@@ -0,0 +1,34 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn derives_app_router_routes_from_route_files() {
+    let routes = discover(&fixtures::app_router());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Post, "/api/auth".to_string()),
+        (HttpMethod::Get, "/api/docs/[[...slug]]".to_string()),
+        (HttpMethod::Get, "/api/stats".to_string()),
+        (HttpMethod::Head, "/api/stats".to_string()),
+        (HttpMethod::Get, "/api/users".to_string()),
+        (HttpMethod::Post, "/api/users".to_string()),
+        (HttpMethod::Get, "/api/users/[id]".to_string()),
+        (HttpMethod::Patch, "/api/users/[id]".to_string()),
+        (HttpMethod::Delete, "/api/users/[id]".to_string()),
+    ]);
+}
+
+#[test]
+fn infers_pages_api_methods_from_req_method_checks() {
+    let routes = discover(&fixtures::pages_router());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/hello".to_string()),
+        (HttpMethod::Get, "/api/posts".to_string()),
+        (HttpMethod::Post, "/api/posts".to_string()),
+        (HttpMethod::Delete, "/api/posts/[id]".to_string()),
+        (HttpMethod::Put, "/api/posts/[id]".to_string()),
+        (HttpMethod::Patch, "/api/posts/[id]".to_string()),
+    ]);
+}
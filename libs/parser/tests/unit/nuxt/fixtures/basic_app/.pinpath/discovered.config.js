@@ -0,0 +1,75 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:28:42.634260024+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:28:42.634260024+00:00",
+    lastModified: "2026-10-17T04:28:42.634260024+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 9,
+    totalSize: 3947,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/nuxt/fixtures/basic_app",
+      framework: "nuxt",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "nuxt dependency found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/nuxt/fixtures/basic_app/package.json" },
+        { type: "config_file", value: "nuxt.config.ts found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/nuxt/fixtures/basic_app/nuxt.config.ts" },
+        { type: "directory", value: "server/api directory found", confidence: 0.85, source: "/root/crate/libs/parser/tests/unit/nuxt/fixtures/basic_app/server/api" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "nuxt.server-api",
+          files: "server/api/**/*.{js,ts}",
+          routes: ["export default defineEventHandler({handler})"],
+          convention: "file-based routing: GET /api/users -> server/api/users.get.ts",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
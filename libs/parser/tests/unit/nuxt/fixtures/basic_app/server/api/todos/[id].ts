@@ -0,0 +1,9 @@
+export default defineEventHandler(async (event) => {
+  assertMethod(event, ['PUT', 'DELETE']);
+  const id = getRouterParam(event, 'id');
+
+  if (isMethod(event, 'DELETE')) {
+    return null;
+  }
+  return { id, ...(await readBody(event)) };
+});
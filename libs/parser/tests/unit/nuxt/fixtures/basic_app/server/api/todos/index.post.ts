@@ -0,0 +1,4 @@
+export default defineEventHandler(async (event) => {
+  const body = await readBody(event);
+  return { id: 1, ...body };
+});
@@ -0,0 +1 @@
+export default defineEventHandler(() => '<urlset />');
@@ -0,0 +1 @@
+export const db = new Map<string, unknown>();
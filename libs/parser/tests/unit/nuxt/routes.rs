@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn derives_methods_from_suffixes_and_method_checks() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/todos".to_string()),
+        (HttpMethod::Post, "/api/todos".to_string()),
+        (HttpMethod::Put, "/api/todos/[id]".to_string()),
+        (HttpMethod::Delete, "/api/todos/[id]".to_string()),
+        (HttpMethod::Get, "/sitemap.xml".to_string()),
+    ]);
+}
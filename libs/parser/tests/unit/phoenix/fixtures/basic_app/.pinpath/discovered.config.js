@@ -0,0 +1,74 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T05:20:01.823070209+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T05:20:01.823070209+00:00",
+    lastModified: "2026-10-17T05:20:01.823070209+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 4218,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/phoenix/fixtures/basic_app",
+      framework: "phoenix",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "mix.exs", value: "phoenix dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/phoenix/fixtures/basic_app/mix.exs" },
+        { type: "file", value: "lib/*_web/router.ex found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/phoenix/fixtures/basic_app/lib/shop_web/router.ex" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "phoenix.router",
+          files: "lib/*_web/router.ex",
+          routes: ["{method} \"{path}\", {Controller}, :{action}", "resources \"{path}\", {Controller}"],
+          convention: "scope \"/api\", MyAppWeb do ... end prefixes paths and controller aliases",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
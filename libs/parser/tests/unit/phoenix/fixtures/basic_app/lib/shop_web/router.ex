@@ -0,0 +1,36 @@
+defmodule ShopWeb.Router do
+  use ShopWeb, :router
+
+  pipeline :browser do
+    plug :accepts, ["html"]
+    plug :fetch_session
+  end
+
+  pipeline :api do
+    plug :accepts, ["json"]
+  end
+
+  pipeline :require_auth do
+    plug ShopWeb.Plugs.RequireAuth
+  end
+
+  scope "/", ShopWeb do
+    pipe_through :browser
+
+    get "/", PageController, :home
+  end
+
+  scope "/api", ShopWeb.API do
+    pipe_through :api
+
+    resources "/products", ProductController, only: [:index, :show]
+
+    scope "/" do
+      pipe_through :require_auth
+
+      resources "/orders", OrderController, except: [:new, :edit] do
+        post "/cancel", OrderController, :cancel
+      end
+    end
+  end
+end
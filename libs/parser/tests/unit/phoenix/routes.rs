@@ -0,0 +1,36 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::{parser_for_language, HttpMethod};
+use std::fs;
+
+#[test]
+fn expands_scopes_and_resources() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/api/orders".to_string()),
+        (HttpMethod::Post, "/api/orders".to_string()),
+        (HttpMethod::Get, "/api/orders/:id".to_string()),
+        (HttpMethod::Patch, "/api/orders/:id".to_string()),
+        (HttpMethod::Put, "/api/orders/:id".to_string()),
+        (HttpMethod::Delete, "/api/orders/:id".to_string()),
+        (HttpMethod::Post, "/api/orders/:order_id/cancel".to_string()),
+        (HttpMethod::Get, "/api/products".to_string()),
+        (HttpMethod::Get, "/api/products/:id".to_string()),
+    ]);
+}
+
+#[test]
+fn records_pipelines_as_middleware() {
+    let content = fs::read_to_string(fixtures::basic_app().join("lib/shop_web/router.ex")).unwrap();
+    let endpoints = parser_for_language("elixir").unwrap().parse(&content).unwrap();
+
+    let home = endpoints.iter().find(|e| e.path == "/").unwrap();
+    assert_eq!(home.handler, "ShopWeb.PageController.home");
+    assert_eq!(home.middleware, vec!["browser".to_string()]);
+
+    let cancel = endpoints.iter().find(|e| e.path == "/api/orders/:order_id/cancel").unwrap();
+    assert_eq!(cancel.handler, "ShopWeb.API.OrderController.cancel");
+    assert_eq!(cancel.middleware, vec!["api".to_string(), "require_auth".to_string()]);
+}
@@ -0,0 +1,68 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:18:14.696995186+00:00
+// Version: 1.0.0
+// Debug Mode: false
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:18:14.696995186+00:00",
+    lastModified: "2026-10-17T04:18:14.696995186+00:00",
+    debugMode: false,
+  },
+
+  // Global configuration
+  debugMode: false,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 4,
+    totalSize: 2207,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/rails/fixtures/basic_app",
+      framework: "rails",
+      confidence: 1,
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "rails.routes",
+          files: "config/routes.rb",
+          routes: ["resources :{name}", "{method} '{path}', to: '{controller}#{action}'"],
+          convention: "resources :users -> 7 RESTful routes on UsersController",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,17 @@
+Rails.application.routes.draw do
+  root "home#index"
+
+  resources :articles do
+    resources :comments, only: [:create, :destroy]
+  end
+
+  namespace :api do
+    namespace :v1 do
+      resources :users, only: %i[index show] do
+        get :me, on: :collection
+      end
+    end
+  end
+
+  get "up" => "rails/health#show"
+end
@@ -0,0 +1,18 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn expands_routes_rb() {
+    let routes = discover(&fixtures::basic_app());
+
+    // 7 article actions + 2 nested comment actions + 3 api actions + root + health check
+    assert_eq!(routes.len(), 14);
+    assert!(routes.contains(&(HttpMethod::Get, "/".to_string())));
+    assert!(routes.contains(&(HttpMethod::Get, "/articles/:id/edit".to_string())));
+    assert!(routes.contains(&(HttpMethod::Post, "/articles/:article_id/comments".to_string())));
+    assert!(routes.contains(&(HttpMethod::Delete, "/articles/:article_id/comments/:id".to_string())));
+    assert!(routes.contains(&(HttpMethod::Get, "/api/v1/users/me".to_string())));
+    assert!(routes.contains(&(HttpMethod::Get, "/api/v1/users/:id".to_string())));
+    assert!(routes.contains(&(HttpMethod::Get, "/up".to_string())));
+}
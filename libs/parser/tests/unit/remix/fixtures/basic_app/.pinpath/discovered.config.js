@@ -0,0 +1,75 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:28:42.946119488+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:28:42.946119488+00:00",
+    lastModified: "2026-10-17T04:28:42.946119488+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 9,
+    totalSize: 4635,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/remix/fixtures/basic_app",
+      framework: "remix",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "@remix-run dependency found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/remix/fixtures/basic_app/package.json" },
+        { type: "config_file", value: "remix.config.js found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/remix/fixtures/basic_app/remix.config.js" },
+        { type: "directory", value: "app/routes directory found", confidence: 0.85, source: "/root/crate/libs/parser/tests/unit/remix/fixtures/basic_app/app/routes" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "remix.flat-routes",
+          files: "app/routes/**/*.{js,jsx,ts,tsx}",
+          routes: ["export async function loader()", "export async function action()"],
+          convention: "flat routes: /api/users/$id -> app/routes/api.users.$id.ts",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1 @@
+export const loader = () => new Response('ok');
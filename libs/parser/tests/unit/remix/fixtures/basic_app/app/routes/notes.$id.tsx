@@ -0,0 +1,7 @@
+import { json, type LoaderFunctionArgs } from '@remix-run/node';
+
+export const loader = async ({ params }: LoaderFunctionArgs) => json({ id: params.id });
+
+export default function Note() {
+  return null;
+}
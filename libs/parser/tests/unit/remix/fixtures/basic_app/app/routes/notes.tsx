@@ -0,0 +1,16 @@
+import { json, redirect, type ActionFunctionArgs } from '@remix-run/node';
+import { Outlet, useLoaderData } from '@remix-run/react';
+
+export async function loader() {
+  return json({ notes: [] });
+}
+
+export async function action({ request }: ActionFunctionArgs) {
+  const form = await request.formData();
+  return redirect(`/notes/${form.get('id')}`);
+}
+
+export default function Notes() {
+  const { notes } = useLoaderData<typeof loader>();
+  return <Outlet context={notes} />;
+}
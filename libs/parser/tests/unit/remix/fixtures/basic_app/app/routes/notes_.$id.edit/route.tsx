@@ -0,0 +1,5 @@
+import { redirect, type ActionFunctionArgs } from '@remix-run/node';
+
+export async function action({ params }: ActionFunctionArgs) {
+  return redirect(`/notes/${params.id}`);
+}
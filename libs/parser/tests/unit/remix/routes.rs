@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn maps_loaders_and_actions_of_flat_routes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/healthz".to_string()),
+        (HttpMethod::Get, "/notes".to_string()),
+        (HttpMethod::Post, "/notes".to_string()),
+        (HttpMethod::Get, "/notes/$id".to_string()),
+        (HttpMethod::Post, "/notes/$id/edit".to_string()),
+    ]);
+}
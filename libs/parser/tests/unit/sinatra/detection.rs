@@ -0,0 +1,19 @@
+use super::fixtures;
+use pinpath_parser::config::ConfigDiscovery;
+
+#[tokio::test]
+async fn detects_sinatra_from_gemfile() {
+    let project_path = fixtures::basic_app();
+    let discovery = ConfigDiscovery::new(false);
+    let config = discovery.discover(&project_path).await.unwrap();
+    
+    assert_eq!(config.frameworks.len(), 1);
+    assert_eq!(config.frameworks[0].framework, "sinatra");
+    assert!(config.frameworks[0].confidence > 0.9);
+    
+    let signal_types: std::collections::HashSet<_> = config.frameworks[0].signals.iter()
+        .map(|s| s.signal_type.as_str())
+        .collect();
+    
+    assert!(signal_types.contains("Gemfile"));
+}
@@ -0,0 +1,67 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:18:14.721056203+00:00
+// Version: 1.0.0
+// Debug Mode: false
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:18:14.721056203+00:00",
+    lastModified: "2026-10-17T04:18:14.721056203+00:00",
+    debugMode: false,
+  },
+
+  // Global configuration
+  debugMode: false,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 3,
+    totalSize: 1720,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/sinatra/fixtures/basic_app",
+      framework: "sinatra",
+      confidence: 1,
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "sinatra.blocks",
+          files: "**/*.rb",
+          routes: ["{method} '{path}' do"],
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,15 @@
+require 'sinatra'
+require 'json'
+
+get '/' do
+  'Hello world!'
+end
+
+get '/todos/:id' do
+  content_type :json
+  { id: params[:id] }.to_json
+end
+
+post '/todos' do
+  status 201
+end
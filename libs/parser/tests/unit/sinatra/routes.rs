@@ -0,0 +1,14 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn reads_sinatra_route_blocks() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Post, "/todos".to_string()),
+        (HttpMethod::Get, "/todos/:id".to_string()),
+    ]);
+}
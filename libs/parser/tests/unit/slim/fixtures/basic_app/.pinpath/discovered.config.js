@@ -0,0 +1,74 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T05:07:32.574613466+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T05:07:32.574613466+00:00",
+    lastModified: "2026-10-17T05:07:32.574613466+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 3,
+    totalSize: 2510,
+    roots: [],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/slim/fixtures/basic_app",
+      framework: "slim",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "composer.json", value: "slim/slim dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/slim/fixtures/basic_app/composer.json" },
+        { type: "code_pattern", value: "AppFactory::create or new \Slim\App or new App( found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/slim/fixtures/basic_app/public/index.php" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "slim.routes",
+          files: "**/*.php",
+          routes: ["$app->{method}('{path}', {handler})", "$app->group('{prefix}', function ($group) { ... })"],
+          convention: "group prefix + route path",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
@@ -0,0 +1,28 @@
+<?php
+
+use App\Action\ArchiveItemsAction;
+use App\Action\CreateItemAction;
+use App\Action\ListItemsAction;
+use App\Middleware\ApiKeyMiddleware;
+use Psr\Http\Message\ResponseInterface as Response;
+use Psr\Http\Message\ServerRequestInterface as Request;
+use Slim\Factory\AppFactory;
+use Slim\Routing\RouteCollectorProxy;
+
+require __DIR__ . '/../vendor/autoload.php';
+
+$app = AppFactory::create();
+
+$app->get('/', function (Request $request, Response $response) {
+    $response->getBody()->write('ok');
+    return $response;
+});
+
+$app->group('/api/items', function (RouteCollectorProxy $group) {
+    $group->get('', ListItemsAction::class);
+    $group->post('', CreateItemAction::class);
+    $group->map(['PUT', 'PATCH'], '/{id}', 'App\Action\UpdateItemAction');
+    $group->get('/archive[/{year:[0-9]{4}}]', ArchiveItemsAction::class);
+})->add(ApiKeyMiddleware::class);
+
+$app->run();
@@ -0,0 +1,37 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+use pinpath_parser::project::parse_files;
+use std::fs;
+
+#[test]
+fn discovers_grouped_routes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/".to_string()),
+        (HttpMethod::Get, "/api/items".to_string()),
+        (HttpMethod::Post, "/api/items".to_string()),
+        (HttpMethod::Get, "/api/items/archive[/{year:[0-9]{4}}]".to_string()),
+        (HttpMethod::Put, "/api/items/{id}".to_string()),
+        (HttpMethod::Patch, "/api/items/{id}".to_string()),
+    ]);
+}
+
+#[test]
+fn optional_segments_are_optional_parameters() {
+    let path = fixtures::basic_app().join("public/index.php");
+    let content = fs::read_to_string(&path).unwrap();
+
+    let endpoints = parse_files(&[(path, content)]);
+    let archive = endpoints.iter()
+        .map(|e| &e.endpoint)
+        .find(|e| e.handler == "ArchiveItemsAction")
+        .unwrap();
+
+    assert_eq!(archive.template, "/api/items/archive/{year}");
+    let params: Vec<_> = archive.parameters.iter()
+        .map(|p| (p.name.as_str(), p.param_type.as_deref(), p.required))
+        .collect();
+    assert_eq!(params, vec![("year", Some("int"), false)]);
+}
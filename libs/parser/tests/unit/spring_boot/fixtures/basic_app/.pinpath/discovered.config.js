@@ -0,0 +1,74 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:15:10.823506899+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:15:10.823506899+00:00",
+    lastModified: "2026-10-17T04:15:10.823506899+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 5,
+    totalSize: 4644,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/spring_boot/fixtures/basic_app",
+      framework: "spring-boot",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "pom.xml", value: "spring-boot dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/spring_boot/fixtures/basic_app/pom.xml" },
+        { type: "code_pattern", value: "@SpringBootApplication found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/spring_boot/fixtures/basic_app/src/main/java/com/example/store/StoreApplication.java" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "spring.controllers",
+          files: "src/main/java/**/*.java",
+          routes: ["@{Method}Mapping(\"{path}\")", "@RequestMapping(value = \"{path}\", method = RequestMethod.{METHOD})"],
+          convention: "class-level @RequestMapping prefix + method mapping",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
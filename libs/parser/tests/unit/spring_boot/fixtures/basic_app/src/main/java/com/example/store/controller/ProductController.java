@@ -0,0 +1,23 @@
+package com.example.store.controller;
+
+import org.springframework.web.bind.annotation.*;
+
+@RestController
+@RequestMapping("/api/products")
+public class ProductController {
+
+    @GetMapping
+    public List<Product> list(@RequestParam(defaultValue = "20") int limit) {
+        return List.of();
+    }
+
+    @GetMapping("/{sku}")
+    public Product get(@PathVariable String sku) {
+        return null;
+    }
+
+    @PostMapping
+    public Product create(@RequestBody ProductRequest request) {
+        return null;
+    }
+}
@@ -0,0 +1,11 @@
+package com.example
+
+import org.springframework.boot.autoconfigure.SpringBootApplication
+import org.springframework.boot.runApplication
+
+@SpringBootApplication
+class OrdersApplication
+
+fun main(args: Array<String>) {
+    runApplication<OrdersApplication>(*args)
+}
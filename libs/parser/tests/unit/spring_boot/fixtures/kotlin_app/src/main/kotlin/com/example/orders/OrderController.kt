@@ -0,0 +1,24 @@
+package com.example.orders
+
+import org.springframework.web.bind.annotation.*
+
+data class OrderRequest(val sku: String, val quantity: Int)
+
+@RestController
+@RequestMapping("/api/orders")
+class OrderController(private val service: OrderService) {
+
+    @GetMapping
+    fun list(@RequestParam(defaultValue = "20") limit: Int): List<Order> = service.list(limit)
+
+    @GetMapping("/{id}")
+    fun get(@PathVariable id: Long): Order = service.get(id)
+
+    @PostMapping
+    fun create(@RequestBody request: OrderRequest): Order {
+        return service.create(request)
+    }
+
+    @RequestMapping(value = ["/{id}/cancel"], method = [RequestMethod.POST])
+    fun cancel(@PathVariable("id") orderId: Long, @RequestHeader("X-Reason") reason: String?) = service.cancel(orderId, reason)
+}
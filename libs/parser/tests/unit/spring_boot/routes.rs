@@ -0,0 +1,54 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::{parser_for_language, HttpMethod, ParameterLocation};
+use std::fs;
+
+#[test]
+fn combines_class_and_method_mappings() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/products".to_string()),
+        (HttpMethod::Post, "/api/products".to_string()),
+        (HttpMethod::Get, "/api/products/{sku}".to_string()),
+    ]);
+}
+
+#[test]
+fn extracts_annotated_parameters() {
+    let path = fixtures::basic_app().join("src/main/java/com/example/store/controller/ProductController.java");
+    let content = fs::read_to_string(path).unwrap();
+    let endpoints = parser_for_language("java").unwrap().parse(&content).unwrap();
+
+    let limit = &endpoints[0].parameters[0];
+    assert_eq!((limit.name.as_str(), &limit.location), ("limit", &ParameterLocation::Query));
+    assert_eq!(limit.default.as_deref(), Some("20"));
+
+    let sku = &endpoints[1].parameters[0];
+    assert_eq!((sku.name.as_str(), &sku.location, sku.required), ("sku", &ParameterLocation::Path, true));
+
+    let body = &endpoints[2].parameters[0];
+    assert_eq!(body.location, ParameterLocation::Body);
+    assert_eq!(body.param_type.as_deref(), Some("ProductRequest"));
+}
+
+#[test]
+fn reads_kotlin_controllers() {
+    let routes = discover(&fixtures::kotlin_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/orders".to_string()),
+        (HttpMethod::Post, "/api/orders".to_string()),
+        (HttpMethod::Get, "/api/orders/{id}".to_string()),
+        (HttpMethod::Post, "/api/orders/{id}/cancel".to_string()),
+    ]);
+
+    let path = fixtures::kotlin_app().join("src/main/kotlin/com/example/orders/OrderController.kt");
+    let content = fs::read_to_string(path).unwrap();
+    let endpoints = parser_for_language("kotlin").unwrap().parse(&content).unwrap();
+
+    let cancel = &endpoints[3];
+    assert_eq!(cancel.handler, "OrderController.cancel");
+    assert_eq!((cancel.parameters[0].name.as_str(), &cancel.parameters[0].location), ("id", &ParameterLocation::Path));
+    assert!(!cancel.parameters[1].required);
+}
@@ -0,0 +1,75 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T04:28:43.287459706+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T04:28:43.287459706+00:00",
+    lastModified: "2026-10-17T04:28:43.287459706+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 8,
+    totalSize: 4284,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/sveltekit/fixtures/basic_app",
+      framework: "sveltekit",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "package.json", value: "@sveltejs/kit dependency found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/sveltekit/fixtures/basic_app/package.json" },
+        { type: "config_file", value: "svelte.config.js found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/sveltekit/fixtures/basic_app/svelte.config.js" },
+        { type: "directory", value: "src/routes directory found", confidence: 0.85, source: "/root/crate/libs/parser/tests/unit/sveltekit/fixtures/basic_app/src/routes" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "sveltekit.server-routes",
+          files: "src/routes/**/+server.{js,ts}",
+          routes: ["export async function {METHOD}()"],
+          convention: "file-based routing: /api/posts -> src/routes/api/posts/+server.ts",
+          confidence: 0.95,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
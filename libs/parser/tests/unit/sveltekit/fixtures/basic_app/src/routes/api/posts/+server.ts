@@ -0,0 +1,11 @@
+import { json } from '@sveltejs/kit';
+import type { RequestHandler } from './$types';
+
+export const GET: RequestHandler = async () => {
+  return json([]);
+};
+
+export const POST: RequestHandler = async ({ request }) => {
+  const post = await request.json();
+  return json(post, { status: 201 });
+};
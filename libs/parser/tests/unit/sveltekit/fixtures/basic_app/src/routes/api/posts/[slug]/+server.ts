@@ -0,0 +1,10 @@
+import { error, json } from '@sveltejs/kit';
+
+export async function GET({ params }) {
+  if (!params.slug) error(404);
+  return json({ slug: params.slug });
+}
+
+export async function DELETE() {
+  return new Response(null, { status: 204 });
+}
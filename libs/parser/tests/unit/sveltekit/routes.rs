@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn derives_routes_from_server_files() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/posts".to_string()),
+        (HttpMethod::Post, "/api/posts".to_string()),
+        (HttpMethod::Get, "/api/posts/[slug]".to_string()),
+        (HttpMethod::Delete, "/api/posts/[slug]".to_string()),
+        (HttpMethod::Get, "/health".to_string()),
+    ]);
+}
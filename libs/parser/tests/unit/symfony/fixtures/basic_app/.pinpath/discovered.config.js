@@ -0,0 +1,80 @@
+// Auto-generated PinPath configuration
+// Generated: 2026-10-17T05:07:32.739909574+00:00
+// Version: 1.0.0
+// Debug Mode: true
+
+export default {
+  // Metadata
+  _meta: {
+    version: "1.0.0",
+    generated: "2026-10-17T05:07:32.739909574+00:00",
+    lastModified: "2026-10-17T05:07:32.739909574+00:00",
+    debugMode: true,
+  },
+
+  // Global configuration
+  debugMode: true,
+
+  // Detected project structure
+  structure: {
+    type: "single",
+    fileCount: 7,
+    totalSize: 3792,
+    roots: ["./src"],
+  },
+
+  // Detected frameworks
+  frameworks: [
+    {
+      path: "/root/crate/libs/parser/tests/unit/symfony/fixtures/basic_app",
+      framework: "symfony",
+      confidence: 1,
+
+      // Detection signals (debug mode)
+      _signals: [
+        { type: "composer.json", value: "symfony/framework-bundle dependency found", confidence: 0.8, source: "/root/crate/libs/parser/tests/unit/symfony/fixtures/basic_app/composer.json" },
+        { type: "code_pattern", value: "MicroKernelTrait or Kernel( found", confidence: 0.9, source: "/root/crate/libs/parser/tests/unit/symfony/fixtures/basic_app/src/Kernel.php" },
+      ],
+
+      // Patterns for route detection
+      patterns: [
+        {
+          name: "symfony.attributes",
+          files: "src/Controller/**/*.php",
+          routes: ["#[Route('{path}', methods: ['{METHOD}'])]", "@Route(\"{path}\", methods={\"{METHOD}\"})"],
+          convention: "class-level #[Route] prefix + method #[Route] path",
+          confidence: 0.95,
+        },
+        {
+          name: "symfony.yaml",
+          files: "config/routes/**/*.yaml",
+          routes: ["{name}: { path: {path}, controller: {Controller}::{method} }"],
+          confidence: 0.9,
+        },
+      ],
+    }
+  ],
+
+  // Performance settings (auto-calculated)
+  performance: {
+    threads: "auto",
+    cacheStrategy: "minimal",
+    maxFileSize: "1MB",
+    estimatedScanTime: "<10 seconds",
+  },
+
+  // User overrides (preserved between regenerations)
+  overrides: {
+    // Add your custom patterns here
+    // These will be preserved when config is regenerated
+    
+    // Example:
+    // customPatterns: [
+    //   {
+    //     name: 'my-custom-api',
+    //     files: 'lib/handlers/**/*.js',
+    //     routes: ['defineHandler("{method}", "{path}", {handler})']
+    //   }
+    // ]
+  },
+};
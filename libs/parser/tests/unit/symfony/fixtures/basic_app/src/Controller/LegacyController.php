@@ -0,0 +1,20 @@
+<?php
+
+namespace App\Controller;
+
+use Sensio\Bundle\FrameworkExtraBundle\Configuration\Route;
+use Symfony\Component\HttpFoundation\Response;
+
+/**
+ * @Route("/legacy")
+ */
+class LegacyController
+{
+    /**
+     * @Route("/reports/{year}", methods={"GET"})
+     */
+    public function reports($year)
+    {
+        return new Response();
+    }
+}
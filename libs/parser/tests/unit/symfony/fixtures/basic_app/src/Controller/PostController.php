@@ -0,0 +1,34 @@
+<?php
+
+namespace App\Controller;
+
+use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+use Symfony\Component\HttpFoundation\JsonResponse;
+use Symfony\Component\Routing\Attribute\Route;
+
+#[Route('/api/posts', name: 'api_posts_')]
+class PostController extends AbstractController
+{
+    #[Route('', name: 'index', methods: ['GET'])]
+    public function index(): JsonResponse
+    {
+        return $this->json([]);
+    }
+
+    #[Route('', name: 'create', methods: ['POST'])]
+    public function create(): JsonResponse
+    {
+        return $this->json([], 201);
+    }
+
+    #[Route(
+        '/{id}',
+        name: 'show',
+        methods: ['GET'],
+        requirements: ['id' => '\d+']
+    )]
+    public function show(int $id): JsonResponse
+    {
+        return $this->json(['id' => $id]);
+    }
+}
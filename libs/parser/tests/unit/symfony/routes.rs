@@ -0,0 +1,16 @@
+use super::fixtures;
+use crate::unit::discover_routes as discover;
+use pinpath_parser::HttpMethod;
+
+#[test]
+fn discovers_attribute_annotation_and_yaml_routes() {
+    let routes = discover(&fixtures::basic_app());
+
+    assert_eq!(routes, vec![
+        (HttpMethod::Get, "/api/posts".to_string()),
+        (HttpMethod::Post, "/api/posts".to_string()),
+        (HttpMethod::Get, "/api/posts/{id}".to_string()),
+        (HttpMethod::Get, "/health".to_string()),
+        (HttpMethod::Get, "/legacy/reports/{year}".to_string()),
+    ]);
+}